@@ -0,0 +1,132 @@
+use crate::Bible;
+use serde::Serialize;
+
+// The binary format the site downloads: a BIBL magic, a format version byte and
+// the Bible encoded with bincode. These types have to stay in step with the
+// site's `Bible`, `Book`, `Chapter` and `Verse`, field order and integer sizes
+// included, since bincode writes neither field names nor types.
+const MAGIC: &[u8; 4] = b"BIBL";
+const FORMAT_VERSION: u8 = 1;
+
+#[derive(Serialize)]
+struct BinaryBible<'a> {
+    books: Vec<BinaryBook<'a>>,
+}
+
+#[derive(Serialize)]
+struct BinaryBook<'a> {
+    name: &'a str,
+    chapters: Vec<BinaryChapter<'a>>,
+}
+
+#[derive(Serialize)]
+struct BinaryChapter<'a> {
+    chapter: u32,
+    name: &'a str,
+    verses: Vec<BinaryVerse<'a>>,
+}
+
+#[derive(Serialize)]
+struct BinaryVerse<'a> {
+    verse: u32,
+    chapter: u32,
+    name: &'a str,
+    text: &'a str,
+}
+
+fn number(value: usize) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| format!("{} is too large for the binary format", value))
+}
+
+/// Encode a Bible in the binary format served at `/bibles/{short_name}.bibl`
+pub fn encode_bible(bible: &Bible) -> Result<Vec<u8>, String> {
+    let mut books = Vec::with_capacity(bible.books.len());
+    for book in &bible.books {
+        let mut chapters = Vec::with_capacity(book.chapters.len());
+        for chapter in &book.chapters {
+            let mut verses = Vec::with_capacity(chapter.verses.len());
+            for verse in &chapter.verses {
+                verses.push(BinaryVerse {
+                    verse: number(verse.verse)?,
+                    chapter: number(verse.chapter)?,
+                    name: &verse.name,
+                    text: &verse.text,
+                });
+            }
+            chapters.push(BinaryChapter {
+                chapter: number(chapter.chapter)?,
+                name: &chapter.name,
+                verses,
+            });
+        }
+        books.push(BinaryBook {
+            name: &book.name,
+            chapters,
+        });
+    }
+
+    let payload = bincode::serialize(&BinaryBible { books })
+        .map_err(|e| format!("Failed to encode Bible: {}", e))?;
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 1 + payload.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(FORMAT_VERSION);
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    // The site's types, as far as bincode can tell
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct SiteVerse {
+        verse: u32,
+        chapter: u32,
+        name: String,
+        text: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct SiteChapter {
+        chapter: u32,
+        name: String,
+        verses: Vec<SiteVerse>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct SiteBook {
+        name: String,
+        chapters: Vec<SiteChapter>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct SiteBible {
+        books: Vec<SiteBook>,
+    }
+
+    #[test]
+    fn test_encode_bible() {
+        let bible: Bible = serde_json::from_str(include_str!("test_repair_fixed.json")).unwrap();
+        let bytes = encode_bible(&bible).unwrap();
+
+        assert_eq!(&bytes[..4], b"BIBL");
+        assert_eq!(bytes[4], FORMAT_VERSION);
+        let decoded: SiteBible = bincode::deserialize(&bytes[5..]).unwrap();
+        assert_eq!(decoded.books.len(), bible.books.len());
+        let book = &decoded.books[0];
+        assert_eq!(book.name, bible.books[0].name);
+        assert_eq!(book.chapters[0].chapter, 1);
+        assert_eq!(book.chapters[0].name, bible.books[0].chapters[0].name);
+        assert_eq!(
+            book.chapters[0].verses[0],
+            SiteVerse {
+                verse: 1,
+                chapter: 1,
+                name: bible.books[0].chapters[0].verses[0].name.clone(),
+                text: bible.books[0].chapters[0].verses[0].text.clone(),
+            }
+        );
+    }
+}
@@ -0,0 +1,159 @@
+/// Windows-1252 characters for bytes 0x80 to 0x9F, with the bytes it leaves
+/// undefined passed through as in Latin-1
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Mac OS Roman characters for bytes 0x80 to 0xFF
+const MAC_ROMAN_HIGH: &str = "ÄÅÇÉÑÖÜáàâäãåçéèêëíìîïñóòôöõúùûü†°¢£§•¶ß®©™´¨≠ÆØ∞±≤≥¥µ∂∑∏π∫ªºΩæø\
+    ¿¡¬√ƒ≈∆«»…\u{A0}ÀÃÕŒœ–—“”‘’÷◊ÿŸ⁄€‹›ﬁﬂ‡·‚„‰ÂÊÁËÈÍÎÏÌÓÔ\u{F8FF}ÒÚÛÙıˆ˜¯˘˙˚¸˝˛ˇ";
+
+fn windows_1252_byte(c: char) -> Option<u8> {
+    match c as u32 {
+        // Latin-1 and Windows-1252 agree from 0xA0 up
+        0x80..=0xFF => Some(c as u32 as u8),
+        _ => WINDOWS_1252_HIGH
+            .iter()
+            .position(|&high| high == c)
+            .map(|i| 0x80 + i as u8),
+    }
+}
+
+fn mac_roman_byte(c: char) -> Option<u8> {
+    MAC_ROMAN_HIGH
+        .chars()
+        .position(|high| high == c)
+        .map(|i| 0x80 + i as u8)
+}
+
+type ToByte = fn(char) -> Option<u8>;
+
+// The encodings UTF-8 text is most often mistaken for
+const LEGACY_ENCODINGS: [(&str, ToByte); 2] = [
+    ("Windows-1252", windows_1252_byte),
+    ("Mac OS Roman", mac_roman_byte),
+];
+
+/// A character problem in a verse text
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodingProblem {
+    /// UTF-8 bytes that were decoded with a legacy encoding, e.g. `√´` for `ë`
+    Mojibake {
+        sequence: String,
+        intended: char,
+        encoding: &'static str,
+    },
+    /// A control character, byte order mark or replacement character
+    InvalidCharacter(char),
+}
+
+pub fn describe_character(c: char) -> String {
+    match c {
+        '\u{FFFD}' => "the replacement character U+FFFD".to_string(),
+        '\u{FEFF}' => "a byte order mark (U+FEFF)".to_string(),
+        _ => format!("the control character U+{:04X}", c as u32),
+    }
+}
+
+// Only letters and punctuation that Bible texts actually use count as the intended
+// character, so legitimate pairs like "’é" are not mistaken for mojibake
+fn is_plausible(c: char) -> bool {
+    matches!(c, '\u{A0}'..='\u{17F}' | '\u{2013}'..='\u{201E}' | '…')
+}
+
+// Length in characters and the intended character when `chars` starts with mojibake
+fn decode_mojibake(chars: &[char], to_byte: ToByte) -> Option<(usize, char)> {
+    let len = match to_byte(*chars.first()?)? {
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => return None,
+    };
+    let bytes = chars
+        .get(..len)?
+        .iter()
+        .map(|&c| to_byte(c))
+        .collect::<Option<Vec<u8>>>()?;
+    let intended = std::str::from_utf8(&bytes).ok()?.chars().next()?;
+    is_plausible(intended).then_some((len, intended))
+}
+
+/// The first encoding problem in `text`, if any
+pub fn find_encoding_problem(text: &str) -> Option<EncodingProblem> {
+    let chars: Vec<char> = text.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        // Mojibake always starts with a non-ASCII character
+        if c.is_ascii() {
+            if c.is_control() {
+                return Some(EncodingProblem::InvalidCharacter(c));
+            }
+            continue;
+        }
+        for (encoding, to_byte) in LEGACY_ENCODINGS {
+            if let Some((len, intended)) = decode_mojibake(&chars[i..], to_byte) {
+                return Some(EncodingProblem::Mojibake {
+                    sequence: chars[i..i + len].iter().collect(),
+                    intended,
+                    encoding,
+                });
+            }
+        }
+        if c.is_control() || c == '\u{FFFD}' || c == '\u{FEFF}' {
+            return Some(EncodingProblem::InvalidCharacter(c));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mac_roman_mojibake() {
+        assert_eq!(
+            find_encoding_problem("En Samu√´l sprak"),
+            Some(EncodingProblem::Mojibake {
+                sequence: "√´".to_string(),
+                intended: 'ë',
+                encoding: "Mac OS Roman",
+            })
+        );
+    }
+
+    #[test]
+    fn test_windows_1252_mojibake() {
+        assert_eq!(
+            find_encoding_problem("En SamuÃ«l sprak"),
+            Some(EncodingProblem::Mojibake {
+                sequence: "Ã«".to_string(),
+                intended: 'ë',
+                encoding: "Windows-1252",
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert_eq!(
+            find_encoding_problem("In den beginne\u{7}"),
+            Some(EncodingProblem::InvalidCharacter('\u{7}'))
+        );
+        assert_eq!(
+            find_encoding_problem("In den \u{FFFD}beginne"),
+            Some(EncodingProblem::InvalidCharacter('\u{FFFD}'))
+        );
+    }
+
+    #[test]
+    fn test_legitimate_text() {
+        assert_eq!(find_encoding_problem("Zo zegt de HEERE: ’t is één."), None);
+        assert_eq!(find_encoding_problem("Hij zeide: ’één dag’"), None);
+        assert_eq!(
+            find_encoding_problem("Israël en Efraïm, “gelijk” — café"),
+            None
+        );
+    }
+}
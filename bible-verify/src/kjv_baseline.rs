@@ -0,0 +1,383 @@
+// Generated from kjv.json: the verse and word count of every chapter.
+
+/// Verse and word counts of each KJV chapter, per book in canonical order
+#[rustfmt::skip]
+pub const KJV_CHAPTERS: [&[(u16, u16)]; 66] = [
+    // Genesis
+    &[
+        (31, 797), (25, 632), (24, 695), (26, 632), (32, 504), (22, 579), (24, 584), (22, 586),
+        (29, 658), (32, 495), (32, 606), (20, 536), (18, 457), (24, 606), (21, 471), (16, 412),
+        (27, 679), (33, 867), (38, 1108), (18, 498), (34, 774), (24, 629), (20, 539), (67, 1816),
+        (34, 706), (35, 889), (46, 1262), (22, 621), (35, 830), (43, 1022), (55, 1417), (32, 794),
+        (20, 508), (31, 790), (29, 664), (43, 845), (36, 942), (30, 819), (23, 666), (23, 580),
+        (57, 1404), (38, 977), (34, 938), (34, 874), (28, 731), (34, 766), (31, 965), (22, 639),
+        (33, 766), (26, 687),
+    ],
+    // Exodus
+    &[
+        (22, 457), (25, 657), (22, 791), (31, 891), (23, 594), (30, 753), (25, 692), (32, 936),
+        (35, 982), (29, 899), (10, 324), (51, 1493), (22, 675), (31, 931), (27, 713), (36, 1062),
+        (16, 465), (27, 760), (25, 702), (26, 561), (36, 893), (31, 790), (33, 827), (18, 492),
+        (40, 926), (37, 937), (21, 558), (43, 1235), (46, 1341), (38, 970), (18, 438), (35, 1093),
+        (23, 710), (35, 1004), (35, 820), (38, 892), (29, 738), (31, 832), (43, 1030), (38, 821),
+    ],
+    // Leviticus
+    &[
+        (17, 525), (16, 486), (17, 511), (35, 1157), (19, 723), (30, 892), (38, 1056), (36, 988),
+        (24, 624), (20, 628), (47, 1115), (8, 262), (59, 1857), (57, 1713), (33, 919), (34, 1157),
+        (16, 553), (30, 667), (37, 898), (27, 836), (24, 587), (33, 879), (44, 1222), (23, 551),
+        (55, 1532), (46, 1247), (34, 956),
+    ],
+    // Numbers
+    &[
+        (54, 1333), (34, 828), (51, 1291), (49, 1416), (31, 898), (27, 742), (89, 1939), (26, 687),
+        (23, 722), (36, 891), (35, 1056), (16, 384), (33, 741), (45, 1181), (41, 1068), (50, 1341),
+        (13, 335), (32, 1112), (22, 683), (29, 792), (35, 931), (41, 1201), (30, 743), (25, 659),
+        (18, 445), (65, 1446), (23, 610), (31, 779), (40, 954), (16, 503), (54, 1208), (42, 1009),
+        (56, 928), (29, 630), (34, 965), (13, 445),
+    ],
+    // Deuteronomy
+    &[
+        (46, 1262), (37, 1021), (29, 815), (49, 1503), (33, 921), (25, 643), (26, 843), (20, 566),
+        (29, 963), (22, 604), (32, 950), (32, 1055), (18, 627), (29, 708), (23, 707), (22, 710),
+        (20, 707), (22, 611), (21, 608), (20, 646), (23, 731), (30, 894), (25, 685), (22, 681),
+        (19, 562), (19, 667), (26, 599), (68, 2075), (29, 851), (20, 665), (30, 1041), (52, 1306),
+        (29, 805), (12, 320),
+    ],
+    // Joshua
+    &[
+        (18, 573), (24, 768), (17, 566), (24, 707), (15, 550), (27, 904), (26, 927), (35, 1221),
+        (27, 800), (43, 1393), (23, 717), (24, 486), (33, 825), (15, 485), (63, 1032), (10, 253),
+        (18, 660), (28, 770), (51, 943), (9, 308), (45, 1026), (34, 1321), (16, 574), (33, 1044),
+    ],
+    // Judges
+    &[
+        (36, 962), (23, 692), (31, 842), (24, 761), (31, 755), (40, 1295), (25, 952), (35, 987),
+        (57, 1667), (18, 473), (40, 1235), (15, 399), (25, 765), (20, 706), (20, 648), (31, 1110),
+        (13, 385), (31, 1035), (30, 1102), (48, 1455), (25, 740),
+    ],
+    // Ruth
+    &[
+        (22, 649), (23, 763), (18, 541), (22, 621),
+    ],
+    // I Samuel
+    &[
+        (28, 782), (36, 1104), (21, 549), (22, 733), (12, 439), (21, 752), (17, 510), (22, 541),
+        (27, 990), (27, 863), (15, 503), (25, 771), (23, 697), (52, 1606), (35, 981), (23, 680),
+        (58, 1719), (30, 845), (24, 696), (42, 1289), (15, 499), (23, 761), (29, 831), (22, 661),
+        (44, 1452), (25, 849), (12, 385), (25, 835), (11, 421), (31, 956), (13, 348),
+    ],
+    // II Samuel
+    &[
+        (27, 712), (32, 911), (39, 1143), (12, 434), (25, 624), (23, 713), (29, 858), (18, 447),
+        (13, 408), (19, 608), (27, 814), (31, 992), (39, 1149), (33, 1117), (37, 1118), (23, 738),
+        (29, 935), (33, 1096), (43, 1473), (26, 848), (22, 778), (51, 951), (39, 878), (25, 855),
+    ],
+    // I Kings
+    &[
+        (53, 1500), (46, 1510), (28, 850), (34, 706), (18, 527), (38, 1018), (51, 1482), (66, 2139),
+        (28, 826), (29, 839), (43, 1253), (33, 1038), (34, 1138), (31, 987), (34, 951), (34, 1035),
+        (24, 680), (46, 1397), (21, 732), (43, 1480), (29, 908), (53, 1517),
+    ],
+    // II Kings
+    &[
+        (18, 669), (25, 848), (27, 848), (44, 1369), (27, 975), (33, 1019), (20, 824), (29, 953),
+        (37, 1205), (36, 1203), (21, 738), (21, 688), (25, 775), (29, 881), (38, 1092), (20, 656),
+        (41, 1245), (37, 1220), (37, 1147), (21, 660), (26, 747), (20, 712), (37, 1477), (20, 584),
+        (30, 982),
+    ],
+    // I Chronicles
+    &[
+        (54, 683), (55, 846), (24, 347), (43, 852), (26, 664), (81, 1341), (40, 831), (40, 491),
+        (44, 960), (14, 364), (47, 944), (40, 987), (14, 386), (17, 357), (29, 714), (43, 850),
+        (27, 779), (17, 399), (19, 630), (8, 289), (30, 914), (19, 605), (32, 681), (31, 541),
+        (31, 543), (32, 710), (34, 805), (21, 864), (30, 988),
+    ],
+    // II Chronicles
+    &[
+        (17, 530), (18, 645), (17, 481), (22, 595), (14, 497), (42, 1402), (22, 748), (18, 536),
+        (31, 882), (19, 560), (23, 486), (16, 481), (22, 642), (15, 448), (19, 518), (14, 479),
+        (19, 459), (34, 1050), (11, 352), (37, 1148), (20, 623), (12, 444), (21, 788), (27, 933),
+        (28, 970), (23, 721), (9, 244), (27, 896), (36, 1157), (27, 869), (21, 757), (33, 1084),
+        (25, 780), (33, 1223), (27, 900), (23, 741),
+    ],
+    // Ezra
+    &[
+        (11, 371), (70, 998), (13, 547), (24, 769), (17, 583), (22, 778), (28, 863), (36, 990),
+        (15, 617), (44, 924),
+    ],
+    // Nehemiah
+    &[
+        (11, 390), (20, 718), (32, 896), (23, 716), (19, 636), (19, 602), (73, 1176), (18, 686),
+        (38, 1328), (39, 652), (36, 788), (47, 930), (31, 962),
+    ],
+    // Esther
+    &[
+        (22, 716), (23, 806), (15, 552), (17, 517), (14, 494), (14, 476), (10, 345), (17, 650),
+        (32, 984), (3, 93),
+    ],
+    // Job
+    &[
+        (22, 641), (13, 387), (26, 434), (21, 336), (27, 446), (30, 482), (21, 394), (22, 340),
+        (35, 544), (22, 402), (20, 337), (25, 399), (28, 413), (22, 410), (35, 549), (22, 375),
+        (16, 262), (21, 342), (29, 475), (29, 521), (34, 522), (30, 482), (17, 280), (25, 481),
+        (6, 90), (14, 206), (23, 373), (28, 455), (25, 404), (31, 514), (40, 691), (22, 375),
+        (33, 525), (37, 615), (16, 254), (33, 495), (24, 402), (41, 659), (30, 477), (24, 361),
+        (34, 507), (17, 441),
+    ],
+    // Psalms
+    &[
+        (6, 130), (12, 206), (8, 139), (8, 165), (12, 245), (10, 159), (17, 327), (9, 166),
+        (20, 367), (18, 350), (7, 128), (8, 147), (6, 107), (7, 149), (5, 99), (11, 206), (15, 311),
+        (50, 918), (14, 272), (9, 144), (13, 246), (31, 580), (6, 118), (10, 178), (22, 342),
+        (12, 183), (14, 340), (9, 201), (11, 179), (12, 231), (24, 498), (11, 241), (22, 350),
+        (22, 347), (28, 564), (12, 223), (40, 702), (22, 361), (13, 270), (17, 400), (13, 240),
+        (11, 280), (5, 130), (26, 451), (17, 327), (11, 204), (9, 143), (14, 229), (20, 337),
+        (23, 400), (19, 322), (9, 165), (6, 152), (7, 110), (23, 435), (13, 225), (11, 230),
+        (11, 218), (17, 339), (12, 211), (8, 133), (12, 228), (11, 194), (10, 184), (13, 267),
+        (20, 327), (7, 111), (35, 698), (36, 670), (5, 102), (24, 471), (20, 369), (28, 423),
+        (23, 413), (10, 189), (12, 193), (20, 330), (72, 1225), (13, 277), (19, 334), (16, 288),
+        (8, 116), (18, 273), (12, 226), (13, 207), (17, 312), (7, 113), (18, 296), (52, 879),
+        (17, 320), (16, 288), (15, 258), (5, 92), (23, 356), (11, 199), (13, 226), (12, 189),
+        (9, 170), (9, 157), (5, 86), (8, 173), (28, 455), (22, 342), (35, 598), (45, 632),
+        (48, 762), (43, 685), (13, 206), (31, 534), (7, 142), (10, 177), (10, 172), (9, 142),
+        (8, 106), (18, 274), (19, 287), (2, 33), (29, 465), (176, 2423), (7, 88), (8, 110),
+        (9, 124), (4, 94), (8, 121), (5, 108), (6, 94), (5, 110), (6, 101), (8, 119), (8, 116),
+        (3, 60), (18, 276), (3, 69), (3, 44), (21, 329), (26, 353), (9, 165), (8, 172), (24, 410),
+        (13, 239), (10, 203), (7, 151), (12, 252), (15, 302), (21, 331), (10, 178), (20, 299),
+        (14, 202), (9, 143), (6, 85),
+    ],
+    // Proverbs
+    &[
+        (33, 519), (22, 309), (35, 541), (27, 418), (23, 354), (35, 546), (27, 411), (36, 562),
+        (18, 288), (32, 527), (31, 524), (28, 476), (25, 400), (35, 572), (33, 544), (33, 543),
+        (28, 476), (24, 381), (29, 506), (30, 498), (31, 501), (29, 495), (35, 566), (34, 580),
+        (28, 522), (28, 459), (27, 460), (28, 528), (27, 425), (33, 640), (31, 467),
+    ],
+    // Ecclesiastes
+    &[
+        (18, 380), (26, 734), (22, 517), (16, 380), (20, 533), (12, 301), (29, 617), (17, 515),
+        (18, 555), (20, 402), (10, 273), (14, 372),
+    ],
+    // Song of Solomon
+    &[
+        (17, 326), (17, 351), (11, 287), (16, 363), (16, 397), (13, 282), (13, 292), (14, 360),
+    ],
+    // Isaiah
+    &[
+        (31, 758), (22, 567), (26, 587), (6, 209), (30, 863), (13, 366), (25, 693), (22, 558),
+        (21, 599), (34, 928), (16, 519), (6, 134), (22, 576), (32, 856), (9, 266), (14, 415),
+        (14, 428), (7, 255), (25, 730), (6, 169), (17, 417), (25, 676), (18, 469), (23, 592),
+        (12, 381), (21, 550), (13, 405), (29, 814), (24, 742), (33, 1059), (9, 322), (20, 436),
+        (24, 585), (17, 522), (10, 277), (22, 690), (38, 1131), (22, 592), (8, 268), (31, 796),
+        (29, 804), (25, 676), (28, 711), (28, 886), (25, 774), (13, 342), (15, 471), (22, 627),
+        (26, 867), (11, 365), (23, 771), (15, 405), (12, 387), (17, 493), (13, 399), (12, 361),
+        (21, 569), (14, 521), (21, 590), (22, 665), (11, 380), (12, 362), (19, 538), (12, 308),
+        (25, 753), (24, 811),
+    ],
+    // Jeremiah
+    &[
+        (19, 528), (37, 1019), (25, 802), (31, 861), (31, 874), (30, 827), (34, 970), (22, 706),
+        (26, 772), (25, 674), (23, 749), (17, 544), (27, 739), (22, 688), (21, 702), (21, 699),
+        (27, 829), (23, 659), (15, 541), (18, 594), (14, 498), (30, 837), (40, 1186), (10, 361),
+        (38, 1138), (24, 800), (22, 762), (17, 540), (32, 1005), (24, 717), (40, 1294), (44, 1432),
+        (26, 842), (22, 824), (19, 657), (32, 1110), (21, 611), (28, 984), (18, 545), (16, 681),
+        (18, 664), (22, 774), (13, 460), (30, 1228), (5, 152), (28, 821), (7, 215), (47, 1172),
+        (39, 1180), (46, 1460), (64, 1853), (34, 1074),
+    ],
+    // Lamentations
+    &[
+        (22, 758), (22, 883), (66, 876), (22, 598), (22, 296),
+    ],
+    // Ezekiel
+    &[
+        (28, 870), (10, 273), (27, 809), (17, 519), (17, 628), (14, 470), (27, 768), (18, 655),
+        (11, 400), (22, 659), (25, 721), (28, 799), (23, 738), (23, 746), (8, 205), (63, 1820),
+        (24, 773), (32, 919), (14, 356), (49, 1601), (32, 945), (31, 840), (49, 1322), (27, 774),
+        (17, 507), (21, 727), (36, 907), (26, 809), (21, 714), (26, 760), (18, 680), (32, 1077),
+        (33, 1071), (31, 958), (15, 402), (38, 1180), (28, 870), (23, 776), (29, 928), (49, 1530),
+        (26, 802), (20, 568), (27, 907), (31, 1031), (25, 882), (24, 880), (23, 780), (35, 1045),
+    ],
+    // Daniel
+    &[
+        (21, 589), (49, 1500), (30, 992), (37, 1290), (31, 995), (28, 905), (28, 926), (27, 852),
+        (27, 966), (21, 647), (45, 1529), (13, 411),
+    ],
+    // Hosea
+    &[
+        (11, 355), (23, 678), (5, 156), (19, 465), (15, 368), (11, 254), (16, 398), (14, 330),
+        (17, 468), (15, 425), (12, 308), (14, 311), (16, 426), (9, 232),
+    ],
+    // Joel
+    &[
+        (20, 508), (32, 958), (21, 567),
+    ],
+    // Amos
+    &[
+        (15, 455), (16, 441), (15, 397), (13, 432), (27, 658), (14, 399), (17, 471), (14, 414),
+        (15, 549),
+    ],
+    // Obadiah
+    &[
+        (21, 669),
+    ],
+    // Jonah
+    &[
+        (17, 514), (10, 214), (10, 260), (11, 332),
+    ],
+    // Micah
+    &[
+        (16, 452), (13, 400), (12, 354), (13, 476), (15, 447), (16, 440), (20, 583),
+    ],
+    // Nahum
+    &[
+        (15, 394), (13, 371), (19, 519),
+    ],
+    // Habakkuk
+    &[
+        (17, 423), (20, 563), (19, 489),
+    ],
+    // Zephaniah
+    &[
+        (18, 555), (15, 463), (20, 598),
+    ],
+    // Haggai
+    &[
+        (15, 451), (23, 679),
+    ],
+    // Zechariah
+    &[
+        (21, 622), (13, 317), (10, 296), (14, 365), (11, 338), (15, 424), (14, 378), (23, 703),
+        (17, 537), (12, 408), (17, 502), (14, 462), (9, 337), (21, 754),
+    ],
+    // Malachi
+    &[
+        (14, 487), (17, 546), (18, 565), (6, 183),
+    ],
+    // Matthew
+    &[
+        (25, 473), (23, 619), (17, 387), (25, 557), (48, 1081), (34, 794), (29, 626), (34, 773),
+        (38, 837), (42, 919), (30, 668), (50, 1168), (58, 1367), (36, 721), (39, 785), (28, 688),
+        (27, 620), (35, 869), (30, 719), (34, 779), (46, 1126), (46, 828), (39, 833), (51, 1047),
+        (46, 995), (75, 1625), (66, 1359), (20, 421),
+    ],
+    // Mark
+    &[
+        (45, 930), (28, 724), (35, 663), (41, 923), (43, 952), (56, 1323), (37, 806), (38, 842),
+        (50, 1164), (52, 1218), (33, 778), (44, 1058), (37, 828), (72, 1595), (47, 913), (20, 449),
+    ],
+    // Luke
+    &[
+        (80, 1583), (52, 1094), (38, 1017), (44, 1021), (39, 949), (49, 1229), (50, 1209),
+        (56, 1431), (62, 1457), (42, 992), (54, 1331), (59, 1407), (35, 864), (35, 812), (32, 725),
+        (31, 752), (37, 809), (43, 868), (48, 1047), (47, 954), (38, 820), (71, 1396), (56, 1117),
+        (53, 1055),
+    ],
+    // John
+    &[
+        (51, 1004), (25, 510), (36, 763), (54, 1094), (47, 1001), (71, 1506), (53, 1002),
+        (59, 1307), (41, 876), (42, 820), (57, 1157), (50, 1060), (38, 826), (31, 731), (27, 613),
+        (33, 789), (26, 636), (40, 947), (42, 1010), (31, 749), (25, 693),
+    ],
+    // Acts
+    &[
+        (26, 661), (47, 1021), (26, 634), (37, 865), (42, 1026), (15, 355), (60, 1430), (40, 883),
+        (43, 1046), (48, 1108), (30, 667), (25, 662), (52, 1272), (28, 627), (41, 925), (40, 943),
+        (34, 855), (28, 664), (41, 975), (38, 882), (40, 1067), (30, 773), (35, 921), (27, 640),
+        (27, 711), (32, 781), (44, 1033), (31, 818),
+    ],
+    // Romans
+    &[
+        (32, 714), (29, 604), (31, 562), (25, 546), (21, 480), (23, 484), (25, 605), (39, 903),
+        (33, 719), (21, 461), (36, 818), (21, 397), (14, 361), (23, 525), (33, 742), (27, 517),
+    ],
+    // I Corinthians
+    &[
+        (31, 649), (16, 373), (23, 459), (21, 509), (13, 321), (20, 465), (40, 959), (13, 309),
+        (27, 671), (33, 667), (34, 718), (31, 593), (13, 270), (40, 887), (58, 1166), (24, 464),
+    ],
+    // II Corinthians
+    &[
+        (24, 591), (17, 401), (18, 389), (18, 406), (21, 487), (18, 357), (16, 452), (24, 568),
+        (15, 355), (18, 437), (33, 718), (21, 589), (14, 333),
+    ],
+    // Galatians
+    &[
+        (24, 453), (21, 573), (29, 648), (31, 610), (26, 447), (18, 359),
+    ],
+    // Ephesians
+    &[
+        (23, 508), (22, 453), (21, 410), (32, 617), (33, 564), (24, 478),
+    ],
+    // Philippians
+    &[
+        (30, 632), (30, 601), (21, 483), (23, 477),
+    ],
+    // Colossians
+    &[
+        (29, 656), (23, 503), (25, 457), (18, 373),
+    ],
+    // I Thessalonians
+    &[
+        (10, 253), (20, 479), (13, 295), (18, 396), (28, 424),
+    ],
+    // II Thessalonians
+    &[
+        (12, 291), (17, 381), (18, 360),
+    ],
+    // I Timothy
+    &[
+        (20, 432), (15, 247), (16, 324), (16, 310), (25, 470), (21, 477),
+    ],
+    // II Timothy
+    &[
+        (18, 438), (26, 506), (17, 297), (22, 453),
+    ],
+    // Titus
+    &[
+        (16, 337), (15, 265), (15, 313),
+    ],
+    // Philemon
+    &[
+        (25, 439),
+    ],
+    // Hebrews
+    &[
+        (14, 338), (18, 472), (19, 362), (16, 404), (14, 321), (20, 425), (28, 607), (13, 387),
+        (28, 678), (39, 784), (40, 922), (29, 696), (25, 509),
+    ],
+    // James
+    &[
+        (27, 544), (26, 528), (18, 378), (17, 369), (20, 485),
+    ],
+    // I Peter
+    &[
+        (25, 597), (25, 549), (22, 551), (19, 479), (14, 300),
+    ],
+    // II Peter
+    &[
+        (21, 488), (22, 581), (18, 484),
+    ],
+    // I John
+    &[
+        (10, 247), (29, 736), (24, 534), (21, 480), (21, 520),
+    ],
+    // II John
+    &[
+        (13, 298),
+    ],
+    // III John
+    &[
+        (14, 294),
+    ],
+    // Jude
+    &[
+        (25, 608),
+    ],
+    // Revelation of John
+    &[
+        (20, 592), (29, 802), (22, 658), (11, 348), (14, 435), (17, 540), (17, 491), (13, 397),
+        (21, 592), (11, 353), (19, 580), (17, 497), (18, 538), (20, 651), (8, 252), (21, 567),
+        (18, 522), (24, 747), (21, 634), (15, 477), (27, 749), (21, 573),
+    ],
+];
@@ -0,0 +1,335 @@
+use crate::{Bible, VALID_BOOK_NAMES};
+
+// Arabic numerals that translations often use instead of the KJV's Roman ones
+const NUMERAL_PREFIXES: [(&str, &str); 3] = [("1", "I"), ("2", "II"), ("3", "III")];
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The valid book name `name` most likely means, when there is one clear candidate
+pub fn suggest_book_name(name: &str) -> Option<&'static str> {
+    let mut normalized = name.trim().to_lowercase();
+    for (arabic, roman) in NUMERAL_PREFIXES {
+        if let Some(rest) = normalized.strip_prefix(arabic) {
+            normalized = format!("{} {}", roman.to_lowercase(), rest.trim_start());
+            break;
+        }
+    }
+
+    // "Revelation" for "Revelation of John"
+    let mut prefixed = VALID_BOOK_NAMES
+        .iter()
+        .filter(|valid| normalized.len() >= 4 && valid.to_lowercase().starts_with(&normalized));
+    if let (Some(valid), None) = (prefixed.next(), prefixed.next()) {
+        return Some(valid);
+    }
+
+    let mut distances: Vec<(usize, &'static str)> = VALID_BOOK_NAMES
+        .iter()
+        .map(|valid| (edit_distance(&normalized, &valid.to_lowercase()), *valid))
+        .collect();
+    distances.sort();
+    let (best, valid) = distances[0];
+    let threshold = (valid.len() / 4).max(2);
+    (best <= threshold && distances[1].0 > best).then_some(valid)
+}
+
+// When numbers are consistently shifted, e.g. chapters counting from 0, the
+// shift to apply to number them from 1
+fn numbering_shift(numbers: impl Iterator<Item = usize>) -> Option<i64> {
+    let mut shift = None;
+    for (idx, number) in numbers.enumerate() {
+        let difference = idx as i64 + 1 - number as i64;
+        if *shift.get_or_insert(difference) != difference {
+            return None;
+        }
+    }
+    shift.filter(|shift| *shift != 0)
+}
+
+// When all numbers count up from 1 except one that is off by one, e.g. verses
+// 1, 2, 2, 4, the position of that number and the number it should have
+fn misnumbered(numbers: impl Iterator<Item = usize>) -> Option<(usize, usize)> {
+    let mut wrong = numbers
+        .enumerate()
+        .filter(|(idx, number)| *number != idx + 1);
+    match (wrong.next(), wrong.next()) {
+        (Some((idx, number)), None) if number.abs_diff(idx + 1) == 1 => Some((idx, idx + 1)),
+        _ => None,
+    }
+}
+
+fn shift(number: usize, shift: i64) -> usize {
+    (number as i64 + shift) as usize
+}
+
+/// Fix what can be fixed without guessing and describe every fix. Names are
+/// only filled in when missing or when they matched the numbers before renumbering.
+pub fn repair_bible(bible: &mut Bible) -> Vec<String> {
+    let mut fixes = Vec::new();
+
+    for book in &mut bible.books {
+        if !VALID_BOOK_NAMES.contains(&book.name.as_str()) {
+            if let Some(valid) = suggest_book_name(&book.name) {
+                fixes.push(format!("Rename book '{}' to '{}'", book.name, valid));
+                // Chapter and verse names repeat the book name
+                let old_prefix = format!("{} ", book.name);
+                let rename = |name: &mut String| {
+                    if let Some(rest) = name.strip_prefix(&old_prefix) {
+                        *name = format!("{} {}", valid, rest);
+                    }
+                };
+                for chapter in &mut book.chapters {
+                    rename(&mut chapter.name);
+                    chapter.verses.iter_mut().for_each(|verse| rename(&mut verse.name));
+                }
+                book.name = valid.to_string();
+            }
+        }
+
+        if let Some(chapter_shift) = numbering_shift(book.chapters.iter().map(|c| c.chapter)) {
+            let first = book.chapters[0].chapter;
+            let last = book.chapters[book.chapters.len() - 1].chapter;
+            fixes.push(format!(
+                "Renumber the chapters of {} from {}-{} to {}-{}",
+                book.name,
+                first,
+                last,
+                shift(first, chapter_shift),
+                shift(last, chapter_shift)
+            ));
+            for chapter in &mut book.chapters {
+                let old_chapter = chapter.chapter;
+                chapter.chapter = shift(old_chapter, chapter_shift);
+                if chapter.name == format!("{} {}", book.name, old_chapter) {
+                    chapter.name.clear();
+                }
+                for verse in chapter
+                    .verses
+                    .iter_mut()
+                    .filter(|v| v.chapter == old_chapter)
+                {
+                    verse.chapter = chapter.chapter;
+                    if verse.name == format!("{} {}:{}", book.name, old_chapter, verse.verse) {
+                        verse.name.clear();
+                    }
+                }
+            }
+        }
+
+        let mut chapter_names_set = 0;
+        let mut verse_names_set = 0;
+        let mut verses_moved = 0;
+        for chapter in &mut book.chapters {
+            if let Some(verse_shift) = numbering_shift(chapter.verses.iter().map(|v| v.verse)) {
+                fixes.push(format!(
+                    "Renumber the verses of {} {} to start at 1",
+                    book.name, chapter.chapter
+                ));
+                for verse in &mut chapter.verses {
+                    let old_name = format!("{} {}:{}", book.name, verse.chapter, verse.verse);
+                    verse.verse = shift(verse.verse, verse_shift);
+                    if verse.name == old_name {
+                        verse.name.clear();
+                    }
+                }
+            } else if let Some((idx, number)) =
+                misnumbered(chapter.verses.iter().map(|v| v.verse))
+            {
+                let verse = &mut chapter.verses[idx];
+                fixes.push(format!(
+                    "Renumber {} {}:{} to {}:{}",
+                    book.name, chapter.chapter, verse.verse, chapter.chapter, number
+                ));
+                if verse.name == format!("{} {}:{}", book.name, verse.chapter, verse.verse) {
+                    verse.name.clear();
+                }
+                verse.verse = number;
+            }
+
+            if chapter.name.is_empty() {
+                chapter.name = format!("{} {}", book.name, chapter.chapter);
+                chapter_names_set += 1;
+            }
+            for verse in &mut chapter.verses {
+                if verse.chapter != chapter.chapter {
+                    verse.chapter = chapter.chapter;
+                    verses_moved += 1;
+                }
+                if verse.name.is_empty() {
+                    verse.name = format!("{} {}:{}", book.name, chapter.chapter, verse.verse);
+                    verse_names_set += 1;
+                }
+            }
+        }
+
+        let names_set: Vec<String> = [(chapter_names_set, "chapters"), (verse_names_set, "verses")]
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, kind)| format!("{} {}", count, kind))
+            .collect();
+        if !names_set.is_empty() {
+            fixes.push(format!(
+                "Set the names of {} in {}",
+                names_set.join(" and "),
+                book.name
+            ));
+        }
+        if verses_moved > 0 {
+            fixes.push(format!(
+                "Set the chapter number of {} verses in {} to the chapter they are in",
+                verses_moved, book.name
+            ));
+        }
+    }
+
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Book, Chapter, Verse};
+
+    #[test]
+    fn test_suggest_book_name() {
+        assert_eq!(suggest_book_name("Genesisa"), Some("Genesis"));
+        assert_eq!(suggest_book_name("1 Samuel"), Some("I Samuel"));
+        assert_eq!(suggest_book_name("2 kings"), Some("II Kings"));
+        assert_eq!(suggest_book_name("Revelation"), Some("Revelation of John"));
+        assert_eq!(suggest_book_name(" Psalm "), Some("Psalms"));
+        // Too far from any book, or as close to one book as to another
+        assert_eq!(suggest_book_name("Maccabees"), None);
+        assert_eq!(suggest_book_name("Jon"), None);
+    }
+
+    #[test]
+    fn test_numbering_shift() {
+        assert_eq!(numbering_shift([0, 1, 2].into_iter()), Some(1));
+        assert_eq!(numbering_shift([2, 3].into_iter()), Some(-1));
+        assert_eq!(numbering_shift([1, 2, 3].into_iter()), None);
+        assert_eq!(numbering_shift([0, 2, 3].into_iter()), None);
+        assert_eq!(numbering_shift(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_misnumbered() {
+        assert_eq!(misnumbered([1, 2, 2, 4].into_iter()), Some((2, 3)));
+        assert_eq!(misnumbered([1, 3, 3].into_iter()), Some((1, 2)));
+        assert_eq!(misnumbered([1, 2, 3].into_iter()), None);
+        assert_eq!(misnumbered([1, 2, 5].into_iter()), None);
+        assert_eq!(misnumbered([1, 3, 2].into_iter()), None);
+    }
+
+    fn verse(chapter: usize, verse: usize, name: &str) -> Verse {
+        Verse {
+            verse,
+            chapter,
+            name: name.to_string(),
+            text: "In the beginning God created the heaven and the earth.".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_repair_renamed_book() {
+        let mut bible = Bible {
+            books: vec![Book {
+                name: "Genesisa".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 1,
+                    name: "Genesisa 1".to_string(),
+                    verses: vec![verse(1, 1, "Genesisa 1:1"), verse(1, 2, "Genesisa 1:2")],
+                }],
+            }],
+        };
+
+        assert_eq!(
+            repair_bible(&mut bible),
+            vec!["Rename book 'Genesisa' to 'Genesis'"]
+        );
+        let chapter = &bible.books[0].chapters[0];
+        assert_eq!(chapter.name, "Genesis 1");
+        assert_eq!(chapter.verses[1].name, "Genesis 1:2");
+    }
+
+    #[test]
+    fn test_repair_misnumbered_verse() {
+        let mut bible = Bible {
+            books: vec![Book {
+                name: "Genesis".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 1,
+                    name: "Genesis 1".to_string(),
+                    verses: vec![
+                        verse(1, 1, "Genesis 1:1"),
+                        verse(1, 2, "Genesis 1:2"),
+                        verse(1, 2, "Genesis 1:2"),
+                        verse(1, 4, "Genesis 1:4"),
+                    ],
+                }],
+            }],
+        };
+
+        assert_eq!(
+            repair_bible(&mut bible),
+            vec![
+                "Renumber Genesis 1:2 to 1:3",
+                "Set the names of 1 verses in Genesis",
+            ]
+        );
+        let verses = &bible.books[0].chapters[0].verses;
+        assert_eq!(
+            verses.iter().map(|v| v.verse).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(verses[2].name, "Genesis 1:3");
+    }
+
+    #[test]
+    fn test_repair_bible() {
+        let mut bible = Bible {
+            books: vec![Book {
+                name: "Genesisa".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 0,
+                    name: "Genesis 0".to_string(),
+                    verses: vec![Verse {
+                        verse: 1,
+                        chapter: 3,
+                        name: String::new(),
+                        text: "In the beginning God created the heaven and the earth.".to_string(),
+                    }],
+                }],
+            }],
+        };
+
+        assert_eq!(
+            repair_bible(&mut bible),
+            vec![
+                "Rename book 'Genesisa' to 'Genesis'",
+                "Renumber the chapters of Genesis from 0-0 to 1-1",
+                "Set the names of 1 chapters and 1 verses in Genesis",
+                "Set the chapter number of 1 verses in Genesis to the chapter they are in",
+            ]
+        );
+        let chapter = &bible.books[0].chapters[0];
+        assert_eq!(chapter.chapter, 1);
+        assert_eq!(chapter.name, "Genesis 1");
+        assert_eq!(chapter.verses[0].chapter, 1);
+        assert_eq!(chapter.verses[0].name, "Genesis 1:1");
+
+        // A repaired Bible needs nothing more
+        assert!(repair_bible(&mut bible).is_empty());
+    }
+}
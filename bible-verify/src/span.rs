@@ -0,0 +1,202 @@
+use miette::SourceSpan;
+use std::collections::HashMap;
+
+/// One step into a JSON document: an object key or an array index
+#[derive(Debug, Clone, Copy)]
+pub enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+enum Frame {
+    Object {
+        key: Option<String>,
+        expect_key: bool,
+    },
+    Array {
+        index: usize,
+    },
+}
+
+// Index just past the closing quote of the string starting at `start`
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+// Index just past the scalar value starting at `start`
+fn value_end(bytes: &[u8], start: usize) -> Option<usize> {
+    match bytes[start] {
+        b'"' => string_end(bytes, start),
+        _ => Some(
+            bytes[start..]
+                .iter()
+                .position(|b| matches!(b, b',' | b'}' | b']') || b.is_ascii_whitespace())
+                .map_or(bytes.len(), |len| start + len),
+        ),
+    }
+}
+
+/// Spans of all scalar values in a JSON document by their path, e.g.
+/// `books[3].chapters[0].name`, so diagnostics can point into the raw JSON
+pub struct SpanIndex {
+    spans: HashMap<Vec<OwnedSegment>, SourceSpan>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum OwnedSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl SpanIndex {
+    pub fn new(content: &str) -> Self {
+        let bytes = content.as_bytes();
+        let mut spans = HashMap::new();
+        let mut stack = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b' ' | b'\t' | b'\n' | b'\r' | b':' => {
+                    i += 1;
+                    continue;
+                }
+                b',' => {
+                    match stack.last_mut() {
+                        Some(Frame::Object { expect_key, .. }) => *expect_key = true,
+                        Some(Frame::Array { index }) => *index += 1,
+                        None => {}
+                    }
+                    i += 1;
+                    continue;
+                }
+                b'}' | b']' => {
+                    stack.pop();
+                    i += 1;
+                    continue;
+                }
+                b'{' => {
+                    stack.push(Frame::Object {
+                        key: None,
+                        expect_key: true,
+                    });
+                    i += 1;
+                    continue;
+                }
+                b'[' => {
+                    stack.push(Frame::Array { index: 0 });
+                    i += 1;
+                    continue;
+                }
+                b'"' => {
+                    if let Some(Frame::Object { key, expect_key }) = stack.last_mut() {
+                        if *expect_key {
+                            let Some(end) = string_end(bytes, i) else {
+                                break;
+                            };
+                            *key = Some(content[i + 1..end - 1].to_string());
+                            *expect_key = false;
+                            i = end;
+                            continue;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            // Anything else is a scalar value
+            let Some(end) = value_end(bytes, i) else {
+                break;
+            };
+            if let Some(path) = owned_path(&stack) {
+                spans.insert(path, (i, end - i).into());
+            }
+            i = end;
+        }
+
+        Self { spans }
+    }
+
+    pub fn get(&self, path: &[PathSegment]) -> Option<SourceSpan> {
+        let path: Vec<OwnedSegment> = path
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Key(key) => OwnedSegment::Key(key.to_string()),
+                PathSegment::Index(index) => OwnedSegment::Index(*index),
+            })
+            .collect();
+        self.spans.get(&path).copied()
+    }
+}
+
+fn owned_path(stack: &[Frame]) -> Option<Vec<OwnedSegment>> {
+    stack
+        .iter()
+        .map(|frame| match frame {
+            Frame::Object { key, .. } => key.clone().map(OwnedSegment::Key),
+            Frame::Array { index } => Some(OwnedSegment::Index(*index)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{
+    "books": [
+        {"name": "Genesis", "chapters": [{"chapter": 1, "verses": []}]},
+        {"name": "Exo\"dus", "chapters": [{"chapter": 12}, {"chapter": 13}]}
+    ]
+}"#;
+
+    fn text(path: &[PathSegment]) -> Option<&'static str> {
+        let span = SpanIndex::new(DOCUMENT).get(path)?;
+        Some(&DOCUMENT[span.offset()..span.offset() + span.len()])
+    }
+
+    #[test]
+    fn test_span_index_get() {
+        use PathSegment::{Index, Key};
+
+        assert_eq!(
+            text(&[Key("books"), Index(0), Key("name")]),
+            Some("\"Genesis\"")
+        );
+        assert_eq!(
+            text(&[Key("books"), Index(1), Key("name")]),
+            Some("\"Exo\\\"dus\"")
+        );
+        assert_eq!(
+            text(&[
+                Key("books"),
+                Index(1),
+                Key("chapters"),
+                Index(1),
+                Key("chapter")
+            ]),
+            Some("13")
+        );
+        assert_eq!(
+            text(&[
+                Key("books"),
+                Index(0),
+                Key("chapters"),
+                Index(0),
+                Key("chapter")
+            ]),
+            Some("1")
+        );
+        // Containers and paths that aren't in the document have no span
+        assert_eq!(text(&[Key("books"), Index(0), Key("chapters")]), None);
+        assert_eq!(text(&[Key("books"), Index(2), Key("name")]), None);
+    }
+}
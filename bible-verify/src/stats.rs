@@ -0,0 +1,241 @@
+use crate::kjv_baseline::KJV_CHAPTERS;
+use crate::{Bible, VALID_BOOK_NAMES};
+use serde::Serialize;
+
+// A chapter is an outlier when its word count is below half or above double
+// the KJV count, scaled by how wordy the translation is overall
+const MIN_WORD_RATIO: f64 = 0.5;
+const MAX_WORD_RATIO: f64 = 2.0;
+
+/// Verse and word counts of a Bible, compared against the KJV
+#[derive(Debug, Serialize)]
+pub struct BibleStats {
+    pub verses: usize,
+    pub words: usize,
+    /// Words per KJV word, over the chapters that exist in both
+    pub word_ratio: f64,
+    pub books: Vec<BookStats>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookStats {
+    pub name: String,
+    pub verses: usize,
+    pub words: usize,
+    pub chapters: Vec<ChapterStats>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub outliers: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChapterStats {
+    pub chapter: usize,
+    pub verses: usize,
+    pub words: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kjv_verses: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kjv_words: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub outliers: Vec<String>,
+}
+
+impl BibleStats {
+    pub fn outlier_count(&self) -> usize {
+        self.books
+            .iter()
+            .map(|book| {
+                book.outliers.len()
+                    + book
+                        .chapters
+                        .iter()
+                        .map(|chapter| chapter.outliers.len())
+                        .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+fn kjv_book(name: &str) -> Option<&'static [(u16, u16)]> {
+    VALID_BOOK_NAMES
+        .iter()
+        .position(|book| *book == name)
+        .map(|index| KJV_CHAPTERS[index])
+}
+
+pub fn compute_stats(bible: &Bible) -> BibleStats {
+    let mut books: Vec<BookStats> = bible
+        .books
+        .iter()
+        .map(|book| {
+            let kjv = kjv_book(&book.name);
+            let chapters: Vec<ChapterStats> = book
+                .chapters
+                .iter()
+                .map(|chapter| {
+                    let baseline = kjv.and_then(|kjv| kjv.get(chapter.chapter.checked_sub(1)?));
+                    ChapterStats {
+                        chapter: chapter.chapter,
+                        verses: chapter.verses.len(),
+                        words: chapter
+                            .verses
+                            .iter()
+                            .map(|verse| verse.text.split_whitespace().count())
+                            .sum(),
+                        kjv_verses: baseline.map(|(verses, _)| *verses as usize),
+                        kjv_words: baseline.map(|(_, words)| *words as usize),
+                        outliers: Vec::new(),
+                    }
+                })
+                .collect();
+
+            let mut outliers = Vec::new();
+            if let Some(kjv) = kjv.filter(|kjv| kjv.len() != chapters.len()) {
+                outliers.push(format!(
+                    "{} chapters where the KJV has {}",
+                    chapters.len(),
+                    kjv.len()
+                ));
+            }
+
+            BookStats {
+                name: book.name.clone(),
+                verses: chapters.iter().map(|chapter| chapter.verses).sum(),
+                words: chapters.iter().map(|chapter| chapter.words).sum(),
+                chapters,
+                outliers,
+            }
+        })
+        .collect();
+
+    // Translations differ in wordiness, so compare chapters against the KJV scaled by the overall ratio
+    let (words, kjv_words) = books
+        .iter()
+        .flat_map(|book| &book.chapters)
+        .filter_map(|chapter| Some((chapter.words, chapter.kjv_words?)))
+        .fold((0, 0), |(words, kjv_words), (w, k)| {
+            (words + w, kjv_words + k)
+        });
+    let word_ratio = if kjv_words > 0 {
+        words as f64 / kjv_words as f64
+    } else {
+        1.0
+    };
+
+    for chapter in books.iter_mut().flat_map(|book| &mut book.chapters) {
+        if let Some(kjv_verses) = chapter.kjv_verses {
+            let tolerance = (kjv_verses / 10).max(2);
+            if chapter.verses.abs_diff(kjv_verses) > tolerance {
+                chapter.outliers.push(format!(
+                    "{} verses where the KJV has {}",
+                    chapter.verses, kjv_verses
+                ));
+            }
+        }
+        if let Some(kjv_words) = chapter.kjv_words {
+            let expected = kjv_words as f64 * word_ratio;
+            let ratio = chapter.words as f64 / expected;
+            if !(MIN_WORD_RATIO..=MAX_WORD_RATIO).contains(&ratio) {
+                chapter.outliers.push(format!(
+                    "{} words where about {} are expected",
+                    chapter.words,
+                    expected.round()
+                ));
+            }
+        }
+    }
+
+    BibleStats {
+        verses: books.iter().map(|book| book.verses).sum(),
+        words: books.iter().map(|book| book.words).sum(),
+        word_ratio,
+        books,
+    }
+}
+
+pub fn print_stats(stats: &BibleStats) {
+    for book in &stats.books {
+        println!(
+            "{}: {} chapters, {} verses, {} words",
+            book.name,
+            book.chapters.len(),
+            book.verses,
+            book.words
+        );
+        for outlier in &book.outliers {
+            println!("  ! {}", outlier);
+        }
+        for chapter in &book.chapters {
+            for outlier in &chapter.outliers {
+                println!("  ! {} {}: {}", book.name, chapter.chapter, outlier);
+            }
+        }
+    }
+
+    println!(
+        "\n{} verses, {} words ({:.2} words per KJV word), {} outliers",
+        stats.verses,
+        stats.words,
+        stats.word_ratio,
+        stats.outlier_count()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Book, Chapter, Verse};
+
+    // A chapter with the given number of verses, spreading `words` over them
+    fn chapter(number: usize, verses: usize, words: usize) -> Chapter {
+        Chapter {
+            chapter: number,
+            name: format!("Ruth {}", number),
+            verses: (0..verses)
+                .map(|i| Verse {
+                    verse: i + 1,
+                    chapter: number,
+                    name: format!("Ruth {}:{}", number, i + 1),
+                    text: vec!["word"; words / verses + usize::from(i < words % verses)].join(" "),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_outliers() {
+        let mut long_chapter = chapter(2, 23, 763);
+        long_chapter.verses[0].text = vec!["word"; 1500].join(" ");
+        let bible = Bible {
+            books: vec![Book {
+                name: "Ruth".to_string(),
+                chapters: vec![
+                    chapter(1, 22, 649),
+                    long_chapter,
+                    chapter(3, 0, 0),
+                    chapter(4, 22, 621),
+                ],
+            }],
+        };
+
+        let stats = compute_stats(&bible);
+        let ruth = &stats.books[0];
+
+        assert_eq!(stats.verses, 67);
+        assert!(ruth.outliers.is_empty());
+        assert!(ruth.chapters[0].outliers.is_empty());
+        assert_eq!(
+            ruth.chapters[1].outliers,
+            vec!["2229 words where about 1037 are expected"]
+        );
+        assert_eq!(
+            ruth.chapters[2].outliers,
+            vec![
+                "0 verses where the KJV has 18",
+                "0 words where about 735 are expected"
+            ]
+        );
+        assert!(ruth.chapters[3].outliers.is_empty());
+        assert_eq!(stats.outlier_count(), 3);
+    }
+}
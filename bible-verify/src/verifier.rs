@@ -3,8 +3,20 @@ use miette::{Diagnostic, NamedSource, SourceSpan};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::cell::OnceCell;
+use std::sync::Arc;
 use thiserror::Error;
 
+mod binary;
+mod encoding;
+mod kjv_baseline;
+mod repair;
+mod span;
+mod stats;
+
+use encoding::{describe_character, find_encoding_problem, EncodingProblem};
+use span::{PathSegment, SpanIndex};
+
 // Valid KJV Bible book names in order
 const VALID_BOOK_NAMES: [&str; 66] = [
     // Old Testament
@@ -25,12 +37,32 @@ const VALID_BOOK_NAMES: [&str; 66] = [
     "Jude", "Revelation of John",
 ];
 
+// More problems than this are counted but not shown
+const MAX_REPORTED_ERRORS: usize = 20;
+
 #[derive(Parser, Debug)]
 #[command(name = "bible-verify")]
 #[command(about = "A Bible JSON verifier that checks for correct verse counts", long_about = None)]
 struct Args {
-    /// Path to the Bible JSON file to verify
-    file: PathBuf,
+    /// Bible JSON files to verify, or directories to search for them
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Print verse and word counts per book, flagging chapters that differ a lot from the KJV
+    #[arg(long)]
+    stats: bool,
+
+    /// Print the statistics, including every chapter, as JSON
+    #[arg(long, requires = "stats")]
+    json: bool,
+
+    /// Write a copy with the suggested fixes applied to this file
+    #[arg(long, value_name = "FILE")]
+    fix_output: Option<PathBuf>,
+
+    /// Write the file in the binary format the site downloads, instead of verifying it
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["stats", "fix_output"])]
+    encode: Option<PathBuf>,
 }
 
 #[derive(Error, Debug, Diagnostic)]
@@ -43,7 +75,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::json_error))]
     JsonError {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Invalid JSON here")]
         span: SourceSpan,
         #[source]
@@ -54,7 +86,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::book_count))]
     InvalidBookCount {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Expected 66 books, found {found}")]
         span: SourceSpan,
         found: usize,
@@ -64,7 +96,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::suspicious_chapter))]
     SuspiciousChapter {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("{book} chapter {chapter} has {verse_count} verses")]
         span: SourceSpan,
         book: String,
@@ -78,7 +110,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::suspicious_verse))]
     SuspiciousVerse {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("{book} {chapter}:{verse} has {word_count} words")]
         span: SourceSpan,
         book: String,
@@ -93,7 +125,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::missing_verse))]
     MissingVerse {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Missing verse {verse} in {book} chapter {chapter}")]
         span: SourceSpan,
         book: String,
@@ -105,7 +137,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::duplicate_verse))]
     DuplicateVerse {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Duplicate verse {verse} in {book} chapter {chapter}")]
         span: SourceSpan,
         book: String,
@@ -113,11 +145,24 @@ enum VerificationError {
         verse: usize,
     },
 
+    #[error("Identical consecutive verses")]
+    #[diagnostic(code(bible_verify::identical_verses))]
+    IdenticalVerses {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("{book} {chapter}:{verse} repeats the text of verse {previous_verse}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        verse: usize,
+        previous_verse: usize,
+    },
+
     #[error("Missing chapter")]
     #[diagnostic(code(bible_verify::missing_chapter))]
     MissingChapter {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Missing chapter {chapter} in {book}")]
         span: SourceSpan,
         book: String,
@@ -128,7 +173,7 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::duplicate_chapter))]
     DuplicateChapter {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Duplicate chapter {chapter} in {book}")]
         span: SourceSpan,
         book: String,
@@ -139,13 +184,118 @@ enum VerificationError {
     #[diagnostic(code(bible_verify::invalid_book_name))]
     InvalidBookName {
         #[source_code]
-        src: NamedSource<String>,
+        src: NamedSource<Arc<String>>,
         #[label("Book name '{book_name}' is not a valid Bible book")]
         span: SourceSpan,
         book_name: String,
         #[help]
         help: String,
     },
+
+    #[error("Book out of order")]
+    #[diagnostic(code(bible_verify::book_order))]
+    BookOutOfOrder {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("'{book_name}' is book {position}, where {expected} belongs")]
+        span: SourceSpan,
+        book_name: String,
+        position: usize,
+        expected: String,
+        #[help]
+        help: String,
+    },
+
+    #[error("Chapter out of order")]
+    #[diagnostic(code(bible_verify::chapter_order))]
+    ChapterOutOfOrder {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("{book} chapter {chapter} is in position {position}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        position: usize,
+    },
+
+    #[error("Verse out of order")]
+    #[diagnostic(code(bible_verify::verse_order))]
+    VerseOutOfOrder {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("{book} {chapter}:{verse} is in position {position}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        verse: usize,
+        position: usize,
+    },
+
+    #[error("Inconsistent name")]
+    #[diagnostic(code(bible_verify::inconsistent_name))]
+    InconsistentName {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("Name is '{found}', but the numbers say '{expected}'")]
+        span: SourceSpan,
+        found: String,
+        expected: String,
+    },
+
+    #[error("Missing name")]
+    #[diagnostic(code(bible_verify::missing_name))]
+    MissingName {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("Name '{expected}' is missing")]
+        span: SourceSpan,
+        expected: String,
+    },
+
+    #[error("Inconsistent chapter number")]
+    #[diagnostic(code(bible_verify::inconsistent_chapter))]
+    InconsistentChapter {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("Verse {verse} of {book} chapter {chapter} says it is in chapter {found}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        verse: usize,
+        found: usize,
+    },
+
+    #[error("Broken character encoding")]
+    #[diagnostic(code(bible_verify::mojibake))]
+    Mojibake {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("{book} {chapter}:{verse} has '{sequence}', which is '{intended}' read as {encoding}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        verse: usize,
+        sequence: String,
+        intended: char,
+        encoding: &'static str,
+        #[help]
+        help: String,
+    },
+
+    #[error("Invalid character")]
+    #[diagnostic(code(bible_verify::invalid_character))]
+    InvalidCharacter {
+        #[source_code]
+        src: NamedSource<Arc<String>>,
+        #[label("{book} {chapter}:{verse} contains {description}")]
+        span: SourceSpan,
+        book: String,
+        chapter: usize,
+        verse: usize,
+        description: String,
+        #[help]
+        help: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -162,6 +312,7 @@ struct Book {
 #[derive(Debug, Serialize, Deserialize)]
 struct Chapter {
     chapter: usize,
+    #[serde(default)]
     name: String,
     verses: Vec<Verse>,
 }
@@ -170,132 +321,85 @@ struct Chapter {
 struct Verse {
     verse: usize,
     chapter: usize,
+    #[serde(default)]
     name: String,
     text: String,
 }
 
-fn find_json_span(content: &str, book_idx: usize, chapter_idx: Option<usize>, verse_idx: Option<usize>) -> Option<SourceSpan> {
-    // If we're looking for a specific verse, use a more precise approach
-    if let Some(verse_idx) = verse_idx {
-        return find_verse_text_span(content, book_idx, chapter_idx.unwrap_or(0), verse_idx);
-    }
-    
-    let mut current_pos = 0;
-    let mut book_count = 0;
-    let mut in_books = false;
-    let mut depth = 0;
-    
-    for (i, ch) in content.char_indices() {
-        match ch {
-            '{' => depth += 1,
-            '}' => depth -= 1,
-            '"' if depth > 0 => {
-                if content[i..].starts_with("\"books\"") && !in_books {
-                    in_books = true;
-                } else if content[i..].starts_with("\"name\"") && in_books && book_count == book_idx {
-                    if chapter_idx.is_none() {
-                        return Some((i, 6).into());
-                    }
-                }
-            }
-            '[' if in_books => {
-                if book_count == book_idx {
-                    current_pos = i;
-                }
-            }
-            _ => {}
+// Points at the book name, the chapter number or the verse text
+fn find_json_span(spans: &SpanIndex, book_idx: usize, chapter_idx: Option<usize>, verse_idx: Option<usize>) -> Option<SourceSpan> {
+    match (chapter_idx, verse_idx) {
+        (Some(chapter_idx), Some(verse_idx)) => {
+            find_verse_field_span(spans, book_idx, chapter_idx, verse_idx, "text")
         }
-        
-        if in_books && ch == '{' && depth == 3 {
-            if book_count == book_idx {
-                return Some((current_pos, 10).into());
-            }
-            book_count += 1;
+        (Some(chapter_idx), None) => {
+            find_chapter_field_span(spans, book_idx, chapter_idx, "chapter")
         }
+        _ => spans.get(
+            &[
+                PathSegment::Key("books"),
+                PathSegment::Index(book_idx),
+                PathSegment::Key("name"),
+            ],
+        ),
     }
-    
-    Some((0, content.len().min(100)).into())
 }
 
-fn find_verse_text_span(content: &str, book_idx: usize, chapter_idx: usize, verse_idx: usize) -> Option<SourceSpan> {
-    let mut book_count = 0;
-    let mut chapter_count = 0;
-    let mut verse_count = 0;
-    let mut in_books = false;
-    let mut in_chapters = false;
-    let mut in_verses = false;
-    let mut in_target_book = false;
-    let mut in_target_chapter = false;
-    
-    let lines: Vec<&str> = content.lines().collect();
-    
-    for (line_idx, line) in lines.iter().enumerate() {
-        
-        if line.contains("\"books\"") && !in_books {
-            in_books = true;
-        }
-        
-        if in_books && line.contains("\"name\"") && line.contains("\"") {
-            if book_count == book_idx {
-                in_target_book = true;
-            } else {
-                in_target_book = false;
-            }
-            if !in_target_book {
-                book_count += 1;
-            }
-        }
-        
-        if in_target_book && line.contains("\"chapters\"") {
-            in_chapters = true;
-            chapter_count = 0;
-        }
-        
-        if in_chapters && in_target_book && line.contains("\"chapter\"") && line.contains(":") {
-            if chapter_count == chapter_idx {
-                in_target_chapter = true;
-            } else {
-                in_target_chapter = false;
-            }
-            chapter_count += 1;
-        }
-        
-        if in_target_chapter && line.contains("\"verses\"") {
-            in_verses = true;
-            verse_count = 0;
-        }
-        
-        if in_verses && in_target_chapter && line.contains("\"verse\"") && line.contains(":") {
-            if verse_count == verse_idx {
-                // Look for the text field in the next few lines
-                for i in line_idx..lines.len().min(line_idx + 5) {
-                    if lines[i].contains("\"text\"") {
-                        // Calculate the offset to the start of this line
-                        let offset = lines.iter()
-                            .take(i)
-                            .map(|l| l.len() + 1)
-                            .sum::<usize>();
-                        
-                        // Find the position of "text" in the line
-                        if let Some(text_pos) = lines[i].find("\"text\"") {
-                            let line_offset = offset + text_pos;
-                            let line_len = lines[i].len() - text_pos;
-                            return Some((line_offset, line_len).into());
-                        }
-                    }
-                }
-            }
-            verse_count += 1;
-        }
-    }
-    
-    None
+fn find_chapter_field_span(
+    spans: &SpanIndex,
+    book_idx: usize,
+    chapter_idx: usize,
+    field: &str,
+) -> Option<SourceSpan> {
+    spans.get(
+        &[
+            PathSegment::Key("books"),
+            PathSegment::Index(book_idx),
+            PathSegment::Key("chapters"),
+            PathSegment::Index(chapter_idx),
+            PathSegment::Key(field),
+        ],
+    )
+}
+
+fn find_verse_field_span(
+    spans: &SpanIndex,
+    book_idx: usize,
+    chapter_idx: usize,
+    verse_idx: usize,
+    field: &str,
+) -> Option<SourceSpan> {
+    spans.get(
+        &[
+            PathSegment::Key("books"),
+            PathSegment::Index(book_idx),
+            PathSegment::Key("chapters"),
+            PathSegment::Index(chapter_idx),
+            PathSegment::Key("verses"),
+            PathSegment::Index(verse_idx),
+            PathSegment::Key(field),
+        ],
+    )
 }
 
-fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
-    let content = fs::read_to_string(path)?;
+// Whitespace differences don't make two verses different
+fn normalize_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Every problem in the file. Checks that only make sense once an earlier check
+/// passed, like chapter order after duplicate chapters, are skipped otherwise.
+fn verify_bible(path: &PathBuf) -> Vec<VerificationError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => Arc::new(content),
+        Err(e) => return vec![e.into()],
+    };
     let filename = path.display().to_string();
-    
+    let src = || NamedSource::new(&filename, Arc::clone(&content));
+    // Only built once there is a problem to point at
+    let span_index = OnceCell::new();
+    let spans = || span_index.get_or_init(|| SpanIndex::new(&content));
+
     let bible: Bible = match serde_json::from_str(&content) {
         Ok(bible) => bible,
         Err(e) => {
@@ -306,20 +410,22 @@ fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
                 .take(line - 1)
                 .map(|l| l.len() + 1)
                 .sum::<usize>()
-                + column - 1;
-            
-            return Err(VerificationError::JsonError {
-                src: NamedSource::new(&filename, content.clone()),
+                + column.saturating_sub(1);
+
+            return vec![VerificationError::JsonError {
+                src: src(),
                 span: (offset, 1).into(),
                 error: e,
-            });
+            }];
         }
     };
 
+    let mut errors = Vec::new();
+
     if bible.books.len() != 66 {
-        return Err(VerificationError::InvalidBookCount {
-            src: NamedSource::new(&filename, content.clone()),
-            span: find_json_span(&content, 0, None, None).unwrap_or((0, 10).into()),
+        errors.push(VerificationError::InvalidBookCount {
+            src: src(),
+            span: find_json_span(spans(), 0, None, None).unwrap_or((0, 10).into()),
             found: bible.books.len(),
         });
     }
@@ -327,29 +433,58 @@ fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
     // Validate book names
     for (book_idx, book) in bible.books.iter().enumerate() {
         if !VALID_BOOK_NAMES.contains(&book.name.as_str()) {
-            let help = format!(
-                "Valid book names include: Genesis, Exodus, Leviticus, Numbers, etc. \
-                Make sure to use the exact spelling as in the KJV Bible, including \
-                Roman numerals like 'I Samuel', 'II Kings', etc."
-            );
-            
-            return Err(VerificationError::InvalidBookName {
-                src: NamedSource::new(&filename, content.clone()),
-                span: find_json_span(&content, book_idx, None, None).unwrap_or((0, 10).into()),
+            let help = match repair::suggest_book_name(&book.name) {
+                Some(suggestion) => format!("Did you mean '{}'?", suggestion),
+                None => "Valid book names include: Genesis, Exodus, Leviticus, Numbers, etc. \
+                    Make sure to use the exact spelling as in the KJV Bible, including \
+                    Roman numerals like 'I Samuel', 'II Kings', etc."
+                    .to_string(),
+            };
+
+            errors.push(VerificationError::InvalidBookName {
+                src: src(),
+                span: find_json_span(spans(), book_idx, None, None).unwrap_or((0, 10).into()),
                 book_name: book.name.clone(),
                 help,
             });
         }
     }
 
+    // With 66 valid names, a name in the wrong place is either moved or duplicated
+    if errors.is_empty() {
+        for (book_idx, book) in bible.books.iter().enumerate() {
+            let expected = VALID_BOOK_NAMES[book_idx];
+            if book.name != expected {
+                let help = if bible.books[..book_idx].iter().any(|b| b.name == book.name) {
+                    format!("'{}' appears more than once", book.name)
+                } else {
+                    "Books must be in the KJV order, from Genesis to Revelation of John".to_string()
+                };
+
+                errors.push(VerificationError::BookOutOfOrder {
+                    src: src(),
+                    span: find_json_span(spans(), book_idx, None, None).unwrap_or((0, 10).into()),
+                    book_name: book.name.clone(),
+                    position: book_idx + 1,
+                    expected: expected.to_string(),
+                    help,
+                });
+            }
+        }
+    }
+
     for (book_idx, book) in bible.books.iter().enumerate() {
+        let errors_before_chapters = errors.len();
+        // Names can only be compared once the book name itself is right
+        let book_name_valid = VALID_BOOK_NAMES.contains(&book.name.as_str());
+
         // First check for duplicate chapters
         let mut seen_chapters = std::collections::HashSet::new();
         for (chapter_idx, chapter) in book.chapters.iter().enumerate() {
             if !seen_chapters.insert(chapter.chapter) {
-                return Err(VerificationError::DuplicateChapter {
-                    src: NamedSource::new(&filename, content.clone()),
-                    span: find_json_span(&content, book_idx, Some(chapter_idx), None)
+                errors.push(VerificationError::DuplicateChapter {
+                    src: src(),
+                    span: find_json_span(spans(), book_idx, Some(chapter_idx), None)
                         .unwrap_or((0, 10).into()),
                     book: book.name.clone(),
                     chapter: chapter.chapter,
@@ -358,35 +493,71 @@ fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
         }
 
         // Then check for missing chapters (only after ensuring no duplicates)
-        let max_chapter = book.chapters.iter().map(|c| c.chapter).max().unwrap_or(0);
-        for i in 1..=max_chapter {
-            if !book.chapters.iter().any(|c| c.chapter == i) {
-                return Err(VerificationError::MissingChapter {
-                    src: NamedSource::new(&filename, content.clone()),
-                    span: find_json_span(&content, book_idx, None, None)
+        if errors.len() == errors_before_chapters {
+            let max_chapter = book.chapters.iter().map(|c| c.chapter).max().unwrap_or(0);
+            for i in 1..=max_chapter {
+                if !book.chapters.iter().any(|c| c.chapter == i) {
+                    errors.push(VerificationError::MissingChapter {
+                        src: src(),
+                        span: find_json_span(spans(), book_idx, None, None)
+                            .unwrap_or((0, 10).into()),
+                        book: book.name.clone(),
+                        chapter: i,
+                    });
+                }
+            }
+        }
+
+        // With no duplicates or gaps, chapter numbers must simply count up from 1
+        let chapters_numbered = errors.len() == errors_before_chapters;
+        for (chapter_idx, chapter) in book.chapters.iter().enumerate() {
+            if chapters_numbered && chapter.chapter != chapter_idx + 1 {
+                errors.push(VerificationError::ChapterOutOfOrder {
+                    src: src(),
+                    span: find_json_span(spans(), book_idx, Some(chapter_idx), None)
                         .unwrap_or((0, 10).into()),
                     book: book.name.clone(),
-                    chapter: i,
+                    chapter: chapter.chapter,
+                    position: chapter_idx + 1,
+                });
+            }
+
+            let expected = format!("{} {}", book.name, chapter.chapter);
+            if chapter.name.is_empty() {
+                errors.push(VerificationError::MissingName {
+                    src: src(),
+                    span: find_chapter_field_span(spans(), book_idx, chapter_idx, "name")
+                        .or_else(|| find_json_span(spans(), book_idx, Some(chapter_idx), None))
+                        .unwrap_or((0, 10).into()),
+                    expected,
+                });
+            } else if book_name_valid && chapter.name != expected {
+                errors.push(VerificationError::InconsistentName {
+                    src: src(),
+                    span: find_chapter_field_span(spans(), book_idx, chapter_idx, "name")
+                        .unwrap_or((0, 10).into()),
+                    found: chapter.name.clone(),
+                    expected,
                 });
             }
         }
 
         for (chapter_idx, chapter) in book.chapters.iter().enumerate() {
             let verse_count = chapter.verses.len();
-            
+
             // Special case: Psalm 117 has only 2 verses
             let is_psalm_117 = book.name == "Psalms" && chapter.chapter == 117;
-            
+
             if (verse_count < 3 && !is_psalm_117) || verse_count > 200 {
                 let help = if verse_count < 3 {
                     "Most Bible chapters have at least 3 verses (except Psalm 117)".to_string()
                 } else {
                     "No Bible chapter has more than 200 verses (Psalm 119 has 176)".to_string()
                 };
-                
-                return Err(VerificationError::SuspiciousChapter {
-                    src: NamedSource::new(&filename, content.clone()),
-                    span: find_json_span(&content, book_idx, Some(chapter_idx), None)
+
+                errors.push(VerificationError::SuspiciousChapter {
+                    src: src(),
+                    span: find_json_span(spans(), book_idx, Some(chapter_idx), None)
                         .unwrap_or((0, 10).into()),
                     book: book.name.clone(),
                     chapter: chapter.chapter,
@@ -395,44 +566,111 @@ fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
                 });
             }
 
+            // Check duplicates before gaps, as a duplicated number also shows up as a missing one
+            let errors_before_verses = errors.len();
             let mut seen_verses = std::collections::HashSet::new();
-            for i in 1..=verse_count {
-                if !chapter.verses.iter().any(|v| v.verse == i) {
-                    return Err(VerificationError::MissingVerse {
-                        src: NamedSource::new(&filename, content.clone()),
-                        span: find_json_span(&content, book_idx, Some(chapter_idx), None)
+            for (verse_idx, verse) in chapter.verses.iter().enumerate() {
+                if !seen_verses.insert(verse.verse) {
+                    errors.push(VerificationError::DuplicateVerse {
+                        src: src(),
+                        span: find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "verse")
                             .unwrap_or((0, 10).into()),
                         book: book.name.clone(),
                         chapter: chapter.chapter,
-                        verse: i,
+                        verse: verse.verse,
                     });
                 }
             }
 
+            if errors.len() == errors_before_verses {
+                for i in 1..=verse_count {
+                    if !chapter.verses.iter().any(|v| v.verse == i) {
+                        errors.push(VerificationError::MissingVerse {
+                            src: src(),
+                            span: find_json_span(spans(), book_idx, Some(chapter_idx), None)
+                                .unwrap_or((0, 10).into()),
+                            book: book.name.clone(),
+                            chapter: chapter.chapter,
+                            verse: i,
+                        });
+                    }
+                }
+            }
+
+            let verses_numbered = errors.len() == errors_before_verses;
             for (verse_idx, verse) in chapter.verses.iter().enumerate() {
-                if !seen_verses.insert(verse.verse) {
-                    return Err(VerificationError::DuplicateVerse {
-                        src: NamedSource::new(&filename, content.clone()),
-                        span: find_json_span(&content, book_idx, Some(chapter_idx), Some(verse_idx))
+                if verses_numbered && verse.verse != verse_idx + 1 {
+                    errors.push(VerificationError::VerseOutOfOrder {
+                        src: src(),
+                        span: find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "verse")
                             .unwrap_or((0, 10).into()),
                         book: book.name.clone(),
                         chapter: chapter.chapter,
                         verse: verse.verse,
+                        position: verse_idx + 1,
+                    });
+                }
+
+                let previous = verse_idx.checked_sub(1).map(|i| &chapter.verses[i]);
+                // Empty verses are reported as suspicious verses below
+                if let Some(previous) = previous.filter(|previous| {
+                    !verse.text.trim().is_empty()
+                        && normalize_text(&previous.text) == normalize_text(&verse.text)
+                }) {
+                    errors.push(VerificationError::IdenticalVerses {
+                        src: src(),
+                        span: find_json_span(spans(), book_idx, Some(chapter_idx), Some(verse_idx))
+                            .unwrap_or((0, 10).into()),
+                        book: book.name.clone(),
+                        chapter: chapter.chapter,
+                        verse: verse.verse,
+                        previous_verse: previous.verse,
+                    });
+                }
+
+                if verse.chapter != chapter.chapter {
+                    errors.push(VerificationError::InconsistentChapter {
+                        src: src(),
+                        span: find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "chapter")
+                            .unwrap_or((0, 10).into()),
+                        book: book.name.clone(),
+                        chapter: chapter.chapter,
+                        verse: verse.verse,
+                        found: verse.chapter,
+                    });
+                }
+
+                let expected = format!("{} {}:{}", book.name, chapter.chapter, verse.verse);
+                if verse.name.is_empty() {
+                    errors.push(VerificationError::MissingName {
+                        src: src(),
+                        span: find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "name")
+                            .or_else(|| find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "verse"))
+                            .unwrap_or((0, 10).into()),
+                        expected,
+                    });
+                } else if book_name_valid && verse.name != expected {
+                    errors.push(VerificationError::InconsistentName {
+                        src: src(),
+                        span: find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "name")
+                            .unwrap_or((0, 10).into()),
+                        found: verse.name.clone(),
+                        expected,
                     });
                 }
 
                 let word_count = verse.text.split_whitespace().count();
                 // Allow 2-word verses as there are a few in the Bible
-                if word_count < 2 || word_count > 150 {
+                if !(2..=150).contains(&word_count) {
                     let help = if word_count < 2 {
                         "Bible verses should have at least 2 words".to_string()
                     } else {
                         "Very few Bible verses exceed 150 words".to_string()
                     };
-                    
-                    return Err(VerificationError::SuspiciousVerse {
-                        src: NamedSource::new(&filename, content.clone()),
-                        span: find_json_span(&content, book_idx, Some(chapter_idx), Some(verse_idx))
+
+                    errors.push(VerificationError::SuspiciousVerse {
+                        src: src(),
+                        span: find_json_span(spans(), book_idx, Some(chapter_idx), Some(verse_idx))
                             .unwrap_or((0, 10).into()),
                         book: book.name.clone(),
                         chapter: chapter.chapter,
@@ -445,17 +683,375 @@ fn verify_bible(path: &PathBuf) -> Result<(), VerificationError> {
         }
     }
 
+    errors.extend(encoding_error(&bible, src(), &content, spans));
+    errors
+}
+
+// Encoding problems usually repeat throughout a file, so all affected verses are
+// collected and listed in the help of the first one
+fn encoding_error<'a>(
+    bible: &Bible,
+    src: NamedSource<Arc<String>>,
+    content: &str,
+    spans: impl Fn() -> &'a SpanIndex,
+) -> Option<VerificationError> {
+    let mut problems = Vec::new();
+    for (book_idx, book) in bible.books.iter().enumerate() {
+        for (chapter_idx, chapter) in book.chapters.iter().enumerate() {
+            for (verse_idx, verse) in chapter.verses.iter().enumerate() {
+                if let Some(problem) = find_encoding_problem(&verse.text) {
+                    problems.push((book_idx, chapter_idx, verse_idx, problem));
+                }
+            }
+        }
+    }
+
+    let (book_idx, chapter_idx, verse_idx, problem) = problems.first().cloned()?;
+    let book = &bible.books[book_idx];
+    let chapter = &book.chapters[chapter_idx];
+    let verse = &chapter.verses[verse_idx];
+
+    let references: Vec<String> = problems
+        .iter()
+        .skip(1)
+        .take(5)
+        .map(|(book_idx, chapter_idx, verse_idx, _)| {
+            let book = &bible.books[*book_idx];
+            let chapter = &book.chapters[*chapter_idx];
+            format!("{} {}:{}", book.name, chapter.chapter, chapter.verses[*verse_idx].verse)
+        })
+        .collect();
+    let affected = match problems.len() - 1 {
+        0 => "No other verses are affected.".to_string(),
+        others if others > references.len() => format!(
+            "Also found in {} and {} more.",
+            references.join(", "),
+            others - references.len()
+        ),
+        _ => format!("Also found in {}.", references.join(", ")),
+    };
+    let span = find_verse_field_span(spans(), book_idx, chapter_idx, verse_idx, "text")
+        .unwrap_or((0, 10).into());
+
+    match problem {
+        EncodingProblem::Mojibake {
+            sequence,
+            intended,
+            encoding,
+        } => {
+            let correct = bible
+                .books
+                .iter()
+                .flat_map(|book| &book.chapters)
+                .flat_map(|chapter| &chapter.verses)
+                .filter(|verse| verse.text.contains(intended))
+                .count();
+            let mixed = if correct > 0 {
+                format!(
+                    " '{}' is written correctly in {} verse{}, so the file mixes encodings.",
+                    intended,
+                    correct,
+                    if correct == 1 { "" } else { "s" }
+                )
+            } else {
+                String::new()
+            };
+
+            // Narrow the span to the sequence when the JSON has it unescaped
+            let text_start = span.offset();
+            let span = content[text_start..text_start + span.len()]
+                .find(&sequence)
+                .map(|pos| (text_start + pos, sequence.len()).into())
+                .unwrap_or(span);
+
+            Some(VerificationError::Mojibake {
+                src,
+                span,
+                book: book.name.clone(),
+                chapter: chapter.chapter,
+                verse: verse.verse,
+                sequence,
+                intended,
+                encoding,
+                help: format!(
+                    "The text was saved as UTF-8 and then read as {}.{} {}",
+                    encoding, mixed, affected
+                ),
+            })
+        }
+        EncodingProblem::InvalidCharacter(c) => Some(VerificationError::InvalidCharacter {
+            src,
+            span,
+            book: book.name.clone(),
+            chapter: chapter.chapter,
+            verse: verse.verse,
+            description: describe_character(c),
+            help: format!("Verse texts should only contain printable characters. {}", affected),
+        }),
+    }
+}
+
+// Directories are searched recursively for .json files
+fn collect_files(paths: &[PathBuf], files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for path in paths {
+        if path.is_dir() {
+            let mut entries = fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<std::io::Result<Vec<_>>>()?;
+            entries.sort();
+            entries.retain(|entry| entry.is_dir() || entry.extension().is_some_and(|ext| ext == "json"));
+            collect_files(&entries, files)?;
+        } else {
+            files.push(path.clone());
+        }
+    }
+    Ok(())
+}
+
+// The first label says the most about a problem, e.g. "Genesis 1:3 has 0 words"
+fn summarize(error: &VerificationError) -> String {
+    error
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .and_then(|label| label.label().map(str::to_string))
+        .unwrap_or_else(|| error.to_string())
+}
+
+fn print_summary(results: &[(String, Vec<VerificationError>)]) {
+    let width = results
+        .iter()
+        .map(|(file, _)| file.len())
+        .chain(["File".len()])
+        .max()
+        .unwrap_or(0);
+
+    println!("{:<width$}  Result  Errors  First problem", "File");
+    for (file, errors) in results {
+        let result = if errors.is_empty() { "pass" } else { "FAIL" };
+        let first = errors.first().map(summarize).unwrap_or_default();
+        println!("{:<width$}  {:<6}  {:>6}  {}", file, result, errors.len(), first);
+    }
+}
+
+// Print the fixes repair can make and, when asked, write a corrected copy
+fn suggest_fixes(path: &PathBuf, fix_output: Option<&PathBuf>) -> miette::Result<()> {
+    let content = fs::read_to_string(path).map_err(VerificationError::from)?;
+    // Invalid JSON can't be repaired
+    let Ok(mut bible) = serde_json::from_str::<Bible>(&content) else {
+        return Ok(());
+    };
+    let fixes = repair::repair_bible(&mut bible);
+    if fixes.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!("Suggested fixes:");
+    for fix in &fixes {
+        eprintln!("  - {}", fix);
+    }
+
+    let Some(fix_output) = fix_output else {
+        eprintln!("Run again with --fix-output <FILE> to write a copy with these fixes");
+        return Ok(());
+    };
+
+    // Same layout as the source files
+    let mut json = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut json, formatter);
+    bible
+        .serialize(&mut serializer)
+        .map_err(|e| miette::miette!("{}", e))?;
+    json.push(b'\n');
+    fs::write(fix_output, json).map_err(VerificationError::from)?;
+
+    let remaining = verify_bible(fix_output).len();
+    eprintln!(
+        "Wrote {} with {} problem{} left",
+        fix_output.display(),
+        remaining,
+        if remaining == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+// Write a Bible JSON file in the site's binary format
+fn encode_file(path: &PathBuf, output: &PathBuf) -> miette::Result<()> {
+    let content = fs::read_to_string(path).map_err(VerificationError::from)?;
+    let bible: Bible = serde_json::from_str(&content)
+        .map_err(|e| miette::miette!("{} is not a Bible JSON file: {}", path.display(), e))?;
+    let bytes = binary::encode_bible(&bible).map_err(|e| miette::miette!("{}", e))?;
+    fs::write(output, &bytes).map_err(VerificationError::from)?;
+    println!(
+        "Wrote {} ({} bytes, {} as JSON)",
+        output.display(),
+        bytes.len(),
+        content.len()
+    );
     Ok(())
 }
 
 fn main() -> miette::Result<()> {
     let args = Args::parse();
-    
-    match verify_bible(&args.file) {
-        Ok(()) => {
-            println!("✓ Bible JSON file is valid");
-            Ok(())
+
+    let mut files = Vec::new();
+    collect_files(&args.files, &mut files).map_err(VerificationError::from)?;
+    let batch = files.len() > 1 || args.files.iter().any(|path| path.is_dir());
+    if files.is_empty() {
+        return Err(miette::miette!("No .json files found"));
+    }
+    if batch && args.fix_output.is_some() {
+        return Err(miette::miette!("--fix-output works on a single file"));
+    }
+    if let Some(output) = &args.encode {
+        if batch {
+            return Err(miette::miette!("--encode works on a single file"));
+        }
+        return encode_file(&files[0], output);
+    }
+
+    // Statistics also help when validation fails, e.g. to spot truncated chapters
+    if args.stats {
+        let mut all_stats = std::collections::BTreeMap::new();
+        for file in &files {
+            // Unreadable files and invalid JSON are reported by the verification below
+            let Ok(content) = fs::read_to_string(file) else {
+                continue;
+            };
+            if let Ok(bible) = serde_json::from_str::<Bible>(&content) {
+                all_stats.insert(file.display().to_string(), stats::compute_stats(&bible));
+            }
+        }
+
+        if args.json {
+            let json = if batch {
+                serde_json::to_string_pretty(&all_stats)
+            } else {
+                serde_json::to_string_pretty(&all_stats.values().next())
+            }
+            .map_err(|e| miette::miette!("{}", e))?;
+            println!("{}", json);
+        } else {
+            for (file, stats) in &all_stats {
+                if batch {
+                    println!("\n{}:", file);
+                }
+                stats::print_stats(stats);
+            }
         }
-        Err(e) => Err(e.into()),
     }
-}
\ No newline at end of file
+
+    if batch {
+        let results: Vec<(String, Vec<VerificationError>)> = files
+            .iter()
+            .map(|file| (file.display().to_string(), verify_bible(file)))
+            .collect();
+        print_summary(&results);
+
+        let failed = results.iter().filter(|(_, errors)| !errors.is_empty()).count();
+        if failed > 0 {
+            return Err(miette::miette!(
+                "{} of {} files failed verification",
+                failed,
+                results.len()
+            ));
+        }
+        println!("✓ All {} Bible JSON files are valid", results.len());
+        return Ok(());
+    }
+
+    let errors = verify_bible(&files[0]);
+    let count = errors.len();
+    if count == 0 {
+        println!("✓ Bible JSON file is valid");
+        return Ok(());
+    }
+    for error in errors.into_iter().take(MAX_REPORTED_ERRORS) {
+        eprintln!("{:?}", miette::Report::new(error));
+    }
+    suggest_fixes(&files[0], args.fix_output.as_ref())?;
+
+    Err(miette::miette!(
+        "Found {} problem{}",
+        count,
+        if count == 1 { "" } else { "s" }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_output() {
+        let fix_output = std::env::temp_dir().join("bible_verify_test_repair.json");
+        suggest_fixes(&PathBuf::from("src/test_repair.json"), Some(&fix_output)).unwrap();
+
+        let fixed = fs::read_to_string(&fix_output).unwrap();
+        fs::remove_file(&fix_output).unwrap();
+        assert_eq!(fixed, include_str!("test_repair_fixed.json"));
+    }
+
+    // The diagnostic codes of every problem found, in the order they are reported
+    fn error_codes(file: &str) -> Vec<String> {
+        verify_bible(&PathBuf::from(file))
+            .iter()
+            .map(|error| error.code().map(|code| code.to_string()).unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_verses() {
+        let errors = verify_bible(&PathBuf::from("src/test_identical_verses.json"));
+        assert!(matches!(
+            errors.last(),
+            Some(VerificationError::IdenticalVerses {
+                verse: 3,
+                previous_verse: 2,
+                ..
+            })
+        ));
+        assert_eq!(
+            error_codes("src/test_identical_verses.json"),
+            ["bible_verify::book_count", "bible_verify::identical_verses"]
+        );
+    }
+
+    #[test]
+    fn test_order_errors() {
+        assert_eq!(
+            error_codes("src/test_book_order.json"),
+            ["bible_verify::book_order", "bible_verify::book_order"]
+        );
+        // Every chapter and verse out of place is reported, not just the first
+        assert_eq!(
+            error_codes("src/test_order_error.json"),
+            [
+                "bible_verify::book_count",
+                "bible_verify::chapter_order",
+                "bible_verify::chapter_order",
+                "bible_verify::verse_order",
+                "bible_verify::verse_order",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_name_errors() {
+        let errors = verify_bible(&PathBuf::from("src/test_name_error.json"));
+        assert!(matches!(
+            &errors[2],
+            VerificationError::InconsistentName { found, expected, .. }
+                if found == "Genesis 1:5" && expected == "Genesis 1:2"
+        ));
+        assert_eq!(
+            error_codes("src/test_name_error.json"),
+            [
+                "bible_verify::book_count",
+                "bible_verify::missing_name",
+                "bible_verify::inconsistent_name",
+                "bible_verify::missing_name",
+            ]
+        );
+    }
+}
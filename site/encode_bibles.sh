@@ -0,0 +1,27 @@
+#!/bin/sh
+# Encode the translations in ../sources into the binary format the site downloads
+# first, as bibles/{short_name}.bibl. Without the sources or bible-verify, as in the
+# Nix build, nothing is encoded and translations are downloaded as JSON.
+set -e
+cd "$(dirname "$0")"
+mkdir -p bibles
+if [ ! -d ../sources ] || [ ! -f ../bible-verify/Cargo.toml ]; then
+    exit 0
+fi
+
+encode() {
+    source="../sources/$1"
+    output="bibles/$2.bibl"
+    if [ "$output" -nt "$source" ]; then
+        return
+    fi
+    cargo run --quiet --release --manifest-path ../bible-verify/Cargo.toml -- \
+        "$source" --encode "$output"
+}
+
+encode nl/svv.json nl_sv
+encode nl/canicius1939.json pcv
+encode en/kjv.json en_kjv
+encode en/akjv.json en_akjv
+encode en/asv.json en_asv
+encode en/mkjv.json en_mkjv
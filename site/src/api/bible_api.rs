@@ -1,66 +1,223 @@
-use crate::core::{init_bible_signal, Bible, BIBLE};
+use crate::core::types::Language;
+use crate::core::{
+    decode_bible, init_bible_signal, is_binary_bible, set_book_loader, Bible, BibleIndex, Book,
+};
 use crate::storage::translations::get_current_translation;
-use gloo_net::http::Request;
-use leptos::prelude::Set;
+use crate::storage::{
+    get_selected_translation, is_translation_downloaded, load_downloaded_book,
+    load_translation_index,
+};
+use crate::translation_map::translation::Translation;
+use gloo_net::http::{Request, Response};
+use leptos::prelude::{Set, Update, WithUntracked};
+use leptos::task::spawn_local;
 use rexie::{ObjectStore, Rexie, TransactionMode};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Where the books of the Bible being read are stored
+#[derive(Debug, Clone, PartialEq)]
+pub enum BookSource {
+    /// The Bible fetched at startup when no translation is downloaded
+    BibleCache,
+    /// A downloaded translation, by short name
+    Translation(String),
+}
+
+struct CurrentBooks {
+    source: BookSource,
+    /// Language the book names are translated to, like the Bible opened at startup
+    language: Option<Language>,
+    /// Books that are loaded or being read
+    requested: HashSet<usize>,
+}
+
+thread_local! {
+    static CURRENT_BOOKS: RefCell<Option<CurrentBooks>> = const { RefCell::new(None) };
+}
+
+/// Make `bible` the current translation. Books it has no verses for are read
+/// from `source` the first time one of their chapters is looked up.
+pub fn set_current_bible(bible: Bible, source: BookSource, language: Option<Language>) {
+    let requested = bible
+        .books
+        .iter()
+        .enumerate()
+        .filter(|(_, book)| book.is_loaded())
+        .map(|(book_index, _)| book_index)
+        .collect();
+    CURRENT_BOOKS.with_borrow_mut(|current| {
+        *current = Some(CurrentBooks {
+            source,
+            language,
+            requested,
+        })
+    });
+    set_book_loader(load_book);
+    init_bible_signal().set(Some(bible));
+}
+
+// Marks a book as requested, returning where to read it from unless it already was
+fn request_book(book_index: usize) -> Option<BookSource> {
+    CURRENT_BOOKS.with_borrow_mut(|current| {
+        let current = current.as_mut()?;
+        current
+            .requested
+            .insert(book_index)
+            .then(|| current.source.clone())
+    })
+}
+
+/// Start reading the book at `book_index` of the current translation, unless it is
+/// loaded or already being read. The Bible signal updates once it is in.
+pub fn load_book(book_index: usize) {
+    let Some(source) = request_book(book_index) else {
+        return;
+    };
+    spawn_local(async move {
+        if let Err(e) = read_book_into_bible(&source, book_index).await {
+            leptos::logging::error!("Failed to load book {}: {}", book_index, e);
+            // Let a later lookup try again
+            CURRENT_BOOKS.with_borrow_mut(|current| {
+                if let Some(current) = current.as_mut().filter(|c| c.source == source) {
+                    current.requested.remove(&book_index);
+                }
+            });
+        }
+    });
+}
+
+/// Read every book of the current translation that is not loaded yet, for features
+/// that go through the whole Bible, such as exports
+pub async fn load_all_books() {
+    let book_count = init_bible_signal()
+        .with_untracked(|bible| bible.as_ref().map_or(0, |bible| bible.books.len()));
+    for book_index in 0..book_count {
+        let is_loaded = init_bible_signal().with_untracked(|bible| {
+            bible
+                .as_ref()
+                .and_then(|bible| bible.books.get(book_index))
+                .is_none_or(|book| book.is_loaded())
+        });
+        if is_loaded {
+            continue;
+        }
+        // A book already being read by `load_book` is read again rather than waited for
+        request_book(book_index);
+        let Some(source) =
+            CURRENT_BOOKS.with_borrow(|current| current.as_ref().map(|c| c.source.clone()))
+        else {
+            return;
+        };
+        if let Err(e) = read_book_into_bible(&source, book_index).await {
+            leptos::logging::error!("Failed to load book {}: {}", book_index, e);
+        }
+    }
+}
+
+async fn read_book_into_bible(
+    source: &BookSource,
+    book_index: usize,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut book = match source {
+        BookSource::BibleCache => load_book_from_cache(book_index).await?,
+        BookSource::Translation(short_name) => load_downloaded_book(short_name, book_index).await?,
+    };
+
+    let Some(language) = CURRENT_BOOKS.with_borrow(|current| {
+        current
+            .as_ref()
+            .filter(|current| &current.source == source)
+            .map(|current| current.language)
+    }) else {
+        // Another translation was opened while the book was read
+        return Ok(());
+    };
+    if let Some(language) = language {
+        book.translate_names(Translation::from_language(language));
+    }
+
+    init_bible_signal().update(|bible| {
+        if let Some(slot) = bible
+            .as_mut()
+            .and_then(|bible| bible.books.get_mut(book_index))
+        {
+            *slot = Arc::new(book);
+        }
+    });
+    Ok(())
+}
 
 pub async fn init_bible() -> std::result::Result<(), Box<dyn std::error::Error>> {
-    if BIBLE.get().is_some() {
+    if init_bible_signal().with_untracked(Option::is_some) {
         return Ok(());
     }
 
-    let mut bible = load_or_fetch_bible().await?;
+    let (mut bible, source) = load_or_fetch_bible().await?;
 
     // Apply translation mapping based on current translation language
-    if let Some(current_translation) = get_current_translation() {
-        if let Some(language) = current_translation.languages.first() {
-            bible = bible.translate_names(*language);
-        }
+    let language =
+        get_current_translation().and_then(|translation| translation.languages.first().copied());
+    if let Some(language) = language {
+        bible = bible.translate_names(language);
     }
 
-    BIBLE
-        .set(bible.clone())
-        .map_err(|_| "Failed to set Bible data")?;
-    let bible_signal = init_bible_signal();
-    bible_signal.set(Some(bible));
+    set_current_bible(bible, source, language);
 
     Ok(())
 }
 
-async fn load_or_fetch_bible() -> std::result::Result<Bible, Box<dyn std::error::Error>> {
-    use crate::storage::{
-        get_selected_translation, is_translation_downloaded, load_downloaded_translation,
-    };
-
+/// The Bible to open at startup. Only book names and chapter counts are read from
+/// the caches; a Bible that had to be fetched is complete.
+async fn load_or_fetch_bible(
+) -> std::result::Result<(Bible, BookSource), Box<dyn std::error::Error>> {
     if let Some(selected_translation) = get_selected_translation() {
         if is_translation_downloaded(&selected_translation) {
-            if let Ok(bible) = load_downloaded_translation(&selected_translation).await {
-                return Ok(bible);
+            if let Ok(index) = load_translation_index(&selected_translation).await {
+                return Ok((
+                    Bible::from_index(&index),
+                    BookSource::Translation(selected_translation),
+                ));
             }
         }
     }
 
-    match load_bible_from_cache().await {
-        Ok(cached_bible) => return Ok(cached_bible),
+    match load_index_from_cache().await {
+        Ok(index) => Ok((Bible::from_index(&index), BookSource::BibleCache)),
         Err(_) => {
             let bible = fetch_bible_from_api().await?;
 
             let _ = save_bible_to_cache(&bible).await;
 
-            Ok(bible)
+            Ok((bible, BookSource::BibleCache))
         }
     }
 }
 
-async fn load_bible_from_cache() -> std::result::Result<Bible, Box<dyn std::error::Error>> {
-    const CACHE_VERSION: &str = "v1";
+/// Bumped when the layout of the cache changes; older caches are cleared and refetched
+const CACHE_VERSION: &str = "v2";
 
+/// The cache keeps the index and version in `bible_data` and every book as a
+/// separate record in `books`, so the Bible is never held as one large JSON string
+async fn open_bible_cache() -> std::result::Result<Rexie, Box<dyn std::error::Error>> {
     let rexie = Rexie::builder("BibleCache")
-        .version(1)
+        .version(2)
         .add_object_store(ObjectStore::new("bible_data"))
+        .add_object_store(ObjectStore::new("books"))
         .build()
         .await
         .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    Ok(rexie)
+}
+
+fn book_key(index: usize) -> String {
+    format!("book_{}", index)
+}
+
+/// Book names and chapter counts of the cached Bible
+async fn load_index_from_cache() -> std::result::Result<BibleIndex, Box<dyn std::error::Error>> {
+    let rexie = open_bible_cache().await?;
 
     let transaction = rexie
         .transaction(&["bible_data"], TransactionMode::ReadOnly)
@@ -88,50 +245,81 @@ async fn load_bible_from_cache() -> std::result::Result<Bible, Box<dyn std::erro
         Err(_) => return Err("Failed to read cache version".into()),
     }
 
-    let data_result = store.get("bible_json".into()).await;
-    match data_result {
-        Ok(Some(data_value)) => {
-            if let Some(json_str) = data_value.as_string() {
-                let bible: Bible = serde_json::from_str(&json_str)
-                    .map_err(|e| format!("Failed to parse cached data: {:?}", e))?;
-                Ok(bible)
-            } else {
-                Err("Invalid cached data format".into())
-            }
+    match store.get("bible_index".into()).await {
+        Ok(Some(index_value)) => {
+            let json_str = index_value
+                .as_string()
+                .ok_or("Invalid cached index format")?;
+            let index = serde_json::from_str(&json_str)
+                .map_err(|e| format!("Failed to parse cached index: {:?}", e))?;
+            Ok(index)
         }
-        Ok(None) => Err("No cached Bible data found".into()),
-        Err(_) => Err("Failed to read cached data".into()),
+        Ok(None) => Err("No cached Bible index found".into()),
+        Err(_) => Err("Failed to read cached index".into()),
     }
 }
 
-async fn save_bible_to_cache(bible: &Bible) -> std::result::Result<(), Box<dyn std::error::Error>> {
-    const CACHE_VERSION: &str = "v1";
+/// Read one book of the cached Bible
+async fn load_book_from_cache(
+    book_index: usize,
+) -> std::result::Result<Book, Box<dyn std::error::Error>> {
+    let rexie = open_bible_cache().await?;
 
-    let rexie = Rexie::builder("BibleCache")
-        .version(1)
-        .add_object_store(ObjectStore::new("bible_data"))
-        .build()
+    let transaction = rexie
+        .transaction(&["books"], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let books_store = transaction
+        .store("books")
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let book_value = books_store
+        .get(book_key(book_index).into())
         .await
-        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+        .map_err(|_| format!("Failed to read cached book {}", book_index))?
+        .ok_or_else(|| format!("Book {} missing from cache", book_index))?;
+    let json_str = book_value.as_string().ok_or("Invalid cached book format")?;
+    let book: Book = serde_json::from_str(&json_str)
+        .map_err(|e| format!("Failed to parse cached book {}: {:?}", book_index, e))?;
+    Ok(book)
+}
+
+async fn save_bible_to_cache(bible: &Bible) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let rexie = open_bible_cache().await?;
 
     let transaction = rexie
-        .transaction(&["bible_data"], TransactionMode::ReadWrite)
+        .transaction(&["bible_data", "books"], TransactionMode::ReadWrite)
         .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
     let store = transaction
         .store("bible_data")
         .map_err(|e| format!("Failed to get store: {:?}", e))?;
+    let books_store = transaction
+        .store("books")
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
-    let json_data = serde_json::to_string(bible)
-        .map_err(|e| format!("Failed to serialize Bible data: {:?}", e))?;
+    for (position, book) in bible.books.iter().enumerate() {
+        let json_data = serde_json::to_string(book)
+            .map_err(|e| format!("Failed to serialize {}: {:?}", book.name, e))?;
+        books_store
+            .put(&json_data.into(), Some(&book_key(position).into()))
+            .await
+            .map_err(|e| format!("Failed to save {}: {:?}", book.name, e))?;
+    }
 
+    let index_json = serde_json::to_string(&bible.index())
+        .map_err(|e| format!("Failed to serialize Bible index: {:?}", e))?;
     store
-        .put(&json_data.into(), Some(&"bible_json".into()))
+        .put(&index_json.into(), Some(&"bible_index".into()))
         .await
-        .map_err(|e| format!("Failed to save Bible data: {:?}", e))?;
+        .map_err(|e| format!("Failed to save Bible index: {:?}", e))?;
     store
         .put(&CACHE_VERSION.into(), Some(&"cache_version".into()))
         .await
         .map_err(|e| format!("Failed to save cache version: {:?}", e))?;
+    // Drop the single-record copy left behind by a v1 cache now that the books are stored
+    store
+        .delete("bible_json".into())
+        .await
+        .map_err(|e| format!("Failed to delete v1 Bible data: {:?}", e))?;
 
     transaction
         .commit()
@@ -142,28 +330,29 @@ async fn save_bible_to_cache(bible: &Bible) -> std::result::Result<(), Box<dyn s
 
 #[allow(dead_code)]
 pub async fn clear_bible_cache() -> std::result::Result<(), Box<dyn std::error::Error>> {
-    let rexie = Rexie::builder("BibleCache")
-        .version(1)
-        .add_object_store(ObjectStore::new("bible_data"))
-        .build()
-        .await
-        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    let rexie = open_bible_cache().await?;
 
     let transaction = rexie
-        .transaction(&["bible_data"], TransactionMode::ReadWrite)
+        .transaction(&["bible_data", "books"], TransactionMode::ReadWrite)
         .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
     let store = transaction
         .store("bible_data")
         .map_err(|e| format!("Failed to get store: {:?}", e))?;
+    let books_store = transaction
+        .store("books")
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
-    store
-        .delete("bible_json".into())
-        .await
-        .map_err(|e| format!("Failed to delete Bible data: {:?}", e))?;
-    store
-        .delete("cache_version".into())
+    // `bible_json` is the single-record layout used by v1 caches
+    for key in ["bible_json", "bible_index", "cache_version"] {
+        store
+            .delete(key.into())
+            .await
+            .map_err(|e| format!("Failed to delete {}: {:?}", key, e))?;
+    }
+    books_store
+        .clear()
         .await
-        .map_err(|e| format!("Failed to delete cache version: {:?}", e))?;
+        .map_err(|e| format!("Failed to clear cached books: {:?}", e))?;
 
     transaction
         .commit()
@@ -195,18 +384,29 @@ async fn fetch_bible_from_api() -> std::result::Result<Bible, Box<dyn std::error
 
 pub async fn try_fetch_bible(url: &str) -> std::result::Result<Bible, Box<dyn std::error::Error>> {
     let response = Request::get(url).send().await?;
+    read_bible_response(url, response).await
+}
 
-    let json_string = if url.contains("allorigins.win") {
+/// Parse a downloaded Bible, either in the binary format published at `/bibles` or
+/// as JSON. The allorigins proxy wraps the body in a JSON string.
+async fn read_bible_response(
+    url: &str,
+    response: Response,
+) -> std::result::Result<Bible, Box<dyn std::error::Error>> {
+    if url.contains("allorigins.win") {
         let wrapped: serde_json::Value = response.json().await?;
-        wrapped["contents"]
+        let json_string = wrapped["contents"]
             .as_str()
-            .ok_or("Failed to extract contents from allorigins response")?
-            .to_string()
-    } else {
-        response.text().await?
-    };
+            .ok_or("Failed to extract contents from allorigins response")?;
+        let bible: Bible = serde_json::from_str(json_string)?;
+        return Ok(bible);
+    }
 
-    let bible: Bible = serde_json::from_str(&json_string)?;
+    let bytes = response.binary().await?;
+    if is_binary_bible(&bytes) {
+        return Ok(decode_bible(&bytes)?);
+    }
+    let bible: Bible = serde_json::from_slice(&bytes)?;
     Ok(bible)
 }
 
@@ -223,18 +423,5 @@ where
 
     progress_callback(0.6, "Processing response...".to_string());
 
-    let json_string = if url.contains("allorigins.win") {
-        let wrapped: serde_json::Value = response.json().await?;
-        wrapped["contents"]
-            .as_str()
-            .ok_or("Failed to extract contents from allorigins response")?
-            .to_string()
-    } else {
-        response.text().await?
-    };
-
-    progress_callback(0.7, "Parsing Bible data...".to_string());
-
-    let bible: Bible = serde_json::from_str(&json_string)?;
-    Ok(bible)
+    read_bible_response(url, response).await
 }
@@ -1,3 +1,5 @@
 pub mod bible_api;
+pub mod translation_index;
 
 pub use bible_api::*;
+pub use translation_index::*;
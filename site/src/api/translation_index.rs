@@ -0,0 +1,24 @@
+use crate::storage::HostedRevision;
+use gloo_net::http::Request;
+use serde::Deserialize;
+
+/// Index of the latest hosted translation revisions, published alongside the app
+const TRANSLATION_INDEX_URL: &str = "/translations.json";
+
+#[derive(Deserialize)]
+struct TranslationIndex {
+    translations: Vec<HostedRevision>,
+}
+
+/// Fetch the hosted translation index. The query string bypasses the HTTP and
+/// service worker caches so a cached app still sees newly published revisions.
+pub async fn fetch_translation_index(
+) -> std::result::Result<Vec<HostedRevision>, Box<dyn std::error::Error>> {
+    let url = format!("{}?t={}", TRANSLATION_INDEX_URL, js_sys::Date::now() as u64);
+    let response = Request::get(&url).send().await?;
+    if !response.ok() {
+        return Err(format!("Failed to fetch translation index: {}", response.status()).into());
+    }
+    let index: TranslationIndex = response.json().await?;
+    Ok(index.translations)
+}
@@ -0,0 +1,156 @@
+use crate::i18n::{t, t_with};
+use crate::instructions::types::Instruction;
+use crate::storage::annotation_backup::{parse_annotation_backup, AnnotationBackup, ImportMode};
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::html::Input;
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, FileReader, HtmlInputElement};
+
+/// How many highlights, notes and pinned chapters a backup has, in the text for `key`
+fn annotation_counts(key: &str, backup: &AnnotationBackup) -> String {
+    t_with(
+        key,
+        &[
+            ("highlights", &backup.highlights.len().to_string()),
+            ("notes", &backup.notes.len().to_string()),
+            ("pinned", &backup.bookmarks.len().to_string()),
+        ],
+    )
+}
+
+/// Panel for restoring highlights, notes and pinned chapters from a JSON backup
+#[component]
+pub fn AnnotationImport(view_state: ViewStateSignal) -> impl IntoView {
+    let file_input_ref = NodeRef::<Input>::new();
+    let (backup, set_backup) = signal::<Option<Result<AnnotationBackup, String>>>(None);
+    let (mode, set_mode) = signal(ImportMode::Merge);
+    let (imported, set_imported) = signal::<Option<String>>(None);
+
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && view_state.with(|state| state.is_annotation_import_open) {
+            evt.prevent_default();
+            view_state.update(|state| {
+                state.execute(&Instruction::CloseAnnotationImport);
+            });
+        }
+    });
+
+    let on_file_change = move |ev: Event| {
+        set_backup.set(None);
+        set_imported.set(None);
+        let Some(file) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0))
+        else {
+            return;
+        };
+        let Ok(file_reader) = FileReader::new() else {
+            return;
+        };
+        let file_reader_clone = file_reader.clone();
+
+        let onload = Closure::wrap(Box::new(move |_: Event| {
+            let text = file_reader_clone
+                .result()
+                .ok()
+                .and_then(|result| result.as_string())
+                .unwrap_or_default();
+            set_backup.set(Some(parse_annotation_backup(&text)));
+        }) as Box<dyn FnMut(_)>);
+
+        file_reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        onload.forget();
+
+        let _ = file_reader.read_as_text(&file);
+    };
+
+    let handle_import = move |_| {
+        let Some(Ok(backup)) = backup.get_untracked() else {
+            return;
+        };
+        set_imported.set(Some(annotation_counts("annotations_imported", &backup)));
+        view_state.update(|state| state.import_annotations(backup, mode.get_untracked()));
+        set_backup.set(None);
+        if let Some(input) = file_input_ref.get() {
+            input.set_value("");
+        }
+    };
+
+    let mode_option = move |value: ImportMode, label: String, description: String| {
+        view! {
+            <label class="flex items-start gap-2">
+                <input
+                    type="radio"
+                    name="annotation-import-mode"
+                    class="mt-0.5"
+                    prop:checked=move || mode.get() == value
+                    on:change=move |_| set_mode.set(value)
+                />
+                <span>
+                    <span class="font-medium">{label}</span>
+                    <span class="block opacity-75">{description}</span>
+                </span>
+            </label>
+        }
+    };
+
+    view! {
+        <Show when=move || view_state.with(|state| state.is_annotation_import_open) fallback=|| view! { <></> }>
+            <div
+                class="fixed top-20 right-4 w-80 p-3 rounded-lg shadow-lg border z-40"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+            >
+                <div class="flex items-center justify-between mb-2">
+                    <h2 class="text-sm font-semibold">{t("import_annotations")}</h2>
+                    <button
+                        class="text-xs underline opacity-75 hover:opacity-100"
+                        aria-label=t("close_annotation_import")
+                        on:click=move |_| view_state.update(|state| {
+                            state.execute(&Instruction::CloseAnnotationImport);
+                        })
+                    >
+                        {t("close")}
+                    </button>
+                </div>
+                <input
+                    type="file"
+                    accept=".json,application/json"
+                    class="w-full text-xs mb-2"
+                    aria-label=t("annotation_backup_file")
+                    node_ref=file_input_ref
+                    on:change=on_file_change
+                />
+                <div class="space-y-1 text-xs mb-2">
+                    {mode_option(ImportMode::Merge, t("import_merge"), t("import_merge_description"))}
+                    {mode_option(ImportMode::Replace, t("import_replace"), t("import_replace_description"))}
+                </div>
+                {move || match backup.get() {
+                    Some(Err(error)) => view! {
+                        <p class="text-xs mb-2 text-red-600">{error}</p>
+                    }.into_any(),
+                    Some(Ok(backup)) => view! {
+                        <p class="text-xs mb-2 opacity-75">
+                            {annotation_counts("annotations_in_backup", &backup)}
+                        </p>
+                    }.into_any(),
+                    None => view! {
+                        <p class="text-xs mb-2 opacity-75">{move || imported.get()}</p>
+                    }.into_any(),
+                }}
+                <button
+                    class="w-full px-3 py-1 text-sm rounded border disabled:opacity-50"
+                    style="border-color: var(--theme-sidebar-border)"
+                    disabled=move || !matches!(backup.get(), Some(Ok(_)))
+                    on:click=handle_import
+                >
+                    {t("import")}
+                </button>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,81 @@
+use crate::storage::book_abbreviations::{
+    add_user_book_abbreviation, get_user_book_abbreviations, remove_user_book_abbreviation,
+};
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+
+/// Settings section for adding book abbreviations on top of the built-in tables
+#[component]
+pub fn BookAbbreviationSettings() -> impl IntoView {
+    let abbreviations = RwSignal::new(get_user_book_abbreviations());
+    let (abbreviation, set_abbreviation) = signal(String::new());
+    let (book, set_book) = signal(String::new());
+    let (error, set_error) = signal::<Option<String>>(None);
+
+    let handle_add = move |_| match add_user_book_abbreviation(&abbreviation.get(), &book.get()) {
+        Ok(()) => {
+            abbreviations.set(get_user_book_abbreviations());
+            set_abbreviation.set(String::new());
+            set_book.set(String::new());
+            set_error.set(None);
+        }
+        Err(e) => set_error.set(Some(e)),
+    };
+
+    let handle_remove = move |abbreviation: String| {
+        remove_user_book_abbreviation(&abbreviation);
+        abbreviations.set(get_user_book_abbreviations());
+    };
+
+    view! {
+        <div class="mt-4 pt-4 border-t space-y-2" style="border-color: var(--theme-sidebar-border)">
+            <h3 class="text-sm font-semibold" style="color: var(--theme-sidebar-text)">"Book abbreviations"</h3>
+            <div class="flex gap-2">
+                <input
+                    type="text"
+                    class="w-20 px-2 py-1 text-xs font-mono border rounded"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    placeholder="mk"
+                    prop:value=move || abbreviation.get()
+                    on:input=move |ev| set_abbreviation.set(event_target_value(&ev))
+                    on:keydown=|ev: KeyboardEvent| ev.stop_propagation()
+                />
+                <input
+                    type="text"
+                    class="flex-1 min-w-0 px-2 py-1 text-xs border rounded"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    placeholder="Mark"
+                    prop:value=move || book.get()
+                    on:input=move |ev| set_book.set(event_target_value(&ev))
+                    on:keydown=|ev: KeyboardEvent| ev.stop_propagation()
+                />
+            </div>
+            <button
+                class="w-full px-3 py-1 text-sm rounded transition-colors"
+                style="background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text)"
+                on:click=handle_add
+            >
+                "Add abbreviation"
+            </button>
+            <Show when=move || error.get().is_some() fallback=|| view! { <></> }>
+                <p class="text-xs text-red-600">{move || error.get().unwrap_or_default()}</p>
+            </Show>
+            <ul class="space-y-1">
+                {move || abbreviations.get().into_iter().map(|(abbreviation, key)| {
+                    let label = format!("{} → {}", abbreviation, key.replace('_', " "));
+                    view! {
+                        <li class="flex items-center justify-between gap-2 text-xs" style="color: var(--theme-text-secondary)">
+                            <span class="truncate font-mono">{label}</span>
+                            <button
+                                class="underline opacity-75 hover:opacity-100"
+                                on:click=move |_| handle_remove(abbreviation.clone())
+                            >
+                                "Remove"
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
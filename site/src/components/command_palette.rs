@@ -1,11 +1,23 @@
-use crate::core::{Bible, Chapter, VerseRange};
+use crate::core::{init_bible_signal, Bible, Book, Chapter, VerseRange};
+use crate::i18n::{t, t_with};
 use crate::instructions::processor::InstructionProcessor;
 use crate::instructions::types::Instruction;
 use crate::instructions::vim_keys::KeyboardMappings;
-use crate::storage::recent_chapters::get_recent_chapters;
+use crate::storage::palette_aliases::{find_palette_alias, get_palette_aliases};
+use crate::storage::pinned_chapters::{
+    get_pinned_chapters, toggle_pinned_chapter, PinnedChapter,
+};
+use crate::storage::recent_chapters::{
+    clear_recent_chapters, get_recent_chapters, get_recent_chapters_limit, is_history_private,
+    save_history_private, save_recent_chapters_limit, RECENT_CHAPTERS_LIMITS,
+};
+use crate::storage::recent_searches::{add_recent_search, get_recent_searches};
+use crate::storage::registers::get_registers;
+use crate::storage::workspaces::{delete_workspace, find_workspace, get_workspaces, Workspace};
+use crate::storage::{HighlightColor, VerseNote};
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
-use crate::view_state::ViewStateSignal;
+use crate::view_state::{InstructionResult, ViewStateSignal};
 use leptos::prelude::*;
 use leptos::web_sys::KeyboardEvent;
 use leptos_router::hooks::{use_location, use_navigate};
@@ -34,6 +46,32 @@ pub enum SearchResult {
         display_name: String,
         path: String,
     },
+    PinnedChapter {
+        book_name: String,
+        chapter: u32,
+        display_name: String,
+        path: String,
+    },
+    Register {
+        name: char,
+        content: String,
+    },
+    Alias {
+        name: String,
+        target: String,
+    },
+    RecentSearch {
+        query: String,
+    },
+    Note {
+        display_name: String,
+        text: String,
+        path: String,
+    },
+    Workspace {
+        name: String,
+        is_saved: bool,
+    },
 }
 
 impl SearchResult {
@@ -49,6 +87,16 @@ impl SearchResult {
             }
             SearchResult::Instruction { name, .. } => name.clone(),
             SearchResult::RecentChapter { display_name, .. } => display_name.clone(),
+            SearchResult::PinnedChapter { display_name, .. } => display_name.clone(),
+            SearchResult::Register { name, .. } => format!("Register \"{}", name),
+            SearchResult::Alias { name, target } => format!("{} → {}", name, target),
+            SearchResult::RecentSearch { query } => query.clone(),
+            SearchResult::Note { display_name, .. } => display_name.clone(),
+            SearchResult::Workspace {
+                name,
+                is_saved: true,
+            } => name.clone(),
+            SearchResult::Workspace { name, .. } => format!("Save workspace \"{}\"", name),
         }
     }
 
@@ -79,8 +127,32 @@ impl SearchResult {
                 String::new()
             }
             SearchResult::RecentChapter { path, .. } => path.clone(),
+            SearchResult::PinnedChapter { path, .. } => path.clone(),
+            SearchResult::Register { .. } => {
+                // Registers are copied to the clipboard, not navigated to
+                String::new()
+            }
+            SearchResult::Alias { target, .. } if target.starts_with('/') => target.clone(),
+            SearchResult::Alias { .. } => String::new(),
+            SearchResult::RecentSearch { .. } => {
+                // Recent searches fill in the query instead of navigating
+                String::new()
+            }
+            SearchResult::Note { path, .. } => path.clone(),
+            SearchResult::Workspace { .. } => {
+                // Workspaces are saved or restored through the view state
+                String::new()
+            }
         }
     }
+
+    /// Whether the result row offers a pin/unpin action
+    pub fn is_pinnable(&self) -> bool {
+        matches!(
+            self,
+            SearchResult::RecentChapter { .. } | SearchResult::PinnedChapter { .. }
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -90,9 +162,43 @@ struct VerseReference {
     verse: Option<u32>,
 }
 
+/// Rewrite references pasted from other software into the "book chapter:verse" form,
+/// e.g. "Gen.1.1", "John 3.16" and "1Cor 13,4" become "gen 1:1", "john 3:16" and "1cor 13:4"
+fn normalize_reference(query: &str) -> String {
+    let mut chars: Vec<char> = query.trim().to_lowercase().chars().collect();
+    let is_digit_at = |chars: &[char], i: usize| chars.get(i).is_some_and(|c| c.is_ascii_digit());
+
+    // Without a colon, the last "." or "," between two numbers separates chapter and verse
+    if !chars.contains(&':') {
+        if let Some(pos) = (1..chars.len()).rev().find(|&i| {
+            matches!(chars[i], '.' | ',') && is_digit_at(&chars, i - 1) && is_digit_at(&chars, i + 1)
+        }) {
+            chars[pos] = ':';
+        }
+    }
+
+    let colon_pos = chars.iter().position(|c| *c == ':').unwrap_or(chars.len());
+    let mut normalized = String::with_capacity(chars.len() + 1);
+    for (i, c) in chars.iter().enumerate() {
+        if i < colon_pos {
+            // OSIS dots between book and chapter, and a missing space before the chapter
+            if *c == '.' {
+                normalized.push(' ');
+                continue;
+            }
+            if c.is_ascii_digit() && i > 0 && chars[i - 1].is_alphabetic() {
+                normalized.push(' ');
+            }
+        }
+        normalized.push(*c);
+    }
+    normalized
+}
+
 fn parse_verse_reference(query: &str) -> Option<VerseReference> {
-    // Handle formats like "gen 1:1", "genesis 1:5", "john 3:16", "mat 5:3-7", and "gen 1:" (incomplete)
-    let query = query.trim().to_lowercase();
+    // Handle formats like "gen 1:1", "genesis 1:5", "john 3:16", "mat 5:3-7", and "gen 1:" (incomplete),
+    // as well as "Gen.1.1", "John 3.16" and "1Cor 13,4"
+    let query = normalize_reference(query);
 
     // Look for colon indicating verse reference
     if let Some(colon_pos) = query.find(':') {
@@ -131,6 +237,218 @@ fn parse_verse_reference(query: &str) -> Option<VerseReference> {
     None
 }
 
+/// The percentage in a query like "50%", for jumping within the current book
+fn parse_book_percent(query: &str) -> Option<u8> {
+    let percent = query.trim().strip_suffix('%')?.trim().parse::<u8>().ok()?;
+    (percent <= 100).then_some(percent)
+}
+
+/// Split a book-scoped text search like `in:psalms refuge` or `ps/ refuge`
+/// into the book query and the text to search for
+fn parse_book_scope(query: &str) -> Option<(&str, &str)> {
+    let (book, text) = match query.strip_prefix("in:") {
+        Some(rest) => rest.trim_start().split_once(char::is_whitespace)?,
+        None => query.split_once("/ ")?,
+    };
+    let (book, text) = (book.trim(), text.trim());
+    if book.is_empty() || text.is_empty() || book.starts_with([':', '>', '"']) {
+        return None;
+    }
+    Some((book, text))
+}
+
+/// Match the reader's notes and pinned chapters against the text after `n:`,
+/// listing all of them when that text is empty. Notes come in Bible order.
+fn search_annotations(
+    bible: &Bible,
+    notes: &[VerseNote],
+    pinned_chapters: &[PinnedChapter],
+    query: &str,
+) -> Vec<(SearchResult, usize)> {
+    let query = normalize_text_for_search(query);
+    let mut results = Vec::new();
+
+    for chapter in bible.books.iter().flat_map(|book| &book.chapters) {
+        let key = bible.chapter_key(chapter);
+        let mut chapter_notes: Vec<&VerseNote> = notes
+            .iter()
+            .filter(|note| note.is_in(&key))
+            .collect();
+        chapter_notes.sort_by_key(|note| note.verse);
+
+        for note in chapter_notes {
+            let text = normalize_text_for_search(&note.text);
+            let score = if query.is_empty() || text.starts_with(&query) {
+                1000
+            } else if text.contains(&query) {
+                800
+            } else {
+                continue;
+            };
+            let verse_range = VerseRange {
+                start: note.verse,
+                end: note.verse,
+            };
+            results.push((
+                SearchResult::Note {
+                    display_name: format!("{}:{}", chapter.name, note.verse),
+                    text: note.text.clone(),
+                    path: chapter.to_path_with_verses(&[verse_range]),
+                },
+                score,
+            ));
+        }
+    }
+
+    results.extend(
+        pinned_chapters
+            .iter()
+            .filter(|pinned| normalize_text_for_search(&pinned.display_name).contains(&query))
+            .map(|pinned| {
+                (
+                    SearchResult::PinnedChapter {
+                        book_name: pinned.book_name.clone(),
+                        chapter: pinned.chapter,
+                        display_name: pinned.display_name.clone(),
+                        path: pinned.path.clone(),
+                    },
+                    600,
+                )
+            }),
+    );
+    results
+}
+
+/// Saved workspaces whose name contains the text after `ws:`, followed by an entry for
+/// saving the current layout under that text when no workspace has that name yet
+fn search_workspaces(workspaces: &[Workspace], query: &str) -> Vec<(SearchResult, usize)> {
+    let query = query.trim();
+    let query_lower = query.to_lowercase();
+    let mut results: Vec<(SearchResult, usize)> = workspaces
+        .iter()
+        .filter(|workspace| workspace.name.to_lowercase().contains(&query_lower))
+        .enumerate()
+        .map(|(index, workspace)| {
+            (
+                SearchResult::Workspace {
+                    name: workspace.name.clone(),
+                    is_saved: true,
+                },
+                1000 - index,
+            )
+        })
+        .collect();
+
+    if !query.is_empty() && find_workspace(workspaces, query).is_none() {
+        results.push((
+            SearchResult::Workspace {
+                name: query.to_string(),
+                is_saved: false,
+            },
+            1,
+        ));
+    }
+    results
+}
+
+/// Resolve a typed book name, preferring exact (translated) names over fuzzy matches
+fn find_book_by_query<'a>(bible: &'a Bible, book_query: &str) -> Option<&'a Book> {
+    let book_query = book_query.to_lowercase();
+    let translated = get_current_translation()
+        .and_then(|translation| translation.languages.first().copied())
+        .and_then(|language| Translation::from_language(language).get(&book_query))
+        .map(|name| name.to_lowercase());
+
+    bible
+        .find_book(&book_query)
+        .or_else(|| {
+            bible
+                .books
+                .iter()
+                .find(|book| Some(&book.name.to_lowercase()) == translated.as_ref())
+                .map(|book| &**book)
+        })
+        .or_else(|| {
+            // Reversed so the first book wins when scores tie
+            bible
+                .books
+                .iter()
+                .rev()
+                .map(|book| (book, fuzzy_score(&book.name.to_lowercase(), &book_query)))
+                .filter(|(_, score)| *score > 0)
+                .max_by_key(|(_, score)| *score)
+                .map(|(book, _)| &**book)
+        })
+}
+
+/// Replace an abbreviated book name in a chapter query ("mt 5") with the book's name
+fn expand_book_abbreviation(bible: &Bible, query: &str) -> Option<String> {
+    let query = query.trim();
+    let (book_part, chapter_part) = match query.rsplit_once(' ') {
+        Some((book, chapter)) if chapter.parse::<u32>().is_ok() => (book, Some(chapter)),
+        _ => (query, None),
+    };
+
+    let name = bible.find_book(book_part)?.name.to_lowercase();
+    Some(match chapter_part {
+        Some(chapter) => format!("{} {}", name, chapter),
+        None => name,
+    })
+}
+
+/// Search verse text in the given books, returning at most 50 scored matches
+fn search_verse_text<'a>(
+    books: impl IntoIterator<Item = &'a Book>,
+    query: &str,
+) -> Vec<(SearchResult, usize)> {
+    let mut verse_matches: Vec<(SearchResult, usize)> = Vec::new();
+    let mut search_count = 0;
+
+    // Normalize query once outside the loop for performance
+    let query_normalized = normalize_text_for_search(query);
+
+    'global_search: for book in books {
+        for chapter in &book.chapters {
+            for verse in &chapter.verses {
+                // Early exit if we have enough results
+                if search_count >= 50 {
+                    break 'global_search;
+                }
+
+                // Normalize verse text for search (no cache needed for real-time search)
+                let verse_text_normalized = normalize_text_for_search(&verse.text);
+                if verse_text_normalized.contains(&query_normalized) {
+                    // Score based on how early the match appears in the verse
+                    let match_position = verse_text_normalized
+                        .find(&query_normalized)
+                        .unwrap_or(verse_text_normalized.len());
+                    let score = if verse_text_normalized.starts_with(&query_normalized) {
+                        1000 // Starts with query
+                    } else if match_position < 10 {
+                        800 // Match near beginning
+                    } else if match_position < 30 {
+                        600 // Match in first part
+                    } else {
+                        400 // Match later in verse
+                    };
+
+                    verse_matches.push((
+                        SearchResult::Verse {
+                            chapter: chapter.clone(),
+                            verse_number: verse.verse,
+                            verse_text: verse.text.clone(),
+                        },
+                        score,
+                    ));
+                    search_count += 1;
+                }
+            }
+        }
+    }
+
+    verse_matches
+}
+
 fn score_verse_number_match(verse_number: u32, search_number: u32) -> usize {
     let verse_str = verse_number.to_string();
     let search_str = search_number.to_string();
@@ -186,6 +504,19 @@ fn vim_key_to_display(vim_key: &str) -> String {
 
 // Convert instruction enum name to user-friendly display name and description
 fn instruction_to_display(instruction_name: &str) -> (String, String) {
+    if let Some(color) = instruction_name
+        .strip_prefix("Highlight")
+        .and_then(HighlightColor::from_name)
+    {
+        return (
+            format!("Highlight {}", color.name()),
+            format!(
+                "Highlight the selected verses {}",
+                color.name().to_lowercase()
+            ),
+        );
+    }
+
     match instruction_name {
         "NextVerse" => (
             "Next Verse".to_string(),
@@ -219,6 +550,14 @@ fn instruction_to_display(instruction_name: &str) -> (String, String) {
             "End of Chapter".to_string(),
             "Go to the last verse of the chapter".to_string(),
         ),
+        "NextParagraph" => (
+            "Next Paragraph".to_string(),
+            "Jump forward by a paragraph".to_string(),
+        ),
+        "PreviousParagraph" => (
+            "Previous Paragraph".to_string(),
+            "Jump backward by a paragraph".to_string(),
+        ),
         "SwitchToPreviousChapter" => (
             "Switch to Previous Chapter".to_string(),
             "Go back to the previously viewed chapter".to_string(),
@@ -235,6 +574,14 @@ fn instruction_to_display(instruction_name: &str) -> (String, String) {
             "Toggle Sidebar".to_string(),
             "Show/hide the books sidebar".to_string(),
         ),
+        "CollapseAllBooks" => (
+            "Collapse All Books".to_string(),
+            "Hide the chapters of every book in the sidebar".to_string(),
+        ),
+        "ExpandCurrentBook" => (
+            "Expand Current Book".to_string(),
+            "Show the chapters of the current book in the sidebar".to_string(),
+        ),
         "ToggleCrossReferences" => (
             "Toggle Cross References".to_string(),
             "Show/hide cross-references panel".to_string(),
@@ -275,10 +622,26 @@ fn instruction_to_display(instruction_name: &str) -> (String, String) {
             "Random Chapter".to_string(),
             "Navigate to a random chapter in the Bible".to_string(),
         ),
+        "PsalmOfTheDay" => (
+            "Psalm of the Day".to_string(),
+            "Read the Psalm scheduled for today".to_string(),
+        ),
+        "ProverbOfTheDay" => (
+            "Proverb of the Day".to_string(),
+            "Read the Proverb matching today's date".to_string(),
+        ),
         "OpenAboutPage" => (
             "About".to_string(),
             "View information about this Bible website".to_string(),
         ),
+        "OpenPeopleIndex" => (
+            "People Index".to_string(),
+            "Browse biblical people and the verses naming them".to_string(),
+        ),
+        "OpenTimeline" => (
+            "Timeline".to_string(),
+            "View major biblical events on a timeline".to_string(),
+        ),
         "ShowTranslations" => (
             "Show Translations".to_string(),
             "Go to the translation selection page".to_string(),
@@ -303,6 +666,82 @@ fn instruction_to_display(instruction_name: &str) -> (String, String) {
             "Compare Translations".to_string(),
             "Open translation comparison panel for current verse".to_string(),
         ),
+        "TogglePlaceMap" => (
+            "Toggle Place Map".to_string(),
+            "Show places named in this chapter on a map".to_string(),
+        ),
+        "MarkChapterComplete" => (
+            "Mark Chapter Complete".to_string(),
+            "Mark the current chapter as read, or undo it".to_string(),
+        ),
+        "ShowRegisters" => (
+            "Show Registers".to_string(),
+            "List the named copy registers and copy one to the clipboard".to_string(),
+        ),
+        "ShowWorkspaces" => (
+            "Workspaces".to_string(),
+            "Save the current layout as a workspace or restore a saved one".to_string(),
+        ),
+        "RemoveHighlight" => (
+            "Remove Highlight".to_string(),
+            "Remove the highlight of the selected verses".to_string(),
+        ),
+        "ToggleHighlightLegend" => (
+            "Highlight Legend".to_string(),
+            "Show what each highlight color means".to_string(),
+        ),
+        "ToggleHighlightedOnly" => (
+            "Show Only Highlighted Verses".to_string(),
+            "Hide the verses of a chapter that aren't highlighted, or show them again".to_string(),
+        ),
+        "ToggleSourceText" => (
+            "Toggle Source Text".to_string(),
+            "Show the Hebrew or Greek text under each verse, instead of the translation, or hide it".to_string(),
+        ),
+        "ToggleTransliteration" => (
+            "Toggle Transliteration".to_string(),
+            "Show the Hebrew or Greek text in Latin letters under each verse".to_string(),
+        ),
+        "ToggleLexicon" => (
+            "Toggle Lexicon".to_string(),
+            "Look up dictionary entries for Hebrew and Greek words".to_string(),
+        ),
+        "ToggleSectionHeadings" => (
+            "Toggle Section Headings".to_string(),
+            "Show or hide section headings and chapter titles for plain text".to_string(),
+        ),
+        "ToggleReadAloud" => (
+            "Read Aloud".to_string(),
+            "Read the chapter aloud from the selected verse, or pause or resume reading".to_string(),
+        ),
+        "StopReadAloud" => (
+            "Stop Reading Aloud".to_string(),
+            "Stop reading the chapter aloud".to_string(),
+        ),
+        "ReadAloudFaster" => (
+            "Read Aloud Faster".to_string(),
+            "Speed up the voice reading the chapter".to_string(),
+        ),
+        "ReadAloudSlower" => (
+            "Read Aloud Slower".to_string(),
+            "Slow down the voice reading the chapter".to_string(),
+        ),
+        "EditNote" => (
+            "Edit Note".to_string(),
+            "Write a note on the selected verse".to_string(),
+        ),
+        "ExportAnnotations" => (
+            "Export Notes and Highlights".to_string(),
+            "Download your notes and highlights as a Markdown document".to_string(),
+        ),
+        "ExportAnnotationBackup" => (
+            "Back Up Annotations".to_string(),
+            "Download your highlights, notes and pinned chapters as a JSON file".to_string(),
+        ),
+        "ImportAnnotationBackup" => (
+            "Import Annotations".to_string(),
+            "Restore highlights, notes and pinned chapters from a JSON backup".to_string(),
+        ),
         _ => (
             instruction_name.to_string(),
             format!("Execute {}", instruction_name),
@@ -333,10 +772,14 @@ fn get_all_instructions() -> Vec<SearchResult> {
         "PreviousBook",
         "BeginningOfChapter",
         "EndOfChapter",
+        "NextParagraph",
+        "PreviousParagraph",
         "SwitchToPreviousChapter",
         "CopyRawVerse",
         "CopyVerseWithReference",
         "ToggleSidebar",
+        "CollapseAllBooks",
+        "ExpandCurrentBook",
         "ToggleCrossReferences",
         "ToggleBiblePallate",
         "ToggleCommandPallate",
@@ -347,19 +790,47 @@ fn get_all_instructions() -> Vec<SearchResult> {
         "OpenGithubRepository",
         "RandomVerse",
         "RandomChapter",
+        "PsalmOfTheDay",
+        "ProverbOfTheDay",
         "OpenAboutPage",
+        "OpenPeopleIndex",
+        "OpenTimeline",
         "ShowTranslations",
         "ToggleVersePallate",
         "ToggleTranslationComparison",
+        "TogglePlaceMap",
         "ExportToPDF",
         "ExportToMarkdown",
         "ExportLinkedMarkdown",
+        "MarkChapterComplete",
+        "ShowRegisters",
+        "ShowWorkspaces",
+        "RemoveHighlight",
+        "ToggleHighlightLegend",
+        "ToggleHighlightedOnly",
+        "ToggleSourceText",
+        "ToggleTransliteration",
+        "ToggleLexicon",
+        "ToggleSectionHeadings",
+        "ToggleReadAloud",
+        "StopReadAloud",
+        "ReadAloudFaster",
+        "ReadAloudSlower",
+        "EditNote",
+        "ExportAnnotations",
+        "ExportAnnotationBackup",
+        "ImportAnnotationBackup",
     ];
+    let highlight_instructions = HighlightColor::ALL.map(|color| format!("Highlight{}", color.name()));
 
-    for instruction in &all_possible_instructions {
-        if !instruction_shortcuts.contains_key(*instruction) {
-            instruction_shortcuts.insert(instruction.to_string(), vec!["No shortcut".to_string()]);
-        }
+    for instruction in all_possible_instructions
+        .iter()
+        .map(|name| name.to_string())
+        .chain(highlight_instructions)
+    {
+        instruction_shortcuts
+            .entry(instruction)
+            .or_insert_with(|| vec!["No shortcut".to_string()]);
     }
 
     // Create SearchResult for each unique instruction
@@ -399,7 +870,7 @@ fn get_current_chapter_from_bible(bible: &Bible, location_pathname: &str) -> Opt
 fn get_current_chapter(view_state: ViewStateSignal, location_pathname: &str) -> Option<Chapter> {
     view_state.with(|state| {
         if let Some(bible) = state.get_bible() {
-            get_current_chapter_from_bible(bible, location_pathname)
+            get_current_chapter_from_bible(&bible, location_pathname)
         } else {
             None
         }
@@ -428,6 +899,13 @@ fn update_view_state_from_url(view_state: ViewStateSignal, pathname: &str, searc
 }
 
 fn instruction_name_to_instruction(name: &str) -> Option<Instruction> {
+    if let Some(color) = name
+        .strip_prefix("Highlight ")
+        .and_then(HighlightColor::from_name)
+    {
+        return Some(Instruction::HighlightVerse(color));
+    }
+
     match name {
         "Next Verse" => Some(Instruction::NextVerse),
         "Previous Verse" => Some(Instruction::PreviousVerse),
@@ -437,10 +915,14 @@ fn instruction_name_to_instruction(name: &str) -> Option<Instruction> {
         "Previous Book" => Some(Instruction::PreviousBook),
         "Beginning of Chapter" => Some(Instruction::BeginningOfChapter),
         "End of Chapter" => Some(Instruction::EndOfChapter),
+        "Next Paragraph" => Some(Instruction::NextParagraph),
+        "Previous Paragraph" => Some(Instruction::PreviousParagraph),
         "Switch to Previous Chapter" => Some(Instruction::SwitchToPreviousChapter),
         "Copy Raw Verse" => Some(Instruction::CopyRawVerse),
         "Copy Verse with Reference" => Some(Instruction::CopyVerseWithReference),
         "Toggle Sidebar" => Some(Instruction::ToggleSidebar),
+        "Collapse All Books" => Some(Instruction::CollapseAllBooks),
+        "Expand Current Book" => Some(Instruction::ExpandCurrentBook),
         "Toggle Cross References" => Some(Instruction::ToggleCrossReferences),
         "Toggle Command Palette" => Some(Instruction::ToggleBiblePallate),
         "Open Command Palette" => Some(Instruction::ToggleCommandPallate),
@@ -451,17 +933,50 @@ fn instruction_name_to_instruction(name: &str) -> Option<Instruction> {
         "Open GitHub Repository" => Some(Instruction::OpenGithubRepository),
         "Random Verse" => Some(Instruction::RandomVerse),
         "Random Chapter" => Some(Instruction::RandomChapter),
+        "Psalm of the Day" => Some(Instruction::PsalmOfTheDay),
+        "Proverb of the Day" => Some(Instruction::ProverbOfTheDay),
         "About" => Some(Instruction::OpenAboutPage),
+        "People Index" => Some(Instruction::OpenPeopleIndex),
+        "Timeline" => Some(Instruction::OpenTimeline),
         "Show Translations" => Some(Instruction::ShowTranslations),
         "Open Verse Palette" => Some(Instruction::ToggleVersePallate),
         "Export to PDF" => Some(Instruction::ExportToPDF),
         "Export to Markdown" => Some(Instruction::ExportToMarkdown),
         "Export to Linked Markdown (Obsidian)" => Some(Instruction::ExportLinkedMarkdown),
         "Compare Translations" => Some(Instruction::ToggleTranslationComparison),
+        "Toggle Place Map" => Some(Instruction::TogglePlaceMap),
+        "Show Registers" => Some(Instruction::ShowRegisters),
+        "Workspaces" => Some(Instruction::ShowWorkspaces),
+        "Mark Chapter Complete" => Some(Instruction::MarkChapterComplete),
+        "Remove Highlight" => Some(Instruction::RemoveHighlight),
+        "Highlight Legend" => Some(Instruction::ToggleHighlightLegend),
+        "Show Only Highlighted Verses" => Some(Instruction::ToggleHighlightedOnly),
+        "Toggle Source Text" => Some(Instruction::ToggleSourceText),
+        "Toggle Transliteration" => Some(Instruction::ToggleTransliteration),
+        "Toggle Lexicon" => Some(Instruction::ToggleLexicon),
+        "Toggle Section Headings" => Some(Instruction::ToggleSectionHeadings),
+        "Read Aloud" => Some(Instruction::ToggleReadAloud),
+        "Stop Reading Aloud" => Some(Instruction::StopReadAloud),
+        "Read Aloud Faster" => Some(Instruction::ReadAloudFaster),
+        "Read Aloud Slower" => Some(Instruction::ReadAloudSlower),
+        "Edit Note" => Some(Instruction::EditNote),
+        "Export Notes and Highlights" => Some(Instruction::ExportAnnotations),
+        "Back Up Annotations" => Some(Instruction::ExportAnnotationBackup),
+        "Import Annotations" => Some(Instruction::ImportAnnotationBackup),
         _ => None,
     }
 }
 
+/// The palette command an alias target names, e.g. "Export to PDF" or ">export to pdf"
+fn alias_instruction(target: &str) -> Option<Instruction> {
+    let target = target.trim_start_matches('>').trim();
+    get_all_instructions()
+        .into_iter()
+        .map(|result| result.get_display_name())
+        .find(|name| name.eq_ignore_ascii_case(target))
+        .and_then(|name| instruction_name_to_instruction(&name))
+}
+
 #[component]
 pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl IntoView {
     let navigate = use_navigate();
@@ -474,6 +989,12 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
     let (navigate_to, set_navigate_to) = signal::<Option<String>>(None);
     let (is_mounted, set_is_mounted) = signal(false);
     let (execute_instruction, set_execute_instruction) = signal::<Option<Instruction>>(None);
+    // Bumped whenever a chapter is pinned or unpinned, or the history changes, so the results are rebuilt
+    let (pins_version, set_pins_version) = signal(0u32);
+    // Bumped when a workspace is deleted so the listing refreshes
+    let (workspaces_version, set_workspaces_version) = signal(0u32);
+    let (history_limit, set_history_limit) = signal(get_recent_chapters_limit());
+    let (history_private, set_history_private) = signal(is_history_private());
 
     // Debouncing effect: update search_query 150ms after input_value stops changing
     Effect::new(move |_| {
@@ -506,43 +1027,65 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                 let handled =
                     view_state.with(|state| processor.process(instruction.clone(), state));
 
-                if !handled {
-                    use web_sys::CustomEvent;
-
-                    // Handle export instructions that need special processing
-                    if matches!(
+                if !handled
+                    && matches!(
                         instruction,
-                        crate::instructions::Instruction::ExportToPDF
-                            | crate::instructions::Instruction::ExportToMarkdown
-                            | crate::instructions::Instruction::ExportLinkedMarkdown
-                    ) {
-                        if let Some(window) = web_sys::window() {
-                            if let Some(document) = window.document() {
-                                // Create custom event with instruction data
-                                let event_name = match instruction {
-                                    crate::instructions::Instruction::ExportToPDF => {
-                                        "palette-pdf-export"
-                                    }
-                                    crate::instructions::Instruction::ExportToMarkdown => {
-                                        "palette-markdown-export"
-                                    }
-                                    crate::instructions::Instruction::ExportLinkedMarkdown => {
-                                        "palette-linked-markdown-export"
-                                    }
-                                    _ => "palette-instruction",
-                                };
-                                if let Ok(event) = CustomEvent::new(event_name) {
-                                    let _ = document.dispatch_event(&event);
-                                }
-                            }
-                        }
-                    }
+                        Instruction::MarkChapterComplete
+                            | Instruction::ShowRegisters
+                            | Instruction::ShowWorkspaces
+                            | Instruction::CollapseAllBooks
+                            | Instruction::ExpandCurrentBook
+                            | Instruction::TogglePlaceMap
+                            | Instruction::HighlightVerse(_)
+                            | Instruction::RemoveHighlight
+                            | Instruction::ToggleHighlightLegend
+                            | Instruction::ToggleHighlightedOnly
+                            | Instruction::ToggleSourceText
+                            | Instruction::ToggleTransliteration
+                            | Instruction::ToggleLexicon
+                            | Instruction::ToggleSectionHeadings
+                            | Instruction::ToggleReadAloud
+                            | Instruction::StopReadAloud
+                            | Instruction::ReadAloudFaster
+                            | Instruction::ReadAloudSlower
+                            | Instruction::EditNote
+                            | Instruction::ImportAnnotationBackup
+                    )
+                {
+                    // Reading progress and the palette itself live in the view state, not in the processor
+                    view_state.update(|state| {
+                        state.execute(&instruction);
+                    });
                 }
             }
             set_execute_instruction.set(None); // Reset
         }
     });
 
+    // Save the current layout under a workspace name, or restore a saved workspace
+    let (pathname, search) = (location.pathname, location.search);
+    let run_workspace = move |name: &str, is_saved: bool| {
+        let result = if is_saved {
+            view_state.try_update(|state| state.restore_workspace(name))
+        } else if update_view_state_from_url(
+            view_state,
+            &pathname.get_untracked(),
+            &search.get_untracked(),
+        ) {
+            view_state.try_update(|state| state.save_workspace(name))
+        } else {
+            None
+        };
+        if let Some(InstructionResult::Navigate(path)) = result {
+            set_navigate_to.set(Some(path));
+        }
+        view_state.update(|state| {
+            state.execute(&Instruction::CloseCommandPalette);
+        });
+        set_search_query.set(String::new());
+        set_selected_index.set(0);
+    };
+
     // Handle initial search query when palette opens
     Effect::new(move |_| {
         if let Some(query) = view_state.with(|state| state.initial_search_query.clone()) {
@@ -554,12 +1097,23 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
     // Helper to check if we're showing global search results
     let is_global_search = Memo::new(move |_| {
         let query = search_query.get();
-        if query.is_empty() || query.starts_with(':') || query.starts_with('>') || query.len() < 3 {
+        if query.is_empty()
+            || query.starts_with(':')
+            || query.starts_with('>')
+            || query.starts_with('"')
+            || query.len() < 3
+            || parse_book_percent(&query).is_some()
+        {
             return false;
         }
 
+        // Book-scoped searches are always text searches
+        if parse_book_scope(&query).is_some() {
+            return true;
+        }
+
         // Check if there would be any chapter results
-        if let Some(bible) = view_state.with(|state| state.get_bible().cloned()) {
+        if let Some(bible) = view_state.with(|state| state.get_bible()) {
             for book in &bible.books {
                 for chapter in book.chapters.iter().take(5) {
                     // Quick check of first few chapters
@@ -576,11 +1130,31 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
     // Create a memo for filtered search results (chapters, verses, and instructions)
     let filtered_results = Memo::new(move |_| {
         let query = search_query.get();
+        // Books are read on demand; search again once one has come in
+        init_bible_signal().track();
         if query.is_empty() {
-            // Show recent chapters when empty
+            pins_version.track();
+
+            // Show pinned chapters first, followed by recent chapters that aren't pinned
+            let pinned_chapters = get_pinned_chapters();
             let recent_chapters = get_recent_chapters();
-            return recent_chapters
+
+            let pinned_results = pinned_chapters.iter().enumerate().map(|(index, pinned)| {
+                let score = 2000 - index; // Pinned chapters always rank above recent ones
+                (
+                    SearchResult::PinnedChapter {
+                        book_name: pinned.book_name.clone(),
+                        chapter: pinned.chapter,
+                        display_name: pinned.display_name.clone(),
+                        path: pinned.path.clone(),
+                    },
+                    score,
+                )
+            });
+
+            let recent_results = recent_chapters
                 .into_iter()
+                .filter(|recent| !pinned_chapters.iter().any(|p| p.path == recent.path))
                 .enumerate()
                 .map(|(index, recent)| {
                     let score = 1000 - index; // Higher score for more recent
@@ -593,10 +1167,90 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         },
                         score,
                     )
+                });
+
+            // Recent text searches sit between pinned and recent chapters
+            let search_results = get_recent_searches()
+                .into_iter()
+                .enumerate()
+                .map(|(index, query)| (SearchResult::RecentSearch { query }, 1500 - index));
+
+            return pinned_results
+                .chain(search_results)
+                .chain(recent_results)
+                .collect();
+        }
+
+        // User-defined aliases are resolved before any other matching. Paths and
+        // palette commands become a single result; anything else replaces the query.
+        let query = match find_palette_alias(&get_palette_aliases(), &query) {
+            Some(alias)
+                if alias.target.starts_with('/') || alias_instruction(&alias.target).is_some() =>
+            {
+                return vec![(
+                    SearchResult::Alias {
+                        name: alias.name.clone(),
+                        target: alias.target.clone(),
+                    },
+                    3000,
+                )];
+            }
+            Some(alias) => alias.target.clone(),
+            None => query,
+        };
+
+        // Check if this is a register listing (starts with `"`, optionally followed by a name)
+        if let Some(register_query) = query.strip_prefix('"') {
+            let register_query = register_query.trim().to_lowercase();
+            return get_registers()
+                .into_iter()
+                .filter(|(name, _)| register_query.is_empty() || register_query.starts_with(*name))
+                .enumerate()
+                .map(|(index, (name, content))| {
+                    (SearchResult::Register { name, content }, 1000 - index)
                 })
                 .collect();
         }
 
+        // Check if this is a workspace listing (e.g., "ws:" or "ws:sermon prep")
+        if let Some(workspace_query) = query.strip_prefix("ws:") {
+            workspaces_version.track();
+            return search_workspaces(&get_workspaces(), workspace_query);
+        }
+
+        // Check if this is a search of the reader's notes and pinned chapters (e.g., "n:covenant")
+        if let Some(annotation_query) = query.strip_prefix("n:") {
+            let Some(bible) = view_state.with(|state| state.get_bible()) else {
+                return Vec::new();
+            };
+            let mut results = view_state.with(|state| {
+                search_annotations(
+                    &bible,
+                    &state.notes,
+                    &get_pinned_chapters(),
+                    annotation_query.trim(),
+                )
+            });
+            results.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+            return results;
+        }
+
+        // Check if this is a text search limited to one book (e.g., "in:psalms refuge")
+        if let Some((book_query, text_query)) = parse_book_scope(&query) {
+            let Some(bible) = view_state.with(|state| state.get_bible()) else {
+                return Vec::new();
+            };
+            let Some(book) = find_book_by_query(&bible, book_query) else {
+                return Vec::new();
+            };
+            bible.request_book(book);
+
+            let mut results = search_verse_text(std::iter::once(book), text_query);
+            results.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+            results.truncate(10);
+            return results;
+        }
+
         // Check if this is an instruction search (starts with ">")
         if query.starts_with('>') {
             let instruction_query = query.strip_prefix('>').unwrap_or("").to_lowercase();
@@ -687,6 +1341,18 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                 }
             }
         }
+        // Check if this is a jump within the current book (e.g., "50%")
+        else if let Some(percent) = parse_book_percent(&query) {
+            let target = view_state.with(|state| {
+                let bible = state.get_bible()?;
+                let current_chapter =
+                    get_current_chapter_from_bible(&bible, &location.pathname.get())?;
+                bible.get_book_percent_chapter(&current_chapter, percent)
+            });
+            if let Some(chapter) = target {
+                results.push((SearchResult::Chapter(chapter), 1000));
+            }
+        }
         // Check if this is a verse reference (e.g., "gen 1:1" or "gen 1:")
         else if let Some(verse_ref) = parse_verse_reference(&query) {
             // Try to find the verse(s)
@@ -703,14 +1369,19 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         };
 
                     // Find the chapter - optimize by searching more efficiently
-                    let bible = match view_state.with(|state| state.get_bible().cloned()) {
+                    let bible = match view_state.with(|state| state.get_bible()) {
                         Some(bible) => bible,
                         None => return Vec::new(), // No Bible data available
                     };
-                    let mut found_chapter = None;
+                    // First try the exact book name or a known abbreviation ("gen", "1 kor")
+                    let mut found_chapter = bible
+                        .find_book(&verse_ref.book_name)
+                        .and_then(|book| {
+                            book.chapters.iter().find(|c| c.chapter == verse_ref.chapter)
+                        });
 
-                    // First try exact book name match
-                    for book in &bible.books {
+                    // Then the translated book name
+                    for book in bible.books.iter().filter(|_| found_chapter.is_none()) {
                         if book.name.to_lowercase() == book_name_to_search.to_lowercase()
                             || book.name.to_lowercase() == verse_ref.book_name.to_lowercase()
                         {
@@ -741,6 +1412,17 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         }
                     }
 
+                    // Its verses come in with the book when that hasn't been read yet
+                    let found_book = found_chapter.and_then(|chapter| {
+                        bible
+                            .books
+                            .iter()
+                            .find(|book| book.chapters.iter().any(|c| std::ptr::eq(c, chapter)))
+                    });
+                    if let Some(book) = found_book {
+                        bible.request_book(book);
+                    }
+
                     if let Some(chapter) = found_chapter {
                         match verse_ref.verse {
                             Some(verse_num) => {
@@ -783,7 +1465,9 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
 
         // Only do expensive chapter search if query is at least 2 characters
         if query.len() >= 2 {
-            if let Some(bible) = view_state.with(|state| state.get_bible().cloned()) {
+            if let Some(bible) = view_state.with(|state| state.get_bible()) {
+                // Abbreviations from the tables ("mt 5") are scored as the full book name
+                let expanded_query = expand_book_abbreviation(&bible, &query);
                 let mut found_count = 0;
                 'outer: for book in &bible.books {
                     for chapter in &book.chapters {
@@ -795,12 +1479,12 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         let original_name = chapter.name.to_lowercase();
                         let original_score = fuzzy_score(&original_name, &query);
 
-                        // Only compute expensive translation if original name doesn't match well
                         let score = if original_score > 0 {
                             original_score
                         } else {
-                            let translated_name = chapter.name.to_lowercase();
-                            fuzzy_score(&translated_name, &query)
+                            expanded_query
+                                .as_deref()
+                                .map_or(0, |expanded| fuzzy_score(&original_name, expanded))
                         };
 
                         if score > 0 {
@@ -820,55 +1504,15 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
             && !query.starts_with(':')
             && !query.starts_with('>')
         {
-            let mut verse_matches: Vec<(SearchResult, usize)> = Vec::new();
-            let mut search_count = 0;
-
-            // Normalize query once outside the loop for performance
-            let query_normalized = normalize_text_for_search(&query);
-
-            if let Some(bible) = view_state.with(|state| state.get_bible().cloned()) {
-                'global_search: for book in &bible.books {
-                    for chapter in &book.chapters {
-                        for verse in &chapter.verses {
-                            // Early exit if we have enough results
-                            if search_count >= 50 {
-                                break 'global_search;
-                            }
-
-                            // Normalize verse text for search (no cache needed for real-time search)
-                            let verse_text_normalized = normalize_text_for_search(&verse.text);
-                            if verse_text_normalized.contains(&query_normalized) {
-                                // Score based on how early the match appears in the verse
-                                let match_position = verse_text_normalized
-                                    .find(&query_normalized)
-                                    .unwrap_or(verse_text_normalized.len());
-                                let score = if verse_text_normalized.starts_with(&query_normalized)
-                                {
-                                    1000 // Starts with query
-                                } else if match_position < 10 {
-                                    800 // Match near beginning
-                                } else if match_position < 30 {
-                                    600 // Match in first part
-                                } else {
-                                    400 // Match later in verse
-                                };
-
-                                verse_matches.push((
-                                    SearchResult::Verse {
-                                        chapter: chapter.clone(),
-                                        verse_number: verse.verse,
-                                        verse_text: verse.text.clone(),
-                                    },
-                                    score,
-                                ));
-                                search_count += 1;
-                            }
-                        }
-                    }
+            if let Some(bible) = view_state.with(|state| state.get_bible()) {
+                for book in &bible.books {
+                    bible.request_book(book);
                 }
+                results.extend(search_verse_text(
+                    bible.books.iter().map(|book| &**book),
+                    &query,
+                ));
             }
-
-            results.extend(verse_matches);
         }
 
         // Sort by score (higher is better)
@@ -989,7 +1633,39 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                         set_search_query.set(String::new());
                                         set_selected_index.set(0);
                                     }
+                                    SearchResult::Alias { target, .. }
+                                        if alias_instruction(target).is_some() =>
+                                    {
+                                        set_execute_instruction.set(alias_instruction(target));
+                                        view_state.update(|state| {
+                                            state.execute(&Instruction::CloseCommandPalette);
+                                        });
+                                        set_search_query.set(String::new());
+                                        set_selected_index.set(0);
+                                    }
+                                    SearchResult::Register { name, .. } => {
+                                        set_execute_instruction
+                                            .set(Some(Instruction::PasteRegister(*name)));
+                                        view_state.update(|state| {
+                                            state.execute(&Instruction::CloseCommandPalette);
+                                        });
+                                        set_search_query.set(String::new());
+                                        set_selected_index.set(0);
+                                    }
+                                    SearchResult::RecentSearch { query } => {
+                                        // Run the search again, keeping the palette open
+                                        set_input_value.set(query.clone());
+                                        set_selected_index.set(0);
+                                    }
+                                    SearchResult::Workspace { name, is_saved } => {
+                                        run_workspace(name, *is_saved);
+                                    }
                                     _ => {
+                                        // Remember text searches that led somewhere
+                                        if is_global_search.get_untracked() {
+                                            add_recent_search(&search_query.get_untracked());
+                                        }
+
                                         // For chapters and verses, navigate
                                         set_navigate_to.set(Some(result.to_path()));
 
@@ -1083,7 +1759,7 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         <input
                             node_ref=input_ref
                             type="text"
-                            placeholder="Search chapters, verses, or text... (e.g., 'Genesis 1', 'john 3:16', 'love', '>' for shortcuts)"
+                            placeholder=t("palette_placeholder")
                             class="w-full px-3 py-2 border rounded-md focus:outline-none focus:ring-2"
                             style="background-color: var(--theme-palette-background); color: var(--theme-palette-text); border-color: var(--theme-palette-border); --tw-ring-color: var(--theme-palette-highlight)"
                             prop:value=input_value
@@ -1117,7 +1793,7 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                             <svg class="w-3 h-3 mr-1" fill="currentColor" viewBox="0 0 20 20">
                                                 <path fill-rule="evenodd" d="M8 4a4 4 0 100 8 4 4 0 000-8zM2 8a6 6 0 1110.89 3.476l4.817 4.817a1 1 0 01-1.414 1.414l-4.816-4.816A6 6 0 012 8z" clip-rule="evenodd"></path>
                                             </svg>
-                                            "Bible text search results"
+                                            {t("bible_text_search_results")}
                                         </div>
                                     </div>
                                 </Show>
@@ -1126,7 +1802,7 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                     id="palette-results-listbox"
                                     class="max-h-64 overflow-y-auto"
                                     role="listbox"
-                                    aria-label="Search results"
+                                    aria-label=t("search_results")
                                 >
                                     {move || {
                                         let results = filtered_results.get();
@@ -1173,8 +1849,27 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                                                 format!("{}, {}, shortcut: {}", name, description, shortcut)
                                                             }
                                                             SearchResult::RecentChapter { display_name, .. } => {
-                                                                format!("Recent chapter: {}", display_name)
+                                                                t_with("recent_chapter_label", &[("name", display_name)])
+                                                            }
+                                                            SearchResult::PinnedChapter { display_name, .. } => {
+                                                                t_with("pinned_chapter_label", &[("name", display_name)])
+                                                            }
+                                                            SearchResult::Register { content, .. } => {
+                                                                format!("{}, contents: {}", display_name, content)
                                                             }
+                                                            SearchResult::Alias { name, target } => {
+                                                                t_with("alias_label", &[("name", name), ("target", target)])
+                                                            }
+                                                            SearchResult::RecentSearch { query } => {
+                                                                t_with("recent_search_label", &[("query", query)])
+                                                            }
+                                                            SearchResult::Note { display_name, text, .. } => {
+                                                                t_with("note_on_label", &[("name", display_name), ("text", text)])
+                                                            }
+                                                            SearchResult::Workspace { is_saved: true, .. } => {
+                                                                t_with("workspace_label", &[("name", &display_name)])
+                                                            }
+                                                            SearchResult::Workspace { .. } => display_name.clone(),
                                                         }
                                                     }
                                                     on:click={
@@ -1192,7 +1887,33 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                                                     set_search_query.set(String::new());
                                                                     set_selected_index.set(0);
                                                                 }
+                                                                SearchResult::Alias { target, .. } if alias_instruction(target).is_some() => {
+                                                                    set_execute_instruction.set(alias_instruction(target));
+                                                                    view_state.update(|state| { state.execute(&Instruction::CloseCommandPalette); });
+                                                                    set_search_query.set(String::new());
+                                                                    set_selected_index.set(0);
+                                                                }
+                                                                SearchResult::Register { name, .. } => {
+                                                                    // Copy the register contents to the clipboard
+                                                                    set_execute_instruction.set(Some(Instruction::PasteRegister(*name)));
+                                                                    view_state.update(|state| { state.execute(&Instruction::CloseCommandPalette); });
+                                                                    set_search_query.set(String::new());
+                                                                    set_selected_index.set(0);
+                                                                }
+                                                                SearchResult::RecentSearch { query } => {
+                                                                    // Run the search again, keeping the palette open
+                                                                    set_input_value.set(query.clone());
+                                                                    set_selected_index.set(0);
+                                                                }
+                                                                SearchResult::Workspace { name, is_saved } => {
+                                                                    run_workspace(name, *is_saved);
+                                                                }
                                                                 _ => {
+                                                                    // Remember text searches that led somewhere
+                                                                    if is_global_search.get_untracked() {
+                                                                        add_recent_search(&search_query.get_untracked());
+                                                                    }
+
                                                                     // Navigate for chapters and verses
                                                                     set_navigate_to.set(Some(path.clone()));
 
@@ -1247,19 +1968,137 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                                                                         <svg class="w-2 h-2 mr-1" fill="currentColor" viewBox="0 0 20 20">
                                                                             <path fill-rule="evenodd" d="M10 18a8 8 0 100-16 8 8 0 000 16zm1-12a1 1 0 10-2 0v4a1 1 0 00.293.707l2.828 2.829a1 1 0 101.415-1.415L11 9.586V6z" clip-rule="evenodd"></path>
                                                                         </svg>
-                                                                        "Recent chapter"
+                                                                        {t("recent_chapter")}
+                                                                    </div>
+                                                                }.into_any()
+                                                            }
+                                                            SearchResult::PinnedChapter { .. } => {
+                                                                view! {
+                                                                    <div class="text-xs opacity-75 mt-1 flex items-center">
+                                                                        <svg class="w-2 h-2 mr-1" fill="currentColor" viewBox="0 0 20 20">
+                                                                            <path d="M9.049 2.927c.3-.921 1.603-.921 1.902 0l1.07 3.292a1 1 0 00.95.69h3.462c.969 0 1.371 1.24.588 1.81l-2.8 2.034a1 1 0 00-.364 1.118l1.07 3.292c.3.921-.755 1.688-1.54 1.118l-2.8-2.034a1 1 0 00-1.175 0l-2.8 2.034c-.784.57-1.838-.197-1.539-1.118l1.07-3.292a1 1 0 00-.364-1.118L2.98 8.72c-.783-.57-.38-1.81.588-1.81h3.461a1 1 0 00.951-.69l1.07-3.292z"></path>
+                                                                        </svg>
+                                                                        {t("pinned_chapter")}
+                                                                    </div>
+                                                                }.into_any()
+                                                            }
+                                                            SearchResult::Alias { .. } => {
+                                                                view! {
+                                                                    <div class="text-xs opacity-75 mt-1">{t("alias")}</div>
+                                                                }.into_any()
+                                                            }
+                                                            SearchResult::Workspace { is_saved, .. } => {
+                                                                view! {
+                                                                    <div class="text-xs opacity-75 mt-1">
+                                                                        {if *is_saved {
+                                                                            t("workspace")
+                                                                        } else {
+                                                                            t("workspace_description")
+                                                                        }}
+                                                                    </div>
+                                                                }.into_any()
+                                                            }
+                                                            SearchResult::RecentSearch { .. } => {
+                                                                view! {
+                                                                    <div class="text-xs opacity-75 mt-1 flex items-center">
+                                                                        <svg class="w-2 h-2 mr-1" fill="currentColor" viewBox="0 0 20 20">
+                                                                            <path fill-rule="evenodd" d="M8 4a4 4 0 100 8 4 4 0 000-8zM2 8a6 6 0 1110.89 3.476l4.817 4.817a1 1 0 01-1.414 1.414l-4.816-4.816A6 6 0 012 8z" clip-rule="evenodd"></path>
+                                                                        </svg>
+                                                                        {t("recent_search")}
+                                                                    </div>
+                                                                }.into_any()
+                                                            }
+                                                            SearchResult::Register { content, .. }
+                                                            | SearchResult::Note { text: content, .. } => {
+                                                                let preview: String = content.chars().take(80).collect();
+                                                                view! {
+                                                                    <div class="text-xs opacity-75 mt-1 truncate">
+                                                                        {if preview.len() < content.len() {
+                                                                            format!("{}...", preview)
+                                                                        } else {
+                                                                            preview
+                                                                        }}
                                                                     </div>
                                                                 }.into_any()
                                                             }
                                                         }}
                                                     </div>
+                                                    {result.is_pinnable().then(|| {
+                                                        let is_pinned = matches!(result, SearchResult::PinnedChapter { .. });
+                                                        let result_clone = result.clone();
+                                                        view! {
+                                                            <button
+                                                                class="ml-2 px-2 py-1 text-xs rounded opacity-75 hover:opacity-100"
+                                                                style="color: inherit"
+                                                                title=if is_pinned { t("unpin_chapter") } else { t("pin_chapter") }
+                                                                aria-label=if is_pinned { t("unpin_chapter") } else { t("pin_chapter") }
+                                                                on:click=move |e| {
+                                                                    // Don't navigate to the chapter when toggling the pin
+                                                                    e.stop_propagation();
+                                                                    if let SearchResult::RecentChapter { book_name, chapter, display_name, path }
+                                                                        | SearchResult::PinnedChapter { book_name, chapter, display_name, path } = &result_clone
+                                                                    {
+                                                                        toggle_pinned_chapter(
+                                                                            book_name.clone(),
+                                                                            *chapter,
+                                                                            display_name.clone(),
+                                                                            path.clone(),
+                                                                        );
+                                                                        set_pins_version.update(|v| *v += 1);
+                                                                    }
+                                                                }
+                                                            >
+                                                                {if is_pinned { t("unpin") } else { t("pin") }}
+                                                            </button>
+                                                        }
+                                                    })}
+                                                    {match &result {
+                                                        SearchResult::Register { name, .. } => {
+                                                            let name = *name;
+                                                            Some(view! {
+                                                                <button
+                                                                    class="ml-2 px-2 py-1 text-xs rounded opacity-75 hover:opacity-100"
+                                                                    style="color: inherit"
+                                                                    title=t("export_register")
+                                                                    aria-label=t("export_register")
+                                                                    on:click=move |e| {
+                                                                        // Don't paste the register as well
+                                                                        e.stop_propagation();
+                                                                        set_execute_instruction.set(Some(Instruction::ExportRegister(name)));
+                                                                    }
+                                                                >
+                                                                    {t("export")}
+                                                                </button>
+                                                            }.into_any())
+                                                        }
+                                                        SearchResult::Workspace { name, is_saved: true } => {
+                                                            let name = name.clone();
+                                                            Some(view! {
+                                                                <button
+                                                                    class="ml-2 px-2 py-1 text-xs rounded opacity-75 hover:opacity-100"
+                                                                    style="color: inherit"
+                                                                    title=t("delete_workspace")
+                                                                    aria-label=t("delete_workspace")
+                                                                    on:click=move |e| {
+                                                                        // Don't restore the workspace being deleted
+                                                                        e.stop_propagation();
+                                                                        delete_workspace(&name);
+                                                                        set_workspaces_version.update(|v| *v += 1);
+                                                                    }
+                                                                >
+                                                                    {t("delete")}
+                                                                </button>
+                                                            }.into_any())
+                                                        }
+                                                        _ => None,
+                                                    }}
                                                 </div>
                                             }
                                         }).collect_view()
                                     }}
                                     <Show when=move || filtered_results.get().is_empty()>
                                         <div class="px-4 py-2 text-black text-sm">
-                                            "No results found"
+                                            {t("no_results")}
                                         </div>
                                     </Show>
                                 </div>
@@ -1267,9 +2106,48 @@ pub fn CommandPalette(view_state: crate::view_state::ViewStateSignal) -> impl In
                         </div>
                     </div>
 
-                    // Footer with hint
-                    <div class="px-4 py-2 border-t border-gray-200 text-xs text-black">
-                        Use up/down arrows or Ctrl+J/K to navigate, Enter to select, Esc to close
+                    // Footer with hint and recent history settings
+                    <div class="flex flex-wrap items-center justify-between gap-x-4 gap-y-1 px-4 py-2 border-t border-gray-200 text-xs text-black">
+                        <span>{t("palette_help")}</span>
+                        <div class="flex items-center gap-2 shrink-0">
+                            <button
+                                class="underline hover:text-blue-600"
+                                title=t("recent_chapters_limit")
+                                on:click=move |_| {
+                                    // Cycle through the offered history sizes
+                                    let limit = RECENT_CHAPTERS_LIMITS
+                                        .into_iter()
+                                        .find(|limit| *limit > history_limit.get())
+                                        .unwrap_or(RECENT_CHAPTERS_LIMITS[0]);
+                                    save_recent_chapters_limit(limit);
+                                    set_history_limit.set(limit);
+                                    set_pins_version.update(|v| *v += 1);
+                                }
+                            >
+                                {move || t_with("keep_count", &[("count", &history_limit.get().to_string())])}
+                            </button>
+                            <button
+                                class="underline hover:text-blue-600"
+                                on:click=move |_| {
+                                    clear_recent_chapters();
+                                    set_pins_version.update(|v| *v += 1);
+                                }
+                            >
+                                {t("clear_history")}
+                            </button>
+                            <button
+                                class="underline hover:text-blue-600"
+                                title=t("stop_recording_history")
+                                aria-pressed=move || history_private.get().to_string()
+                                on:click=move |_| {
+                                    let private = !history_private.get();
+                                    save_history_private(private);
+                                    set_history_private.set(private);
+                                }
+                            >
+                                {move || if history_private.get() { t("private_on") } else { t("private_off") }}
+                            </button>
+                        </div>
                     </div>
                 </div>
             </div>
@@ -1338,7 +2216,7 @@ fn convert_roman_to_arabic(text: &str) -> String {
         .replace("i ", "1 ")
 }
 
-fn fuzzy_score(text: &str, query: &str) -> usize {
+pub fn fuzzy_score(text: &str, query: &str) -> usize {
     if query.is_empty() {
         return 0;
     }
@@ -1505,6 +2383,109 @@ fn character_fuzzy_score(text: &str, query: &str, is_positional_match: bool) ->
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_book_scope() {
+        assert_eq!(
+            parse_book_scope("in:psalms refuge"),
+            Some(("psalms", "refuge"))
+        );
+        assert_eq!(
+            parse_book_scope("ps/ my refuge"),
+            Some(("ps", "my refuge"))
+        );
+        assert_eq!(parse_book_scope("in:psalms"), None);
+        assert_eq!(parse_book_scope("/Matthew/5"), None);
+        assert_eq!(parse_book_scope("and/or"), None);
+    }
+
+    #[test]
+    fn test_search_workspaces() {
+        let workspace = |name: &str| Workspace {
+            name: name.to_string(),
+            path: "/Romans/8".to_string(),
+            is_left_sidebar_open: true,
+            is_right_sidebar_open: false,
+            is_translation_comparison_open: false,
+            is_place_map_open: false,
+            comparison_translations: Vec::new(),
+            text_columns: 1,
+        };
+        let workspaces = vec![workspace("Sermon prep"), workspace("Romans study")];
+        let names = |query: &str| -> Vec<String> {
+            search_workspaces(&workspaces, query)
+                .iter()
+                .map(|(result, _)| result.get_display_name())
+                .collect()
+        };
+
+        assert_eq!(names(""), vec!["Sermon prep", "Romans study"]);
+        assert_eq!(
+            names("ROMANS"),
+            vec!["Romans study", "Save workspace \"ROMANS\""]
+        );
+        assert_eq!(names(" sermon prep "), vec!["Sermon prep"]);
+    }
+
+    #[test]
+    fn test_search_annotations() {
+        let chapter = |name: &str, number: u32| Chapter {
+            chapter: number,
+            name: format!("{} {}", name, number),
+            verses: vec![],
+        };
+        let bible = Bible::new(vec![
+            Book {
+                name: "Genesis".to_string(),
+                chapters: vec![chapter("Genesis", 15)],
+            },
+            Book {
+                name: "John".to_string(),
+                chapters: vec![chapter("John", 3)],
+            },
+        ]);
+        let note = |book: &str, chapter: u32, verse: u32, text: &str| VerseNote {
+            book: book.to_string(),
+            chapter,
+            verse,
+            text: text.to_string(),
+        };
+        let notes = vec![
+            note("john", 3, 16, "The covenant love of God"),
+            note("genesis", 15, 18, "Covenant with Abram"),
+            note("genesis", 15, 6, "Faith counted as righteousness"),
+        ];
+        let pinned = vec![PinnedChapter {
+            book_name: "John".to_string(),
+            chapter: 3,
+            display_name: "John 3".to_string(),
+            path: "/John/3".to_string(),
+        }];
+
+        let results = search_annotations(&bible, &notes, &pinned, "covenant");
+        let found: Vec<(String, String, usize)> = results
+            .iter()
+            .map(|(result, score)| (result.get_display_name(), result.to_path(), *score))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "Genesis 15:18".to_string(),
+                    "/Genesis/15?verses=18".to_string(),
+                    1000
+                ),
+                ("John 3:16".to_string(), "/John/3?verses=16".to_string(), 800),
+            ]
+        );
+
+        // Pinned chapters match by name, and an empty query lists everything
+        assert_eq!(
+            search_annotations(&bible, &notes, &pinned, "john").len(),
+            1
+        );
+        assert_eq!(search_annotations(&bible, &notes, &pinned, "").len(), 4);
+    }
+
     #[test]
     fn test_normalize_text_for_search() {
         // Test Dutch character normalization
@@ -1561,6 +2542,16 @@ mod tests {
         assert!(score5 > 0, "Should match ii samuel with 2 Samuel");
     }
 
+    #[test]
+    fn test_parse_book_percent() {
+        assert_eq!(parse_book_percent("50%"), Some(50));
+        assert_eq!(parse_book_percent(" 0 % "), Some(0));
+        assert_eq!(parse_book_percent("100%"), Some(100));
+        assert_eq!(parse_book_percent("101%"), None);
+        assert_eq!(parse_book_percent("50"), None);
+        assert_eq!(parse_book_percent("gen%"), None);
+    }
+
     #[test]
     fn test_parse_verse_reference() {
         // Test basic format "gen 1:1"
@@ -1606,6 +2597,27 @@ mod tests {
         assert_eq!(result.verse, None);
 
         // Test invalid formats
+        // OSIS and dotted formats
+        let result = parse_verse_reference("Gen.1.1").unwrap();
+        assert_eq!(result.book_name, "gen");
+        assert_eq!(result.chapter, 1);
+        assert_eq!(result.verse, Some(1));
+
+        let result = parse_verse_reference("John 3.16").unwrap();
+        assert_eq!(result.book_name, "john");
+        assert_eq!(result.chapter, 3);
+        assert_eq!(result.verse, Some(16));
+
+        let result = parse_verse_reference("1Cor 13,4").unwrap();
+        assert_eq!(result.book_name, "1cor");
+        assert_eq!(result.chapter, 13);
+        assert_eq!(result.verse, Some(4));
+
+        let result = parse_verse_reference("rom8:28").unwrap();
+        assert_eq!(result.book_name, "rom");
+        assert_eq!(result.chapter, 8);
+        assert_eq!(result.verse, Some(28));
+
         assert!(parse_verse_reference("genesis 1").is_none()); // No colon
         assert!(parse_verse_reference("gen:1").is_none()); // No chapter
         assert!(parse_verse_reference("gen 1:abc").is_none()); // Invalid verse
@@ -1669,6 +2681,26 @@ mod tests {
         let path = verse_result.to_path();
         assert_eq!(path, "/Genesis/1?verses=5");
     }
+
+    #[test]
+    fn test_pinned_chapter_result() {
+        let pinned_result = SearchResult::PinnedChapter {
+            book_name: "Genesis".to_string(),
+            chapter: 1,
+            display_name: "Genesis 1".to_string(),
+            path: "/Genesis/1".to_string(),
+        };
+        assert_eq!(pinned_result.to_path(), "/Genesis/1");
+        assert_eq!(pinned_result.get_display_name(), "Genesis 1");
+        assert!(pinned_result.is_pinnable());
+
+        let instruction_result = SearchResult::Instruction {
+            name: "Next Chapter".to_string(),
+            description: String::new(),
+            shortcut: "l".to_string(),
+        };
+        assert!(!instruction_result.is_pinnable());
+    }
     #[test]
     fn test_fuzzy_score_with_translated_names() {
         // Test fuzzy search functionality with translated names
@@ -1,6 +1,7 @@
-use crate::core::load_cross_references;
-use crate::core::types::Language;
+use crate::components::VerseCommentary;
 use crate::core::types::{Reference, References, VerseId};
+use crate::core::{init_bible_signal, load_cross_references};
+use crate::i18n::t;
 use crate::instructions::types::Instruction;
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
@@ -12,7 +13,9 @@ use leptos::wasm_bindgen::JsCast;
 use leptos::web_sys::KeyboardEvent;
 use leptos_router::hooks::use_navigate;
 use leptos_router::NavigateOptions;
-use std::sync::OnceLock;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 use urlencoding::encode;
 
 // Global cross-references cache (already optimized with your compile-time system)
@@ -27,6 +30,69 @@ fn get_cross_references() -> &'static References {
     })
 }
 
+/// Sorted references per verse for a single chapter
+type ChapterReferences = HashMap<u32, Vec<Reference>>;
+
+/// A cache entry keyed by canonical book name and chapter number
+type CachedChapter = ((String, u32), Arc<ChapterReferences>);
+
+/// Chapters kept in the reference cache: the current one plus prefetched neighbours
+const CHAPTER_REFERENCE_CACHE_SIZE: usize = 6;
+
+thread_local! {
+    // Most recently used chapters first
+    static CHAPTER_REFERENCE_CACHE: RefCell<Vec<CachedChapter>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Load all references for a chapter at once, sorted by votes, reusing a
+/// cached copy when the chapter was shown or prefetched recently
+fn get_chapter_references(canonical_book_name: &str, chapter: u32) -> Arc<ChapterReferences> {
+    let key = (canonical_book_name.to_string(), chapter);
+    let cached = CHAPTER_REFERENCE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let position = cache.iter().position(|(k, _)| *k == key)?;
+        let entry = cache.remove(position);
+        let references = entry.1.clone();
+        cache.insert(0, entry);
+        Some(references)
+    });
+    if let Some(references) = cached {
+        return references;
+    }
+
+    let references = get_cross_references();
+    let mut chapter_refs = HashMap::new();
+
+    // Load all verses in the chapter at once to prevent per-verse lookups during fast scrolling
+    for verse_num in 1..=200 {
+        // Conservative upper bound for verses in a chapter
+        if let Some(verse_id) = VerseId::from_book_name(canonical_book_name, chapter, verse_num) {
+            if let Some(refs) = references.0.get(&verse_id) {
+                let mut sorted = refs.to_vec();
+                sorted.sort_unstable_by_key(|r| std::cmp::Reverse(r.votes));
+                chapter_refs.insert(verse_num, sorted);
+            }
+        }
+    }
+
+    let chapter_refs = Arc::new(chapter_refs);
+    CHAPTER_REFERENCE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.insert(0, (key, chapter_refs.clone()));
+        cache.truncate(CHAPTER_REFERENCE_CACHE_SIZE);
+    });
+    chapter_refs
+}
+
+/// Prepare the references of a chapter ahead of navigation. Does nothing until
+/// the cross-reference data has been loaded by opening the panel, so prefetching
+/// never pulls in the full data set on its own.
+pub fn prefetch_chapter_references(book_name: &str, chapter: u32) {
+    if CROSS_REFERENCES.get().is_some() {
+        get_chapter_references(&get_canonical_book_name(book_name), chapter);
+    }
+}
+
 fn get_canonical_book_name(display_name: &str) -> String {
     // Convert display book names (potentially translated) back to canonical English names
     // that the cross-reference system recognizes
@@ -113,32 +179,6 @@ fn get_canonical_book_name(display_name: &str) -> String {
     }
 }
 
-fn get_ui_text(key: &str) -> String {
-    if let Some(current_translation) = get_current_translation() {
-        if let Some(first_language) = current_translation.languages.first() {
-            match (key, first_language) {
-                ("cross_references", Language::Dutch) => "Kruisverwijzingen".to_string(),
-                ("cross_references", Language::English) => "Cross References".to_string(),
-                ("no_references", Language::Dutch) => "Geen kruisverwijzingen gevonden".to_string(),
-                ("no_references", Language::English) => "No cross references found".to_string(),
-                ("votes", Language::Dutch) => "stemmen".to_string(),
-                ("votes", Language::English) => "votes".to_string(),
-                _ => key.to_string(),
-            }
-        } else {
-            key.to_string()
-        }
-    } else {
-        // Default to English
-        match key {
-            "cross_references" => "Cross References".to_string(),
-            "no_references" => "No cross references found".to_string(),
-            "votes" => "votes".to_string(),
-            _ => key.to_string(),
-        }
-    }
-}
-
 fn get_translated_book_name(book_name: &str) -> String {
     if let Some(current_translation) = get_current_translation() {
         if let Some(first_language) = current_translation.languages.first() {
@@ -202,14 +242,17 @@ fn get_verse_content_for_reference(reference: &Reference) -> String {
         // that the Bible data uses. E.g., "1 Samuel" -> "I Samuel"
         let bible_book_name = get_display_book_name(&reference.to_book_name);
 
-        // Try to get the verse content for the reference
-        if let Ok(bible) = get_bible().get_chapter(&bible_book_name, reference.to_chapter) {
-            if let Some(verse) = bible
+        // Try to get the verse content for the reference, every verse of a range
+        if let Ok(chapter) = get_bible().get_chapter(&bible_book_name, reference.to_chapter) {
+            let last_verse = reference.to_verse_end.unwrap_or(reference.to_verse_start);
+            let verses: Vec<&str> = chapter
                 .verses
                 .iter()
-                .find(|v| v.verse == reference.to_verse_start)
-            {
-                return verse.text.to_string(); // More explicit than clone for strings
+                .filter(|v| (reference.to_verse_start..=last_verse).contains(&v.verse))
+                .map(|v| v.text.as_str())
+                .collect();
+            if !verses.is_empty() {
+                return verses.join(" ");
             }
         }
 
@@ -321,16 +364,16 @@ pub fn CrossReferencesSidebar(
             return view! {
                 <div class="cross-references-sidebar">
                     <div class="mb-4">
-                        <h2 class="text-lg font-bold mb-2" style="color: var(--theme-sidebar-text)">{get_ui_text("cross_references")}</h2>
+                        <h2 class="text-lg font-bold mb-2" style="color: var(--theme-sidebar-text)">{t("cross_references")}</h2>
                         <div class="text-sm mb-4" style="color: var(--theme-text-secondary)">
                             {get_translated_book_name(&book_name)} " " {chapter} ":" {verse}
                         </div>
                     </div>
                     <div class="text-sm italic" style="color: var(--theme-text-muted)">
-                        {get_ui_text("no_references")}
+                        {t("no_references")}
                     </div>
                 </div>
-            };
+            }.into_any();
         }
     };
 
@@ -348,47 +391,7 @@ pub fn CrossReferencesSidebar(
     // NOTE: References are only loaded when this component is actually rendered (panel is open)
     let chapter_references = Memo::new({
         let canonical_book_name = canonical_book_name.clone();
-        move |_| {
-            let mut chapter_refs = std::collections::HashMap::new();
-
-            // Only load references if the panel is actually open (this component exists)
-            let references = get_cross_references();
-
-            web_sys::console::log_1(
-                &format!(
-                    "Pre-loading references for chapter: {} {} (panel open)",
-                    canonical_book_name, chapter
-                )
-                .into(),
-            );
-
-            // Load all verses in the chapter at once to prevent per-verse lookups during fast scrolling
-            for verse_num in 1..=200 {
-                // Conservative upper bound for verses in a chapter
-                if let Some(verse_id) =
-                    VerseId::from_book_name(&canonical_book_name, chapter, verse_num)
-                {
-                    if let Some(refs) = references.0.get(&verse_id) {
-                        // Sort in-place without cloning the entire vector
-                        let mut sorted = refs.to_vec(); // More explicit than clone
-                        sorted.sort_unstable_by(|a, b| b.votes.cmp(&a.votes)); // Faster sort
-                        chapter_refs.insert(verse_num, sorted);
-                    }
-                }
-            }
-
-            web_sys::console::log_1(
-                &format!(
-                    "Pre-loaded references for {} verses in chapter {} {} (panel open)",
-                    chapter_refs.len(),
-                    canonical_book_name,
-                    chapter
-                )
-                .into(),
-            );
-
-            chapter_refs
-        }
+        move |_| get_chapter_references(&canonical_book_name, chapter)
     });
 
     // Get references for current verse from the pre-loaded chapter data
@@ -601,7 +604,7 @@ pub fn CrossReferencesSidebar(
             on:mouseleave=move |_| set_sidebar_has_focus.set(false)
         >
             <div class="mb-4">
-                <h2 class="text-lg font-bold mb-2" style="color: var(--theme-sidebar-text)">{get_ui_text("cross_references")}</h2>
+                <h2 class="text-lg font-bold mb-2" style="color: var(--theme-sidebar-text)">{t("cross_references")}</h2>
                 <div class="text-sm mb-4" style="color: var(--theme-text-secondary)">
                     {get_translated_book_name(&book_name)} " " {chapter} ":" {verse}
                 </div>
@@ -611,7 +614,7 @@ pub fn CrossReferencesSidebar(
                 when=move || sorted_references.get().is_some()
                 fallback=move || view! {
                     <div class="text-sm italic" style="color: var(--theme-text-muted)">
-                        {get_ui_text("no_references")}
+                        {t("no_references")}
                     </div>
                 }
             >
@@ -694,8 +697,34 @@ pub fn CrossReferencesSidebar(
                     </div>
                 </Show>
             </Show>
+
+            <VerseCommentary book_name=canonical_book_name.clone() chapter=chapter verse=verse />
         </div>
     }
+    .into_any()
+}
+
+/// Width of the verse preview popover in pixels (`w-72`)
+const PREVIEW_POPOVER_WIDTH: i32 = 288;
+/// Room kept for the popover below its top edge before it is moved up
+const PREVIEW_POPOVER_MAX_HEIGHT: i32 = 200;
+const PREVIEW_POPOVER_OFFSET: i32 = 12;
+
+/// Top-left corner of the preview popover for a pointer or element at `(x, y)`.
+/// The sidebar sits at the right edge, so the popover opens to the left.
+fn preview_popover_position(x: i32, y: i32, viewport_height: i32) -> (i32, i32) {
+    let left = (x - PREVIEW_POPOVER_WIDTH - PREVIEW_POPOVER_OFFSET).max(PREVIEW_POPOVER_OFFSET);
+    let top = (y + PREVIEW_POPOVER_OFFSET)
+        .min(viewport_height - PREVIEW_POPOVER_MAX_HEIGHT)
+        .max(PREVIEW_POPOVER_OFFSET);
+    (left, top)
+}
+
+fn viewport_height() -> i32 {
+    web_sys::window()
+        .and_then(|window| window.inner_height().ok())
+        .and_then(|height| height.as_f64())
+        .map_or(i32::MAX, |height| height as i32)
 }
 
 #[component]
@@ -709,11 +738,52 @@ fn ReferenceItem(
     let reference_text = format_reference_text(&reference);
     let reference_url = reference_to_url(&reference);
     let votes_text = format_votes_with_emoji(reference.votes);
+    let preview_id = format!("{}-preview", reference_id);
+
+    // Where the hover/focus preview is shown, if at all
+    let preview_position = RwSignal::new(None::<(i32, i32)>);
+    let show_preview_at_pointer = move |e: ev::MouseEvent| {
+        preview_position.set(Some(preview_popover_position(
+            e.client_x(),
+            e.client_y(),
+            viewport_height(),
+        )));
+    };
+    let is_preview_open = Memo::new(move |_| preview_position.with(Option::is_some));
+    // Only the position changes while the pointer moves; the text is looked up once,
+    // and again when the book it is in has been loaded
+    let preview_reference = reference.clone();
+    let preview_content = Memo::new(move |_| {
+        init_bible_signal().track();
+        get_verse_content_for_reference(&preview_reference)
+    });
+    let preview_reference_text = reference_text.clone();
 
     view! {
         <div class="reference-item">
             <button
                 id=reference_id.clone()
+                aria-describedby={
+                    let preview_id = preview_id.clone();
+                    move || is_preview_open.get().then(|| preview_id.clone())
+                }
+                on:mouseenter=show_preview_at_pointer
+                on:mousemove=show_preview_at_pointer
+                on:mouseleave=move |_| preview_position.set(None)
+                on:focus=move |e: ev::FocusEvent| {
+                    if let Some(element) = e
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    {
+                        let rect = element.get_bounding_client_rect();
+                        preview_position.set(Some(preview_popover_position(
+                            rect.left() as i32,
+                            rect.top() as i32,
+                            viewport_height(),
+                        )));
+                    }
+                }
+                on:blur=move |_| preview_position.set(None)
                 class="w-full text-left p-3 rounded-lg border transition-colors duration-150 group"
                 style=move || {
                     if is_selected.get() {
@@ -770,6 +840,27 @@ fn ReferenceItem(
                     </div>
                 </div>
             </button>
+            <Show when=move || is_preview_open.get() fallback=|| view! { <></> }>
+                <div
+                    id=preview_id.clone()
+                    role="tooltip"
+                    class="fixed z-50 w-72 p-3 rounded-lg shadow-lg border pointer-events-none"
+                    style=move || {
+                        let (left, top) = preview_position.get().unwrap_or_default();
+                        format!(
+                            "left: {}px; top: {}px; background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border)",
+                            left, top
+                        )
+                    }
+                >
+                    <div class="text-xs mb-1" style="color: var(--theme-text-muted)">
+                        {preview_reference_text.clone()}
+                    </div>
+                    <div class="text-sm leading-relaxed max-h-40 overflow-hidden" style="color: var(--theme-text-primary)">
+                        {move || preview_content.get()}
+                    </div>
+                </div>
+            </Show>
         </div>
     }
 }
@@ -779,6 +870,14 @@ mod tests {
     use super::*;
     use crate::core::types::Reference;
 
+    #[test]
+    fn test_preview_popover_position() {
+        // Opens to the left of the pointer, just below it
+        assert_eq!(preview_popover_position(1000, 100, 800), (700, 112));
+        // Kept on screen near the left and bottom edges
+        assert_eq!(preview_popover_position(100, 780, 800), (12, 600));
+    }
+
     #[test]
     fn test_format_reference_logic() {
         // Test the basic formatting logic without web dependencies
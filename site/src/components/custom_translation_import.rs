@@ -1,5 +1,11 @@
 use crate::core::types::Language;
-use crate::core::Bible;
+use crate::core::{
+    extract_section_headings, extract_source_text, parse_delimited, parse_usfm_files, split_row,
+    validate_bible, Bible, ColumnMapping, DelimitedOptions, SectionHeading, SourceVerse,
+};
+use crate::i18n::{t, t_with};
+use crate::storage::section_headings::save_section_headings;
+use crate::storage::source_text::save_source_text;
 use crate::storage::{
     add_downloaded_translation, save_translation_to_cache, set_selected_translation,
     switch_bible_translation, BibleTranslation,
@@ -8,6 +14,7 @@ use gloo_storage::{LocalStorage, Storage};
 use leptos::html::Input;
 use leptos::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
 use wasm_bindgen::{closure::Closure, JsCast};
 use wasm_bindgen_futures::spawn_local;
 use web_sys::{Event, FileReader, HtmlInputElement};
@@ -43,6 +50,94 @@ pub fn _remove_custom_translation(
     LocalStorage::set(CUSTOM_TRANSLATIONS_KEY, &custom_translations)
 }
 
+fn is_usfm_file(file_name: &str) -> bool {
+    let lower = file_name.to_lowercase();
+    lower.ends_with(".usfm") || lower.ends_with(".sfm")
+}
+
+fn is_delimited_file(file_name: &str) -> bool {
+    let lower = file_name.to_lowercase();
+    lower.ends_with(".csv") || lower.ends_with(".tsv") || lower.ends_with(".txt")
+}
+
+/// Read all USFM files from a zip archive
+fn extract_usfm_from_zip(bytes: &[u8]) -> Result<Vec<String>, String> {
+    let mut archive =
+        zip::ZipArchive::new(Cursor::new(bytes)).map_err(|e| t_with("invalid_zip", &[("error", &e.to_string())]))?;
+    let mut sources = Vec::new();
+
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| t_with("zip_read_error", &[("error", &e.to_string())]))?;
+        if entry.is_file() && is_usfm_file(entry.name()) {
+            let mut content = String::new();
+            entry
+                .read_to_string(&mut content)
+                .map_err(|e| t_with("file_read_error", &[("file", entry.name()), ("error", &e.to_string())]))?;
+            sources.push(content);
+        }
+    }
+
+    Ok(sources)
+}
+
+/// A Bible with the row warnings, section headings and source text that came with it
+type ParsedImport = (Bible, Vec<String>, Vec<SectionHeading>, Vec<SourceVerse>);
+
+/// Build a Bible from the uploaded files: a single JSON or delimited file,
+/// or any number of USFM files and zip archives containing USFM files.
+/// Returns the Bible together with warnings about skipped rows, the
+/// section headings found in USFM or JSON files and the source text found in JSON.
+fn parse_import_files(
+    files: &[(String, Vec<u8>)],
+    delimited_options: Option<&DelimitedOptions>,
+) -> Result<ParsedImport, String> {
+    if let [(file_name, bytes)] = files {
+        let text = String::from_utf8_lossy(bytes);
+        if file_name.to_lowercase().ends_with(".json") {
+            return serde_json::from_str::<Bible>(&text)
+                .map(|bible| {
+                    (
+                        bible,
+                        Vec::new(),
+                        extract_section_headings(&text),
+                        extract_source_text(&text),
+                    )
+                })
+                .map_err(|e| t_with("invalid_json", &[("error", &e.to_string())]));
+        }
+        if is_delimited_file(file_name) {
+            let options = delimited_options
+                .cloned()
+                .unwrap_or_else(|| DelimitedOptions::detect(&text));
+            return parse_delimited(&text, &options)
+                .map(|(bible, warnings)| (bible, warnings, Vec::new(), Vec::new()))
+                .map_err(|e| t_with("invalid_table", &[("error", &e.to_string())]));
+        }
+    }
+
+    let mut sources = Vec::new();
+    for (file_name, bytes) in files {
+        let lower = file_name.to_lowercase();
+        if lower.ends_with(".zip") {
+            sources.extend(extract_usfm_from_zip(bytes)?);
+        } else if is_usfm_file(&lower) {
+            sources.push(String::from_utf8_lossy(bytes).into_owned());
+        } else {
+            return Err(t_with("unsupported_file", &[("file", file_name)]));
+        }
+    }
+
+    if sources.is_empty() {
+        return Err(t("no_usfm_files"));
+    }
+
+    parse_usfm_files(&sources)
+        .map(|(bible, headings)| (bible, Vec::new(), headings, Vec::new()))
+        .map_err(|e| t_with("invalid_usfm", &[("error", &e.to_string())]))
+}
+
 async fn save_custom_translation_to_cache(
     translation: &BibleTranslation,
     bible: &Bible,
@@ -63,7 +158,12 @@ pub fn CustomTranslationImport(
     let (translation_name, set_translation_name) = signal(String::new());
     let (release_year, set_release_year) = signal(String::new());
     let (_file_selected, set_file_selected) = signal(false);
-    let (file_content, set_file_content) = signal::<Option<String>>(None);
+    // (file name, raw bytes) of every selected file
+    let (file_contents, set_file_contents) = signal::<Vec<(String, Vec<u8>)>>(Vec::new());
+    // Validation warnings the user still has to confirm before importing
+    let (validation_warnings, set_validation_warnings) = signal::<Vec<String>>(Vec::new());
+    // Column mapping for CSV/TSV imports, detected when the file is loaded
+    let delimited_options = RwSignal::new(None::<DelimitedOptions>);
 
     let file_input_ref = NodeRef::<Input>::new();
 
@@ -71,7 +171,9 @@ pub fn CustomTranslationImport(
         set_translation_name.set(String::new());
         set_release_year.set(String::new());
         set_file_selected.set(false);
-        set_file_content.set(None);
+        set_file_contents.set(Vec::new());
+        set_validation_warnings.set(Vec::new());
+        delimited_options.set(None);
         set_import_error.set(None);
         if let Some(input) = file_input_ref.get() {
             input.set_value("");
@@ -84,26 +186,34 @@ pub fn CustomTranslationImport(
             .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
         if let Some(input) = input {
             if let Some(files) = input.files() {
-                if files.length() > 0 {
-                    if let Some(file) = files.get(0) {
-                        set_file_selected.set(true);
-                        set_import_error.set(None);
-
+                set_file_contents.set(Vec::new());
+                set_validation_warnings.set(Vec::new());
+                delimited_options.set(None);
+                set_file_selected.set(files.length() > 0);
+                set_import_error.set(None);
+
+                for index in 0..files.length() {
+                    if let Some(file) = files.get(index) {
+                        let file_name = file.name();
                         let file_reader = FileReader::new().unwrap();
                         let file_reader_clone = file_reader.clone();
 
                         let onload = Closure::wrap(Box::new(move |_: Event| {
                             if let Some(result) = file_reader_clone.result().ok() {
-                                if let Some(text) = result.as_string() {
-                                    set_file_content.set(Some(text));
+                                let bytes = js_sys::Uint8Array::new(&result).to_vec();
+                                if is_delimited_file(&file_name) {
+                                    let text = String::from_utf8_lossy(&bytes);
+                                    delimited_options.set(Some(DelimitedOptions::detect(&text)));
                                 }
+                                set_file_contents
+                                    .update(|contents| contents.push((file_name.clone(), bytes)));
                             }
                         }) as Box<dyn FnMut(_)>);
 
                         file_reader.set_onload(Some(onload.as_ref().unchecked_ref()));
                         onload.forget();
 
-                        let _ = file_reader.read_as_text(&file);
+                        let _ = file_reader.read_as_array_buffer(&file);
                     }
                 }
             }
@@ -112,92 +222,133 @@ pub fn CustomTranslationImport(
 
     let handle_import = Callback::new(move |_| {
         if translation_name.get().trim().is_empty() {
-            set_import_error.set(Some("Voer een naam in voor de vertaling".to_string()));
+            set_import_error.set(Some(t("enter_translation_name")));
             return;
         }
 
         if release_year.get().trim().is_empty() {
-            set_import_error.set(Some("Voer een uitgavejaar in".to_string()));
+            set_import_error.set(Some(t("enter_release_year")));
             return;
         }
 
         let year: u16 = match release_year.get().parse() {
             Ok(y) => y,
             Err(_) => {
-                set_import_error.set(Some("Uitgavejaar moet een geldig getal zijn".to_string()));
+                set_import_error.set(Some(t("invalid_release_year")));
                 return;
             }
         };
 
-        if let Some(text) = file_content.get() {
-            set_is_importing.set(true);
+        let files = file_contents.get();
+        if !files.is_empty() {
             set_import_error.set(None);
 
+            let options = delimited_options.get();
+            let (bible, row_warnings, headings, source_text) = match parse_import_files(&files, options.as_ref())
+            {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    set_import_error.set(Some(e));
+                    return;
+                }
+            };
+
+            let mut report = validate_bible(&bible);
+            report.warnings.splice(0..0, row_warnings);
+            if !report.is_valid() {
+                set_import_error.set(Some(t_with(
+                    "invalid_translation",
+                    &[("errors", &report.errors.join("; "))],
+                )));
+                return;
+            }
+
+            // Show warnings first; a second click imports anyway
+            if !report.warnings.is_empty() && validation_warnings.get().is_empty() {
+                set_validation_warnings.set(report.warnings);
+                return;
+            }
+
+            set_is_importing.set(true);
+
             let name = translation_name.get();
             let lang = selected_language.get();
             let success_callback = on_success.clone();
 
             spawn_local(async move {
-                match serde_json::from_str::<Bible>(&text) {
-                    Ok(bible) => {
-                        let short_name = format!(
-                            "custom_{}",
-                            js_sys::Math::random().to_string().replace("0.", "")[..8]
-                                .to_lowercase()
-                        );
-
-                        let translation = BibleTranslation {
-                            name: name,
-                            short_name: short_name.clone(),
-                            release_year: year,
-                            iagon: String::new(),
-                            languages: vec![lang],
-                        };
-
-                        match save_custom_translation_to_cache(&translation, &bible).await {
-                            Ok(_) => {
-                                if let Err(e) = add_custom_translation(&translation) {
-                                    set_import_error.set(Some(format!("Fout bij opslaan: {}", e)));
-                                    set_is_importing.set(false);
-                                    return;
-                                }
+                let short_name = format!(
+                    "custom_{}",
+                    js_sys::Math::random().to_string().replace("0.", "")[..8]
+                        .to_lowercase()
+                );
+
+                let translation = BibleTranslation {
+                    name,
+                    short_name: short_name.clone(),
+                    release_year: year,
+                    iagon: String::new(),
+                    languages: vec![lang],
+                    revision: 0,
+                    changelog: Vec::new(),
+                    versification: Default::default(),
+                };
+
+                match save_custom_translation_to_cache(&translation, &bible).await {
+                    Ok(_) => {
+                        if let Err(e) = save_section_headings(&short_name, &headings).await {
+                            set_import_error.set(Some(t_with(
+                                "section_headings_save_error",
+                                &[("error", &e.to_string())],
+                            )));
+                            set_is_importing.set(false);
+                            return;
+                        }
 
-                                if let Err(e) = add_downloaded_translation(&short_name) {
-                                    set_import_error
-                                        .set(Some(format!("Fout bij registreren: {}", e)));
-                                    set_is_importing.set(false);
-                                    return;
-                                }
+                        if let Err(e) = save_source_text(&short_name, &source_text).await {
+                            set_import_error.set(Some(t_with(
+                                "source_text_save_error",
+                                &[("error", &e.to_string())],
+                            )));
+                            set_is_importing.set(false);
+                            return;
+                        }
 
-                                let _ = set_selected_translation(&short_name);
+                        if let Err(e) = add_custom_translation(&translation) {
+                            set_import_error.set(Some(t_with("save_error", &[("error", &e.to_string())])));
+                            set_is_importing.set(false);
+                            return;
+                        }
 
-                                if let Err(e) = switch_bible_translation(&short_name).await {
-                                    leptos::logging::error!(
-                                        "Failed to switch to imported translation: {}",
-                                        e
-                                    );
-                                }
+                        if let Err(e) = add_downloaded_translation(&short_name) {
+                            set_import_error
+                                .set(Some(t_with("register_error", &[("error", &e.to_string())])));
+                            set_is_importing.set(false);
+                            return;
+                        }
 
-                                set_is_importing.set(false);
-                                set_show_import_modal.set(false);
-                                reset_form();
-                                success_callback();
-                            }
-                            Err(e) => {
-                                set_import_error
-                                    .set(Some(format!("Fout bij opslaan naar cache: {}", e)));
-                                set_is_importing.set(false);
-                            }
+                        let _ = set_selected_translation(&short_name);
+
+                        if let Err(e) = switch_bible_translation(&short_name).await {
+                            leptos::logging::error!(
+                                "Failed to switch to imported translation: {}",
+                                e
+                            );
                         }
+
+                        set_is_importing.set(false);
+                        set_show_import_modal.set(false);
+                        reset_form();
+                        success_callback();
                     }
                     Err(e) => {
-                        set_import_error.set(Some(format!("Ongeldig JSON formaat: {}", e)));
+                        set_import_error
+                            .set(Some(t_with("cache_save_error", &[("error", &e.to_string())])));
                         set_is_importing.set(false);
                     }
                 }
             });
         } else {
-            set_import_error.set(Some("Selecteer een JSON bestand".to_string()));
+            set_import_error.set(Some(t("select_import_file")));
         }
     });
 
@@ -214,10 +365,10 @@ pub fn CustomTranslationImport(
                 <div class="flex items-center justify-between">
                     <div class="flex-1">
                         <h3 class="text-xl font-semibold mb-1" style="color: var(--theme-text-primary)">
-                            "Importeer je eigen vertaling"
+                            {t("import_own_translation")}
                         </h3>
                         <p class="text-sm" style="color: var(--theme-text-secondary)">
-                            "Upload een JSON bestand met je Bijbelvertaling"
+                            {t("import_own_translation_description")}
                         </p>
                     </div>
                     <div class="ml-6">
@@ -236,7 +387,7 @@ pub fn CustomTranslationImport(
                     <div class="max-w-md w-full mx-4 rounded-lg p-6" style="background-color: var(--theme-background); border: 1px solid var(--theme-sidebar-border)">
                         <div class="flex justify-between items-center mb-4">
                             <h2 class="text-xl font-semibold" style="color: var(--theme-text-primary)">
-                                "Vertaling importeren"
+                                {t("import_translation")}
                             </h2>
                             <button
                                 class="text-gray-400 hover:text-gray-600"
@@ -251,13 +402,13 @@ pub fn CustomTranslationImport(
                         <div class="space-y-4">
                             <div>
                                 <label class="block text-sm font-medium mb-1" style="color: var(--theme-text-primary)">
-                                    "Naam van de vertaling"
+                                    {t("translation_name")}
                                 </label>
                                 <input
                                     type="text"
                                     class="w-full px-3 py-2 border rounded-md"
                                     style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
-                                    placeholder="Bijv. Mijn Bijbelvertaling"
+                                    placeholder=t("translation_name_placeholder")
                                     prop:value=move || translation_name.get()
                                     on:input=move |ev| set_translation_name.set(event_target_value(&ev))
                                 />
@@ -265,7 +416,7 @@ pub fn CustomTranslationImport(
 
                             <div>
                                 <label class="block text-sm font-medium mb-1" style="color: var(--theme-text-primary)">
-                                    "Uitgavejaar"
+                                    {t("release_year")}
                                 </label>
                                 <input
                                     type="number"
@@ -280,21 +431,58 @@ pub fn CustomTranslationImport(
 
                             <div>
                                 <label class="block text-sm font-medium mb-1" style="color: var(--theme-text-primary)">
-                                    "JSON bestand"
+                                    {t("import_files")}
                                 </label>
                                 <input
                                     type="file"
-                                    accept=".json"
+                                    accept=".json,.csv,.tsv,.txt,.usfm,.sfm,.zip"
+                                    multiple=true
                                     class="w-full px-3 py-2 border rounded-md"
                                     style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
                                     node_ref=file_input_ref
                                     on:change=on_file_change
                                 />
                                 <p class="text-xs mt-1" style="color: var(--theme-text-muted)">
-                                    "Upload een JSON bestand met de Bijbel structuur"
+                                    {t("import_files_description")}
                                 </p>
                             </div>
 
+                            <Show
+                                when=move || delimited_options.get().is_some()
+                                fallback=|| view! { <></> }
+                            >
+                                <ColumnMappingStep
+                                    options=delimited_options
+                                    first_line=Signal::derive(move || {
+                                        file_contents
+                                            .get()
+                                            .first()
+                                            .and_then(|(_, bytes)| {
+                                                String::from_utf8_lossy(bytes)
+                                                    .lines()
+                                                    .find(|line| !line.trim().is_empty())
+                                                    .map(str::to_string)
+                                            })
+                                            .unwrap_or_default()
+                                    })
+                                    on_change=move || set_validation_warnings.set(Vec::new())
+                                />
+                            </Show>
+
+                            <Show
+                                when=move || !validation_warnings.get().is_empty()
+                                fallback=|| view! { <></> }
+                            >
+                                <div class="p-3 rounded-md border text-sm max-h-32 overflow-y-auto" style="border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)">
+                                    <p class="font-medium mb-1">
+                                        {move || t_with("warnings_found", &[("count", &validation_warnings.get().len().to_string())])}
+                                    </p>
+                                    <ul class="list-disc ml-4 text-xs" style="color: var(--theme-text-secondary)">
+                                        {move || validation_warnings.get().into_iter().map(|warning| view! { <li>{warning}</li> }).collect_view()}
+                                    </ul>
+                                </div>
+                            </Show>
+
                             <Show
                                 when=move || import_error.get().is_some()
                                 fallback=|| view! { <></> }
@@ -311,7 +499,7 @@ pub fn CustomTranslationImport(
                                     on:click=move |_| set_show_import_modal.set(false)
                                     disabled=move || is_importing.get()
                                 >
-                                    "Annuleren"
+                                    {t("cancel")}
                                 </button>
                                 <button
                                     class="flex-1 px-4 py-2 rounded-md transition-colors translation-button-primary"
@@ -319,9 +507,11 @@ pub fn CustomTranslationImport(
                                     disabled=move || is_importing.get()
                                 >
                                     {move || if is_importing.get() {
-                                        "Importeren..."
+                                        t("importing")
+                                    } else if !validation_warnings.get().is_empty() {
+                                        t("import_anyway")
                                     } else {
-                                        "Importeren"
+                                        t("import")
                                     }}
                                 </button>
                             </div>
@@ -332,3 +522,122 @@ pub fn CustomTranslationImport(
         </div>
     }
 }
+
+/// Column-mapping step shown when a CSV/TSV file is selected
+#[component]
+fn ColumnMappingStep(
+    options: RwSignal<Option<DelimitedOptions>>,
+    /// First non-empty line of the file, used to label the columns
+    first_line: Signal<String>,
+    /// Called whenever the mapping changes so stale warnings are cleared
+    on_change: impl Fn() + Copy + Send + Sync + 'static,
+) -> impl IntoView {
+    let delimiters = [('|', "|"), ('\t', "Tab"), (';', ";"), (',', ",")];
+
+    let column_labels = Memo::new(move |_| {
+        let Some(current) = options.get() else {
+            return Vec::new();
+        };
+        split_row(&first_line.get(), current.delimiter)
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                if current.has_header {
+                    value
+                } else {
+                    t_with(
+                        "column_option",
+                        &[
+                            ("number", &(index + 1).to_string()),
+                            ("preview", &value.chars().take(15).collect::<String>()),
+                        ],
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let update = move |f: &dyn Fn(&mut DelimitedOptions)| {
+        options.update(|current| {
+            if let Some(current) = current {
+                f(current);
+            }
+        });
+        on_change();
+    };
+
+    let column_select = move |label: String, get: fn(&ColumnMapping) -> usize, set: fn(&mut ColumnMapping, usize)| {
+        view! {
+            <label class="flex items-center justify-between gap-2 text-sm">
+                <span>{label}</span>
+                <select
+                    class="flex-1 px-2 py-1 border rounded-md text-sm"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    on:change=move |ev| {
+                        if let Ok(column) = event_target_value(&ev).parse::<usize>() {
+                            update(&|current| set(&mut current.mapping, column));
+                        }
+                    }
+                >
+                    {move || {
+                        let selected = options.get().map(|o| get(&o.mapping)).unwrap_or_default();
+                        column_labels
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, name)| view! {
+                                <option value=index.to_string() selected=index == selected>{name}</option>
+                            })
+                            .collect_view()
+                    }}
+                </select>
+            </label>
+        }
+    };
+
+    view! {
+        <div class="p-3 rounded-md border space-y-2" style="border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)">
+            <p class="text-sm font-medium">"Kolommen koppelen"</p>
+            <div class="flex items-center gap-4 text-sm">
+                <label class="flex items-center gap-2">
+                    <span>{t("delimiter")}</span>
+                    <select
+                        class="px-2 py-1 border rounded-md text-sm"
+                        style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            if let Some((delimiter, _)) = delimiters.iter().find(|(_, name)| *name == value) {
+                                update(&|current| current.delimiter = *delimiter);
+                            }
+                        }
+                    >
+                        {move || {
+                            let selected = options.get().map(|o| o.delimiter);
+                            delimiters
+                                .iter()
+                                .map(|(delimiter, name)| view! {
+                                    <option value=*name selected=selected == Some(*delimiter)>{*name}</option>
+                                })
+                                .collect_view()
+                        }}
+                    </select>
+                </label>
+                <label class="flex items-center gap-2">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || options.get().is_some_and(|o| o.has_header)
+                        on:change=move |ev| {
+                            let checked = event_target_checked(&ev);
+                            update(&|current| current.has_header = checked);
+                        }
+                    />
+                    <span>{t("first_row_is_header")}</span>
+                </label>
+            </div>
+            {column_select(t("column_book"), |m| m.book, |m, c| m.book = c)}
+            {column_select(t("column_chapter"), |m| m.chapter, |m, c| m.chapter = c)}
+            {column_select(t("column_verse"), |m| m.verse, |m, c| m.verse = c)}
+            {column_select(t("column_text"), |m| m.text, |m, c| m.text = c)}
+        </div>
+    }
+}
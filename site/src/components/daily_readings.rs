@@ -0,0 +1,38 @@
+use crate::core::{daily_reading_links, init_bible_signal, todays_readings};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Links to today's Psalm and Proverb, shown once a Bible is loaded
+#[component]
+pub fn DailyReadingsCard() -> impl IntoView {
+    move || {
+        let readings = todays_readings();
+        let links = init_bible_signal()
+            .with(|bible| {
+                bible
+                    .as_ref()
+                    .map(|bible| daily_reading_links(bible, readings))
+            })
+            .unwrap_or_default();
+        (!links.is_empty()).then(|| {
+            view! {
+                <section
+                    class="max-w-2xl mx-auto mt-8 px-4 py-3 border rounded-lg text-sm flex flex-wrap items-center gap-x-4 gap-y-1"
+                    style="border-color: var(--theme-sidebar-border); color: var(--theme-text-secondary)"
+                >
+                    <span class="font-medium" style="color: var(--theme-text-primary)">"Today"</span>
+                    {links
+                        .into_iter()
+                        .map(|(name, path)| {
+                            view! {
+                                <A href=path attr:class="hover:underline translation-link">
+                                    {name}
+                                </A>
+                            }
+                        })
+                        .collect_view()}
+                </section>
+            }
+        })
+    }
+}
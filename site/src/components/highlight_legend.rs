@@ -0,0 +1,115 @@
+use crate::i18n::{t, t_with};
+use crate::instructions::logic::update_view_state_from_url;
+use crate::instructions::types::Instruction;
+use crate::storage::{get_highlight_labels, save_highlight_label, HighlightColor};
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+use leptos_router::hooks::use_location;
+
+/// Name of a highlight color in the interface language
+fn color_name(color: HighlightColor) -> String {
+    t(&format!("color_{}", color.name().to_lowercase()))
+}
+
+/// Panel with the highlight colors, what the reader uses each for, and the chapter filter
+#[component]
+pub fn HighlightLegend(view_state: ViewStateSignal) -> impl IntoView {
+    let location = use_location();
+    let (labels, set_labels) = signal(get_highlight_labels());
+
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && view_state.with(|state| state.is_highlight_legend_open) {
+            evt.prevent_default();
+            view_state.update(|state| {
+                state.execute(&Instruction::CloseHighlightLegend);
+            });
+        }
+    });
+
+    // Apply to the verses selected in the URL
+    let (pathname, search) = (location.pathname, location.search);
+    let execute = move |instruction: Instruction| {
+        if update_view_state_from_url(
+            view_state,
+            &pathname.get_untracked(),
+            &search.get_untracked(),
+        ) {
+            view_state.update(|state| {
+                state.execute(&instruction);
+            });
+        }
+    };
+
+    let rows = move || {
+        HighlightColor::ALL.map(|color| {
+            let name = color_name(color);
+            let lowercase_name = name.to_lowercase();
+            view! {
+                <li class="flex items-center gap-2">
+                    <button
+                        class="w-6 h-6 flex-shrink-0 rounded border"
+                        style=format!("background-color: {}; border-color: var(--theme-sidebar-border)", color.css_background())
+                        title=t_with("highlight_selected_verses", &[("color", &lowercase_name)])
+                        aria-label=t_with("highlight_color", &[("color", &name)])
+                        on:click=move |_| execute(Instruction::HighlightVerse(color))
+                    ></button>
+                    <input
+                        type="text"
+                        class="flex-1 min-w-0 px-2 py-1 text-xs border rounded"
+                        style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                        placeholder=name.clone()
+                        aria-label=t_with("meaning_of_color", &[("color", &lowercase_name)])
+                        prop:value=move || labels.with(|labels| labels.get(&color).cloned().unwrap_or_default())
+                        on:change=move |ev| {
+                            save_highlight_label(color, &event_target_value(&ev));
+                            set_labels.set(get_highlight_labels());
+                        }
+                    />
+                </li>
+            }
+        })
+    };
+
+    view! {
+        <Show when=move || view_state.with(|state| state.is_highlight_legend_open) fallback=|| view! { <></> }>
+            <div
+                class="fixed bottom-4 left-4 w-72 p-3 rounded-lg shadow-lg border z-40"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+            >
+                <div class="flex items-center justify-between mb-2">
+                    <h2 class="text-sm font-semibold">{t("highlights")}</h2>
+                    <button
+                        class="text-xs underline opacity-75 hover:opacity-100"
+                        aria-label=t("close_highlight_legend")
+                        on:click=move |_| view_state.update(|state| {
+                            state.execute(&Instruction::CloseHighlightLegend);
+                        })
+                    >
+                        {t("close")}
+                    </button>
+                </div>
+                <ul class="space-y-1">{rows}</ul>
+                <div class="flex items-center justify-between mt-3 text-xs">
+                    <button
+                        class="underline opacity-75 hover:opacity-100"
+                        on:click=move |_| execute(Instruction::RemoveHighlight)
+                    >
+                        {t("remove_highlight")}
+                    </button>
+                    <label class="flex items-center gap-1">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || view_state.with(|state| state.show_only_highlighted)
+                            on:change=move |_| view_state.update(|state| {
+                                state.execute(&Instruction::ToggleHighlightedOnly);
+                            })
+                        />
+                        {t("only_highlighted_verses")}
+                    </label>
+                </div>
+            </div>
+        </Show>
+    }
+}
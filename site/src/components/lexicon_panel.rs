@@ -0,0 +1,317 @@
+use crate::core::{words_with_lexicon_key, SourceScript};
+use crate::i18n::{t, t_with};
+use crate::instructions::types::Instruction;
+use crate::storage::lexicon::{
+    clear_lexicon, count_lexicon_entries, get_lexicon_entry, import_lexicon, parse_lexicon,
+    LexiconEntry,
+};
+use crate::storage::source_text::get_source_text;
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::html::Input;
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{Event, FileReader, HtmlInputElement};
+
+fn show_entry(view_state: ViewStateSignal, key: String) {
+    view_state.update(|state| {
+        state.execute(&Instruction::ShowLexiconEntry(key));
+    });
+}
+
+/// Dictionary entries for Strong's numbers and lemmas, from an imported lexicon
+/// or else the glosses the current translation was packaged with
+#[component]
+pub fn LexiconPanel(view_state: ViewStateSignal) -> impl IntoView {
+    let file_input_ref = NodeRef::<Input>::new();
+    let (import_status, set_import_status) = signal::<Option<Result<String, String>>>(None);
+    // Bumped after an import or clear so the stored entries are read again
+    let lexicon_version = RwSignal::new(0u32);
+    let lexicon_count = RwSignal::new(0);
+    let imported = RwSignal::new(None::<(String, LexiconEntry)>);
+
+    let is_open = Memo::new(move |_| view_state.with(|state| state.is_lexicon_open));
+    let shown_key = Memo::new(move |_| {
+        view_state.with(|state| {
+            state
+                .is_lexicon_open
+                .then(|| state.lexicon_entry.clone())
+                .flatten()
+        })
+    });
+
+    Effect::new(move |_| {
+        lexicon_version.track();
+        if !is_open.get() {
+            return;
+        }
+        spawn_local(async move {
+            let count = count_lexicon_entries().await;
+            let _ = lexicon_count.try_set(count);
+        });
+    });
+
+    Effect::new(move |_| {
+        lexicon_version.track();
+        let Some(key) = shown_key.get() else {
+            return;
+        };
+        spawn_local(async move {
+            let entry = get_lexicon_entry(&key).await;
+            if shown_key.try_get_untracked().flatten().as_ref() == Some(&key) {
+                imported.set(entry.map(|entry| (key, entry)));
+            }
+        });
+    });
+
+    // The whole source text of the translation, for every occurrence of a word;
+    // only read once the panel shows an entry
+    let source_text = RwSignal::new(Vec::new());
+    let source_text_for = RwSignal::new(None::<String>);
+    let wanted_source_text = Memo::new(move |_| {
+        view_state.with(|state| {
+            (state.is_lexicon_open && state.lexicon_entry.is_some())
+                .then(|| state.current_translation_short_name.clone())
+        })
+    });
+    Effect::new(move |_| {
+        let Some(short_name) = wanted_source_text.get() else {
+            return;
+        };
+        if source_text_for.get_untracked().as_ref() == Some(&short_name) {
+            return;
+        }
+        source_text_for.set(Some(short_name.clone()));
+        source_text.set(Vec::new());
+        spawn_local(async move {
+            let loaded = get_source_text(&short_name).await;
+            if source_text_for.try_get_untracked().flatten().as_ref() == Some(&short_name) {
+                source_text.set(loaded);
+            }
+        });
+    });
+
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && view_state.with(|state| state.is_lexicon_open) {
+            evt.prevent_default();
+            view_state.update(|state| {
+                state.execute(&Instruction::CloseLexicon);
+            });
+        }
+    });
+
+    let on_search_keydown = move |evt: KeyboardEvent| {
+        if evt.key() != "Enter" {
+            return;
+        }
+        let Some(input) = evt
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        let key = input.value();
+        if !key.trim().is_empty() {
+            show_entry(view_state, key);
+            input.set_value("");
+        }
+    };
+
+    let on_file_change = move |ev: Event| {
+        set_import_status.set(None);
+        let Some(file) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0))
+        else {
+            return;
+        };
+        let Ok(file_reader) = FileReader::new() else {
+            return;
+        };
+        let file_reader_clone = file_reader.clone();
+
+        let onload = Closure::wrap(Box::new(move |_: Event| {
+            let text = file_reader_clone
+                .result()
+                .ok()
+                .and_then(|result| result.as_string())
+                .unwrap_or_default();
+            match parse_lexicon(&text) {
+                Ok(entries) => spawn_local(async move {
+                    let status = import_lexicon(entries)
+                        .await
+                        .map(|count| t_with("lexicon_imported", &[("count", &count.to_string())]))
+                        .map_err(|error| t_with("save_error", &[("error", &error.to_string())]));
+                    set_import_status.set(Some(status));
+                    lexicon_version.update(|version| *version += 1);
+                }),
+                Err(error) => set_import_status.set(Some(Err(error))),
+            }
+            if let Some(input) = file_input_ref.get_untracked() {
+                input.set_value("");
+            }
+        }) as Box<dyn FnMut(_)>);
+
+        file_reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        onload.forget();
+
+        let _ = file_reader.read_as_text(&file);
+    };
+
+    let entry = move || {
+        let Some(key) = view_state.with(|state| state.lexicon_entry.clone()) else {
+            return view! {
+                <p class="text-xs mb-2" style="color: var(--theme-text-muted)">{t("lexicon_empty")}</p>
+            }
+            .into_any();
+        };
+
+        let imported = imported.with(|imported| {
+            imported
+                .as_ref()
+                .filter(|(imported_key, _)| *imported_key == key)
+                .map(|(_, entry)| entry.clone())
+        });
+        let (lemma, gloss, occurrences) = source_text.with(|source_text| {
+            let words = words_with_lexicon_key(source_text, &key);
+            (
+                words.iter().find_map(|word| word.lemma.clone()),
+                words.iter().find_map(|word| word.gloss.clone()),
+                words.len(),
+            )
+        });
+        let imported = imported.unwrap_or_default();
+        let lemma = imported.lemma.or(lemma);
+        let gloss = imported.gloss.or(gloss);
+
+        if lemma.is_none() && gloss.is_none() && imported.definition.is_none() {
+            return view! {
+                <p class="text-xs mb-2" style="color: var(--theme-text-muted)">
+                    {t_with("lexicon_no_entry", &[("key", &key)])}
+                </p>
+            }
+            .into_any();
+        }
+
+        let script = SourceScript::detect(lemma.as_deref().unwrap_or_default());
+        view! {
+            <div class="mb-3">
+                <div class="flex items-baseline justify-between gap-2">
+                    {lemma.map(|lemma| view! {
+                        <span class=format!("text-lg {}", script.css_class()) lang=script.lang() dir=script.dir()>
+                            {lemma}
+                        </span>
+                    })}
+                    <span class="text-xs font-mono" style="color: var(--theme-text-muted)">{key.clone()}</span>
+                </div>
+                {imported.transliteration.map(|transliteration| view! {
+                    <div class="text-sm italic" style="color: var(--theme-text-muted)">{transliteration}</div>
+                })}
+                {gloss.map(|gloss| view! {
+                    <div class="text-sm font-medium mt-1">{gloss}</div>
+                })}
+                {imported.definition.map(|definition| view! {
+                    <p class="text-sm mt-1">{definition}</p>
+                })}
+                {(occurrences > 0).then(|| view! {
+                    <p class="text-xs mt-1" style="color: var(--theme-text-muted)">
+                        {t_with("lexicon_occurrences", &[("count", &occurrences.to_string())])}
+                    </p>
+                })}
+            </div>
+        }
+        .into_any()
+    };
+
+    let history = move || {
+        let history = view_state.with(|state| state.lexicon_history.clone());
+        (!history.is_empty()).then(|| {
+            view! {
+                <div class="mb-3">
+                    <h3 class="text-xs font-semibold mb-1">{t("lexicon_recent")}</h3>
+                    <div class="flex flex-wrap gap-1">
+                        {history.into_iter().map(|key| {
+                            let label = key.clone();
+                            view! {
+                                <button
+                                    class="px-1.5 py-0.5 text-xs rounded border"
+                                    style="border-color: var(--theme-sidebar-border)"
+                                    on:click=move |_| show_entry(view_state, key.clone())
+                                >
+                                    {label}
+                                </button>
+                            }
+                        }).collect_view()}
+                    </div>
+                </div>
+            }
+        })
+    };
+
+    view! {
+        <Show when=move || view_state.with(|state| state.is_lexicon_open) fallback=|| view! { <></> }>
+            <div
+                class="fixed bottom-4 left-4 w-80 max-h-[70vh] overflow-y-auto p-3 rounded-lg shadow-lg border z-40"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+            >
+                <div class="flex items-center justify-between mb-2">
+                    <h2 class="text-sm font-semibold">{t("lexicon")}</h2>
+                    <button
+                        class="text-xs underline opacity-75 hover:opacity-100"
+                        on:click=move |_| view_state.update(|state| {
+                            state.execute(&Instruction::CloseLexicon);
+                        })
+                    >
+                        {t("close")}
+                    </button>
+                </div>
+                <input
+                    type="search"
+                    class="w-full px-2 py-1 mb-2 text-sm rounded border"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    placeholder=t("lexicon_search")
+                    aria-label=t("lexicon_search")
+                    on:keydown=on_search_keydown
+                />
+                {entry}
+                {history}
+                <h3 class="text-xs font-semibold mb-1">{t("lexicon_import")}</h3>
+                <input
+                    type="file"
+                    accept=".json,application/json"
+                    class="w-full text-xs mb-1"
+                    aria-label=t("lexicon_import")
+                    node_ref=file_input_ref
+                    on:change=on_file_change
+                />
+                {move || import_status.get().map(|status| match status {
+                    Ok(message) => view! { <p class="text-xs opacity-75">{message}</p> }.into_any(),
+                    Err(error) => view! { <p class="text-xs text-red-600">{error}</p> }.into_any(),
+                })}
+                <Show when=move || lexicon_count.get() != 0>
+                    <button
+                        class="mt-1 text-xs underline opacity-75 hover:opacity-100"
+                        on:click=move |_| {
+                            set_import_status.set(None);
+                            spawn_local(async move {
+                                if let Err(error) = clear_lexicon().await {
+                                    set_import_status.set(Some(Err(t_with(
+                                        "save_error",
+                                        &[("error", &error.to_string())],
+                                    ))));
+                                }
+                                lexicon_version.update(|version| *version += 1);
+                            });
+                        }
+                    >
+                        {t("lexicon_clear")}
+                    </button>
+                </Show>
+            </div>
+        </Show>
+    }
+}
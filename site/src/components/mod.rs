@@ -1,23 +1,53 @@
 // === UI Components ===
 // Core interface components for the Bible application
 
+pub mod annotation_import;
+pub mod book_abbreviation_settings;
 pub mod command_palette;
 pub mod cross_references_sidebar;
 pub mod custom_translation_import;
+pub mod daily_readings;
+pub mod highlight_legend;
+pub mod lexicon_panel;
+pub mod note_editor;
+pub mod palette_alias_settings;
 pub mod pdf_loading_progress;
+pub mod place_map;
+pub mod read_aloud;
+pub mod reading_layout_settings;
+pub mod reference_text;
 pub mod sidebar;
 pub mod theme_sidebar;
 pub mod theme_switcher;
 pub mod translation_comparison;
 pub mod translation_switcher;
+pub mod translation_updates;
+pub mod ui_language_settings;
+pub mod verse_commentary;
+pub mod word_popover;
 
 // === Component Exports ===
 // Re-export all public components for easy importing
 
+pub use annotation_import::*;
+pub use book_abbreviation_settings::*;
 pub use command_palette::*;
 pub use cross_references_sidebar::*;
 pub use custom_translation_import::*;
+pub use daily_readings::*;
+pub use highlight_legend::*;
+pub use lexicon_panel::*;
+pub use note_editor::*;
+pub use palette_alias_settings::*;
 pub use pdf_loading_progress::*;
+pub use place_map::*;
+pub use read_aloud::*;
+pub use reading_layout_settings::*;
+pub use reference_text::*;
 pub use sidebar::*;
 pub use theme_sidebar::*;
 pub use translation_comparison::*;
+pub use translation_updates::*;
+pub use ui_language_settings::*;
+pub use verse_commentary::*;
+pub use word_popover::*;
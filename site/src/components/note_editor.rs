@@ -0,0 +1,96 @@
+use crate::components::{reference_links, ReferenceText};
+use crate::i18n::{t, t_with};
+use crate::instructions::types::Instruction;
+use crate::storage::find_note;
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+
+/// Panel for writing the note on a verse, saved as it is typed
+#[component]
+pub fn NoteEditor(view_state: ViewStateSignal) -> impl IntoView {
+    let textarea_ref = NodeRef::<leptos::html::Textarea>::new();
+    let editing = Memo::new(move |_| view_state.with(|state| state.note_editor_verse.clone()));
+
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && editing.with_untracked(Option::is_some) {
+            evt.prevent_default();
+            view_state.update(|state| {
+                state.execute(&Instruction::CloseNoteEditor);
+            });
+        }
+    });
+
+    // Start typing right away
+    Effect::new(move |_| {
+        if editing.get().is_some() {
+            if let Some(textarea) = textarea_ref.get() {
+                let _ = textarea.focus();
+            }
+        }
+    });
+
+    let close = move |_| {
+        view_state.update(|state| {
+            state.execute(&Instruction::CloseNoteEditor);
+        })
+    };
+
+    view! {
+        <Show when=move || editing.with(Option::is_some) fallback=|| view! { <></> }>
+            {move || {
+                let (key, verse) = editing.get()?;
+                let text = view_state.with_untracked(|state| {
+                    find_note(&state.notes, &key, verse)
+                        .map(|note| note.text.clone())
+                        .unwrap_or_default()
+                });
+                let heading = t_with("note_on_verse", &[("verse", &verse.to_string())]);
+                let key_for_links = key.clone();
+                Some(view! {
+                    <div
+                        class="fixed top-20 right-4 w-80 p-3 rounded-lg shadow-lg border z-40"
+                        style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    >
+                        <div class="flex items-center justify-between mb-2">
+                            <h2 class="text-sm font-semibold">{heading}</h2>
+                            <button
+                                class="text-xs underline opacity-75 hover:opacity-100"
+                                aria-label=t("close_note")
+                                on:click=close
+                            >
+                                {t("close")}
+                            </button>
+                        </div>
+                        <textarea
+                            node_ref=textarea_ref
+                            class="w-full px-2 py-1 text-sm border rounded"
+                            style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                            rows="5"
+                            aria-label=t("note")
+                            prop:value=text
+                            on:input=move |ev| {
+                                let text = event_target_value(&ev);
+                                view_state.update(|state| state.save_note(&key, verse, &text));
+                            }
+                        ></textarea>
+                        // The note as it is shown, once it mentions a verse
+                        {move || {
+                            let text = view_state.with(|state| {
+                                find_note(&state.notes, &key_for_links, verse)
+                                    .map(|note| note.text.clone())
+                                    .unwrap_or_default()
+                            });
+                            (!reference_links(&text).is_empty()).then(|| view! {
+                                <p class="mt-2 text-sm" style="color: var(--theme-text-secondary)">
+                                    <ReferenceText text=text/>
+                                </p>
+                            })
+                        }}
+                    </div>
+                })
+            }}
+        </Show>
+    }
+}
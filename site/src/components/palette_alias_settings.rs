@@ -0,0 +1,82 @@
+use crate::storage::palette_aliases::{
+    add_palette_alias, get_palette_aliases, remove_palette_alias,
+};
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+
+/// Settings section for managing command palette aliases
+#[component]
+pub fn PaletteAliasSettings() -> impl IntoView {
+    let aliases = RwSignal::new(get_palette_aliases());
+    let (alias_name, set_alias_name) = signal(String::new());
+    let (alias_target, set_alias_target) = signal(String::new());
+    let (alias_error, set_alias_error) = signal::<Option<String>>(None);
+
+    let handle_add_alias = move |_| match add_palette_alias(&alias_name.get(), &alias_target.get())
+    {
+        Ok(()) => {
+            aliases.set(get_palette_aliases());
+            set_alias_name.set(String::new());
+            set_alias_target.set(String::new());
+            set_alias_error.set(None);
+        }
+        Err(e) => set_alias_error.set(Some(e)),
+    };
+
+    let handle_remove_alias = move |name: String| {
+        remove_palette_alias(&name);
+        aliases.set(get_palette_aliases());
+    };
+
+    view! {
+        <div class="mt-4 pt-4 border-t space-y-2" style="border-color: var(--theme-sidebar-border)">
+            <h3 class="text-sm font-semibold" style="color: var(--theme-sidebar-text)">"Palette aliases"</h3>
+            <div class="flex gap-2">
+                <input
+                    type="text"
+                    class="w-20 px-2 py-1 text-xs font-mono border rounded"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    placeholder="sotm"
+                    prop:value=move || alias_name.get()
+                    on:input=move |ev| set_alias_name.set(event_target_value(&ev))
+                    on:keydown=|ev: KeyboardEvent| ev.stop_propagation()
+                />
+                <input
+                    type="text"
+                    class="flex-1 min-w-0 px-2 py-1 text-xs font-mono border rounded"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    placeholder="/Matthew/5 or Export to PDF"
+                    prop:value=move || alias_target.get()
+                    on:input=move |ev| set_alias_target.set(event_target_value(&ev))
+                    on:keydown=|ev: KeyboardEvent| ev.stop_propagation()
+                />
+            </div>
+            <button
+                class="w-full px-3 py-1 text-sm rounded transition-colors"
+                style="background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text)"
+                on:click=handle_add_alias
+            >
+                "Add alias"
+            </button>
+            <Show when=move || alias_error.get().is_some() fallback=|| view! { <></> }>
+                <p class="text-xs text-red-600">{move || alias_error.get().unwrap_or_default()}</p>
+            </Show>
+            <ul class="space-y-1">
+                {move || aliases.get().into_iter().map(|alias| {
+                    let name = alias.name.clone();
+                    view! {
+                        <li class="flex items-center justify-between gap-2 text-xs" style="color: var(--theme-text-secondary)">
+                            <span class="truncate font-mono">{format!("{} → {}", alias.name, alias.target)}</span>
+                            <button
+                                class="underline opacity-75 hover:opacity-100"
+                                on:click=move |_| handle_remove_alias(name.clone())
+                            >
+                                "Remove"
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        </div>
+    }
+}
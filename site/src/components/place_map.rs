@@ -0,0 +1,160 @@
+use crate::core::{
+    init_bible_signal, places_in_verses, Chapter, MapBounds, PlaceMention, VerseRange,
+};
+use crate::instructions::types::Instruction;
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::web_sys::KeyboardEvent;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+
+const MAP_WIDTH: f64 = 320.0;
+const MAP_HEIGHT: f64 = 200.0;
+
+fn verse_path(chapter: &Chapter, verse: u32) -> String {
+    chapter.to_path_with_verses(&[VerseRange {
+        start: verse,
+        end: verse,
+    }])
+}
+
+/// Small map of the places named in the current chapter
+#[component]
+pub fn PlaceMap(
+    /// Book name and chapter number of the chapter being read
+    current_book_chapter: Memo<Option<(String, u32)>>,
+    view_state: ViewStateSignal,
+) -> impl IntoView {
+    let navigate = use_navigate();
+
+    let chapter = Memo::new(move |_| {
+        let (book_name, chapter) = current_book_chapter.get()?;
+        init_bible_signal().with(|bible| {
+            bible
+                .as_ref()
+                .and_then(|b| b.get_chapter(&book_name, chapter).ok())
+        })
+    });
+
+    let mentions = Memo::new(move |_| {
+        chapter.with(|chapter| {
+            chapter
+                .as_ref()
+                .map(|chapter| places_in_verses(&chapter.verses))
+                .unwrap_or_default()
+        })
+    });
+
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && view_state.with(|state| state.is_place_map_open) {
+            evt.prevent_default();
+            view_state.update(|state| {
+                state.execute(&Instruction::ClosePlaceMap);
+            });
+        }
+    });
+
+    let go_to_verse = Callback::new(move |verse: u32| {
+        if let Some(chapter) = chapter.get_untracked() {
+            navigate(
+                &verse_path(&chapter, verse),
+                NavigateOptions {
+                    scroll: false,
+                    ..Default::default()
+                },
+            );
+        }
+    });
+
+    let map = move || {
+        let mentions = mentions.get();
+        let bounds = MapBounds::fit(mentions.iter().map(|m| m.place))?
+            .with_aspect(MAP_WIDTH / MAP_HEIGHT);
+
+        // A line every whole degree gives a sense of distance
+        let meridians = (bounds.min_longitude.ceil() as i32..=bounds.max_longitude.floor() as i32)
+            .map(|longitude| {
+                let (x, _) = bounds.project(0.0, longitude as f64, MAP_WIDTH, MAP_HEIGHT);
+                view! { <line x1=x y1=0 x2=x y2=MAP_HEIGHT stroke="currentColor" stroke-opacity="0.1"/> }
+            })
+            .collect_view();
+        let parallels = (bounds.min_latitude.ceil() as i32..=bounds.max_latitude.floor() as i32)
+            .map(|latitude| {
+                let (_, y) = bounds.project(latitude as f64, 0.0, MAP_WIDTH, MAP_HEIGHT);
+                view! { <line x1=0 y1=y x2=MAP_WIDTH y2=y stroke="currentColor" stroke-opacity="0.1"/> }
+            })
+            .collect_view();
+
+        let markers = mentions
+            .into_iter()
+            .map(|PlaceMention { place, verses }| {
+                let (x, y) = bounds.project(place.latitude, place.longitude, MAP_WIDTH, MAP_HEIGHT);
+                let first_verse = verses[0];
+                view! {
+                    <g class="cursor-pointer" on:click=move |_| go_to_verse.run(first_verse)>
+                        <title>{place.name.clone()}</title>
+                        <circle cx=x cy=y r="4" style="fill: var(--theme-button-primary-background)"/>
+                        <text x=x + 6.0 y=y + 3.0 font-size="10" fill="currentColor">{place.name.clone()}</text>
+                    </g>
+                }
+            })
+            .collect_view();
+
+        Some(view! {
+            <svg
+                viewBox=format!("0 0 {} {}", MAP_WIDTH, MAP_HEIGHT)
+                class="w-full rounded border"
+                style="border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                role="img"
+                aria-label="Map of places in this chapter"
+            >
+                {meridians}
+                {parallels}
+                {markers}
+            </svg>
+        })
+    };
+
+    view! {
+        <Show when=move || view_state.with(|state| state.is_place_map_open) fallback=|| view! { <></> }>
+            <div
+                class="fixed bottom-4 right-4 w-80 max-h-[70vh] overflow-y-auto p-3 rounded-lg shadow-lg border z-40"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+            >
+                <div class="flex items-center justify-between mb-2">
+                    <h2 class="text-sm font-semibold">"Places"</h2>
+                    <button
+                        class="text-xs underline opacity-75 hover:opacity-100"
+                        aria-label="Close place map"
+                        on:click=move |_| view_state.update(|state| {
+                            state.execute(&Instruction::ClosePlaceMap);
+                        })
+                    >
+                        "Close"
+                    </button>
+                </div>
+                <Show
+                    when=move || mentions.with(|mentions| !mentions.is_empty())
+                    fallback=|| view! {
+                        <p class="text-xs" style="color: var(--theme-text-muted)">"No known places are named in this chapter."</p>
+                    }
+                >
+                    {map}
+                    <ul class="mt-2 space-y-1 text-xs">
+                        {move || mentions.get().into_iter().map(|PlaceMention { place, verses }| view! {
+                            <li class="flex flex-wrap gap-x-1">
+                                <span class="font-medium">{place.name.clone()}</span>
+                                {verses.into_iter().map(|verse| view! {
+                                    <button class="underline opacity-75 hover:opacity-100" on:click=move |_| go_to_verse.run(verse)>
+                                        {verse}
+                                    </button>
+                                }).collect_view()}
+                            </li>
+                        }).collect_view()}
+                    </ul>
+                </Show>
+            </div>
+        </Show>
+    }
+}
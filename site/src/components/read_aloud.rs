@@ -0,0 +1,226 @@
+use crate::core::types::Language;
+use crate::core::{Chapter, ReadAloudState, VerseRange};
+use crate::i18n::{t, t_with};
+use crate::instructions::types::Instruction;
+use crate::storage::translations::get_current_translation;
+use crate::view_state::ViewStateSignal;
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{SpeechSynthesis, SpeechSynthesisUtterance};
+
+fn speech_synthesis() -> Option<SpeechSynthesis> {
+    web_sys::window()?.speech_synthesis().ok()
+}
+
+/// Whether the browser can read text aloud
+pub fn is_read_aloud_supported() -> bool {
+    speech_synthesis().is_some()
+}
+
+/// Language the voice reads in, taken from the current translation
+fn speech_language() -> &'static str {
+    match get_current_translation().and_then(|translation| translation.languages.first().copied()) {
+        Some(Language::Dutch) => "nl-NL",
+        _ => "en-US",
+    }
+}
+
+/// Reads the chapter aloud with the browser's voices, selecting each verse as
+/// it is read so the chapter scrolls along, and shows controls while reading
+#[component]
+pub fn ReadAloud(
+    chapter: Chapter,
+    /// Verses selected in the URL; reading starts at the first of them
+    selected_verses: Memo<Vec<VerseRange>>,
+    view_state: ViewStateSignal,
+) -> impl IntoView {
+    let navigate = use_navigate();
+    let chapter = StoredValue::new(chapter);
+    // The verse being read, and the last verse whose reading ended
+    let reading_verse = RwSignal::new(None::<u32>);
+    let finished_verse = RwSignal::new(None::<u32>);
+    // Counts utterances so that the end of a cancelled one is ignored
+    let utterance_count = Rc::new(Cell::new(0u32));
+
+    let speak_verse = {
+        let utterance_count = utterance_count.clone();
+        move |verse: u32| {
+            let Some(synthesis) = speech_synthesis() else {
+                return;
+            };
+            let Some(text) = chapter.with_value(|chapter| {
+                chapter
+                    .verses
+                    .iter()
+                    .find(|v| v.verse == verse)
+                    .map(|v| v.text.clone())
+            }) else {
+                return;
+            };
+            let Ok(utterance) = SpeechSynthesisUtterance::new_with_text(&text) else {
+                return;
+            };
+
+            let count = utterance_count.get() + 1;
+            utterance_count.set(count);
+            let current_count = utterance_count.clone();
+            let onend = Closure::once_into_js(move || {
+                if current_count.get() == count {
+                    finished_verse.set(Some(verse));
+                }
+            });
+            utterance.set_onend(Some(onend.unchecked_ref()));
+            utterance.set_rate(view_state.with_untracked(|state| state.read_aloud_rate));
+            utterance.set_lang(speech_language());
+
+            synthesis.cancel();
+            synthesis.speak(&utterance);
+            reading_verse.set(Some(verse));
+
+            // Select the verse so the chapter scrolls to it
+            if !selected_verses.with_untracked(|ranges| {
+                matches!(ranges.as_slice(), [range] if range.start == verse && range.end == verse)
+            }) {
+                let path = chapter.with_value(|chapter| {
+                    chapter.to_path_with_verses(&[VerseRange {
+                        start: verse,
+                        end: verse,
+                    }])
+                });
+                navigate(
+                    &path,
+                    NavigateOptions {
+                        scroll: false,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    };
+    let speak_verse = StoredValue::new_local(speak_verse);
+
+    // Start, pause, resume or stop when the state changes, and restart the
+    // current verse when the rate changes
+    Effect::new(move |previous: Option<(ReadAloudState, f32)>| {
+        let current = view_state.with(|state| (state.read_aloud, state.read_aloud_rate));
+        let Some(synthesis) = speech_synthesis() else {
+            return current;
+        };
+        let (state, rate) = current;
+        let previous_state = previous.map(|(state, _)| state);
+        match state {
+            ReadAloudState::Stopped => {
+                if previous_state.is_some_and(|state| state != ReadAloudState::Stopped) {
+                    synthesis.cancel();
+                    reading_verse.set(None);
+                }
+            }
+            ReadAloudState::Paused => synthesis.pause(),
+            ReadAloudState::Playing if previous_state == Some(ReadAloudState::Paused) => {
+                synthesis.resume()
+            }
+            ReadAloudState::Playing => {
+                let rate_changed = previous.is_some_and(|(_, previous_rate)| previous_rate != rate);
+                if previous_state != Some(ReadAloudState::Playing) || rate_changed {
+                    let verse = reading_verse
+                        .get_untracked()
+                        .or_else(|| {
+                            selected_verses.with_untracked(|ranges| ranges.first().map(|r| r.start))
+                        })
+                        .unwrap_or(1);
+                    speak_verse.with_value(|speak| speak(verse));
+                }
+            }
+        }
+        current
+    });
+
+    // Go on to the next verse when one has been read, and stop after the last
+    Effect::new(move |_| {
+        let Some(verse) = finished_verse.get() else {
+            return;
+        };
+        if view_state.with_untracked(|state| state.read_aloud) != ReadAloudState::Playing {
+            return;
+        }
+        match chapter.with_value(|chapter| chapter.get_next_verse(verse)) {
+            Some(next_verse) => speak_verse.with_value(|speak| speak(next_verse)),
+            None => view_state.update(|state| {
+                state.execute(&Instruction::StopReadAloud);
+            }),
+        }
+    });
+
+    // Follow the reader when they select another verse while listening
+    Effect::new(move |_| {
+        let Some(selected) = selected_verses.with(|ranges| ranges.first().map(|range| range.start))
+        else {
+            return;
+        };
+        if view_state.with_untracked(|state| state.read_aloud) == ReadAloudState::Playing
+            && reading_verse.get_untracked() != Some(selected)
+        {
+            speak_verse.with_value(|speak| speak(selected));
+        }
+    });
+
+    // Reading stops when the chapter is left
+    on_cleanup(move || {
+        if let Some(synthesis) = speech_synthesis() {
+            synthesis.cancel();
+        }
+        view_state.try_update(|state| {
+            state.execute(&Instruction::StopReadAloud);
+        });
+    });
+
+    let control = move |label: String, instruction: Instruction| {
+        view! {
+            <button
+                class="px-2 py-1 rounded border"
+                style="border-color: var(--theme-sidebar-border)"
+                on:click=move |_| view_state.update(|state| {
+                    state.execute(&instruction);
+                })
+            >
+                {label}
+            </button>
+        }
+    };
+
+    view! {
+        <Show
+            when=move || view_state.with(|state| state.read_aloud != ReadAloudState::Stopped)
+            fallback=|| view! { <></> }
+        >
+            <div
+                role="region"
+                aria-label=t("read_aloud")
+                class="fixed bottom-4 left-1/2 -translate-x-1/2 flex items-center gap-2 px-3 py-2 rounded-lg shadow-lg border text-sm z-40"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+            >
+                <span class="mr-1" aria-live="polite">
+                    {move || reading_verse.get().map(|verse| t_with("reading_verse", &[("verse", &verse.to_string())]))}
+                </span>
+                {move || {
+                    let label = if view_state.with(|state| state.read_aloud == ReadAloudState::Paused) {
+                        t("resume")
+                    } else {
+                        t("pause")
+                    };
+                    control(label, Instruction::ToggleReadAloud)
+                }}
+                {control(t("stop"), Instruction::StopReadAloud)}
+                {control("−".to_string(), Instruction::ReadAloudSlower)}
+                <span class="w-10 text-center" aria-label=t("reading_speed")>
+                    {move || format!("{}×", view_state.with(|state| state.read_aloud_rate))}
+                </span>
+                {control("+".to_string(), Instruction::ReadAloudFaster)}
+            </div>
+        </Show>
+    }
+}
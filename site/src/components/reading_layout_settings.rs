@@ -0,0 +1,34 @@
+use crate::storage::MAX_TEXT_COLUMNS;
+use crate::view_state::ViewStateSignal;
+use leptos::prelude::*;
+
+/// Settings section for splitting the chapter text into columns on wide screens
+#[component]
+pub fn ReadingLayoutSettings(view_state: ViewStateSignal) -> impl IntoView {
+    let text_columns = move || view_state.with(|state| state.text_columns);
+
+    view! {
+        <div class="mt-4 pt-4 border-t space-y-2" style="border-color: var(--theme-sidebar-border)">
+            <h3 class="text-sm font-semibold" style="color: var(--theme-sidebar-text)">"Text columns"</h3>
+            <div class="flex gap-2" role="group" aria-label="Text columns">
+                {(1..=MAX_TEXT_COLUMNS).map(|columns| {
+                    view! {
+                        <button
+                            class="flex-1 px-3 py-1 text-sm rounded border transition-colors"
+                            style=move || if text_columns() == columns {
+                                "background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text); border-color: var(--theme-button-primary-background)"
+                            } else {
+                                "color: var(--theme-sidebar-text); border-color: var(--theme-sidebar-border)"
+                            }
+                            aria-pressed=move || (text_columns() == columns).to_string()
+                            on:click=move |_| view_state.update(|state| state.set_text_columns(columns))
+                        >
+                            {columns}
+                        </button>
+                    }
+                }).collect_view()}
+            </div>
+            <p class="text-xs" style="color: var(--theme-text-muted)">"Columns are used on wide screens only."</p>
+        </div>
+    }
+}
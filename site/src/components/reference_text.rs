@@ -0,0 +1,63 @@
+use crate::core::{detect_references, init_bible_signal, Bible, DetectedReference};
+use crate::translation_map::abbreviations::{book_key_for_name, resolve_book_abbreviation};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Book key for a name or abbreviation found in text, using the user's abbreviations too
+fn resolve_book(name: &str) -> Option<&'static str> {
+    resolve_book_abbreviation(name).or_else(|| book_key_for_name(name))
+}
+
+/// Internal link for a detected reference, if the loaded Bible has that chapter
+fn reference_path(reference: &DetectedReference) -> Option<String> {
+    let to_path = |bible: &Bible| {
+        let chapter = bible
+            .book_for_key(reference.book_key)?
+            .chapters
+            .iter()
+            .find(|c| c.chapter == reference.chapter)?;
+        Some(match &reference.verses {
+            Some(verses) => chapter.to_path_with_verses(std::slice::from_ref(verses)),
+            None => chapter.to_path(),
+        })
+    };
+    init_bible_signal().with(|bible| bible.as_ref().and_then(to_path))
+}
+
+/// The text and link of every reference in `text` that can be opened
+pub fn reference_links(text: &str) -> Vec<(String, String)> {
+    detect_references(text, resolve_book)
+        .into_iter()
+        .filter_map(|reference| {
+            let path = reference_path(&reference)?;
+            Some((text[reference.range].to_string(), path))
+        })
+        .collect()
+}
+
+/// Text with the scripture references in it turned into links to those verses
+#[component]
+pub fn ReferenceText(#[prop(into)] text: String) -> impl IntoView {
+    move || {
+        let mut segments = Vec::new();
+        let mut rest = 0;
+        for reference in detect_references(&text, resolve_book) {
+            let Some(path) = reference_path(&reference) else {
+                continue;
+            };
+            segments.push(text[rest..reference.range.start].to_string().into_any());
+            let label = text[reference.range.clone()].to_string();
+            segments.push(
+                view! {
+                    <A href=path attr:class="hover:underline translation-link">
+                        {label}
+                    </A>
+                }
+                .into_any(),
+            );
+            rest = reference.range.end;
+        }
+        segments.push(text[rest..].to_string().into_any());
+        segments
+    }
+}
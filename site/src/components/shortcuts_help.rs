@@ -121,11 +121,49 @@ pub fn ShortcutsHelp() -> impl IntoView {
                             </div>
                         </div>
                         
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Copy into Register (A appends)</span>
+                            <div class="flex items-center gap-1">
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">"ay</kbd>
+                                <span class="text-gray-400">or</span>
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">"aY</kbd>
+                            </div>
+                        </div>
+                        
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Collapse All Books / Expand Current</span>
+                            <div class="flex items-center gap-1">
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">zm</kbd>
+                                <span class="text-gray-400">/</span>
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">zo</kbd>
+                            </div>
+                        </div>
+                        
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Mark Chapter Complete</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">X</kbd>
+                        </div>
+                        
                         <div class="flex items-center justify-between">
                             <span class="text-black">Translation Comparison</span>
                             <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">C</kbd>
                         </div>
                         
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Place Map</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">gm</kbd>
+                        </div>
+                        
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Highlight Legend</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">gh</kbd>
+                        </div>
+                        
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Edit Note</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">gn</kbd>
+                        </div>
+                        
                         <div class="flex items-center justify-between">
                             <span class="text-black">Previous Chapter</span>
                             <div class="flex items-center gap-2">
@@ -191,9 +229,16 @@ pub fn ShortcutsHelp() -> impl IntoView {
                                 <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">5g</kbd>
                                 <span class="text-gray-400">or</span>
                                 <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">33g</kbd>
+                                <span class="text-gray-400">or</span>
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">16G</kbd>
                             </div>
                         </div>
                         
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Go to Percentage of Book</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">50%</kbd>
+                        </div>
+                        
                         <div class="flex items-center justify-between">
                             <span class="text-black">Vim-style Multipliers</span>
                             <div class="flex items-center gap-1">
@@ -203,6 +248,11 @@ pub fn ShortcutsHelp() -> impl IntoView {
                             </div>
                         </div>
                         
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Repeat Last Instruction</span>
+                            <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">.</kbd>
+                        </div>
+                        
                         <div class="flex items-center justify-between">
                             <span class="text-black">Beginning of Chapter</span>
                             <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">gg</kbd>
@@ -217,6 +267,15 @@ pub fn ShortcutsHelp() -> impl IntoView {
                             </div>
                         </div>
                         
+                        <div class="flex items-center justify-between">
+                            <span class="text-black">Previous / Next Paragraph</span>
+                            <div class="flex items-center gap-1">
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">{"{"}</kbd>
+                                <span class="text-gray-400">/</span>
+                                <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">{"}"}</kbd>
+                            </div>
+                        </div>
+                        
                         <div class="flex items-center justify-between">
                             <span class="text-black">Show This Help</span>
                             <kbd class="px-2 py-1 bg-gray-100 border border-gray-300 rounded text-xs">?</kbd>
@@ -1,36 +1,48 @@
-use crate::core::types::Language;
+use crate::components::command_palette::fuzzy_score;
+use crate::components::reference_text::reference_links;
 use crate::core::*;
-use crate::core::{get_bible, init_bible_signal};
+use crate::core::init_bible_signal;
+use crate::i18n::{t, ui_language};
 use crate::instructions::Instruction;
-use crate::storage::translations::get_current_translation;
+use crate::storage::{
+    get_collapsed_testaments, get_expanded_books, get_selected_translation,
+    save_collapsed_testaments,
+};
+use crate::storage::section_headings::get_chapter_section_headings;
 use crate::utils::execute_with_navigation;
 use crate::view_state::ViewStateSignal;
 use leptos::component;
+use leptos::wasm_bindgen::JsCast;
 use leptos::prelude::*;
 use leptos::view;
 use leptos::IntoView;
+use leptos_router::components::A;
 use leptos_router::hooks::{use_location, use_navigate};
 use leptos_router::location::Location;
+use std::sync::Arc;
 use urlencoding::decode;
+use wasm_bindgen_futures::spawn_local;
 
-fn get_ui_text(key: &str) -> String {
-    if let Some(current_translation) = get_current_translation() {
-        if let Some(first_language) = current_translation.languages.first() {
-            match (key, first_language) {
-                ("books", Language::Dutch) => "Boeken".to_string(),
-                ("books", Language::English) => "Books".to_string(),
-                _ => key.to_string(),
-            }
-        } else {
-            key.to_string()
-        }
-    } else {
-        // Default to English
-        match key {
-            "books" => "Books".to_string(),
-            _ => key.to_string(),
-        }
-    }
+/// Chapters per row in the sidebar's chapter grid
+const CHAPTER_GRID_COLUMNS: usize = 10;
+
+/// Index of the chapter the arrow, Home or End `key` moves to in a grid of `count` chapters
+fn chapter_grid_target(index: usize, count: usize, key: &str) -> Option<usize> {
+    let row_start = index - index % CHAPTER_GRID_COLUMNS;
+    let target = match key {
+        "ArrowRight" => index + 1,
+        "ArrowLeft" => index.checked_sub(1)?,
+        "ArrowDown" => index + CHAPTER_GRID_COLUMNS,
+        "ArrowUp" => index.checked_sub(CHAPTER_GRID_COLUMNS)?,
+        "Home" => row_start,
+        "End" => (row_start + CHAPTER_GRID_COLUMNS - 1).min(count.checked_sub(1)?),
+        _ => return None,
+    };
+    (target < count).then_some(target)
+}
+
+fn chapter_grid_cell_id(book_index: usize, chapter_index: usize) -> String {
+    format!("chapter-grid-{}-{}", book_index, chapter_index)
 }
 
 #[component]
@@ -53,58 +65,304 @@ pub fn Sidebar(view_state: ViewStateSignal) -> impl IntoView {
         String::new() // Return empty string if no valid book found
     });
 
-    // Removed local selected_book signal - now using ViewState
+    let current_chapter_number = Memo::new(move |_| {
+        let pathname = location.pathname.get();
+        pathname
+            .trim_start_matches('/')
+            .split('/')
+            .nth(1)
+            .and_then(|chapter| chapter.parse::<u32>().ok())
+    });
+
+    // Section headings of the chapter being read, reloaded when the translation changes
+    let section_headings = RwSignal::new(Vec::<SectionHeading>::new());
+    Effect::new(move |_| {
+        let chapter_key = (current_book.get(), current_chapter_number.get());
+        let book_index = bible_signal.with(|bible| {
+            bible
+                .as_ref()?
+                .books
+                .iter()
+                .position(|book| book.name == chapter_key.0)
+        });
+        let (Some(short_name), Some(book_index), Some(chapter)) =
+            (get_selected_translation(), book_index, chapter_key.1)
+        else {
+            section_headings.set(Vec::new());
+            return;
+        };
+        spawn_local(async move {
+            let headings = get_chapter_section_headings(&short_name, book_index, chapter).await;
+            // Skip the result when the reader moved on while it was loading
+            let current = (
+                current_book.try_get_untracked(),
+                current_chapter_number.try_get_untracked().flatten(),
+            );
+            if current == (Some(chapter_key.0), chapter_key.1) {
+                section_headings.set(headings);
+            }
+        });
+    });
+
+    // Expand a book when navigating into it; on a first visit also the book being read
+    Effect::new(move |previous: Option<String>| {
+        let book = current_book.get();
+        let is_new_book = match &previous {
+            Some(previous) => *previous != book,
+            None => get_expanded_books().is_none(),
+        };
+        if is_new_book && !book.is_empty() {
+            view_state.update(|state| state.expand_book(&book));
+        }
+        book
+    });
 
-    // Create reactive books list
+    // Book names and chapters without verses, so the list isn't redrawn when a book loads
     let books = Memo::new(move |_| {
-        if let Some(bible) = bible_signal.get() {
-            bible.books
-        } else {
-            get_bible().books.clone() // Keep clone for now, optimize component later
+        bible_signal.with(|bible| {
+            bible
+                .as_ref()
+                .map(|bible| Bible::from_index(&bible.index()).books)
+                .unwrap_or_default()
+        })
+    });
+
+    // Genre and testament of each book, so the list can be narrowed to e.g. all
+    // wisdom literature and grouped by testament
+    let book_classes = Memo::new(move |_| {
+        let classify = |bible: &Bible| {
+            (0..bible.books.len())
+                .map(|index| (bible.book_genre(index), bible.book_testament(index)))
+                .collect::<Vec<_>>()
+        };
+        bible_signal.with(|bible| bible.as_ref().map(classify).unwrap_or_default())
+    });
+    let (genre_filter, set_genre_filter) = signal::<Option<Genre>>(None);
+
+    // Typed filter, matched fuzzily against book names and as an abbreviation ("1 kor")
+    let (book_filter, set_book_filter) = signal(String::new());
+    let abbreviated_book = Memo::new(move |_| {
+        let query = book_filter.get();
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
         }
+        bible_signal.with(|bible| {
+            bible
+                .as_ref()?
+                .find_book(query)
+                .map(|book| book.name.clone())
+        })
     });
+    let matches_book_filter = move |book: &Book| {
+        let query = book_filter.with(|query| query.trim().to_lowercase());
+        query.is_empty()
+            || fuzzy_score(&book.name.to_lowercase(), &query) > 0
+            || abbreviated_book.with(|name| name.as_ref() == Some(&book.name))
+    };
+
+    let collapsed_testaments = RwSignal::new(get_collapsed_testaments());
+    let toggle_testament = move |testament: Testament| {
+        collapsed_testaments.update(|collapsed| {
+            if collapsed.contains(&testament) {
+                collapsed.retain(|t| *t != testament);
+            } else {
+                collapsed.push(testament);
+            }
+            save_collapsed_testaments(collapsed);
+        });
+    };
 
     view! {
         <div class="sidebar">
-            <h2 class="text-lg font-bold mb-4" style="color: var(--theme-sidebar-text)">{get_ui_text("books")}</h2>
-            <ul class="space-y-2">
-            {move || books.get().iter().map(|b| view! {
-                <BookView
-                    book=b.clone() // Required by component signature
-                    current_book=current_book
-                    location=location.clone()
-                    view_state=view_state
-                />
-            }).collect::<Vec<_>>()}
-            </ul>
+            <h2 class="text-lg font-bold mb-4" style="color: var(--theme-sidebar-text)">{t("books")}</h2>
+            <input
+                type="search"
+                class="w-full mb-2 px-2 py-1 text-sm border rounded"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-sidebar-text)"
+                placeholder={t("filter_books")}
+                aria-label={t("filter_books")}
+                prop:value=move || book_filter.get()
+                on:input=move |ev| set_book_filter.set(event_target_value(&ev))
+                on:keydown=move |ev: leptos::web_sys::KeyboardEvent| {
+                    // Keep typing out of the global keyboard shortcuts
+                    ev.stop_propagation();
+                    if ev.key() == "Escape" {
+                        set_book_filter.set(String::new());
+                    }
+                }
+            />
+            <div class="flex justify-between mb-2 text-xs" style="color: var(--theme-text-secondary)">
+                <button
+                    class="underline opacity-75 hover:opacity-100"
+                    on:click=move |_| view_state.update(|state| {
+                        state.execute(&Instruction::CollapseAllBooks);
+                    })
+                >
+                    {t("collapse_all")}
+                </button>
+                <button
+                    class="underline opacity-75 hover:opacity-100"
+                    on:click=move |_| view_state.update(|state| {
+                        state.execute(&Instruction::ExpandCurrentBook);
+                    })
+                >
+                    {t("expand_current")}
+                </button>
+            </div>
+            <select
+                class="w-full mb-3 px-2 py-1 text-sm border rounded"
+                style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border); color: var(--theme-sidebar-text)"
+                aria-label={t("genre")}
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    set_genre_filter.set(
+                        Genre::ALL.into_iter().find(|genre| format!("{:?}", genre) == value),
+                    );
+                }
+                on:keydown=|ev: leptos::web_sys::KeyboardEvent| ev.stop_propagation()
+            >
+                <option value="">{t("all_genres")}</option>
+                {Genre::ALL.into_iter().map(|genre| view! {
+                    <option value=format!("{:?}", genre)>{genre.name(ui_language())}</option>
+                }).collect_view()}
+            </select>
+            {move || {
+                let books = books.get();
+                let classes = book_classes.get();
+                let class_of = |book_index: usize| classes.get(book_index).copied().unwrap_or((None, None));
+                let genre = genre_filter.get();
+                let is_filtering = book_filter.with(|query| !query.trim().is_empty());
+                let location = location.clone();
+
+                // Books outside the 66-book canon are listed last, without a header
+                [Some(Testament::Old), Some(Testament::New), None].into_iter().filter_map(|testament| {
+                    let group: Vec<(usize, Arc<Book>)> = books
+                        .iter()
+                        .enumerate()
+                        .filter(|(book_index, book)| {
+                            let (book_genre, book_testament) = class_of(*book_index);
+                            book_testament == testament
+                                && genre.is_none_or(|genre| book_genre == Some(genre))
+                                && matches_book_filter(book)
+                        })
+                        .map(|(book_index, book)| (book_index, book.clone()))
+                        .collect();
+                    if group.is_empty() {
+                        return None;
+                    }
+
+                    // Filtered results are shown even in collapsed testaments
+                    let is_collapsed = move || {
+                        !is_filtering
+                            && testament.is_some_and(|testament| collapsed_testaments.with(|c| c.contains(&testament)))
+                    };
+                    let label = match testament {
+                        Some(testament) => testament.name(ui_language()).to_string(),
+                        None => t("other_books"),
+                    };
+                    let location = location.clone();
+
+                    Some(view! {
+                        <section class="mb-3">
+                            <button
+                                class="w-full flex items-center gap-1 px-1 mb-1 text-xs font-semibold uppercase opacity-75"
+                                style="color: var(--theme-sidebar-text)"
+                                aria-expanded=move || (!is_collapsed()).to_string()
+                                on:click=move |_| {
+                                    if let Some(testament) = testament {
+                                        toggle_testament(testament);
+                                    }
+                                }
+                            >
+                                {testament.is_some().then(|| view! {
+                                    <svg
+                                        class=move || if is_collapsed() { "w-3 h-3 -rotate-90 transition-transform" } else { "w-3 h-3 transition-transform" }
+                                        fill="none" stroke="currentColor" stroke-width="3" viewBox="0 0 24 24" aria-hidden="true"
+                                    >
+                                        <path stroke-linecap="round" stroke-linejoin="round" d="M19 9l-7 7-7-7"></path>
+                                    </svg>
+                                })}
+                                {label}
+                            </button>
+                            {move || (!is_collapsed()).then(|| view! {
+                                <ul class="space-y-2">
+                                {group.iter().map(|(book_index, book)| view! {
+                                    <BookView
+                                        book=book.clone() // Required by component signature
+                                        book_index=*book_index
+                                        current_book=current_book
+                                        current_chapter_number=current_chapter_number
+                                        section_headings=section_headings
+                                        location=location.clone()
+                                        view_state=view_state
+                                    />
+                                }).collect::<Vec<_>>()}
+                                </ul>
+                            })}
+                        </section>
+                    })
+                }).collect_view()
+            }}
         </div>
     }
 }
 
 #[component]
 fn BookView(
-    book: Book,
+    book: Arc<Book>,
+    book_index: usize,
     current_book: Memo<String>,
+    current_chapter_number: Memo<Option<u32>>,
+    section_headings: RwSignal<Vec<SectionHeading>>,
     location: Location,
     view_state: ViewStateSignal,
 ) -> impl IntoView {
     let navigate = use_navigate();
 
+    // Outline of the chapter being read, when it's in this book and the translation has headings
+    let outline = {
+        let book_name = book.name.clone();
+        let book = book.clone();
+        Memo::new(move |_| {
+            if current_book.get() != book_name {
+                return Vec::new();
+            }
+            let Some(chapter) = current_chapter_number
+                .get()
+                .and_then(|number| book.chapters.iter().find(|c| c.chapter == number))
+            else {
+                return Vec::new();
+            };
+            section_headings.with(|headings| {
+                chapter_outline(headings, book_index, chapter.chapter)
+                    .into_iter()
+                    .map(|heading| {
+                        let path = chapter.to_path_with_verses(&[VerseRange {
+                            start: heading.verse,
+                            end: heading.verse,
+                        }]);
+                        (heading, path)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
     view! {
         <li>
             <button
                 class="w-full text-left px-3 py-2 rounded-md transition-colors duration-150 font-medium"
                 style="color: var(--theme-sidebar-text); background-color: var(--theme-sidebar-background)"
+                aria-expanded={
+                    let book_name = book.name.clone();
+                    move || view_state.with(|state| state.is_book_expanded(&book_name)).to_string()
+                }
                 on:click={
                     let book_name = book.name.clone();
                     move |_| {
                         view_state.update(|state| {
-                            if state.get_selected_book() == book_name {
-                                // When you want to collapse the chapters
-                                state.execute(&Instruction::ClearSelectedBook);
-                            } else {
-                                state.execute(&Instruction::SelectBook(book_name.clone()));
-                            }
+                            state.execute(&Instruction::ToggleBook(book_name.clone()));
                         });
                     }
                 }
@@ -116,31 +374,66 @@ fn BookView(
             <Show
                 when={
                     let book_name = book.name.clone();
-                    move || {
-                        let current = current_book.get();
-                        let selected = view_state.with(|state| state.get_selected_book().to_string());
-                        // Show if it's the current book from URL OR manually selected
-                        book_name == current || book_name == selected
-                    }
+                    move || view_state.with(|state| state.is_book_expanded(&book_name))
                 }
                 fallback=|| view! { <></> }
             >
-            <div class="ml-4 mt-2 grid grid-cols-5 gap-1">
-            {book.chapters.iter().cloned().map(|c| {
+            <div
+                class="ml-4 mt-2 grid grid-cols-10 gap-0.5"
+                data-keyboard-grid
+                on:keydown={
+                    let chapter_count = book.chapters.len();
+                    move |ev: leptos::web_sys::KeyboardEvent| {
+                        let Some(index) = ev
+                            .target()
+                            .and_then(|target| target.dyn_into::<leptos::web_sys::Element>().ok())
+                            .and_then(|element| element.get_attribute("data-chapter-index"))
+                            .and_then(|index| index.parse::<usize>().ok())
+                        else {
+                            return;
+                        };
+                        let Some(target) = chapter_grid_target(index, chapter_count, &ev.key()) else {
+                            return;
+                        };
+                        ev.prevent_default();
+                        if let Some(cell) = leptos::web_sys::window()
+                            .and_then(|window| window.document())
+                            .and_then(|document| document.get_element_by_id(&chapter_grid_cell_id(book_index, target)))
+                            .and_then(|element| element.dyn_into::<leptos::web_sys::HtmlElement>().ok())
+                        {
+                            let _ = cell.focus();
+                        }
+                    }
+                }
+            >
+            {book.chapters.iter().enumerate().map(|(chapter_index, c)| {
+                let chapter_number = c.chapter;
                 let chapter_path = c.to_path();
                 let chapter_path_for_class = chapter_path.clone();
                 let chapter_path_for_style = chapter_path.clone();
+                let chapter_path_for_complete = chapter_path.clone();
+                let chapter_path_for_tabindex = chapter_path.clone();
+                let book_name = book.name.clone();
                 let location = location.clone();
+                let location_for_tabindex = location.clone();
 
                 view! {
                     <button
+                        id=chapter_grid_cell_id(book_index, chapter_index)
+                        data-chapter-index=chapter_index
+                        // Tab enters the grid once, at the current chapter; arrows move within it
+                        tabindex=move || {
+                            let is_current = location_for_tabindex.pathname.get() == chapter_path_for_tabindex;
+                            let is_entry = chapter_index == 0 && current_book.get() != book_name;
+                            if is_current || is_entry { "0" } else { "-1" }
+                        }
                         class={
                             move || {
                                 let current_path = location.pathname.get();
                                 if current_path == chapter_path_for_class {
-                                    "w-full text-center px-3 py-2 text-xs rounded transition-colors duration-150"
+                                    "relative w-full text-center px-0 py-1 text-xs rounded transition-colors duration-150"
                                 } else {
-                                    "w-full text-center px-3 py-2 text-xs rounded transition-colors duration-150"
+                                    "relative w-full text-center px-0 py-1 text-xs rounded transition-colors duration-150"
                                 }
                             }
                         }
@@ -162,12 +455,84 @@ fn BookView(
                             }
                         }
                     >
-                        {c.chapter}
+                        {chapter_number}
+                        <Show
+                            when=move || view_state.with(|state| state.is_chapter_complete(&chapter_path_for_complete))
+                            fallback=|| view! { <></> }
+                        >
+                            <svg class="absolute top-0.5 right-0.5 w-2 h-2 opacity-60" fill="none" stroke="currentColor" stroke-width="4" viewBox="0 0 24 24" aria-label="Completed">
+                                <path stroke-linecap="round" stroke-linejoin="round" d="M5 13l4 4L19 7"></path>
+                            </svg>
+                        </Show>
                     </button>
                 }
             }).collect_view()}
             </div>
+            {
+                let navigate = navigate.clone();
+                move || {
+                    let outline = outline.get();
+                    if outline.is_empty() {
+                        return None;
+                    }
+                    let items = outline.into_iter().map(|(heading, path)| {
+                        let navigate = navigate.clone();
+                        // Parallel passages named in the heading, e.g. "(Mark 1:1-8)"
+                        let heading_references = reference_links(&heading.title);
+                        view! {
+                            <li>
+                                <button
+                                    class="w-full text-left px-3 py-1 text-xs rounded transition-colors duration-150"
+                                    style="color: var(--theme-sidebar-text); background-color: var(--theme-sidebar-background)"
+                                    title=format!("{} ({})", heading.title, heading.verse)
+                                    on:click=move |_| {
+                                        execute_with_navigation(view_state, &navigate, Instruction::GoToChapter(path.clone()));
+                                    }
+                                >
+                                    <span class="opacity-60 mr-1">{heading.verse}</span>
+                                    {heading.title.clone()}
+                                </button>
+                                {(!heading_references.is_empty()).then(|| view! {
+                                    <div class="flex flex-wrap gap-x-2 px-3 text-xs">
+                                        {heading_references.into_iter().map(|(label, path)| view! {
+                                            <A href=path attr:class="hover:underline translation-link">{label}</A>
+                                        }).collect_view()}
+                                    </div>
+                                })}
+                            </li>
+                        }
+                    }).collect_view();
+                    Some(view! {
+                        <nav class="ml-4 mt-3" aria-label={t("sections")}>
+                            <h3 class="px-3 mb-1 text-xs font-semibold uppercase opacity-75" style="color: var(--theme-sidebar-text)">
+                                {t("sections")}
+                            </h3>
+                            <ul class="space-y-1">{items}</ul>
+                        </nav>
+                    })
+                }
+            }
             </Show>
         </li>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chapter_grid_target() {
+        // 25 chapters: rows 0-9, 10-19 and 20-24
+        assert_eq!(chapter_grid_target(0, 25, "ArrowRight"), Some(1));
+        assert_eq!(chapter_grid_target(0, 25, "ArrowLeft"), None);
+        assert_eq!(chapter_grid_target(3, 25, "ArrowDown"), Some(13));
+        assert_eq!(chapter_grid_target(17, 25, "ArrowDown"), None);
+        assert_eq!(chapter_grid_target(13, 25, "ArrowUp"), Some(3));
+        assert_eq!(chapter_grid_target(14, 25, "Home"), Some(10));
+        assert_eq!(chapter_grid_target(14, 25, "End"), Some(19));
+        assert_eq!(chapter_grid_target(21, 25, "End"), Some(24));
+        assert_eq!(chapter_grid_target(24, 25, "ArrowRight"), None);
+        assert_eq!(chapter_grid_target(5, 25, "Enter"), None);
+    }
+}
@@ -1,6 +1,13 @@
+use crate::components::{
+    BookAbbreviationSettings, PaletteAliasSettings, ReadingLayoutSettings, UiLanguageSettings,
+};
+use crate::i18n::{t, t_with};
 use crate::instructions::types::Instruction;
 use crate::storage::save_selected_theme;
-use crate::themes::{get_themes, Theme};
+use crate::themes::{
+    add_user_theme, fetch_user_theme, get_default_theme, get_themes, get_user_themes,
+    load_missing_themes, parse_theme_json, remove_user_theme, Theme,
+};
 use crate::utils::is_mobile_screen;
 use crate::view_state::ViewStateSignal;
 use leptos::ev;
@@ -14,27 +21,27 @@ pub fn ThemeSidebar(
     set_current_theme: WriteSignal<Theme>,
     view_state: ViewStateSignal,
 ) -> impl IntoView {
-    let themes = get_themes();
-    let themes_len = themes.len();
+    let themes = RwSignal::new(get_themes());
+
+    // Fetch the themes that aren't bundled; the list grows as they arrive
+    load_missing_themes(move || themes.set(get_themes()));
 
     // Track selected theme index for keyboard navigation
     let (selected_theme_index, set_selected_theme_index) = signal(0usize);
 
     // Update selected index when current theme changes
-    let themes_for_effect = themes.clone();
     Effect::new(move |_| {
         let current_theme_id = current_theme.get().id;
-        if let Some(index) = themes_for_effect
-            .iter()
-            .position(|t| t.id == current_theme_id)
+        if let Some(index) =
+            themes.with(|themes| themes.iter().position(|t| t.id == current_theme_id))
         {
             set_selected_theme_index.set(index);
         }
     });
 
     // Keyboard navigation handler
-    let themes_for_keydown = themes.clone();
     let handle_keydown = move |e: KeyboardEvent| {
+        let themes_len = themes.with_untracked(|themes| themes.len());
         if themes_len == 0 {
             return;
         }
@@ -57,7 +64,9 @@ pub fn ThemeSidebar(
                 set_selected_theme_index.set(next_index);
 
                 // Apply the theme instantly
-                if let Some(selected_theme) = themes_for_keydown.get(next_index) {
+                if let Some(selected_theme) =
+                    themes.with_untracked(|themes| themes.get(next_index).cloned())
+                {
                     set_current_theme.set(selected_theme.clone());
                     save_selected_theme(&selected_theme.id);
                 }
@@ -89,7 +98,9 @@ pub fn ThemeSidebar(
                 set_selected_theme_index.set(prev_index);
 
                 // Apply the theme instantly
-                if let Some(selected_theme) = themes_for_keydown.get(prev_index) {
+                if let Some(selected_theme) =
+                    themes.with_untracked(|themes| themes.get(prev_index).cloned())
+                {
                     set_current_theme.set(selected_theme.clone());
                     save_selected_theme(&selected_theme.id);
                 }
@@ -116,18 +127,80 @@ pub fn ThemeSidebar(
     // Add keyboard event listener
     let _cleanup = window_event_listener(ev::keydown, handle_keydown);
 
+    // Themes added at runtime from a URL or pasted JSON
+    let user_themes = RwSignal::new(get_user_themes());
+    let (theme_input, set_theme_input) = signal(String::new());
+    let (theme_error, set_theme_error) = signal::<Option<String>>(None);
+    let (is_adding_theme, set_is_adding_theme) = signal(false);
+
+    let install_theme = move |theme: Theme| {
+        if let Err(e) = add_user_theme(theme.clone()) {
+            set_theme_error.set(Some(t_with(
+                "theme_save_error",
+                &[("error", &e.to_string())],
+            )));
+            return;
+        }
+        save_selected_theme(&theme.id);
+        set_current_theme.set(theme);
+        user_themes.set(get_user_themes());
+        themes.set(get_themes());
+        set_theme_input.set(String::new());
+    };
+
+    let handle_add_theme = move |_| {
+        let input = theme_input.get_untracked().trim().to_string();
+        if input.is_empty() {
+            return;
+        }
+        set_theme_error.set(None);
+
+        if input.starts_with("http://") || input.starts_with("https://") {
+            set_is_adding_theme.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match fetch_user_theme(&input).await {
+                    Ok(theme) => install_theme(theme),
+                    Err(e) => set_theme_error.set(Some(e)),
+                }
+                set_is_adding_theme.set(false);
+            });
+        } else {
+            match parse_theme_json(&input) {
+                Ok(theme) => install_theme(theme),
+                Err(e) => set_theme_error.set(Some(e)),
+            }
+        }
+    };
+
+    let handle_remove_theme = move |id: String| {
+        if let Err(e) = remove_user_theme(&id) {
+            set_theme_error.set(Some(t_with(
+                "theme_remove_error",
+                &[("error", &e.to_string())],
+            )));
+            return;
+        }
+        if current_theme.get_untracked().id == id {
+            let theme = get_default_theme();
+            save_selected_theme(&theme.id);
+            set_current_theme.set(theme);
+        }
+        user_themes.set(get_user_themes());
+        themes.set(get_themes());
+    };
+
     view! {
         <div class="theme-sidebar h-full flex flex-col">
             <div class="flex items-center justify-between mb-4 pb-4 border-b" style="border-color: var(--theme-sidebar-border)">
-                <h2 class="text-lg font-bold" style="color: var(--theme-sidebar-text)">Themes</h2>
+                <h2 class="text-lg font-bold" style="color: var(--theme-sidebar-text)">{t("themes")}</h2>
                 <button
                     class="p-2 hover:bg-gray-100 rounded transition-colors"
                     style="color: var(--theme-text-secondary)"
                     on:click=move |_| {
                         view_state.update(|state| { state.execute(&Instruction::CloseThemeSidebar); });
                     }
-                    aria-label="Close themes"
-                    title="Close themes"
+                    aria-label=t("close_themes")
+                    title=t("close_themes")
                 >
                     <svg
                         width="20"
@@ -144,8 +217,8 @@ pub fn ThemeSidebar(
                 </button>
             </div>
 
-            <div class="flex-1 space-y-3" role="listbox" aria-label="Available themes">
-                {themes.into_iter().enumerate().map(|(index, theme)| {
+            <div class="flex-1 space-y-3" role="listbox" aria-label=t("available_themes")>
+                {move || themes.get().into_iter().enumerate().map(|(index, theme)| {
                     let theme_clone = theme.clone();
                     let theme_id_for_style = theme.id.clone();
                     let theme_id_for_click = theme.id.clone();
@@ -176,7 +249,7 @@ pub fn ThemeSidebar(
                             }
                             role="option"
                             aria-selected=move || (selected_theme_index.get() == index).to_string()
-                            aria-label=format!("{} theme", theme_name)
+                            aria-label=t_with("theme_label", &[("name", &theme_name)])
                             tabindex=move || if is_selected() { "0" } else { "-1" }
                             on:click=move |_| {
                                 set_current_theme.set(theme_clone.clone());
@@ -196,22 +269,22 @@ pub fn ThemeSidebar(
                                         <div
                                             class="w-4 h-4 rounded-full border"
                                             style=format!("background-color: {}; border-color: var(--theme-sidebar-border)", theme.colors.background)
-                                            title="Background color"
+                                            title=t("background_color")
                                         />
                                         <div
                                             class="w-4 h-4 rounded-full border"
                                             style=format!("background-color: {}; border-color: var(--theme-sidebar-border)", theme.colors.text.primary)
-                                            title="Text color"
+                                            title=t("text_color")
                                         />
                                         <div
                                             class="w-4 h-4 rounded-full border"
                                             style=format!("background-color: {}; border-color: var(--theme-sidebar-border)", theme.colors.sidebar.background)
-                                            title="Sidebar color"
+                                            title=t("sidebar_color")
                                         />
                                         <div
                                             class="w-4 h-4 rounded-full border"
                                             style=format!("background-color: {}; border-color: var(--theme-sidebar-border)", theme.colors.buttons.primary.background)
-                                            title="Accent color"
+                                            title=t("accent_color")
                                         />
                                     </div>
                                 </div>
@@ -238,10 +311,58 @@ pub fn ThemeSidebar(
                 }).collect_view()}
             </div>
 
+            <div class="mt-4 pt-4 border-t space-y-2" style="border-color: var(--theme-sidebar-border)">
+                <h3 class="text-sm font-semibold" style="color: var(--theme-sidebar-text)">{t("add_theme")}</h3>
+                <textarea
+                    class="w-full px-2 py-1 text-xs font-mono border rounded"
+                    style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                    rows="3"
+                    placeholder=t("theme_url_or_json")
+                    prop:value=move || theme_input.get()
+                    on:input=move |ev| set_theme_input.set(event_target_value(&ev))
+                    on:keydown=|ev: KeyboardEvent| ev.stop_propagation()
+                ></textarea>
+                <button
+                    class="w-full px-3 py-1 text-sm rounded transition-colors"
+                    style="background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text)"
+                    disabled=move || is_adding_theme.get()
+                    on:click=handle_add_theme
+                >
+                    {move || if is_adding_theme.get() { t("loading") } else { t("add_theme") }}
+                </button>
+                <Show when=move || theme_error.get().is_some() fallback=|| view! { <></> }>
+                    <p class="text-xs text-red-600">{move || theme_error.get().unwrap_or_default()}</p>
+                </Show>
+                <ul class="space-y-1">
+                    {move || user_themes.get().into_iter().map(|theme| {
+                        let id = theme.id.clone();
+                        view! {
+                            <li class="flex items-center justify-between text-xs" style="color: var(--theme-text-secondary)">
+                                <span class="truncate">{theme.name.clone()}</span>
+                                <button
+                                    class="underline opacity-75 hover:opacity-100"
+                                    on:click=move |_| handle_remove_theme(id.clone())
+                                >
+                                    {t("remove")}
+                                </button>
+                            </li>
+                        }
+                    }).collect_view()}
+                </ul>
+            </div>
+
+            <ReadingLayoutSettings view_state=view_state />
+
+            <UiLanguageSettings />
+
+            <PaletteAliasSettings />
+
+            <BookAbbreviationSettings />
+
             <div class="mt-4 pt-4 border-t" style="border-color: var(--theme-sidebar-border)">
                 <div class="space-y-1">
                     <p class="text-xs opacity-75" style="color: var(--theme-text-muted)">
-                        "Navigate & Apply: "
+                        {t("navigate_and_apply")}
                         <kbd class="px-1.5 py-0.5 rounded text-xs font-mono border" style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border)">
                             "Ctrl+J"
                         </kbd>
@@ -251,7 +372,7 @@ pub fn ThemeSidebar(
                         </kbd>
                     </p>
                     <p class="text-xs opacity-75" style="color: var(--theme-text-muted)">
-                        "Toggle: "
+                        {t("toggle")}
                         <kbd class="px-1.5 py-0.5 rounded text-xs font-mono border" style="background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border)">
                             "T"
                         </kbd>
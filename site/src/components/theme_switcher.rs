@@ -1,5 +1,5 @@
 use crate::storage::save_selected_theme;
-use crate::themes::{get_themes, Theme};
+use crate::themes::{get_themes, load_missing_themes, Theme};
 use leptos::prelude::*;
 
 #[component]
@@ -8,7 +8,16 @@ pub fn ThemeSwitcher(
     set_current_theme: WriteSignal<Theme>,
 ) -> impl IntoView {
     let (is_open, set_is_open) = signal(false);
-    let themes = StoredValue::new(get_themes());
+    let themes = RwSignal::new(get_themes());
+
+    // Fetch the themes that aren't bundled the first time the menu is opened
+    Effect::new(move |loading_started: Option<bool>| {
+        if loading_started == Some(true) || !is_open.get() {
+            return loading_started.unwrap_or(false);
+        }
+        load_missing_themes(move || themes.set(get_themes()));
+        true
+    });
 
     view! {
         <div class="relative">
@@ -56,7 +65,7 @@ pub fn ThemeSwitcher(
                              style="color: var(--theme-text-muted)">
                             "Themes"
                         </div>
-                        {themes.get_value().into_iter().map(|theme| {
+                        {move || themes.get().into_iter().map(|theme| {
                             let theme_clone = theme.clone();
                             let theme_id_for_click = theme.id.clone();
                             let theme_id_for_show = theme.id.clone();
@@ -7,6 +7,9 @@
  *
  * Features:
  * - Multi-translation selection via checkboxes
+ * - Verses aligned in columns per translation (stacked on mobile)
+ * - Versification-aware rows that mark merged verses explicitly
+ * - Per-column show/hide without deselecting the translation
  * - Automatic verse context from current URL
  * - Responsive design with theming support
  * - Loading states and error handling
@@ -19,19 +22,29 @@ use leptos::web_sys::KeyboardEvent;
 use wasm_bindgen_futures::spawn_local;
 
 // Core types and utilities
-use crate::core::{parse_verse_ranges_from_url, Verse, VerseRange};
+use crate::core::{
+    align_verses, map_verses, parse_verse_ranges_from_url, source_chapters, AlignedVerse, Bible,
+    MappedVerse, VerseRange,
+};
 use crate::instructions::types::Instruction;
 use crate::storage::translations::get_translations;
-use crate::storage::{get_downloaded_translations, load_downloaded_translation};
+use crate::storage::{get_downloaded_translations, load_downloaded_book, load_translation_index};
+use crate::utils::is_mobile_screen;
 use crate::view_state::ViewStateSignal;
 
 /// Internal state for tracking comparison data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct ComparisonData {
+    /// Translation short name, used to toggle column visibility
+    translation_key: String,
     /// Translation name for display
     translation_name: String,
-    /// Verses from this translation
-    verses: Vec<Verse>,
+    /// Chapter being compared, in English numbering
+    chapter: u32,
+    /// All verses of the chapter in this translation, placed on their English
+    /// verse numbers, so rows can be aligned even when the selection starts
+    /// inside a merged verse
+    verses: Vec<MappedVerse>,
 }
 
 /**
@@ -55,12 +68,17 @@ pub fn TranslationComparison(
 ) -> impl IntoView {
     // === State Management ===
 
-    // List of translation keys selected for comparison
-    let (selected_translations, set_selected_translations) = signal::<Vec<String>>(Vec::new());
+    // List of translation keys selected for comparison, kept in the view state so
+    // workspaces can save and restore them
+    let selected_translations =
+        Memo::new(move |_| view_state.with(|state| state.comparison_translations.clone()));
 
     // Processed comparison data with translation names and verses
     let (comparison_data, set_comparison_data) = signal::<Vec<ComparisonData>>(Vec::new());
 
+    // Translation keys whose column is currently hidden
+    let (hidden_translations, set_hidden_translations) = signal::<Vec<String>>(Vec::new());
+
     // Loading state for async operations
     let (loading, set_loading) = signal(false);
 
@@ -72,6 +90,16 @@ pub fn TranslationComparison(
     // Get the current verse ranges from the URL parameters
     let current_verse_ranges = Memo::new(move |_| parse_verse_ranges_from_url());
 
+    // Number of columns currently shown, used to size the panel
+    let visible_column_count = Memo::new(move |_| {
+        let hidden = hidden_translations.get();
+        comparison_data
+            .get()
+            .iter()
+            .filter(|data| !hidden.contains(&data.translation_key))
+            .count()
+    });
+
     // === Effects ===
 
     // Load available translations when panel opens
@@ -93,7 +121,6 @@ pub fn TranslationComparison(
         if !selected.is_empty() && view_state.with(|state| state.is_translation_comparison_open) {
             let book = current_book.get();
             let chapter = current_chapter.get();
-            let verse_ranges = current_verse_ranges.get();
 
             set_loading.set(true);
 
@@ -102,33 +129,60 @@ pub fn TranslationComparison(
 
                 // Load each selected translation
                 for translation_key in selected {
-                    if let Ok(bible) = load_downloaded_translation(&translation_key).await {
-                        if let Ok(chapter_data) = bible.get_chapter(&book, chapter) {
-                            // Filter verses based on current selection
-                            let filtered_verses: Vec<Verse> = chapter_data
-                                .verses
-                                .iter()
-                                .filter(|verse| {
-                                    verse_ranges.iter().any(|range| {
-                                        verse.verse >= range.start && verse.verse <= range.end
-                                    })
-                                })
-                                .cloned()
-                                .collect();
-
-                            // Get user-friendly translation name
-                            let translation_name = get_translations()
-                                .iter()
-                                .find(|t| t.short_name == translation_key)
-                                .map(|t| t.name.clone())
-                                .unwrap_or_else(|| translation_key.clone());
-
-                            comparison_results.push(ComparisonData {
-                                translation_name,
-                                verses: filtered_verses,
-                            });
-                        }
+                    let Ok(index) = load_translation_index(&translation_key).await else {
+                        continue;
+                    };
+                    let bible = Bible::from_index(&index);
+                    let Some(book_index) = bible.find_book(&book).and_then(|found| {
+                        bible.books.iter().position(|b| std::ptr::eq(&**b, found))
+                    }) else {
+                        continue;
+                    };
+                    // Only the compared book is read
+                    let Ok(book_data) = load_downloaded_book(&translation_key, book_index).await
+                    else {
+                        continue;
+                    };
+                    let translation = get_translations()
+                        .into_iter()
+                        .find(|t| t.short_name == translation_key);
+                    let versification = translation
+                        .as_ref()
+                        .map(|t| t.versification)
+                        .unwrap_or_default();
+
+                    // The chapter's verses may start in the translation's previous chapter
+                    let verses: Vec<MappedVerse> =
+                        source_chapters(versification, book_index, chapter)
+                            .into_iter()
+                            .filter_map(|own_chapter| {
+                                let chapter_data = book_data
+                                    .chapters
+                                    .iter()
+                                    .find(|c| c.chapter == own_chapter)?;
+                                Some(map_verses(
+                                    versification,
+                                    book_index,
+                                    chapter,
+                                    own_chapter,
+                                    &chapter_data.verses,
+                                ))
+                            })
+                            .flatten()
+                            .collect();
+                    if verses.is_empty() {
+                        continue;
                     }
+
+                    comparison_results.push(ComparisonData {
+                        translation_key: translation_key.clone(),
+                        // Get user-friendly translation name
+                        translation_name: translation
+                            .map(|t| t.name)
+                            .unwrap_or_else(|| translation_key.clone()),
+                        chapter,
+                        verses,
+                    });
                 }
 
                 set_comparison_data.set(comparison_results);
@@ -159,7 +213,10 @@ pub fn TranslationComparison(
     view! {
         <Show when=move || view_state.with(|state| state.is_translation_comparison_open) fallback=|| view! { <></> }>
             {/* Main Panel Container */}
-            <div class="fixed inset-y-0 right-0 w-96 bg-white shadow-lg z-30 flex flex-col border-l border-gray-200">
+            <div
+                class="fixed inset-y-0 right-0 bg-white shadow-lg z-30 flex flex-col border-l border-gray-200"
+                style=move || panel_width_style(visible_column_count.get())
+            >
 
                 {/* Panel Header */}
                 <div class="flex items-center justify-between p-4 border-b border-gray-200">
@@ -187,7 +244,8 @@ pub fn TranslationComparison(
                                 render_translation_checkbox(
                                     translation,
                                     selected_translations,
-                                    set_selected_translations
+                                    view_state,
+                                    set_hidden_translations
                                 )
                             }
                         />
@@ -196,7 +254,13 @@ pub fn TranslationComparison(
 
                 {/* Comparison Results Section */}
                 <div class="flex-1 overflow-y-auto p-4">
-                    {render_comparison_results(loading, comparison_data, current_verse_ranges)}
+                    {render_comparison_results(
+                        loading,
+                        comparison_data,
+                        hidden_translations,
+                        set_hidden_translations,
+                        current_verse_ranges
+                    )}
                 </div>
 
                 {/* Panel Footer */}
@@ -224,8 +288,9 @@ pub fn TranslationComparison(
  */
 fn render_translation_checkbox(
     translation: String,
-    selected_translations: ReadSignal<Vec<String>>,
-    set_selected_translations: WriteSignal<Vec<String>>,
+    selected_translations: Memo<Vec<String>>,
+    view_state: ViewStateSignal,
+    set_hidden_translations: WriteSignal<Vec<String>>,
 ) -> impl IntoView {
     let translation_clone = translation.clone();
 
@@ -248,13 +313,14 @@ fn render_translation_checkbox(
                 on:change=move |_| {
                     let mut current = selected_translations.get();
                     if current.contains(&translation_clone) {
-                        // Remove if already selected
+                        // Remove if already selected, forgetting its hidden state too
                         current.retain(|t| t != &translation_clone);
+                        set_hidden_translations.update(|hidden| hidden.retain(|t| t != &translation_clone));
                     } else {
                         // Add if not selected
                         current.push(translation_clone.clone());
                     }
-                    set_selected_translations.set(current);
+                    view_state.update(|state| state.comparison_translations = current);
                 }
             />
             <span class="text-sm text-gray-700">{translation_display}</span>
@@ -271,13 +337,20 @@ fn render_translation_checkbox(
 fn render_comparison_results(
     loading: ReadSignal<bool>,
     comparison_data: ReadSignal<Vec<ComparisonData>>,
+    hidden_translations: ReadSignal<Vec<String>>,
+    set_hidden_translations: WriteSignal<Vec<String>>,
     current_verse_ranges: Memo<Vec<VerseRange>>,
 ) -> impl IntoView {
     view! {
         <Show
             when=move || loading.get()
             fallback=move || {
-                render_comparison_content(comparison_data, current_verse_ranges)
+                render_comparison_content(
+                    comparison_data,
+                    hidden_translations,
+                    set_hidden_translations,
+                    current_verse_ranges,
+                )
             }
         >
             {/* Loading State */}
@@ -292,12 +365,33 @@ fn render_comparison_results(
 /**
  * Render the actual comparison content
  *
- * Shows either the verse comparisons or an empty state message.
+ * Shows either the aligned verse comparisons or an empty state message.
+ * Hidden columns are listed above the table so they can be shown again.
  */
 fn render_comparison_content(
     comparison_data: ReadSignal<Vec<ComparisonData>>,
+    hidden_translations: ReadSignal<Vec<String>>,
+    set_hidden_translations: WriteSignal<Vec<String>>,
     current_verse_ranges: Memo<Vec<VerseRange>>,
 ) -> impl IntoView {
+    let visible_data = Memo::new(move |_| {
+        let hidden = hidden_translations.get();
+        comparison_data
+            .get()
+            .into_iter()
+            .filter(|data| !hidden.contains(&data.translation_key))
+            .collect::<Vec<_>>()
+    });
+
+    let hidden_data = Memo::new(move |_| {
+        let hidden = hidden_translations.get();
+        comparison_data
+            .get()
+            .into_iter()
+            .filter(|data| hidden.contains(&data.translation_key))
+            .collect::<Vec<_>>()
+    });
+
     view! {
         <Show
             when=move || !comparison_data.get().is_empty()
@@ -305,16 +399,41 @@ fn render_comparison_content(
                 render_empty_state(current_verse_ranges)
             }
         >
+            {/* Hidden Columns */}
+            <Show when=move || !hidden_data.get().is_empty()>
+                <div class="flex flex-wrap items-center gap-2 mb-4 text-xs text-gray-500">
+                    <span>"Hidden:"</span>
+                    <For
+                        each=move || hidden_data.get()
+                        key=|data| data.translation_key.clone()
+                        children=move |data: ComparisonData| {
+                            let translation_key = data.translation_key.clone();
+                            view! {
+                                <button
+                                    class="px-2 py-0.5 rounded border border-gray-300 hover:bg-gray-100 transition-colors"
+                                    title="Show column"
+                                    on:click=move |_| {
+                                        set_hidden_translations.update(|hidden| hidden.retain(|t| t != &translation_key));
+                                    }
+                                >
+                                    {data.translation_name}
+                                </button>
+                            }
+                        }
+                    />
+                </div>
+            </Show>
+
             {/* Comparison Results */}
-            <div class="space-y-6">
-                <For
-                    each=move || comparison_data.get()
-                    key=|data| data.translation_name.clone()
-                    children=move |data: ComparisonData| {
-                        render_translation_verses(data)
-                    }
-                />
-            </div>
+            {move || {
+                let data = visible_data.get();
+                if data.is_empty() {
+                    return view! {
+                        <p class="text-sm text-gray-500 text-center mt-8">"All columns are hidden"</p>
+                    }.into_any();
+                }
+                render_aligned_verses(data, current_verse_ranges.get(), set_hidden_translations).into_any()
+            }}
         </Show>
     }
 }
@@ -334,38 +453,164 @@ fn render_empty_state(current_verse_ranges: Memo<Vec<VerseRange>>) -> impl IntoV
 }
 
 /**
- * Render verses for a single translation
+ * Render verses of all visible translations aligned by verse number
  *
- * Creates a card showing the translation name and its verses.
+ * On wide screens each translation gets its own column; on mobile the
+ * translations are stacked under each verse number instead. Only rows
+ * within the selected verse ranges are shown.
  */
-fn render_translation_verses(data: ComparisonData) -> impl IntoView {
-    view! {
-        <div class="border rounded-lg p-4 bg-gray-50">
-            <h4 class="font-medium text-gray-800 mb-3 text-sm">
-                {data.translation_name}
-            </h4>
-            <div class="space-y-2">
-                <For
-                    each=move || data.verses.clone()
-                    key=|verse| verse.verse
-                    children=move |verse: Verse| {
-                        view! {
-                            <div class="flex gap-2">
-                                <span class="text-xs font-medium text-gray-500 mt-1 min-w-[20px]">
-                                    {verse.verse}
-                                </span>
-                                <p class="text-sm text-gray-700 leading-relaxed">
-                                    {verse.text}
-                                </p>
-                            </div>
+fn render_aligned_verses(
+    data: Vec<ComparisonData>,
+    verse_ranges: Vec<VerseRange>,
+    set_hidden_translations: WriteSignal<Vec<String>>,
+) -> impl IntoView {
+    let stacked = is_mobile_screen();
+    let columns_style = if stacked {
+        "grid-template-columns: minmax(0, 1fr)".to_string()
+    } else {
+        format!("grid-template-columns: repeat({}, minmax(0, 1fr))", data.len())
+    };
+    let columns: Vec<&[MappedVerse]> =
+        data.iter().map(|column| column.verses.as_slice()).collect();
+    // A Psalm title is shown along with the first verse
+    let aligned_rows: Vec<_> = align_verses(&columns)
+        .into_iter()
+        .filter(|row| {
+            verse_ranges
+                .iter()
+                .any(|range| range.contains(row.verse.max(1)))
+        })
+        .collect();
+
+    let headers = (!stacked).then(|| {
+        let headers = data
+            .iter()
+            .map(|column| render_column_header(column, set_hidden_translations))
+            .collect_view();
+        view! {
+            <div class="grid gap-4 pb-2 mb-2 border-b border-gray-200" style=columns_style.clone()>
+                {headers}
+            </div>
+        }
+    });
+
+    let rows = aligned_rows
+        .into_iter()
+        .map(|row| {
+            let verse_number = row.verse;
+            let verse_label = if verse_number == 0 {
+                "Title".to_string()
+            } else {
+                verse_number.to_string()
+            };
+            let cells = data
+                .iter()
+                .zip(row.cells)
+                .map(|(column, cell)| {
+                    let label = stacked.then(|| render_column_header(column, set_hidden_translations));
+                    let content = match cell {
+                        AlignedVerse::Text { chapter: own_chapter, verse: own_verse, text } => {
+                            // Show where the translation numbers the verse differently
+                            let own_number = (own_verse != verse_number || own_chapter != column.chapter)
+                                .then(|| view! {
+                                    <span class="text-xs text-gray-400 mr-1">
+                                        {format!("({}:{})", own_chapter, own_verse)}
+                                    </span>
+                                });
+                            view! {
+                                <p class="text-sm text-gray-700 leading-relaxed">{own_number}{text}</p>
+                            }
+                            .into_any()
+                        }
+                        AlignedVerse::MergedInto(merged_verse) => view! {
+                            <p class="text-xs italic text-gray-400">
+                                {format!("Merged with verse {}", merged_verse)}
+                            </p>
                         }
+                        .into_any(),
+                        AlignedVerse::Missing => view! {
+                            <p class="text-xs italic text-gray-400">"—"</p>
+                        }
+                        .into_any(),
+                    };
+                    view! {
+                        <div>
+                            {label}
+                            {content}
+                        </div>
                     }
-                />
+                })
+                .collect_view();
+
+            view! {
+                <div class="flex gap-2">
+                    <span class="text-xs font-medium text-gray-500 mt-1 min-w-[20px]">
+                        {verse_label}
+                    </span>
+                    <div class="grid gap-4 flex-1" style=columns_style.clone()>
+                        {cells}
+                    </div>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div>
+            {headers}
+            <div class="space-y-4">
+                {rows}
             </div>
         </div>
     }
 }
 
+/**
+ * Render a column header with a button to hide the column
+ */
+fn render_column_header(
+    data: &ComparisonData,
+    set_hidden_translations: WriteSignal<Vec<String>>,
+) -> impl IntoView {
+    let translation_key = data.translation_key.clone();
+    view! {
+        <div class="flex items-center justify-between gap-2">
+            <h4 class="font-medium text-gray-800 text-sm truncate">
+                {data.translation_name.clone()}
+            </h4>
+            <button
+                class="text-xs text-gray-400 hover:text-gray-700 transition-colors"
+                title="Hide column"
+                aria-label="Hide column"
+                on:click=move |_| {
+                    let translation_key = translation_key.clone();
+                    set_hidden_translations.update(|hidden| {
+                        if !hidden.contains(&translation_key) {
+                            hidden.push(translation_key);
+                        }
+                    });
+                }
+            >
+                {/* Eye-off Icon */}
+                <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13.875 18.825A10.05 10.05 0 0112 19c-4.478 0-8.268-2.943-9.543-7a9.97 9.97 0 011.563-3.029m5.858.908a3 3 0 114.243 4.243M9.878 9.878l4.242 4.242M9.88 9.88l-3.29-3.29m7.532 7.532l3.29 3.29M3 3l3.59 3.59m0 0A9.953 9.953 0 0112 5c4.478 0 8.268 2.943 9.543 7a10.025 10.025 0 01-4.132 5.411m0 0L21 21"></path>
+                </svg>
+            </button>
+        </div>
+    }
+}
+
+/**
+ * Width of the panel for the given number of visible columns
+ *
+ * A single column keeps the original sidebar width; every extra
+ * column widens the panel, capped at the viewport width.
+ */
+fn panel_width_style(columns: usize) -> String {
+    let width_rem = 24 + 16 * columns.saturating_sub(1);
+    format!("width: min(100vw, {}rem)", width_rem)
+}
+
 /**
  * Format verse ranges for display
  *
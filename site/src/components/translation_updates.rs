@@ -0,0 +1,158 @@
+use crate::api::fetch_translation_index;
+use crate::i18n::{t, t_with};
+use crate::storage::{
+    download_translation, get_selected_translation, get_translations_with_updates,
+    switch_bible_translation, BibleTranslation,
+};
+use leptos::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+/// Delay before checking for updates so the check doesn't compete with startup
+const UPDATE_CHECK_DELAY_MS: u32 = 3000;
+/// How often an open app checks the hosted index again
+const UPDATE_CHECK_INTERVAL_MS: u32 = 60 * 60 * 1000;
+
+#[component]
+pub fn TranslationUpdateNotice() -> impl IntoView {
+    let (updates, set_updates) = signal::<Vec<BibleTranslation>>(Vec::new());
+    let (updating, set_updating) = signal::<Option<String>>(None);
+    let (expanded_changelog, set_expanded_changelog) = signal::<Option<String>>(None);
+    let (update_error, set_update_error) = signal::<Option<String>>(None);
+    let (dismissed, set_dismissed) = signal(false);
+
+    // Check the hosted index for newer revisions in the background once the app
+    // has loaded, and again periodically for as long as it stays open
+    Effect::new(move |_| {
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(UPDATE_CHECK_DELAY_MS).await;
+            loop {
+                match fetch_translation_index().await {
+                    Ok(hosted) => set_updates.set(get_translations_with_updates(&hosted)),
+                    Err(e) => {
+                        leptos::logging::warn!("Failed to check for translation updates: {}", e)
+                    }
+                }
+                gloo_timers::future::TimeoutFuture::new(UPDATE_CHECK_INTERVAL_MS).await;
+            }
+        });
+    });
+
+    let handle_update = move |translation: BibleTranslation| {
+        let short_name = translation.short_name.clone();
+        set_updating.set(Some(short_name.clone()));
+        set_update_error.set(None);
+
+        spawn_local(async move {
+            match download_translation(&translation).await {
+                Ok(_) => {
+                    // Reload the Bible if the updated translation is the one being read
+                    if get_selected_translation().as_deref() == Some(short_name.as_str()) {
+                        if let Err(e) = switch_bible_translation(&short_name).await {
+                            leptos::logging::error!("Failed to reload translation: {}", e);
+                        }
+                    }
+                    set_updates.update(|updates| updates.retain(|t| t.short_name != short_name));
+                }
+                Err(e) => {
+                    set_update_error.set(Some(t_with(
+                        "translation_update_failed",
+                        &[("name", &translation.name), ("error", &e.to_string())],
+                    )));
+                }
+            }
+            set_updating.set(None);
+        });
+    };
+
+    view! {
+        <Show when=move || !dismissed.get() && !updates.get().is_empty()>
+            <div
+                class="fixed bottom-4 right-4 z-50 w-80 max-w-full rounded-lg shadow-lg border p-4 text-sm"
+                style="background-color: var(--theme-sidebar-background); color: var(--theme-sidebar-text); border-color: var(--theme-sidebar-border)"
+                role="status"
+            >
+                <div class="flex items-center justify-between mb-2">
+                    <h3 class="font-semibold">{t("translation_updates_available")}</h3>
+                    <button
+                        class="opacity-60 hover:opacity-100 transition-opacity"
+                        on:click=move |_| set_dismissed.set(true)
+                        aria-label=t("dismiss_translation_updates")
+                    >
+                        <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                            <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M6 18L18 6M6 6l12 12"></path>
+                        </svg>
+                    </button>
+                </div>
+
+                <ul class="space-y-2">
+                    <For
+                        each=move || updates.get()
+                        key=|translation| (translation.short_name.clone(), translation.revision)
+                        children=move |translation: BibleTranslation| {
+                            let short_name = translation.short_name.clone();
+                            let short_name_for_changelog = short_name.clone();
+                            let short_name_for_toggle = short_name.clone();
+                            let changelog = translation.changelog.clone();
+                            let has_changelog = !changelog.is_empty();
+                            let translation_for_update = translation.clone();
+
+                            view! {
+                                <li>
+                                    <div class="flex items-center justify-between gap-2">
+                                        <span class="truncate">{translation.name.clone()}</span>
+                                        <div class="flex items-center gap-2 shrink-0">
+                                            <Show when=move || has_changelog>
+                                                <button
+                                                    class="text-xs underline opacity-75 hover:opacity-100"
+                                                    on:click={
+                                                        let short_name = short_name_for_toggle.clone();
+                                                        move |_| {
+                                                            let short_name = short_name.clone();
+                                                            set_expanded_changelog.update(|expanded| {
+                                                                *expanded = if expanded.as_deref() == Some(short_name.as_str()) {
+                                                                    None
+                                                                } else {
+                                                                    Some(short_name)
+                                                                };
+                                                            });
+                                                        }
+                                                    }
+                                                >
+                                                    {t("changelog")}
+                                                </button>
+                                            </Show>
+                                            <button
+                                                class="px-2 py-1 text-xs rounded transition-colors"
+                                                style="background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text)"
+                                                disabled=move || updating.get().is_some()
+                                                on:click={
+                                                    let translation = translation_for_update.clone();
+                                                    move |_| handle_update(translation.clone())
+                                                }
+                                            >
+                                                {move || if updating.get().as_deref() == Some(short_name.as_str()) {
+                                                    t("updating")
+                                                } else {
+                                                    t("update")
+                                                }}
+                                            </button>
+                                        </div>
+                                    </div>
+                                    <Show when=move || expanded_changelog.get().as_deref() == Some(short_name_for_changelog.as_str())>
+                                        <ul class="mt-1 ml-4 list-disc text-xs opacity-75">
+                                            {changelog.iter().cloned().map(|entry| view! { <li>{entry}</li> }).collect_view()}
+                                        </ul>
+                                    </Show>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+
+                <Show when=move || update_error.get().is_some()>
+                    <p class="mt-2 text-xs text-red-600">{move || update_error.get().unwrap_or_default()}</p>
+                </Show>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,49 @@
+use crate::core::types::Language;
+use crate::i18n::{t, UI_LANGUAGES};
+use crate::storage::{get_ui_language, save_ui_language};
+use leptos::prelude::*;
+
+/// Settings section for the language of the interface, independent of the translation being read
+#[component]
+pub fn UiLanguageSettings() -> impl IntoView {
+    let selected = get_ui_language();
+    let options: Vec<(Option<Language>, String)> =
+        std::iter::once((None, t("interface_language_automatic")))
+            .chain(
+                UI_LANGUAGES
+                    .iter()
+                    .map(|language| (Some(*language), language.display_name().to_string())),
+            )
+            .collect();
+
+    view! {
+        <div class="mt-4 pt-4 border-t space-y-2" style="border-color: var(--theme-sidebar-border)">
+            <h3 class="text-sm font-semibold" style="color: var(--theme-sidebar-text)">{t("interface_language")}</h3>
+            <div class="flex flex-col gap-2" role="group" aria-label=t("interface_language")>
+                {options.into_iter().map(|(language, label)| {
+                    let is_selected = language == selected;
+                    view! {
+                        <button
+                            class="px-3 py-1 text-sm text-left rounded border transition-colors"
+                            style=if is_selected {
+                                "background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text); border-color: var(--theme-button-primary-background)"
+                            } else {
+                                "color: var(--theme-sidebar-text); border-color: var(--theme-sidebar-border)"
+                            }
+                            aria-pressed=is_selected.to_string()
+                            on:click=move |_| {
+                                if language != selected {
+                                    save_ui_language(language);
+                                    let _ = window().location().reload();
+                                }
+                            }
+                        >
+                            {label}
+                        </button>
+                    }
+                }).collect_view()}
+            </div>
+            <p class="text-xs" style="color: var(--theme-text-muted)">{t("interface_language_note")}</p>
+        </div>
+    }
+}
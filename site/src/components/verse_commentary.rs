@@ -0,0 +1,191 @@
+use crate::core::{commentary_for_verse, parse_commentary, CommentaryEntry};
+use crate::i18n::{t, t_with};
+use crate::storage::commentaries::{
+    get_commentaries, get_commentary_book, remove_commentary, save_commentary,
+};
+use leptos::html::Input;
+use leptos::prelude::*;
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{Event, FileReader, HtmlInputElement};
+
+/// Comments from the imported commentaries on one verse, shown under its cross references
+#[component]
+pub fn VerseCommentary(
+    /// English book name as used by the cross references, e.g. "1 Samuel"
+    book_name: String,
+    chapter: u32,
+    verse: u32,
+) -> impl IntoView {
+    let file_input_ref = NodeRef::<Input>::new();
+    let commentaries = RwSignal::new(get_commentaries());
+    let (import_status, set_import_status) = signal::<Option<Result<String, String>>>(None);
+
+    // The comments of every commentary on this book, with the commentary's abbreviation
+    let book_comments = RwSignal::new(Vec::<(String, Vec<CommentaryEntry>)>::new());
+    let book = book_name.clone();
+    Effect::new(move |_| {
+        let infos: Vec<_> = commentaries.with(|commentaries| {
+            commentaries
+                .iter()
+                .filter(|info| info.books.contains(&book))
+                .cloned()
+                .collect()
+        });
+        let book = book.clone();
+        spawn_local(async move {
+            let mut loaded = Vec::new();
+            for info in infos {
+                let entries = get_commentary_book(&info.name, &book).await;
+                loaded.push((info.abbreviation, entries));
+            }
+            let _ = book_comments.try_set(loaded);
+        });
+    });
+
+    let on_file_change = move |ev: Event| {
+        set_import_status.set(None);
+        let Some(file) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+            .and_then(|input| input.files())
+            .and_then(|files| files.get(0))
+        else {
+            return;
+        };
+        let Ok(file_reader) = FileReader::new() else {
+            return;
+        };
+        let file_reader_clone = file_reader.clone();
+
+        let onload = Closure::wrap(Box::new(move |_: Event| {
+            let text = file_reader_clone
+                .result()
+                .ok()
+                .and_then(|result| result.as_string())
+                .unwrap_or_default();
+            match parse_commentary(&text) {
+                Ok(commentary) => spawn_local(async move {
+                    let count = commentary.entries.len();
+                    let status = save_commentary(commentary)
+                        .await
+                        .map(|info| {
+                            t_with(
+                                "commentary_imported",
+                                &[("name", &info.name), ("count", &count.to_string())],
+                            )
+                        })
+                        .map_err(|error| t_with("save_error", &[("error", &error.to_string())]));
+                    set_import_status.set(Some(status));
+                    commentaries.set(get_commentaries());
+                }),
+                Err(error) => set_import_status.set(Some(Err(error))),
+            }
+            if let Some(input) = file_input_ref.get_untracked() {
+                input.set_value("");
+            }
+        }) as Box<dyn FnMut(_)>);
+
+        file_reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        onload.forget();
+
+        let _ = file_reader.read_as_text(&file);
+    };
+
+    let comments = move || {
+        let comments: Vec<(String, String, String)> = book_comments.with(|book_comments| {
+            book_comments
+                .iter()
+                .flat_map(|(abbreviation, entries)| {
+                    commentary_for_verse(entries, &book_name, chapter, verse)
+                        .into_iter()
+                        .map(|entry| {
+                            (
+                                abbreviation.clone(),
+                                entry.verse_label(),
+                                entry.text.clone(),
+                            )
+                        })
+                })
+                .collect()
+        });
+
+        if comments.is_empty() {
+            let message = if commentaries.with(|commentaries| commentaries.is_empty()) {
+                t("no_commentaries")
+            } else {
+                t("no_commentary_for_verse")
+            };
+            return view! {
+                <p class="text-sm italic" style="color: var(--theme-text-muted)">{message}</p>
+            }
+            .into_any();
+        }
+
+        comments
+            .into_iter()
+            .map(|(abbreviation, verse_label, text)| {
+                view! {
+                    <div class="mb-3">
+                        <div class="text-xs mb-1" style="color: var(--theme-text-muted)">
+                            {abbreviation} " " {verse_label}
+                        </div>
+                        <p class="text-sm leading-relaxed whitespace-pre-line" style="color: var(--theme-text-primary)">
+                            {text}
+                        </p>
+                    </div>
+                }
+            })
+            .collect_view()
+            .into_any()
+    };
+
+    view! {
+        <div class="mt-4 border-t pt-4" style="border-color: var(--theme-sidebar-border)">
+            <h3 class="text-sm font-medium mb-2" style="color: var(--theme-sidebar-text)">{t("commentary")}</h3>
+            {comments}
+            <details class="mt-2 text-xs" style="color: var(--theme-text-secondary)">
+                <summary class="cursor-pointer">{t("import_commentary")}</summary>
+                <input
+                    type="file"
+                    accept=".json,application/json"
+                    class="w-full mt-2 mb-1"
+                    aria-label=t("import_commentary")
+                    node_ref=file_input_ref
+                    on:change=on_file_change
+                />
+                {move || import_status.get().map(|status| match status {
+                    Ok(message) => view! { <p class="opacity-75">{message}</p> }.into_any(),
+                    Err(error) => view! { <p class="text-red-600">{error}</p> }.into_any(),
+                })}
+                <ul class="mt-1 space-y-1">
+                    {move || commentaries.get().into_iter().map(|info| {
+                        let name = info.name.clone();
+                        view! {
+                            <li class="flex items-center justify-between gap-2">
+                                <span>{info.name}</span>
+                                <button
+                                    class="underline opacity-75 hover:opacity-100"
+                                    on:click=move |_| {
+                                        let name = name.clone();
+                                        spawn_local(async move {
+                                            if let Err(error) = remove_commentary(&name).await {
+                                                set_import_status.set(Some(Err(t_with(
+                                                    "save_error",
+                                                    &[("error", &error.to_string())],
+                                                ))));
+                                            }
+                                            commentaries.set(get_commentaries());
+                                        });
+                                    }
+                                >
+                                    {t("remove")}
+                                </button>
+                            </li>
+                        }
+                    }).collect_view()}
+                </ul>
+            </details>
+        </div>
+    }
+}
@@ -0,0 +1,215 @@
+use crate::core::{describe_morphology, SourceScript, SourceWord};
+use crate::i18n::t;
+use crate::instructions::types::Instruction;
+use crate::view_state::ViewStateSignal;
+use leptos::ev;
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::{KeyboardEvent, MouseEvent};
+
+/// Width of the word popover in pixels (`w-64`)
+const WORD_POPOVER_WIDTH: i32 = 256;
+/// Room kept for the popover below a word before it opens above it instead
+const WORD_POPOVER_MAX_HEIGHT: i32 = 280;
+const WORD_POPOVER_OFFSET: i32 = 8;
+
+/// A source text word whose parsing is shown, and where
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectedWord {
+    pub word: SourceWord,
+    pub script: SourceScript,
+    pub position: (i32, i32),
+}
+
+/// Top-left corner of the popover for a word spanning `left`, `top` and `bottom`:
+/// below the word, or above it when there's no room below
+fn word_popover_position(
+    left: i32,
+    top: i32,
+    bottom: i32,
+    viewport_width: i32,
+    viewport_height: i32,
+) -> (i32, i32) {
+    let left = left
+        .min(viewport_width - WORD_POPOVER_WIDTH - WORD_POPOVER_OFFSET)
+        .max(WORD_POPOVER_OFFSET);
+    let top = if bottom + WORD_POPOVER_OFFSET + WORD_POPOVER_MAX_HEIGHT <= viewport_height {
+        bottom + WORD_POPOVER_OFFSET
+    } else {
+        (top - WORD_POPOVER_OFFSET - WORD_POPOVER_MAX_HEIGHT).max(WORD_POPOVER_OFFSET)
+    };
+    (left, top)
+}
+
+fn viewport_size() -> (i32, i32) {
+    let window = web_sys::window();
+    let size = |value: Option<wasm_bindgen::JsValue>| {
+        value
+            .and_then(|value| value.as_f64())
+            .map_or(i32::MAX, |value| value as i32)
+    };
+    (
+        size(window.as_ref().and_then(|w| w.inner_width().ok())),
+        size(window.as_ref().and_then(|w| w.inner_height().ok())),
+    )
+}
+
+/// Source text words that show their parsing when clicked
+pub fn source_words_view(
+    words: Vec<SourceWord>,
+    script: SourceScript,
+    selected_word: RwSignal<Option<SelectedWord>>,
+) -> impl IntoView {
+    words
+        .into_iter()
+        .map(|word| {
+            let text = word.text.clone();
+            view! {
+                <button
+                    class="source-word"
+                    on:click=move |ev: MouseEvent| {
+                        // Keep the window click listener from closing the popover again
+                        ev.stop_propagation();
+                        let Some(element) = ev
+                            .current_target()
+                            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        else {
+                            return;
+                        };
+                        let rect = element.get_bounding_client_rect();
+                        let (viewport_width, viewport_height) = viewport_size();
+                        selected_word.set(Some(SelectedWord {
+                            word: word.clone(),
+                            script,
+                            position: word_popover_position(
+                                rect.left() as i32,
+                                rect.top() as i32,
+                                rect.bottom() as i32,
+                                viewport_width,
+                                viewport_height,
+                            ),
+                        }));
+                    }
+                >
+                    {text}
+                </button>
+                " "
+            }
+        })
+        .collect_view()
+}
+
+/// Parsing and lemma of the clicked source text word; closes on Escape or a click elsewhere
+#[component]
+pub fn WordPopover(
+    selected_word: RwSignal<Option<SelectedWord>>,
+    view_state: ViewStateSignal,
+) -> impl IntoView {
+    window_event_listener(ev::keydown, move |evt: KeyboardEvent| {
+        if evt.key() == "Escape" && selected_word.with_untracked(|word| word.is_some()) {
+            evt.prevent_default();
+            selected_word.set(None);
+        }
+    });
+    window_event_listener(ev::click, move |_| {
+        if selected_word.with_untracked(|word| word.is_some()) {
+            selected_word.set(None);
+        }
+    });
+
+    move || {
+        selected_word.get().map(|selected| {
+            let SelectedWord {
+                word,
+                script,
+                position: (left, top),
+            } = selected;
+            let features = word
+                .morph
+                .as_deref()
+                .map(describe_morphology)
+                .unwrap_or_default();
+            let rows = word
+                .lemma
+                .clone()
+                .map(|lemma| (t("lemma"), lemma))
+                .into_iter()
+                .chain(word.strongs.clone().map(|strongs| (t("strongs"), strongs)))
+                .chain(
+                    features
+                        .into_iter()
+                        .map(|(label, value)| (t(label), t(value))),
+                )
+                .map(|(label, value)| {
+                    view! {
+                        <tr>
+                            <th class="pr-3 py-0.5 text-left font-normal" style="color: var(--theme-text-muted)">{label}</th>
+                            <td class="py-0.5" style="color: var(--theme-text-primary)">{value}</td>
+                        </tr>
+                    }
+                })
+                .collect_view();
+
+            view! {
+                <div
+                    role="dialog"
+                    aria-label=t("word_details")
+                    class="fixed z-50 w-64 p-3 rounded-lg shadow-lg border text-sm"
+                    style=format!(
+                        "left: {}px; top: {}px; background-color: var(--theme-sidebar-background); border-color: var(--theme-sidebar-border)",
+                        left, top
+                    )
+                    on:click=|ev: MouseEvent| ev.stop_propagation()
+                >
+                    <div class="flex items-start justify-between gap-2 mb-2">
+                        <span class=format!("text-lg {}", script.css_class()) style="color: var(--theme-text-primary)" lang=script.lang() dir=script.dir()>
+                            {word.text.clone()}
+                        </span>
+                        <button
+                            class="px-1 rounded"
+                            style="color: var(--theme-text-muted)"
+                            aria-label=t("close")
+                            on:click=move |_| selected_word.set(None)
+                        >
+                            "×"
+                        </button>
+                    </div>
+                    <table class="w-full text-xs">
+                        <tbody>{rows}</tbody>
+                    </table>
+                    {word.morph.clone().map(|morph| view! {
+                        <div class="mt-2 text-xs font-mono" style="color: var(--theme-text-muted)">{morph}</div>
+                    })}
+                    {word.lexicon_key().map(|key| view! {
+                        <button
+                            class="mt-2 text-xs underline opacity-75 hover:opacity-100"
+                            on:click=move |_| {
+                                selected_word.set(None);
+                                view_state.update(|state| {
+                                    state.execute(&Instruction::ShowLexiconEntry(key.clone()));
+                                });
+                            }
+                        >
+                            {t("open_in_lexicon")}
+                        </button>
+                    })}
+                </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_popover_position() {
+        // Below the word, aligned with its left edge
+        assert_eq!(word_popover_position(100, 200, 220, 1200, 800), (100, 228));
+        // Kept inside the right edge of the viewport
+        assert_eq!(word_popover_position(1100, 200, 220, 1200, 800), (936, 228));
+        // Above the word near the bottom of the viewport
+        assert_eq!(word_popover_position(100, 700, 720, 1200, 800), (100, 412));
+    }
+}
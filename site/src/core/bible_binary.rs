@@ -0,0 +1,116 @@
+use crate::core::bible_core::{Bible, Book};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Marks a binary Bible file; followed by a format version byte and the bincode payload
+const MAGIC: &[u8; 4] = b"BIBL";
+const FORMAT_VERSION: u8 = 1;
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    let payload =
+        bincode::serialize(value).map_err(|e| format!("Failed to encode Bible: {}", e))?;
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 1 + payload.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(FORMAT_VERSION);
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, String> {
+    let rest = bytes
+        .strip_prefix(MAGIC)
+        .ok_or("Not a binary Bible: missing BIBL header")?;
+
+    match rest.split_first() {
+        Some((&FORMAT_VERSION, payload)) => {
+            bincode::deserialize(payload).map_err(|e| format!("Invalid binary Bible data: {}", e))
+        }
+        Some((version, _)) => Err(format!("Unsupported binary Bible version {}", version)),
+        None => Err("Binary Bible data is empty".to_string()),
+    }
+}
+
+/// Whether `bytes` start like a binary Bible rather than JSON
+pub fn is_binary_bible(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Encode a Bible in the compact binary format, as published at `/bibles` by
+/// `bible-verify --encode` and kept in the translation cache
+pub fn encode_bible(bible: &Bible) -> Result<Vec<u8>, String> {
+    encode(bible)
+}
+
+/// Decode a Bible written by `encode_bible`
+pub fn decode_bible(bytes: &[u8]) -> Result<Bible, String> {
+    decode(bytes)
+}
+
+/// Encode a single book, so the translation cache can read books one at a time
+pub fn encode_book(book: &Book) -> Result<Vec<u8>, String> {
+    encode(book)
+}
+
+/// Decode a book written by `encode_book`
+pub fn decode_book(bytes: &[u8]) -> Result<Book, String> {
+    decode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bible_core::{Chapter, Verse};
+    use std::sync::Arc;
+
+    fn sample_bible() -> Bible {
+        Bible {
+            books: vec![Arc::new(Book {
+                name: "Genesis".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 1,
+                    name: "Genesis 1".to_string(),
+                    verses: vec![Verse {
+                        verse: 1,
+                        chapter: 1,
+                        name: "Genesis 1".to_string(),
+                        text: "In the beginning God created the heaven and the earth.".to_string(),
+                    }],
+                }],
+            })],
+        }
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let bible = sample_bible();
+        let bytes = encode_bible(&bible).unwrap();
+        let json = serde_json::to_vec(&bible).unwrap();
+
+        assert!(bytes.len() < json.len());
+        assert_eq!(decode_bible(&bytes).unwrap().books, bible.books);
+    }
+
+    #[test]
+    fn test_book_roundtrip() {
+        let book = sample_bible().books[0].clone();
+        let bytes = encode_book(&book).unwrap();
+
+        assert_eq!(decode_book(&bytes).unwrap(), *book);
+    }
+
+    #[test]
+    fn test_decode_rejects_json() {
+        let json = serde_json::to_vec(&sample_bible()).unwrap();
+        assert!(!is_binary_bible(&json));
+        assert!(decode_bible(&json).is_err());
+    }
+
+    #[test]
+    fn test_decode_unsupported_version() {
+        let mut bytes = encode_bible(&sample_bible()).unwrap();
+        bytes[MAGIC.len()] = FORMAT_VERSION + 1;
+        assert!(decode_bible(&bytes).is_err());
+        assert!(decode_bible(MAGIC).is_err());
+    }
+}
@@ -1,48 +1,58 @@
+use crate::core::genre::{Genre, Testament};
 use crate::core::types::Language;
+use crate::translation_map::abbreviations::{
+    book_key_for_name, book_names, normalize_abbreviation, resolve_book_abbreviation, BOOK_KEYS,
+};
 use crate::translation_map::translation::Translation;
 use leptos::prelude::*;
 use leptos_router::hooks::{use_location, use_params_map};
 use serde::{Deserialize, Serialize};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use urlencoding::{decode, encode};
 
-pub static BIBLE: OnceLock<Bible> = OnceLock::new();
+/// Verses moved by one paragraph motion (`{` / `}`)
+pub const PARAGRAPH_FALLBACK_VERSES: u32 = 5;
 static CURRENT_BIBLE_SIGNAL: OnceLock<RwSignal<Option<Bible>>> = OnceLock::new();
 
 pub fn init_bible_signal() -> RwSignal<Option<Bible>> {
     *CURRENT_BIBLE_SIGNAL.get_or_init(|| RwSignal::new(None))
 }
 
-pub fn get_bible() -> &'static Bible {
-    BIBLE
-        .get()
-        .expect("Bible not initialized - call init_bible() first")
+/// Reads a book that has not been loaded yet, given its position; set by whatever
+/// opened the current translation
+static BOOK_LOADER: OnceLock<fn(usize)> = OnceLock::new();
+
+pub fn set_book_loader(loader: fn(usize)) {
+    let _ = BOOK_LOADER.set(loader);
 }
 
-pub fn get_current_bible() -> Option<Bible> {
-    let bible_signal = init_bible_signal();
-    bible_signal.get()
+/// The current translation. Books are shared, so this is cheap to call and never
+/// copies verses; books that have not been loaded yet have empty chapters.
+pub fn get_bible() -> Bible {
+    init_bible_signal()
+        .get_untracked()
+        .expect("Bible not initialized - call init_bible() first")
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bible {
-    pub books: Vec<Book>,
+    pub books: Vec<Arc<Book>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Book {
     pub name: String,
     pub chapters: Vec<Chapter>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Chapter {
     pub chapter: u32,
     pub name: String,
     pub verses: Vec<Verse>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Verse {
     pub verse: u32,
     pub chapter: u32,
@@ -50,33 +60,132 @@ pub struct Verse {
     pub text: String,
 }
 
+/// Book names and chapter counts, cached ahead of the books themselves so the
+/// books can be stored and parsed one at a time
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BibleIndex {
+    pub books: Vec<BookSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BookSummary {
+    pub name: String,
+    pub chapter_count: u32,
+}
+
 impl Bible {
+    pub fn new(books: Vec<Book>) -> Self {
+        Bible {
+            books: books.into_iter().map(Arc::new).collect(),
+        }
+    }
+
+    /// A Bible with every book and chapter of `index` but no verses yet, to be
+    /// filled in a book at a time
+    pub fn from_index(index: &BibleIndex) -> Self {
+        Bible {
+            books: index
+                .books
+                .iter()
+                .map(|summary| {
+                    Arc::new(Book {
+                        name: summary.name.clone(),
+                        chapters: (1..=summary.chapter_count)
+                            .map(|chapter| Chapter {
+                                chapter,
+                                name: format!("{} {}", summary.name, chapter),
+                                verses: Vec::new(),
+                            })
+                            .collect(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Build the index describing this Bible's books
+    pub fn index(&self) -> BibleIndex {
+        BibleIndex {
+            books: self
+                .books
+                .iter()
+                .map(|book| BookSummary {
+                    name: book.name.clone(),
+                    chapter_count: book.chapters.len() as u32,
+                })
+                .collect(),
+        }
+    }
+
     /// Apply name translations to all books and chapters based on the specified language
     pub fn translate_names(mut self, language: Language) -> Self {
         let translation = Translation::from_language(language);
-
         for book in &mut self.books {
-            // Translate book names by trying the lowercase underscore version first
-            let lookup_key = book.name.to_lowercase().replace(' ', "_");
-            if let Some(translated_book_name) = translation.get_book(&lookup_key) {
-                book.name = translated_book_name.to_string();
-            }
+            Arc::make_mut(book).translate_names(translation);
+        }
+        self
+    }
+}
 
-            // Update chapter names to match the new book names
-            for chapter in &mut book.chapters {
-                // Extract the chapter number from the current name
-                let chapter_number = chapter.chapter;
-                // Update the chapter name to use the translated book name
-                chapter.name = format!("{} {}", book.name, chapter_number);
+impl Book {
+    /// Whether the verses of this book have been read; books start out as their
+    /// chapter list only
+    pub fn is_loaded(&self) -> bool {
+        self.chapters
+            .iter()
+            .any(|chapter| !chapter.verses.is_empty())
+    }
 
-                // Also update verse names to match
-                for verse in &mut chapter.verses {
-                    verse.name = chapter.name.clone();
-                }
+    /// Translate the book name and the chapter and verse names that repeat it
+    pub fn translate_names(&mut self, translation: &Translation) {
+        // Translate book names by trying the lowercase underscore version first
+        let lookup_key = self.name.to_lowercase().replace(' ', "_");
+        if let Some(translated_book_name) = translation.get_book(&lookup_key) {
+            self.name = translated_book_name.to_string();
+        }
+
+        // Update chapter names to match the new book names
+        for chapter in &mut self.chapters {
+            // Extract the chapter number from the current name
+            let chapter_number = chapter.chapter;
+            // Update the chapter name to use the translated book name
+            chapter.name = format!("{} {}", self.name, chapter_number);
+
+            // Also update verse names to match
+            for verse in &mut chapter.verses {
+                verse.name = chapter.name.clone();
             }
         }
+    }
+}
 
-        self
+/// A chapter by book key (such as "first_john") and number instead of by its
+/// translated name, so what is stored for it survives a change of translation
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChapterKey {
+    pub book: String,
+    pub chapter: u32,
+}
+
+impl ChapterKey {
+    /// Key for a book name in any supported language; books outside the 66
+    /// are keyed by their own name
+    pub fn for_book_name(book_name: &str, chapter: u32) -> Self {
+        ChapterKey {
+            book: book_key_for_name(book_name)
+                .map(str::to_string)
+                .unwrap_or_else(|| book_name.to_string()),
+            chapter,
+        }
+    }
+
+    /// Key for a chapter path such as "/Johannes/3", the way annotations were
+    /// stored before they had keys
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (book, chapter) = path.strip_prefix('/')?.rsplit_once('/')?;
+        let book = decode(book).ok()?;
+        let chapter = chapter.parse().ok().filter(|chapter| *chapter > 0)?;
+        (!book.is_empty()).then(|| Self::for_book_name(&book, chapter))
     }
 }
 
@@ -139,17 +248,20 @@ pub fn parse_verse_ranges_from_url() -> Vec<VerseRange> {
 }
 
 impl Chapter {
-    pub fn to_path(&self) -> String {
+    /// The book part of the chapter name, e.g. "1 John" for "1 John 3"
+    pub fn book_name(&self) -> String {
         let name_parts: Vec<&str> = self.name.split_whitespace().collect();
 
-        let book_name = if name_parts.len() > 1 {
+        if name_parts.len() > 1 {
             name_parts[..name_parts.len().saturating_sub(1)].join(" ")
         } else {
             self.name.clone()
-        };
+        }
+    }
 
-        let encoded_book = encode(&book_name);
-        format!("/{}/{}", encoded_book, self.chapter)
+    pub fn to_path(&self) -> String {
+        let book_name = self.book_name();
+        format!("/{}/{}", encode(&book_name), self.chapter)
     }
 
     pub fn to_path_with_verses(&self, verse_ranges: &[VerseRange]) -> String {
@@ -203,9 +315,116 @@ impl Chapter {
             None
         }
     }
+
+    /// Verse reached by `count` paragraph motions forward. The Bible data has
+    /// no section or paragraph markers, so a paragraph is a fixed block of verses.
+    pub fn get_next_paragraph_verse(&self, current_verse: u32, count: u32) -> Option<u32> {
+        let last_verse = self.verses.len() as u32;
+        if current_verse >= last_verse {
+            return None;
+        }
+        let target = current_verse.saturating_add(PARAGRAPH_FALLBACK_VERSES.saturating_mul(count));
+        Some(target.min(last_verse))
+    }
+
+    /// Verse reached by `count` paragraph motions backward, stopping at verse 1
+    pub fn get_previous_paragraph_verse(&self, current_verse: u32, count: u32) -> Option<u32> {
+        if current_verse <= 1 {
+            return None;
+        }
+        let target = current_verse.saturating_sub(PARAGRAPH_FALLBACK_VERSES.saturating_mul(count));
+        Some(target.max(1))
+    }
 }
 
 impl Bible {
+    /// Find a book by its name or an abbreviation such as "gen" or "1 Kor"
+    pub fn find_book(&self, name: &str) -> Option<&Book> {
+        let name_lower = name.to_lowercase();
+        if let Some(book) = self
+            .books
+            .iter()
+            .find(|b| b.name.to_lowercase() == name_lower)
+        {
+            return Some(&**book);
+        }
+
+        self.book_for_key(resolve_book_abbreviation(name)?)
+    }
+
+    /// The book for a book key such as "first_samuel", in whatever language this Bible uses
+    pub fn book_for_key(&self, key: &str) -> Option<&Book> {
+        let names: Vec<String> = book_names(key)
+            .into_iter()
+            .map(normalize_abbreviation)
+            .collect();
+        self.books
+            .iter()
+            .find(|b| names.contains(&normalize_abbreviation(&b.name)))
+            .or_else(|| {
+                // Book names that differ from ours ("Revelation of John") still
+                // line up by position in a complete Bible
+                if self.books.len() != BOOK_KEYS.len() {
+                    return None;
+                }
+                let index = BOOK_KEYS.iter().position(|k| *k == key)?;
+                self.books.get(index)
+            })
+            .map(|book| &**book)
+    }
+
+    /// Genre of the book at `book_index`, by its name or else its position in a complete Bible
+    pub fn book_genre(&self, book_index: usize) -> Option<Genre> {
+        let book = self.books.get(book_index)?;
+        Genre::for_book_name(&book.name).or_else(|| {
+            if self.books.len() != BOOK_KEYS.len() {
+                return None;
+            }
+            Genre::for_canonical_index(book_index)
+        })
+    }
+
+    /// Testament of the book at `book_index`, by its name or else its position in a complete Bible
+    pub fn book_testament(&self, book_index: usize) -> Option<Testament> {
+        let book = self.books.get(book_index)?;
+        Testament::for_book_name(&book.name).or_else(|| {
+            if self.books.len() != BOOK_KEYS.len() {
+                return None;
+            }
+            Testament::for_canonical_index(book_index)
+        })
+    }
+
+    /// The key annotations on `chapter` are stored under. Books whose name is not
+    /// recognized are keyed by their position in a complete Bible.
+    pub fn chapter_key(&self, chapter: &Chapter) -> ChapterKey {
+        let book_name = chapter.book_name();
+        let key = ChapterKey::for_book_name(&book_name, chapter.chapter);
+        if book_key_for_name(&book_name).is_some() || self.books.len() != BOOK_KEYS.len() {
+            return key;
+        }
+        self.books
+            .iter()
+            .position(|book| book.name == book_name)
+            .map(|index| ChapterKey {
+                book: BOOK_KEYS[index].to_string(),
+                chapter: chapter.chapter,
+            })
+            .unwrap_or(key)
+    }
+
+    /// Have a book of this Bible read if it hasn't been yet. Its verses arrive
+    /// through the Bible signal.
+    pub fn request_book(&self, book: &Book) {
+        if book.is_loaded() {
+            return;
+        }
+        let position = self.books.iter().position(|b| std::ptr::eq(&**b, book));
+        if let (Some(position), Some(load)) = (position, BOOK_LOADER.get()) {
+            load(position);
+        }
+    }
+
     pub fn get_chapter(
         &self,
         book: &str,
@@ -216,10 +435,10 @@ impl Bible {
             .into_owned();
 
         let book = self
-            .books
-            .iter()
-            .find(|b| b.name.to_lowercase() == book_name.to_lowercase())
+            .find_book(&book_name)
             .ok_or(ParamParseError::BookNotFound)?;
+        // Load the book on first access; the chapter has no verses until it is in
+        self.request_book(book);
 
         let chapter = book
             .chapters
@@ -310,6 +529,19 @@ impl Bible {
         None
     }
 
+    /// The chapter `percent` of the way through the current book, e.g. 50% of Isaiah is chapter 33
+    pub fn get_book_percent_chapter(&self, current: &Chapter, percent: u8) -> Option<Chapter> {
+        let book = self.books.iter().find(|book| {
+            book.chapters
+                .iter()
+                .any(|c| c.chapter == current.chapter && c.name == current.name)
+        })?;
+
+        let percent = usize::from(percent.min(100));
+        let index = (book.chapters.len() * percent).div_ceil(100).max(1) - 1;
+        book.chapters.get(index).cloned()
+    }
+
     /// Fast navigation method for multiple chapters ahead without cloning
     pub fn get_nth_next_chapter_path(&self, current: &Chapter, n: u32) -> Option<String> {
         let mut current_book_idx = None;
@@ -483,6 +715,31 @@ mod tests {
         assert_eq!(chapter.get_previous_verse(0), None); // Can't go before first verse
     }
 
+    #[test]
+    fn test_paragraph_navigation() {
+        let chapter = Chapter {
+            chapter: 1,
+            name: "Genesis 1".to_string(),
+            verses: (1..=12)
+                .map(|verse| Verse {
+                    verse,
+                    chapter: 1,
+                    name: "Genesis 1".to_string(),
+                    text: String::new(),
+                })
+                .collect(),
+        };
+
+        assert_eq!(chapter.get_next_paragraph_verse(0, 1), Some(5));
+        assert_eq!(chapter.get_next_paragraph_verse(3, 1), Some(8));
+        assert_eq!(chapter.get_next_paragraph_verse(3, 2), Some(12));
+        assert_eq!(chapter.get_next_paragraph_verse(12, 1), None);
+
+        assert_eq!(chapter.get_previous_paragraph_verse(8, 1), Some(3));
+        assert_eq!(chapter.get_previous_paragraph_verse(4, 1), Some(1));
+        assert_eq!(chapter.get_previous_paragraph_verse(1, 1), None);
+    }
+
     #[test]
     fn test_cross_chapter_navigation_logic() {
         // Test that we properly handle chapter boundaries for cross-chapter navigation
@@ -576,6 +833,34 @@ mod tests {
         assert_eq!(path, "/Genesis/1?verses=1-3,5,10-12");
     }
 
+    #[test]
+    fn test_chapter_key() {
+        let key = |book: &str, chapter: u32| ChapterKey {
+            book: book.to_string(),
+            chapter,
+        };
+        assert_eq!(ChapterKey::from_path("/John/3"), Some(key("john", 3)));
+        assert_eq!(ChapterKey::from_path("/Johannes/3"), Some(key("john", 3)));
+        assert_eq!(
+            ChapterKey::from_path("/1%20Johannes/4"),
+            Some(key("first_john", 4))
+        );
+        assert_eq!(ChapterKey::from_path("/Enoch/1"), Some(key("Enoch", 1)));
+        assert_eq!(ChapterKey::from_path("John 3"), None);
+        assert_eq!(ChapterKey::from_path("/John/0"), None);
+
+        let chapter = Chapter {
+            chapter: 3,
+            name: "Johannes 3".to_string(),
+            verses: vec![],
+        };
+        let bible = Bible::new(vec![Book {
+            name: "Johannes".to_string(),
+            chapters: vec![chapter.clone()],
+        }]);
+        assert_eq!(bible.chapter_key(&chapter), key("john", 3));
+    }
+
     proptest! {
         #[test]
         fn test_chapter_to_path_roundtrip(
@@ -630,9 +915,7 @@ mod tests {
                 chapters: vec![test_chapter.clone()],
             };
 
-            let bible = Bible {
-                books: vec![test_book],
-            };
+            let bible = Bible::new(vec![test_book]);
 
             let upper_result = bible.get_chapter(&book_name.to_uppercase(), chapter_num);
             let lower_result = bible.get_chapter(&book_name.to_lowercase(), chapter_num);
@@ -663,9 +946,7 @@ mod tests {
                 chapters: vec![test_chapter.clone()],
             };
 
-            let bible = Bible {
-                books: vec![test_book],
-            };
+            let bible = Bible::new(vec![test_book]);
 
             let encoded_book = urlencoding::encode(clean_book_name);
             let result = bible.get_chapter(&encoded_book, chapter_num);
@@ -694,9 +975,7 @@ mod tests {
                 chapters,
             };
 
-            let bible = Bible {
-                books: vec![book],
-            };
+            let bible = Bible::new(vec![book]);
 
             for i in 1..num_chapters - 1 {
                 let current_chapter = &bible.books[0].chapters[i];
@@ -728,9 +1007,7 @@ mod tests {
                 chapters,
             };
 
-            let bible = Bible {
-                books: vec![book],
-            };
+            let bible = Bible::new(vec![book]);
 
             let first_chapter = &bible.books[0].chapters[0];
             prop_assert!(bible.get_previous_chapter(first_chapter).is_none());
@@ -761,7 +1038,7 @@ mod tests {
                 })
                 .collect();
 
-            let bible = Bible { books };
+            let bible = Bible::new(books);
 
             let last_chapter_book1 = &bible.books[0].chapters[chapters_per_book - 1];
             let first_chapter_book2 = &bible.books[1].chapters[0];
@@ -778,38 +1055,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_book_percent_chapter() {
+        let chapters: Vec<Chapter> = (1..=66)
+            .map(|chapter| Chapter {
+                chapter,
+                name: format!("Isaiah {}", chapter),
+                verses: vec![],
+            })
+            .collect();
+        let bible = Bible::new(vec![Book {
+            name: "Isaiah".to_string(),
+            chapters,
+        }]);
+        let current = &bible.books[0].chapters[9];
+        let chapter_at = |percent| {
+            bible
+                .get_book_percent_chapter(current, percent)
+                .map(|c| c.chapter)
+        };
+
+        assert_eq!(chapter_at(0), Some(1));
+        assert_eq!(chapter_at(1), Some(1));
+        assert_eq!(chapter_at(50), Some(33));
+        assert_eq!(chapter_at(100), Some(66));
+        assert_eq!(chapter_at(250), Some(66));
+    }
+
     #[test]
     fn test_bible_translate_names() {
-        let bible = Bible {
-            books: vec![
-                Book {
-                    name: "Genesis".to_string(),
-                    chapters: vec![Chapter {
+        let bible = Bible::new(vec![
+            Book {
+                name: "Genesis".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 1,
+                    name: "Genesis 1".to_string(),
+                    verses: vec![Verse {
+                        verse: 1,
                         chapter: 1,
                         name: "Genesis 1".to_string(),
-                        verses: vec![Verse {
-                            verse: 1,
-                            chapter: 1,
-                            name: "Genesis 1".to_string(),
-                            text: "In the beginning...".to_string(),
-                        }],
+                        text: "In the beginning...".to_string(),
                     }],
-                },
-                Book {
-                    name: "Matthew".to_string(),
-                    chapters: vec![Chapter {
+                }],
+            },
+            Book {
+                name: "Matthew".to_string(),
+                chapters: vec![Chapter {
+                    chapter: 1,
+                    name: "Matthew 1".to_string(),
+                    verses: vec![Verse {
+                        verse: 1,
                         chapter: 1,
                         name: "Matthew 1".to_string(),
-                        verses: vec![Verse {
-                            verse: 1,
-                            chapter: 1,
-                            name: "Matthew 1".to_string(),
-                            text: "The book of the generation...".to_string(),
-                        }],
+                        text: "The book of the generation...".to_string(),
                     }],
-                },
-            ],
-        };
+                }],
+            },
+        ]);
 
         // Test Dutch translation
         let dutch_bible = bible.clone().translate_names(Language::Dutch);
@@ -831,4 +1133,27 @@ mod tests {
         assert_eq!(english_bible.books[0].name, "Genesis");
         assert_eq!(english_bible.books[1].name, "Matthew");
     }
+
+    #[test]
+    fn test_bible_index() {
+        let chapter = |number: u32| Chapter {
+            chapter: number,
+            name: format!("Ruth {}", number),
+            verses: vec![],
+        };
+        let bible = Bible::new(vec![Book {
+            name: "Ruth".to_string(),
+            chapters: vec![chapter(1), chapter(2), chapter(3), chapter(4)],
+        }]);
+
+        assert_eq!(
+            bible.index(),
+            BibleIndex {
+                books: vec![BookSummary {
+                    name: "Ruth".to_string(),
+                    chapter_count: 4,
+                }],
+            }
+        );
+    }
 }
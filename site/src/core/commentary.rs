@@ -0,0 +1,167 @@
+use crate::core::types::book_name_to_id;
+use serde::{Deserialize, Serialize};
+
+/// A commentary such as Matthew Henry's, as imported from a JSON file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentarySet {
+    pub name: String,
+    /// Short name shown next to each comment, e.g. "MHC"
+    #[serde(default)]
+    pub abbreviation: String,
+    pub entries: Vec<CommentaryEntry>,
+}
+
+/// A comment on one verse or a range of verses. Books use the English names
+/// of the cross references, such as "1 Samuel" and "Revelation".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentaryEntry {
+    pub book: String,
+    pub chapter: u32,
+    pub verse: u32,
+    /// Last verse the comment covers, when it covers more than one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_verse: Option<u32>,
+    pub text: String,
+}
+
+impl CommentaryEntry {
+    pub fn covers(&self, book: &str, chapter: u32, verse: u32) -> bool {
+        self.book == book
+            && self.chapter == chapter
+            && (self.verse..=self.end_verse.unwrap_or(self.verse)).contains(&verse)
+    }
+
+    /// "3:16" or "3:16-18"
+    pub fn verse_label(&self) -> String {
+        match self.end_verse {
+            Some(end_verse) if end_verse != self.verse => {
+                format!("{}:{}-{}", self.chapter, self.verse, end_verse)
+            }
+            _ => format!("{}:{}", self.chapter, self.verse),
+        }
+    }
+}
+
+/// Check an imported commentary, dropping empty comments. Every book must be
+/// one of the 66 English book names.
+pub fn parse_commentary(json: &str) -> Result<CommentarySet, String> {
+    let mut commentary: CommentarySet =
+        serde_json::from_str(json).map_err(|e| format!("Invalid commentary: {}", e))?;
+
+    commentary.name = commentary.name.trim().to_string();
+    if commentary.name.is_empty() {
+        return Err("The commentary has no name".to_string());
+    }
+    if commentary.abbreviation.trim().is_empty() {
+        commentary.abbreviation = commentary
+            .name
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .filter(|c| c.is_uppercase())
+            .collect();
+    }
+
+    let mut unknown_books: Vec<String> = commentary
+        .entries
+        .iter()
+        .filter(|entry| book_name_to_id(&entry.book).is_none())
+        .map(|entry| entry.book.clone())
+        .collect();
+    unknown_books.sort();
+    unknown_books.dedup();
+    if !unknown_books.is_empty() {
+        return Err(format!("Unknown books: {}", unknown_books.join(", ")));
+    }
+
+    commentary
+        .entries
+        .retain(|entry| !entry.text.trim().is_empty());
+    if commentary.entries.is_empty() {
+        return Err("The commentary has no entries".to_string());
+    }
+    Ok(commentary)
+}
+
+/// The comments on a verse, including comments on a range of verses around it
+pub fn commentary_for_verse<'a>(
+    entries: &'a [CommentaryEntry],
+    book: &str,
+    chapter: u32,
+    verse: u32,
+) -> Vec<&'a CommentaryEntry> {
+    entries
+        .iter()
+        .filter(|entry| entry.covers(book, chapter, verse))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commentary() {
+        let json = r#"{
+            "name": "Matthew Henry Concise Commentary",
+            "entries": [
+                {"book": "John", "chapter": 3, "verse": 16, "end_verse": 18, "text": "Here is God's love in giving his Son for the world."},
+                {"book": "John", "chapter": 3, "verse": 19, "text": "  "},
+                {"book": "1 Samuel", "chapter": 1, "verse": 1, "text": "Elkanah and his family."}
+            ]
+        }"#;
+
+        let commentary = parse_commentary(json).unwrap();
+        assert_eq!(commentary.abbreviation, "MHCC");
+        assert_eq!(commentary.entries.len(), 2);
+        assert_eq!(commentary.entries[0].verse_label(), "3:16-18");
+        assert_eq!(commentary.entries[1].verse_label(), "1:1");
+    }
+
+    #[test]
+    fn test_parse_commentary_errors() {
+        assert!(parse_commentary("not json").is_err());
+        assert_eq!(
+            parse_commentary(r#"{"name": " ", "entries": []}"#),
+            Err("The commentary has no name".to_string())
+        );
+        assert_eq!(
+            parse_commentary(
+                r#"{"name": "Notes", "entries": [{"book": "I Samuel", "chapter": 1, "verse": 1, "text": "Elkanah"}]}"#
+            ),
+            Err("Unknown books: I Samuel".to_string())
+        );
+        assert_eq!(
+            parse_commentary(
+                r#"{"name": "Notes", "entries": [
+                    {"book": "Jn", "chapter": 1, "verse": 1, "text": "The Word"},
+                    {"book": "Gen", "chapter": 1, "verse": 1, "text": "The beginning"},
+                    {"book": "Jn", "chapter": 3, "verse": 16, "text": "God's love"}
+                ]}"#
+            ),
+            Err("Unknown books: Gen, Jn".to_string())
+        );
+    }
+
+    #[test]
+    fn test_commentary_for_verse() {
+        let entry = |chapter: u32, verse: u32, end_verse: Option<u32>| CommentaryEntry {
+            book: "John".to_string(),
+            chapter,
+            verse,
+            end_verse,
+            text: format!("On {}:{}", chapter, verse),
+        };
+        let entries = vec![entry(3, 1, Some(8)), entry(3, 5, None), entry(4, 5, None)];
+
+        let texts = |verse: u32| -> Vec<String> {
+            commentary_for_verse(&entries, "John", 3, verse)
+                .into_iter()
+                .map(|entry| entry.text.clone())
+                .collect()
+        };
+        assert_eq!(texts(5), vec!["On 3:1", "On 3:5"]);
+        assert_eq!(texts(8), vec!["On 3:1"]);
+        assert!(texts(9).is_empty());
+        assert!(commentary_for_verse(&entries, "Mark", 3, 5).is_empty());
+    }
+}
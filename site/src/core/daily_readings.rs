@@ -0,0 +1,87 @@
+use crate::core::Bible;
+
+const PSALM_COUNT: u32 = 150;
+const PROVERB_COUNT: u32 = 31;
+
+/// The Psalm and Proverb scheduled for a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyReadings {
+    /// Psalms are read one a day, starting over after Psalm 150
+    pub psalm: u32,
+    /// The Proverb matching the day of the month
+    pub proverb: u32,
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Day of the year, 1 for January 1st. `month` runs from 1 to 12.
+pub fn day_of_year(year: i32, month: u32, day: u32) -> u32 {
+    const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let leap_day = u32::from(month > 2 && is_leap_year(year));
+    DAYS_BEFORE_MONTH[(month.clamp(1, 12) - 1) as usize] + leap_day + day
+}
+
+/// The readings for a date. `month` runs from 1 to 12.
+pub fn daily_readings(year: i32, month: u32, day: u32) -> DailyReadings {
+    DailyReadings {
+        psalm: (day_of_year(year, month, day) - 1) % PSALM_COUNT + 1,
+        proverb: day.clamp(1, PROVERB_COUNT),
+    }
+}
+
+/// The readings for today in the user's time zone
+pub fn todays_readings() -> DailyReadings {
+    let now = js_sys::Date::new_0();
+    daily_readings(
+        now.get_full_year() as i32,
+        now.get_month() + 1,
+        now.get_date(),
+    )
+}
+
+/// Name and path of a chapter of the book with `book_key` in `bible`
+pub fn daily_reading_link(bible: &Bible, book_key: &str, chapter: u32) -> Option<(String, String)> {
+    let chapter = bible
+        .book_for_key(book_key)?
+        .chapters
+        .iter()
+        .find(|c| c.chapter == chapter)?;
+    Some((chapter.name.clone(), chapter.to_path()))
+}
+
+/// Links to the Psalm and Proverb of `readings` in `bible`, as (name, path)
+pub fn daily_reading_links(bible: &Bible, readings: DailyReadings) -> Vec<(String, String)> {
+    [("psalms", readings.psalm), ("proverbs", readings.proverb)]
+        .into_iter()
+        .filter_map(|(book_key, chapter)| daily_reading_link(bible, book_key, chapter))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_of_year() {
+        assert_eq!(day_of_year(2025, 1, 1), 1);
+        assert_eq!(day_of_year(2025, 3, 1), 60);
+        assert_eq!(day_of_year(2024, 3, 1), 61);
+        assert_eq!(day_of_year(2024, 12, 31), 366);
+    }
+
+    #[test]
+    fn test_daily_readings() {
+        assert_eq!(
+            daily_readings(2025, 3, 17),
+            DailyReadings {
+                psalm: 76,
+                proverb: 17
+            }
+        );
+        // The Psalms start over after the 150th day
+        assert_eq!(daily_readings(2025, 5, 31).psalm, 1);
+        assert_eq!(daily_readings(2025, 12, 31).psalm, 65);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::core::bible_core::{Bible, Book, Chapter, Verse};
+use crate::core::usfm::usfm_book_name;
+
+/// Delimiters recognised in spreadsheet exports, in order of preference
+const DELIMITERS: [char; 4] = ['|', '\t', ';', ','];
+
+/// Which column holds which part of a verse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnMapping {
+    pub book: usize,
+    pub chapter: usize,
+    pub verse: usize,
+    pub text: usize,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            book: 0,
+            chapter: 1,
+            verse: 2,
+            text: 3,
+        }
+    }
+}
+
+/// Options chosen in the column-mapping step of the import
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelimitedOptions {
+    pub delimiter: char,
+    pub has_header: bool,
+    pub mapping: ColumnMapping,
+}
+
+impl DelimitedOptions {
+    /// Guess the delimiter, header row and column mapping from the file contents
+    pub fn detect(text: &str) -> Self {
+        let first_line = text.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+        let delimiter = DELIMITERS
+            .iter()
+            .copied()
+            .max_by_key(|d| {
+                // Prefer earlier delimiters on ties by weighting their position
+                let count = first_line.matches(*d).count();
+                let preference = DELIMITERS.len() - DELIMITERS.iter().position(|x| x == d).unwrap_or(0);
+                (count, preference)
+            })
+            .unwrap_or('|');
+
+        let headers = split_row(first_line, delimiter);
+        let mapping = guess_mapping(&headers);
+
+        Self {
+            delimiter,
+            has_header: mapping.is_some(),
+            mapping: mapping.unwrap_or_default(),
+        }
+    }
+}
+
+fn guess_mapping(headers: &[String]) -> Option<ColumnMapping> {
+    let find = |names: &[&str]| {
+        headers
+            .iter()
+            .position(|h| names.contains(&h.trim().to_lowercase().as_str()))
+    };
+
+    Some(ColumnMapping {
+        book: find(&["book", "boek", "bookname", "book_name"])?,
+        chapter: find(&["chapter", "hoofdstuk", "chap"])?,
+        verse: find(&["verse", "vers", "verse_number"])?,
+        text: find(&["text", "tekst", "content"])?,
+    })
+}
+
+/// Split one line into fields. Fields may be wrapped in double quotes, in
+/// which case the delimiter may appear inside and `""` is an escaped quote.
+pub fn split_row(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            c if c == delimiter && !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+            }
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Parse a delimited file into a `Bible`.
+///
+/// Books appear in the order they are first seen. Rows that can't be read
+/// are skipped and reported as warnings, with 1-based line numbers.
+pub fn parse_delimited(
+    text: &str,
+    options: &DelimitedOptions,
+) -> Result<(Bible, Vec<String>), String> {
+    let mapping = options.mapping;
+    let column_count = [mapping.book, mapping.chapter, mapping.verse, mapping.text]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    let mut books: Vec<Book> = Vec::new();
+    let mut warnings = Vec::new();
+
+    let rows = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .skip(usize::from(options.has_header));
+
+    for (index, line) in rows {
+        let line_number = index + 1;
+        let mut fields = split_row(line, options.delimiter);
+
+        if fields.len() < column_count {
+            warnings.push(format!(
+                "Line {}: expected {} columns, found {}",
+                line_number,
+                column_count,
+                fields.len()
+            ));
+            continue;
+        }
+
+        // Unquoted delimiters in the last column belong to the verse text
+        if mapping.text == column_count - 1 && fields.len() > column_count {
+            let rest = fields.split_off(mapping.text).join(&options.delimiter.to_string());
+            fields.push(rest);
+        }
+
+        let book_field = fields[mapping.book].trim();
+        let book_name = usfm_book_name(book_field).unwrap_or(book_field).to_string();
+        let chapter_number = fields[mapping.chapter].trim().parse::<u32>();
+        let verse_number = fields[mapping.verse].trim().parse::<u32>();
+
+        let (Ok(chapter_number), Ok(verse_number)) = (chapter_number, verse_number) else {
+            warnings.push(format!(
+                "Line {}: chapter and verse must be numbers",
+                line_number
+            ));
+            continue;
+        };
+        if book_name.is_empty() {
+            warnings.push(format!("Line {}: missing book name", line_number));
+            continue;
+        }
+
+        let book = match books.iter().position(|b| b.name == book_name) {
+            Some(position) => &mut books[position],
+            None => {
+                books.push(Book {
+                    name: book_name.clone(),
+                    chapters: Vec::new(),
+                });
+                books.last_mut().expect("book was just added")
+            }
+        };
+
+        let chapter_name = format!("{} {}", book_name, chapter_number);
+        let chapter = match book
+            .chapters
+            .iter()
+            .position(|c| c.chapter == chapter_number)
+        {
+            Some(position) => &mut book.chapters[position],
+            None => {
+                book.chapters.push(Chapter {
+                    chapter: chapter_number,
+                    name: chapter_name.clone(),
+                    verses: Vec::new(),
+                });
+                book.chapters.last_mut().expect("chapter was just added")
+            }
+        };
+
+        chapter.verses.push(Verse {
+            verse: verse_number,
+            chapter: chapter_number,
+            name: chapter_name,
+            text: fields[mapping.text].trim().to_string(),
+        });
+    }
+
+    if books.is_empty() {
+        return Err("No verses found".to_string());
+    }
+
+    for book in &mut books {
+        book.chapters.sort_by_key(|c| c.chapter);
+    }
+
+    Ok((Bible::new(books), warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_pipe_without_header() {
+        let options = DelimitedOptions::detect("GEN|1|1|In the beginning\n");
+        assert_eq!(options.delimiter, '|');
+        assert!(!options.has_header);
+        assert_eq!(options.mapping, ColumnMapping::default());
+    }
+
+    #[test]
+    fn test_detect_header_mapping() {
+        let options = DelimitedOptions::detect("Tekst\tBoek\tHoofdstuk\tVers\n");
+        assert_eq!(options.delimiter, '\t');
+        assert!(options.has_header);
+        assert_eq!(
+            options.mapping,
+            ColumnMapping {
+                book: 1,
+                chapter: 2,
+                verse: 3,
+                text: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_row_quotes() {
+        assert_eq!(
+            split_row(r#"Genesis,1,1,"In the beginning, God ""created""""#, ','),
+            vec!["Genesis", "1", "1", r#"In the beginning, God "created""#]
+        );
+    }
+
+    #[test]
+    fn test_parse_delimited() {
+        let text = "book|chapter|verse|text\n\
+                    GEN|1|1|In the beginning\n\
+                    GEN|1|2|And the earth | was void\n\
+                    GEN|x|3|Broken row\n\
+                    Exodus|1|1|Now these\n";
+        let options = DelimitedOptions::detect(text);
+        let (bible, warnings) = parse_delimited(text, &options).unwrap();
+
+        assert_eq!(bible.books.len(), 2);
+        assert_eq!(bible.books[0].name, "Genesis");
+        assert_eq!(bible.books[0].chapters[0].name, "Genesis 1");
+        assert_eq!(
+            bible.books[0].chapters[0].verses[1].text,
+            "And the earth | was void"
+        );
+        assert_eq!(warnings, vec!["Line 4: chapter and verse must be numbers"]);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::core::types::Language;
+use crate::translation_map::abbreviations::{book_key_for_name, BOOK_KEYS};
+use serde::{Deserialize, Serialize};
+
+/// Number of Old Testament books in the canonical order
+const OLD_TESTAMENT_BOOKS: usize = 39;
+
+/// Position of a book in the canonical order, by name in any supported language
+fn canonical_index(name: &str) -> Option<usize> {
+    let key = book_key_for_name(name)?;
+    BOOK_KEYS.iter().position(|k| *k == key)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Testament {
+    Old,
+    New,
+}
+
+impl Testament {
+    pub const ALL: [Testament; 2] = [Testament::Old, Testament::New];
+
+    /// Testament of the book at `index` in the 66-book canonical order
+    pub fn for_canonical_index(index: usize) -> Option<Testament> {
+        match index {
+            0..OLD_TESTAMENT_BOOKS => Some(Testament::Old),
+            OLD_TESTAMENT_BOOKS..66 => Some(Testament::New),
+            _ => None,
+        }
+    }
+
+    /// Testament of a book by name in any supported language
+    pub fn for_book_name(name: &str) -> Option<Testament> {
+        canonical_index(name).and_then(Testament::for_canonical_index)
+    }
+
+    pub fn name(&self, language: Language) -> &'static str {
+        match (self, language) {
+            (Testament::Old, Language::English) => "Old Testament",
+            (Testament::Old, Language::Dutch) => "Oude Testament",
+            (Testament::New, Language::English) => "New Testament",
+            (Testament::New, Language::Dutch) => "Nieuwe Testament",
+        }
+    }
+}
+
+/// Literary genre of a book, used to browse related books together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Genre {
+    Law,
+    History,
+    Wisdom,
+    Prophets,
+    Gospels,
+    Epistles,
+    Apocalyptic,
+}
+
+impl Genre {
+    pub const ALL: [Genre; 7] = [
+        Genre::Law,
+        Genre::History,
+        Genre::Wisdom,
+        Genre::Prophets,
+        Genre::Gospels,
+        Genre::Epistles,
+        Genre::Apocalyptic,
+    ];
+
+    /// Genre of the book at `index` in the 66-book canonical order
+    pub fn for_canonical_index(index: usize) -> Option<Genre> {
+        let genre = match index {
+            0..=4 => Genre::Law,
+            5..=16 => Genre::History,
+            17..=21 => Genre::Wisdom,
+            22..=38 => Genre::Prophets,
+            39..=42 => Genre::Gospels,
+            43 => Genre::History,
+            44..=64 => Genre::Epistles,
+            65 => Genre::Apocalyptic,
+            _ => return None,
+        };
+        Some(genre)
+    }
+
+    /// Genre of a book by name in any supported language, e.g. "Psalms" or "Psalmen"
+    pub fn for_book_name(name: &str) -> Option<Genre> {
+        canonical_index(name).and_then(Genre::for_canonical_index)
+    }
+
+    pub fn name(&self, language: Language) -> &'static str {
+        match (self, language) {
+            (Genre::Law, Language::English) => "Law",
+            (Genre::Law, Language::Dutch) => "Wet",
+            (Genre::History, Language::English) => "History",
+            (Genre::History, Language::Dutch) => "Geschiedenis",
+            (Genre::Wisdom, Language::English) => "Wisdom",
+            (Genre::Wisdom, Language::Dutch) => "Wijsheid",
+            (Genre::Prophets, Language::English) => "Prophets",
+            (Genre::Prophets, Language::Dutch) => "Profeten",
+            (Genre::Gospels, Language::English) => "Gospels",
+            (Genre::Gospels, Language::Dutch) => "Evangeliën",
+            (Genre::Epistles, Language::English) => "Epistles",
+            (Genre::Epistles, Language::Dutch) => "Brieven",
+            (Genre::Apocalyptic, Language::English) => "Apocalyptic",
+            (Genre::Apocalyptic, Language::Dutch) => "Apocalyptiek",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genre_for_canonical_index() {
+        assert_eq!(Genre::for_canonical_index(0), Some(Genre::Law));
+        assert_eq!(Genre::for_canonical_index(18), Some(Genre::Wisdom));
+        assert_eq!(Genre::for_canonical_index(43), Some(Genre::History));
+        assert_eq!(Genre::for_canonical_index(65), Some(Genre::Apocalyptic));
+        assert_eq!(Genre::for_canonical_index(66), None);
+    }
+
+    #[test]
+    fn test_genre_for_book_name() {
+        assert_eq!(Genre::for_book_name("Psalms"), Some(Genre::Wisdom));
+        assert_eq!(Genre::for_book_name("Psalmen"), Some(Genre::Wisdom));
+        assert_eq!(Genre::for_book_name("Handelingen"), Some(Genre::History));
+        assert_eq!(Genre::for_book_name("1 Corinthians"), Some(Genre::Epistles));
+        assert_eq!(Genre::for_book_name("Tobit"), None);
+    }
+
+    #[test]
+    fn test_testament() {
+        assert_eq!(Testament::for_canonical_index(38), Some(Testament::Old));
+        assert_eq!(Testament::for_canonical_index(39), Some(Testament::New));
+        assert_eq!(Testament::for_canonical_index(66), None);
+        assert_eq!(Testament::for_book_name("Maleachi"), Some(Testament::Old));
+        assert_eq!(Testament::for_book_name("Matthew"), Some(Testament::New));
+    }
+}
@@ -1,7 +1,39 @@
+pub mod bible_binary;
 pub mod bible_core;
+pub mod commentary;
 pub mod cross_references;
+pub mod daily_readings;
+pub mod delimited;
+pub mod genre;
+pub mod morphology;
+pub mod people;
+pub mod places;
+pub mod read_aloud;
+pub mod reference_detection;
+pub mod section_headings;
+pub mod source_text;
+pub mod timeline;
 pub mod types;
+pub mod usfm;
+pub mod validation;
+pub mod versification;
 
+pub use bible_binary::*;
 pub use bible_core::*;
+pub use commentary::*;
 pub use cross_references::*;
+pub use daily_readings::*;
+pub use delimited::*;
+pub use genre::*;
+pub use morphology::*;
+pub use people::*;
+pub use places::*;
+pub use read_aloud::*;
+pub use reference_detection::*;
+pub use section_headings::*;
+pub use source_text::*;
+pub use timeline::*;
+pub use usfm::*;
+pub use validation::*;
+pub use versification::*;
 // pub use types::{ParamParseError};
@@ -0,0 +1,495 @@
+/// A decoded grammatical feature: the i18n keys of its label and its value,
+/// e.g. `("tense", "morph_aorist")`
+pub type MorphologyFeature = (&'static str, &'static str);
+
+// Indeclinable Greek words are coded by a single word in Robinson's scheme
+const ROBINSON_INDECLINABLE: [(&str, &str); 8] = [
+    ("ADV", "morph_adverb"),
+    ("CONJ", "morph_conjunction"),
+    ("COND", "morph_conditional_particle"),
+    ("PRT", "morph_particle"),
+    ("PREP", "morph_preposition"),
+    ("INJ", "morph_interjection"),
+    ("ARAM", "morph_aramaic_word"),
+    ("HEB", "morph_hebrew_word"),
+];
+
+fn greek_part_of_speech(code: char) -> Option<&'static str> {
+    Some(match code {
+        'N' => "morph_noun",
+        'A' => "morph_adjective",
+        'T' => "morph_article",
+        'V' => "morph_verb",
+        'P' => "morph_personal_pronoun",
+        'R' => "morph_relative_pronoun",
+        'C' => "morph_reciprocal_pronoun",
+        'D' => "morph_demonstrative_pronoun",
+        'K' => "morph_correlative_pronoun",
+        'I' => "morph_interrogative_pronoun",
+        'X' => "morph_indefinite_pronoun",
+        'Q' => "morph_correlative_or_interrogative_pronoun",
+        'F' => "morph_reflexive_pronoun",
+        'S' => "morph_possessive_pronoun",
+        _ => return None,
+    })
+}
+
+fn greek_tense(code: char) -> Option<&'static str> {
+    Some(match code {
+        'P' => "morph_present",
+        'I' => "morph_imperfect",
+        'F' => "morph_future",
+        'A' => "morph_aorist",
+        'R' => "morph_perfect",
+        'L' => "morph_pluperfect",
+        _ => return None,
+    })
+}
+
+fn greek_voice(code: char) -> Option<&'static str> {
+    Some(match code {
+        'A' => "morph_active",
+        'M' => "morph_middle",
+        'P' => "morph_passive",
+        'E' => "morph_middle_or_passive",
+        'D' => "morph_middle_deponent",
+        'O' => "morph_passive_deponent",
+        'N' => "morph_middle_or_passive_deponent",
+        'Q' => "morph_impersonal_active",
+        _ => return None,
+    })
+}
+
+fn greek_mood(code: char) -> Option<&'static str> {
+    Some(match code {
+        'I' => "morph_indicative",
+        'S' => "morph_subjunctive",
+        'O' => "morph_optative",
+        'M' => "morph_imperative",
+        'N' => "morph_infinitive",
+        'P' => "morph_participle",
+        'R' => "morph_imperative_participle",
+        _ => return None,
+    })
+}
+
+fn greek_case(code: char) -> Option<&'static str> {
+    Some(match code {
+        'N' => "morph_nominative",
+        'G' => "morph_genitive",
+        'D' => "morph_dative",
+        'A' => "morph_accusative",
+        'V' => "morph_vocative",
+        _ => return None,
+    })
+}
+
+fn person(code: char) -> Option<&'static str> {
+    Some(match code {
+        '1' => "morph_first",
+        '2' => "morph_second",
+        '3' => "morph_third",
+        _ => return None,
+    })
+}
+
+fn number(code: char) -> Option<&'static str> {
+    Some(match code.to_ascii_lowercase() {
+        's' => "morph_singular",
+        'p' => "morph_plural",
+        'd' => "morph_dual",
+        _ => return None,
+    })
+}
+
+fn gender(code: char) -> Option<&'static str> {
+    Some(match code.to_ascii_lowercase() {
+        'm' => "morph_masculine",
+        'f' => "morph_feminine",
+        'n' => "morph_neuter",
+        'c' => "morph_common",
+        'b' => "morph_masculine_and_feminine",
+        _ => return None,
+    })
+}
+
+type Decoder = fn(char) -> Option<&'static str>;
+
+// Decodes `codes` one character at a time, stopping at the first one that doesn't fit
+fn decode(
+    features: &mut Vec<MorphologyFeature>,
+    codes: &str,
+    decoders: &[(&'static str, Decoder)],
+) {
+    for (code, (label, decoder)) in codes.chars().zip(decoders) {
+        match decoder(code) {
+            Some(value) => features.push((label, value)),
+            None => return,
+        }
+    }
+}
+
+/// Robinson codes as used by Greek New Testaments, e.g. `V-AAI-3S` or `N-GSF`
+fn describe_robinson(code: &str) -> Vec<MorphologyFeature> {
+    if let Some((_, part_of_speech)) = ROBINSON_INDECLINABLE
+        .iter()
+        .find(|(indeclinable, _)| code.starts_with(indeclinable))
+    {
+        return vec![("part_of_speech", *part_of_speech)];
+    }
+
+    let mut parts = code.split('-');
+    let Some(part_of_speech) = parts
+        .next()
+        .and_then(|part| part.chars().next())
+        .and_then(greek_part_of_speech)
+    else {
+        return Vec::new();
+    };
+    let mut features = vec![("part_of_speech", part_of_speech)];
+
+    if part_of_speech == "morph_verb" {
+        // A leading digit marks e.g. a second aorist
+        let Some(tense_voice_mood) = parts.next().map(|part| part.trim_start_matches('2')) else {
+            return features;
+        };
+        decode(
+            &mut features,
+            tense_voice_mood,
+            &[
+                ("tense", greek_tense),
+                ("voice", greek_voice),
+                ("mood", greek_mood),
+            ],
+        );
+        let is_participle = tense_voice_mood
+            .chars()
+            .nth(2)
+            .is_some_and(|mood| mood == 'P' || mood == 'R');
+        if let Some(rest) = parts.next() {
+            if is_participle {
+                decode(
+                    &mut features,
+                    rest,
+                    &[("case", greek_case), ("number", number), ("gender", gender)],
+                );
+            } else {
+                decode(
+                    &mut features,
+                    rest,
+                    &[("person", person), ("number", number)],
+                );
+            }
+        }
+        return features;
+    }
+
+    if let Some(rest) = parts.next() {
+        // Personal pronouns of the first and second person start with their person
+        let rest = match rest.chars().next().and_then(person) {
+            Some(value) => {
+                features.push(("person", value));
+                &rest[1..]
+            }
+            None => rest,
+        };
+        decode(
+            &mut features,
+            rest,
+            &[("case", greek_case), ("number", number), ("gender", gender)],
+        );
+    }
+    features
+}
+
+fn hebrew_part_of_speech(code: char) -> Option<&'static str> {
+    Some(match code {
+        'A' => "morph_adjective",
+        'C' => "morph_conjunction",
+        'D' => "morph_adverb",
+        'N' => "morph_noun",
+        'P' => "morph_pronoun",
+        'R' => "morph_preposition",
+        'S' => "morph_suffix",
+        'T' => "morph_particle",
+        'V' => "morph_verb",
+        _ => return None,
+    })
+}
+
+fn hebrew_stem(code: char) -> Option<&'static str> {
+    Some(match code {
+        'q' => "morph_qal",
+        'N' => "morph_niphal",
+        'p' => "morph_piel",
+        'P' => "morph_pual",
+        'h' => "morph_hiphil",
+        'H' => "morph_hophal",
+        't' => "morph_hithpael",
+        'o' => "morph_polel",
+        'O' => "morph_polal",
+        'r' => "morph_hithpolel",
+        'm' => "morph_poel",
+        'M' => "morph_poal",
+        'k' => "morph_palel",
+        'K' => "morph_pulal",
+        'Q' => "morph_qal_passive",
+        'l' => "morph_pilpel",
+        'L' => "morph_polpal",
+        'f' => "morph_hithpalpel",
+        'D' => "morph_nithpael",
+        'j' => "morph_pealal",
+        'i' => "morph_pilel",
+        'u' => "morph_hothpaal",
+        'c' => "morph_tiphil",
+        'v' => "morph_hishtaphel",
+        'w' => "morph_nithpalel",
+        'y' => "morph_nithpoel",
+        'z' => "morph_hithpoel",
+        _ => return None,
+    })
+}
+
+fn aramaic_stem(code: char) -> Option<&'static str> {
+    Some(match code {
+        'q' => "morph_peal",
+        'Q' => "morph_peil",
+        'u' => "morph_hithpeel",
+        'p' => "morph_pael",
+        'P' => "morph_ithpaal",
+        'M' => "morph_hithpaal",
+        'a' => "morph_aphel",
+        'h' => "morph_haphel",
+        's' => "morph_saphel",
+        'e' => "morph_shaphel",
+        'H' => "morph_hophal",
+        'i' => "morph_ithpeel",
+        't' => "morph_hishtaphel",
+        'v' => "morph_ishtaphel",
+        'w' => "morph_hithaphel",
+        'o' => "morph_polel",
+        'z' => "morph_ithpoel",
+        'r' => "morph_hithpolel",
+        'f' => "morph_hithpalpel",
+        'b' => "morph_hephal",
+        'c' => "morph_tiphel",
+        'm' => "morph_poel",
+        'l' => "morph_palpel",
+        'L' => "morph_ithpalpel",
+        'O' => "morph_ithpolel",
+        'G' => "morph_ittaphal",
+        _ => return None,
+    })
+}
+
+fn hebrew_verb_form(code: char) -> Option<&'static str> {
+    Some(match code {
+        'p' => "morph_perfect",
+        'q' => "morph_sequential_perfect",
+        'i' => "morph_imperfect",
+        'w' => "morph_sequential_imperfect",
+        'h' => "morph_cohortative",
+        'j' => "morph_jussive",
+        'v' => "morph_imperative",
+        'r' => "morph_active_participle",
+        's' => "morph_passive_participle",
+        'a' => "morph_infinitive_absolute",
+        'c' => "morph_infinitive_construct",
+        _ => return None,
+    })
+}
+
+fn hebrew_state(code: char) -> Option<&'static str> {
+    Some(match code {
+        'a' => "morph_absolute",
+        'c' => "morph_construct",
+        'd' => "morph_determined",
+        _ => return None,
+    })
+}
+
+/// OSHB codes as used by the Hebrew Old Testament, e.g. `HVqp3ms` or
+/// `HC/Ncmpa`, where `/` separates prefixes and suffixes from the main word
+fn describe_oshb(code: &str) -> Vec<MorphologyFeature> {
+    let is_aramaic = code.starts_with('A');
+    let morphemes: Vec<&str> = code[1..].split('/').collect();
+    // The main word is the first verb, noun, adjective or pronoun
+    let Some(morpheme) = morphemes
+        .iter()
+        .find(|morpheme| matches!(morpheme.chars().next(), Some('V' | 'N' | 'A' | 'P')))
+        .or_else(|| morphemes.last())
+    else {
+        return Vec::new();
+    };
+
+    let mut chars = morpheme.chars();
+    let Some(part_of_speech) = chars.next().and_then(hebrew_part_of_speech) else {
+        return Vec::new();
+    };
+    let mut features = vec![("part_of_speech", part_of_speech)];
+    let rest: String = chars.collect();
+
+    match part_of_speech {
+        "morph_verb" => {
+            let stem = if is_aramaic {
+                aramaic_stem
+            } else {
+                hebrew_stem
+            };
+            decode(
+                &mut features,
+                &rest,
+                &[("stem", stem), ("tense", hebrew_verb_form)],
+            );
+            let details = rest.get(2..).unwrap_or_default();
+            if rest
+                .chars()
+                .nth(1)
+                .is_some_and(|form| form == 'r' || form == 's')
+            {
+                decode(
+                    &mut features,
+                    details,
+                    &[
+                        ("gender", gender),
+                        ("number", number),
+                        ("state", hebrew_state),
+                    ],
+                );
+            } else {
+                decode(
+                    &mut features,
+                    details,
+                    &[("person", person), ("gender", gender), ("number", number)],
+                );
+            }
+        }
+        // The first letter is the kind of noun or adjective, e.g. common or proper
+        "morph_noun" | "morph_adjective" => decode(
+            &mut features,
+            rest.get(1..).unwrap_or_default(),
+            &[
+                ("gender", gender),
+                ("number", number),
+                ("state", hebrew_state),
+            ],
+        ),
+        "morph_pronoun" | "morph_suffix" => decode(
+            &mut features,
+            rest.get(1..).unwrap_or_default(),
+            &[("person", person), ("gender", gender), ("number", number)],
+        ),
+        _ => {}
+    }
+    features
+}
+
+/// The grammatical features in a Greek (Robinson) or Hebrew (OSHB) morphology
+/// code, starting with the part of speech; empty when the code can't be read
+pub fn describe_morphology(code: &str) -> Vec<MorphologyFeature> {
+    let code = code.trim();
+    let is_oshb = matches!(code.chars().next(), Some('H' | 'A'))
+        && !code.contains('-')
+        && code.chars().nth(1).is_some_and(|c| c.is_ascii_uppercase())
+        && !ROBINSON_INDECLINABLE
+            .iter()
+            .any(|(indeclinable, _)| code == *indeclinable);
+    if is_oshb {
+        describe_oshb(code)
+    } else {
+        describe_robinson(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_greek_verb() {
+        assert_eq!(
+            describe_morphology("V-AAI-3S"),
+            vec![
+                ("part_of_speech", "morph_verb"),
+                ("tense", "morph_aorist"),
+                ("voice", "morph_active"),
+                ("mood", "morph_indicative"),
+                ("person", "morph_third"),
+                ("number", "morph_singular"),
+            ]
+        );
+        assert_eq!(
+            describe_morphology("V-2AAP-NPM"),
+            vec![
+                ("part_of_speech", "morph_verb"),
+                ("tense", "morph_aorist"),
+                ("voice", "morph_active"),
+                ("mood", "morph_participle"),
+                ("case", "morph_nominative"),
+                ("number", "morph_plural"),
+                ("gender", "morph_masculine"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_greek_nominals() {
+        assert_eq!(
+            describe_morphology("N-DSF"),
+            vec![
+                ("part_of_speech", "morph_noun"),
+                ("case", "morph_dative"),
+                ("number", "morph_singular"),
+                ("gender", "morph_feminine"),
+            ]
+        );
+        assert_eq!(
+            describe_morphology("P-1GS"),
+            vec![
+                ("part_of_speech", "morph_personal_pronoun"),
+                ("person", "morph_first"),
+                ("case", "morph_genitive"),
+                ("number", "morph_singular"),
+            ]
+        );
+        assert_eq!(
+            describe_morphology("CONJ"),
+            vec![("part_of_speech", "morph_conjunction")]
+        );
+        assert_eq!(
+            describe_morphology("ADV"),
+            vec![("part_of_speech", "morph_adverb")]
+        );
+    }
+
+    #[test]
+    fn test_describe_hebrew() {
+        assert_eq!(
+            describe_morphology("HVqp3ms"),
+            vec![
+                ("part_of_speech", "morph_verb"),
+                ("stem", "morph_qal"),
+                ("tense", "morph_perfect"),
+                ("person", "morph_third"),
+                ("gender", "morph_masculine"),
+                ("number", "morph_singular"),
+            ]
+        );
+        assert_eq!(
+            describe_morphology("HC/Ncmpa"),
+            vec![
+                ("part_of_speech", "morph_noun"),
+                ("gender", "morph_masculine"),
+                ("number", "morph_plural"),
+                ("state", "morph_absolute"),
+            ]
+        );
+        assert_eq!(describe_morphology("AVhp3ms")[1], ("stem", "morph_haphel"));
+    }
+
+    #[test]
+    fn test_describe_unknown_code() {
+        assert!(describe_morphology("").is_empty());
+        assert!(describe_morphology("??").is_empty());
+    }
+}
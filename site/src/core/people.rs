@@ -0,0 +1,107 @@
+use crate::core::places::{contains_word, word_starts};
+use crate::core::{Bible, Chapter, Verse};
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+const PEOPLE_JSON: &str = include_str!("people.json");
+
+/// A person from the bundled index of biblical people
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Person {
+    pub name: String,
+    /// Other names or spellings, such as the Dutch "Mozes"
+    pub aliases: Vec<String>,
+    pub description: String,
+}
+
+impl Person {
+    fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// Whether this person is named in `text`
+    pub fn is_named_in(&self, text: &str) -> bool {
+        self.names().any(|name| contains_word(text, name))
+    }
+}
+
+static PEOPLE: LazyLock<Vec<Person>> =
+    LazyLock::new(|| serde_json::from_str(PEOPLE_JSON).expect("Failed to parse people"));
+
+pub fn people() -> &'static [Person] {
+    &PEOPLE
+}
+
+/// The person going by `name` or one of their other names
+pub fn find_person(name: &str) -> Option<&'static Person> {
+    people()
+        .iter()
+        .find(|person| person.names().any(|n| n == name))
+}
+
+/// The person named at byte `index` of `text`, such as where a verse was clicked.
+/// The longest name there wins, so "Mary Magdalene" is found rather than "Mary".
+pub fn person_named_at(text: &str, index: usize) -> Option<&'static Person> {
+    named_at(people(), text, index)
+}
+
+fn named_at<'a>(people: &'a [Person], text: &str, index: usize) -> Option<&'a Person> {
+    people
+        .iter()
+        .flat_map(|person| person.names().map(move |name| (person, name)))
+        // A caret right after a name still picks it
+        .filter(|(_, name)| {
+            word_starts(text, name).any(|start| (start..=start + name.len()).contains(&index))
+        })
+        .max_by_key(|(_, name)| name.len())
+        .map(|(person, _)| person)
+}
+
+/// Every verse of `bible` naming `person`, in canonical order
+pub fn person_verses<'a>(bible: &'a Bible, person: &Person) -> Vec<(&'a Chapter, &'a Verse)> {
+    bible
+        .books
+        .iter()
+        .flat_map(|book| &book.chapters)
+        .flat_map(|chapter| chapter.verses.iter().map(move |verse| (chapter, verse)))
+        .filter(|(_, verse)| person.is_named_in(&verse.text))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_person() {
+        assert_eq!(find_person("Moses").map(|p| p.name.as_str()), Some("Moses"));
+        assert_eq!(find_person("Mozes").map(|p| p.name.as_str()), Some("Moses"));
+        assert_eq!(find_person("moses"), None);
+        assert_eq!(find_person("Egypt"), None);
+    }
+
+    #[test]
+    fn test_person_named_at() {
+        let name_at = |text, index| person_named_at(text, index).map(|p| p.name.as_str());
+        let text = "Toen zei Petrus tot Maria Magdalena:";
+        assert_eq!(name_at(text, 0), None);
+        assert_eq!(name_at(text, 11), Some("Peter"));
+        assert_eq!(name_at(text, 15), Some("Peter"));
+        // Both names cover "Maria"; the longer one wins
+        assert_eq!(name_at(text, 21), Some("Mary Magdalene"));
+        assert_eq!(name_at(text, 30), Some("Mary Magdalene"));
+        assert_eq!(name_at("Maria zei", 2), Some("Mary"));
+        assert_eq!(
+            name_at("In those days came John the Baptist", 20),
+            Some("John the Baptist")
+        );
+    }
+
+    #[test]
+    fn test_is_named_in() {
+        let peter = find_person("Peter").unwrap();
+        assert!(peter.is_named_in("Then Peter said"));
+        assert!(peter.is_named_in("Toen zei Petrus"));
+        assert!(!peter.is_named_in("Peterson said"));
+    }
+}
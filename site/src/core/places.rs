@@ -0,0 +1,196 @@
+use crate::core::Verse;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+const PLACES_JSON: &str = include_str!("places.json");
+
+/// Degrees of margin kept around the places shown on a map
+const MAP_MARGIN_DEGREES: f64 = 0.5;
+/// Smallest area a map shows, so a single place isn't zoomed in on a few streets
+const MIN_MAP_SPAN_DEGREES: f64 = 2.0;
+
+/// A place from the bundled gazetteer
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Place {
+    pub name: String,
+    /// Other spellings, such as the Dutch "Jeruzalem"
+    pub aliases: Vec<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Place {
+    fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+static GAZETTEER: LazyLock<Vec<Place>> =
+    LazyLock::new(|| serde_json::from_str(PLACES_JSON).expect("Failed to parse places"));
+
+pub fn gazetteer() -> &'static [Place] {
+    &GAZETTEER
+}
+
+/// A place named in a chapter and the verses naming it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaceMention {
+    pub place: &'static Place,
+    pub verses: Vec<u32>,
+}
+
+/// Whether `name` occurs in `text` as a whole word
+pub(crate) fn contains_word(text: &str, name: &str) -> bool {
+    word_starts(text, name).next().is_some()
+}
+
+/// Byte offsets where `name` occurs in `text` as a whole word
+pub(crate) fn word_starts<'a>(text: &'a str, name: &'a str) -> impl Iterator<Item = usize> + 'a {
+    text.match_indices(name)
+        .map(|(start, _)| start)
+        .filter(move |&start| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + name.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+}
+
+/// Gazetteer places named in `verses`, ordered by the first verse naming them
+pub fn places_in_verses(verses: &[Verse]) -> Vec<PlaceMention> {
+    let mut mentions: Vec<PlaceMention> = Vec::new();
+    for verse in verses {
+        for place in gazetteer() {
+            if !place.names().any(|name| contains_word(&verse.text, name)) {
+                continue;
+            }
+            match mentions.iter_mut().find(|m| std::ptr::eq(m.place, place)) {
+                Some(mention) => mention.verses.push(verse.verse),
+                None => mentions.push(PlaceMention {
+                    place,
+                    verses: vec![verse.verse],
+                }),
+            }
+        }
+    }
+    mentions
+}
+
+/// The area a map shows, in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapBounds {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub min_longitude: f64,
+    pub max_longitude: f64,
+}
+
+impl MapBounds {
+    /// The smallest bounds showing all `places` with some margin
+    pub fn fit<'a>(places: impl IntoIterator<Item = &'a Place>) -> Option<Self> {
+        let mut places = places.into_iter();
+        let first = places.next()?;
+        let mut bounds = MapBounds {
+            min_latitude: first.latitude,
+            max_latitude: first.latitude,
+            min_longitude: first.longitude,
+            max_longitude: first.longitude,
+        };
+        for place in places {
+            bounds.min_latitude = bounds.min_latitude.min(place.latitude);
+            bounds.max_latitude = bounds.max_latitude.max(place.latitude);
+            bounds.min_longitude = bounds.min_longitude.min(place.longitude);
+            bounds.max_longitude = bounds.max_longitude.max(place.longitude);
+        }
+
+        let widen = |min: &mut f64, max: &mut f64| {
+            let span = (*max - *min + 2.0 * MAP_MARGIN_DEGREES).max(MIN_MAP_SPAN_DEGREES);
+            let center = (*min + *max) / 2.0;
+            *min = center - span / 2.0;
+            *max = center + span / 2.0;
+        };
+        widen(&mut bounds.min_latitude, &mut bounds.max_latitude);
+        widen(&mut bounds.min_longitude, &mut bounds.max_longitude);
+        Some(bounds)
+    }
+
+    /// Widen the bounds so a map with this width to height ratio isn't stretched
+    pub fn with_aspect(self, aspect: f64) -> Self {
+        let latitude_span = self.max_latitude - self.min_latitude;
+        // A degree of longitude shrinks towards the poles
+        let longitude_scale = ((self.min_latitude + self.max_latitude) / 2.0)
+            .to_radians()
+            .cos();
+        let longitude_span = (self.max_longitude - self.min_longitude) * longitude_scale;
+
+        let mut bounds = self;
+        if longitude_span / latitude_span < aspect {
+            let extra = (latitude_span * aspect - longitude_span) / longitude_scale / 2.0;
+            bounds.min_longitude -= extra;
+            bounds.max_longitude += extra;
+        } else {
+            let extra = (longitude_span / aspect - latitude_span) / 2.0;
+            bounds.min_latitude -= extra;
+            bounds.max_latitude += extra;
+        }
+        bounds
+    }
+
+    /// Position of a coordinate on a `width` by `height` map, north up
+    pub fn project(&self, latitude: f64, longitude: f64, width: f64, height: f64) -> (f64, f64) {
+        let x = (longitude - self.min_longitude) / (self.max_longitude - self.min_longitude);
+        let y = (self.max_latitude - latitude) / (self.max_latitude - self.min_latitude);
+        (x * width, y * height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verse(number: u32, text: &str) -> Verse {
+        Verse {
+            verse: number,
+            chapter: 2,
+            name: format!("Matthew 2:{}", number),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_places_in_verses() {
+        let verses = [
+            verse(
+                1,
+                "Jesus was born in Bethlehem of Judea, and wise men came to Jerusalem",
+            ),
+            verse(13, "Flee into Egypt"),
+            verse(14, "He went to Egypte; Romeinen is not Rome"),
+        ];
+        let mentions: Vec<(&str, Vec<u32>)> = places_in_verses(&verses)
+            .into_iter()
+            .map(|m| (m.place.name.as_str(), m.verses))
+            .collect();
+
+        assert_eq!(
+            mentions,
+            vec![
+                ("Jerusalem", vec![1]),
+                ("Bethlehem", vec![1]),
+                ("Judea", vec![1]),
+                ("Egypt", vec![13, 14]),
+                ("Rome", vec![14]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_bounds() {
+        let places = gazetteer();
+        let jerusalem = places.iter().find(|p| p.name == "Jerusalem").unwrap();
+        let bounds = MapBounds::fit([jerusalem]).unwrap();
+        assert!((bounds.max_latitude - bounds.min_latitude - MIN_MAP_SPAN_DEGREES).abs() < 1e-9);
+
+        let (x, y) = bounds.project(jerusalem.latitude, jerusalem.longitude, 200.0, 100.0);
+        assert!((x - 100.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+    }
+}
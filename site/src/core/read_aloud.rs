@@ -0,0 +1,61 @@
+/// Speaking rates offered for reading aloud, 1.0 being the voice's normal speed
+pub const READ_ALOUD_RATES: [f32; 6] = [0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Whether the chapter is being read aloud, cycled by `Instruction::ToggleReadAloud`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReadAloudState {
+    #[default]
+    Stopped,
+    Playing,
+    Paused,
+}
+
+impl ReadAloudState {
+    /// Start when stopped, pause when playing and resume when paused
+    pub fn toggled(self) -> Self {
+        match self {
+            ReadAloudState::Stopped | ReadAloudState::Paused => ReadAloudState::Playing,
+            ReadAloudState::Playing => ReadAloudState::Paused,
+        }
+    }
+}
+
+/// The next offered rate above `rate`, or the fastest
+pub fn faster_read_aloud_rate(rate: f32) -> f32 {
+    READ_ALOUD_RATES
+        .into_iter()
+        .find(|offered| *offered > rate)
+        .unwrap_or(READ_ALOUD_RATES[READ_ALOUD_RATES.len() - 1])
+}
+
+/// The next offered rate below `rate`, or the slowest
+pub fn slower_read_aloud_rate(rate: f32) -> f32 {
+    READ_ALOUD_RATES
+        .into_iter()
+        .rev()
+        .find(|offered| *offered < rate)
+        .unwrap_or(READ_ALOUD_RATES[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_aloud_state_toggled() {
+        assert_eq!(ReadAloudState::Stopped.toggled(), ReadAloudState::Playing);
+        assert_eq!(ReadAloudState::Playing.toggled(), ReadAloudState::Paused);
+        assert_eq!(ReadAloudState::Paused.toggled(), ReadAloudState::Playing);
+    }
+
+    #[test]
+    fn test_read_aloud_rates() {
+        assert_eq!(faster_read_aloud_rate(1.0), 1.25);
+        assert_eq!(faster_read_aloud_rate(2.0), 2.0);
+        assert_eq!(slower_read_aloud_rate(1.0), 0.75);
+        assert_eq!(slower_read_aloud_rate(0.5), 0.5);
+        // A stored rate between the offered ones moves to its neighbours
+        assert_eq!(faster_read_aloud_rate(1.1), 1.25);
+        assert_eq!(slower_read_aloud_rate(1.1), 1.0);
+    }
+}
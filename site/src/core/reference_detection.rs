@@ -0,0 +1,208 @@
+use crate::core::VerseRange;
+use std::ops::Range;
+
+/// Most words a book name spans, as in "Song of Solomon" or "1 Kor"
+const MAX_BOOK_NAME_WORDS: usize = 4;
+
+/// A scripture reference found in running text, such as "1 Kor. 13:4-7"
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedReference {
+    /// Byte range of the reference in the text
+    pub range: Range<usize>,
+    pub book_key: &'static str,
+    pub chapter: u32,
+    pub verses: Option<VerseRange>,
+}
+
+/// Find the references in `text`. `resolve_book` maps a book name or abbreviation
+/// to its book key; only names starting with a capital or a number are tried, so
+/// words like "am" or "job" in a sentence aren't taken for books.
+pub fn detect_references(
+    text: &str,
+    resolve_book: impl Fn(&str) -> Option<&'static str>,
+) -> Vec<DetectedReference> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut references = Vec::new();
+    let mut position = 0;
+
+    while position < chars.len() {
+        let is_number_start = chars[position].1.is_ascii_digit()
+            && (position == 0 || !chars[position - 1].1.is_alphanumeric());
+        if is_number_start {
+            if let Some((reference, end)) = reference_at(text, &chars, position, &resolve_book) {
+                // The book name may begin with a number that was already passed
+                references.retain(|r: &DetectedReference| r.range.end <= reference.range.start);
+                references.push(reference);
+                position = end;
+                continue;
+            }
+        }
+        position += 1;
+    }
+
+    references
+}
+
+/// The reference whose chapter number starts at `chapter_start`, and the index of the character after it
+fn reference_at(
+    text: &str,
+    chars: &[(usize, char)],
+    chapter_start: usize,
+    resolve_book: &impl Fn(&str) -> Option<&'static str>,
+) -> Option<(DetectedReference, usize)> {
+    let (book_key, book_start) = book_before(text, chars, chapter_start, resolve_book)?;
+
+    let (chapter, mut end) = number_at(chars, chapter_start)?;
+    let mut verses = None;
+    if chars.get(end).is_some_and(|(_, c)| *c == ':') {
+        if let Some((start, after_start)) = number_at(chars, end + 1) {
+            end = after_start;
+            let mut verse_end = start;
+            if chars.get(end).is_some_and(|(_, c)| matches!(c, '-' | '–')) {
+                if let Some((last, after_last)) = number_at(chars, end + 1) {
+                    if last >= start {
+                        verse_end = last;
+                        end = after_last;
+                    }
+                }
+            }
+            verses = Some(VerseRange {
+                start,
+                end: verse_end,
+            });
+        }
+    }
+
+    let byte_end = chars.get(end).map_or(text.len(), |(index, _)| *index);
+    Some((
+        DetectedReference {
+            range: book_start..byte_end,
+            book_key,
+            chapter,
+            verses,
+        },
+        end,
+    ))
+}
+
+/// The book named right before the chapter number, trying the longest name first
+fn book_before(
+    text: &str,
+    chars: &[(usize, char)],
+    chapter_start: usize,
+    resolve_book: &impl Fn(&str) -> Option<&'static str>,
+) -> Option<(&'static str, usize)> {
+    // Step back over the space, and the period of an abbreviation, before the chapter
+    let mut position = chapter_start;
+    while position > 0 && chars[position - 1].1.is_whitespace() {
+        position -= 1;
+    }
+    if position == chapter_start {
+        return None;
+    }
+    let book_end = position;
+    if position > 0 && chars[position - 1].1 == '.' {
+        position -= 1;
+    }
+
+    // Start indices of the words before the chapter, nearest first
+    let mut word_starts = Vec::new();
+    while word_starts.len() < MAX_BOOK_NAME_WORDS {
+        let word_end = position;
+        while position > 0 && chars[position - 1].1.is_alphanumeric() {
+            position -= 1;
+        }
+        if position == word_end {
+            break;
+        }
+        word_starts.push(position);
+        if position == 0 || !chars[position - 1].1.is_whitespace() {
+            break;
+        }
+        while position > 0 && chars[position - 1].1.is_whitespace() {
+            position -= 1;
+        }
+    }
+
+    word_starts.into_iter().rev().find_map(|start| {
+        let first = chars[start].1;
+        if !(first.is_uppercase() || first.is_ascii_digit()) {
+            return None;
+        }
+        // A word glued to something before it ("x1 Kor") isn't a book name
+        if start > 0 && chars[start - 1].1.is_alphanumeric() {
+            return None;
+        }
+        let name = &text[chars[start].0..chars[book_end].0];
+        resolve_book(name.trim()).map(|key| (key, chars[start].0))
+    })
+}
+
+/// The number starting at `start`, and the index of the character after it
+fn number_at(chars: &[(usize, char)], start: usize) -> Option<(u32, usize)> {
+    let end = chars[start..]
+        .iter()
+        .position(|(_, c)| !c.is_ascii_digit())
+        .map_or(chars.len(), |offset| start + offset);
+    if end == start {
+        return None;
+    }
+    let number = chars[start..end]
+        .iter()
+        .map(|(_, c)| *c)
+        .collect::<String>()
+        .parse()
+        .ok()?;
+    Some((number, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translation_map::abbreviations::book_key_for_name;
+
+    /// The matched text and book key of each reference
+    fn detect(text: &str) -> Vec<(&str, &'static str)> {
+        detect_references(text, book_key_for_name)
+            .into_iter()
+            .map(|r| (&text[r.range], r.book_key))
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_references() {
+        assert_eq!(
+            detect("See John 3:16 and Gen. 1."),
+            vec![("John 3:16", "john"), ("Gen. 1", "genesis")]
+        );
+        assert_eq!(
+            detect("Compare 1 Kor. 13:4-7, Rom 8:28–30"),
+            vec![
+                ("1 Kor. 13:4-7", "first_corinthians"),
+                ("Rom 8:28–30", "romans")
+            ]
+        );
+        assert_eq!(
+            detect("Love song (Song of Solomon 2:4)"),
+            vec![("Song of Solomon 2:4", "song_of_solomon")]
+        );
+    }
+
+    #[test]
+    fn test_detect_reference_chapter_and_verses() {
+        let references = detect_references("Rom 8:28–30, Gen. 1", book_key_for_name);
+        assert_eq!(references[0].chapter, 8);
+        assert_eq!(
+            references[0].verses,
+            Some(VerseRange { start: 28, end: 30 })
+        );
+        assert_eq!(references[1].chapter, 1);
+        assert_eq!(references[1].verses, None);
+    }
+
+    #[test]
+    fn test_detect_references_ignores_plain_text() {
+        assert!(detect("I am 5 years old and had a job 2 days").is_empty());
+        assert!(detect("Version 2.0 of the app").is_empty());
+    }
+}
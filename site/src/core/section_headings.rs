@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A section heading such as "The Parable of the Sower", shown before `verse`.
+/// Books are identified by position, like verse edits, so headings survive book name translation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SectionHeading {
+    pub book_index: usize,
+    pub chapter: u32,
+    pub verse: u32,
+    pub title: String,
+    #[serde(default)]
+    pub kind: HeadingKind,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub enum HeadingKind {
+    /// Starts a section within the chapter
+    #[default]
+    Section,
+    /// Describes the whole chapter, like the titles of many Psalms
+    ChapterTitle,
+}
+
+fn in_chapter(
+    headings: &[SectionHeading],
+    book_index: usize,
+    chapter: u32,
+    kind: HeadingKind,
+) -> impl Iterator<Item = &SectionHeading> {
+    headings.iter().filter(move |heading| {
+        heading.book_index == book_index && heading.chapter == chapter && heading.kind == kind
+    })
+}
+
+/// The section headings of one chapter in reading order
+pub fn chapter_outline(
+    headings: &[SectionHeading],
+    book_index: usize,
+    chapter: u32,
+) -> Vec<SectionHeading> {
+    let mut outline: Vec<SectionHeading> =
+        in_chapter(headings, book_index, chapter, HeadingKind::Section)
+            .cloned()
+            .collect();
+    outline.sort_by_key(|heading| heading.verse);
+    outline
+}
+
+/// The titles of one chapter, such as "A Psalm of David"
+pub fn chapter_titles(headings: &[SectionHeading], book_index: usize, chapter: u32) -> Vec<String> {
+    in_chapter(headings, book_index, chapter, HeadingKind::ChapterTitle)
+        .map(|heading| heading.title.clone())
+        .collect()
+}
+
+/// The section headings of one chapter by the verse they are shown before
+pub fn headings_by_verse(
+    headings: &[SectionHeading],
+    book_index: usize,
+    chapter: u32,
+) -> HashMap<u32, Vec<String>> {
+    let mut by_verse: HashMap<u32, Vec<String>> = HashMap::new();
+    for heading in in_chapter(headings, book_index, chapter, HeadingKind::Section) {
+        by_verse
+            .entry(heading.verse)
+            .or_default()
+            .push(heading.title.clone());
+    }
+    by_verse
+}
+
+#[derive(Deserialize)]
+struct HeadingBible {
+    books: Vec<HeadingBook>,
+}
+
+#[derive(Deserialize)]
+struct HeadingBook {
+    chapters: Vec<HeadingChapter>,
+}
+
+#[derive(Deserialize)]
+struct HeadingChapter {
+    chapter: u32,
+    #[serde(default)]
+    title: Option<String>,
+    verses: Vec<HeadingVerse>,
+}
+
+#[derive(Deserialize)]
+struct HeadingVerse {
+    verse: u32,
+    #[serde(default)]
+    heading: Option<String>,
+}
+
+/// The headings in a Bible JSON file whose chapters carry an optional `title`
+/// and whose verses carry an optional `heading` shown before them
+pub fn extract_section_headings(json: &str) -> Vec<SectionHeading> {
+    let Ok(bible) = serde_json::from_str::<HeadingBible>(json) else {
+        return Vec::new();
+    };
+
+    let mut headings = Vec::new();
+    for (book_index, book) in bible.books.into_iter().enumerate() {
+        for chapter in book.chapters {
+            let first_verse = chapter.verses.first().map_or(1, |verse| verse.verse);
+            let title = chapter
+                .title
+                .map(|title| (first_verse, title, HeadingKind::ChapterTitle));
+            let sections = chapter.verses.into_iter().filter_map(|verse| {
+                verse
+                    .heading
+                    .map(|heading| (verse.verse, heading, HeadingKind::Section))
+            });
+            for (verse, title, kind) in title.into_iter().chain(sections) {
+                let title = title.trim();
+                if !title.is_empty() {
+                    headings.push(SectionHeading {
+                        book_index,
+                        chapter: chapter.chapter,
+                        verse,
+                        title: title.to_string(),
+                        kind,
+                    });
+                }
+            }
+        }
+    }
+    headings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heading(book_index: usize, chapter: u32, verse: u32, title: &str) -> SectionHeading {
+        SectionHeading {
+            book_index,
+            chapter,
+            verse,
+            title: title.to_string(),
+            kind: HeadingKind::Section,
+        }
+    }
+
+    #[test]
+    fn test_chapter_outline() {
+        let headings = vec![
+            heading(39, 13, 24, "The Parable of the Weeds"),
+            heading(39, 13, 1, "The Parable of the Sower"),
+            heading(39, 14, 1, "The Death of John the Baptist"),
+            heading(40, 13, 1, "Signs of the End of the Age"),
+        ];
+
+        let titles: Vec<String> = chapter_outline(&headings, 39, 13)
+            .into_iter()
+            .map(|heading| heading.title)
+            .collect();
+        assert_eq!(
+            titles,
+            vec!["The Parable of the Sower", "The Parable of the Weeds"]
+        );
+        assert!(chapter_outline(&headings, 0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_extract_section_headings() {
+        let json = r#"{"books": [
+            {"name": "Genesis", "chapters": [{"chapter": 1, "name": "Genesis 1", "verses": [
+                {"verse": 1, "chapter": 1, "name": "Genesis 1:1", "text": "In the beginning", "heading": "The Creation"}
+            ]}]},
+            {"name": "Psalms", "chapters": [{"chapter": 3, "name": "Psalms 3", "title": "A Psalm of David", "verses": [
+                {"verse": 1, "chapter": 3, "name": "Psalms 3:1", "text": "LORD, how are they increased"},
+                {"verse": 3, "chapter": 3, "name": "Psalms 3:3", "text": "But thou, O LORD", "heading": " "}
+            ]}]}
+        ]}"#;
+
+        let headings = extract_section_headings(json);
+        assert_eq!(headings.len(), 2);
+        assert_eq!(chapter_outline(&headings, 0, 1)[0].title, "The Creation");
+        assert!(chapter_outline(&headings, 1, 3).is_empty());
+        assert_eq!(chapter_titles(&headings, 1, 3), vec!["A Psalm of David"]);
+        assert!(extract_section_headings("not json").is_empty());
+    }
+
+    #[test]
+    fn test_headings_by_verse() {
+        let mut headings = vec![
+            heading(39, 5, 1, "The Sermon on the Mount"),
+            heading(39, 5, 1, "The Beatitudes"),
+            heading(39, 5, 13, "Salt and Light"),
+        ];
+        headings.push(SectionHeading {
+            kind: HeadingKind::ChapterTitle,
+            ..heading(39, 5, 1, "Not a section")
+        });
+
+        let by_verse = headings_by_verse(&headings, 39, 5);
+        assert_eq!(
+            by_verse[&1],
+            vec!["The Sermon on the Mount", "The Beatitudes"]
+        );
+        assert_eq!(by_verse[&13], vec!["Salt and Light"]);
+        assert_eq!(by_verse.len(), 2);
+    }
+}
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The Hebrew or Greek text behind one verse, packaged with a translation.
+/// Books are identified by position, like section headings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceVerse {
+    pub book_index: usize,
+    pub chapter: u32,
+    pub verse: u32,
+    /// Empty when the package only has a transliteration for the verse
+    pub text: String,
+    /// The source text in Latin letters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+    /// The words of the source text with their morphology, when the package has it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<SourceWord>,
+}
+
+/// One word of a source text with its dictionary form and parsing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SourceWord {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lemma: Option<String>,
+    /// Strong's number such as `G3056` or `H430`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strongs: Option<String>,
+    /// Robinson code for Greek, e.g. `V-AAI-3S`, or OSHB code for Hebrew, e.g. `HVqp3ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub morph: Option<String>,
+    /// A short translation of the word
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<String>,
+}
+
+impl SourceWord {
+    /// The key the word is looked up by in a lexicon: its Strong's number, or else its lemma
+    pub fn lexicon_key(&self) -> Option<String> {
+        self.strongs
+            .as_deref()
+            .or(self.lemma.as_deref())
+            .map(normalize_lexicon_key)
+            .filter(|key| !key.is_empty())
+    }
+}
+
+/// Strong's numbers in one spelling: `g03056` and `G3056` both become `G3056`.
+/// Anything else, such as a lemma, is only trimmed.
+pub fn normalize_lexicon_key(key: &str) -> String {
+    let key = key.trim();
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(prefix @ ('G' | 'g' | 'H' | 'h'))
+            if !chars.as_str().is_empty() && chars.as_str().chars().all(|c| c.is_ascii_digit()) =>
+        {
+            let number = chars.as_str().trim_start_matches('0');
+            format!(
+                "{}{}",
+                prefix.to_ascii_uppercase(),
+                if number.is_empty() { "0" } else { number }
+            )
+        }
+        _ => key.to_string(),
+    }
+}
+
+/// Script of a source text, which decides its font and writing direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SourceScript {
+    Hebrew,
+    Greek,
+}
+
+impl SourceScript {
+    /// Hebrew when the text contains Hebrew letters (including the Aramaic
+    /// parts of the Old Testament), Greek otherwise
+    pub fn detect(text: &str) -> Self {
+        if text.chars().any(|c| ('\u{0590}'..='\u{05FF}').contains(&c)) {
+            SourceScript::Hebrew
+        } else {
+            SourceScript::Greek
+        }
+    }
+
+    pub fn lang(&self) -> &'static str {
+        match self {
+            SourceScript::Hebrew => "he",
+            SourceScript::Greek => "grc",
+        }
+    }
+
+    pub fn dir(&self) -> &'static str {
+        match self {
+            SourceScript::Hebrew => "rtl",
+            SourceScript::Greek => "ltr",
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            SourceScript::Hebrew => "source-text-hebrew",
+            SourceScript::Greek => "source-text-greek",
+        }
+    }
+}
+
+/// How a chapter's source text is shown, cycled by `Instruction::ToggleSourceText`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SourceTextMode {
+    #[default]
+    Hidden,
+    /// Under the translation of each verse
+    UnderVerses,
+    /// Instead of the translation
+    Replacing,
+}
+
+impl SourceTextMode {
+    pub fn next(self) -> Self {
+        match self {
+            SourceTextMode::Hidden => SourceTextMode::UnderVerses,
+            SourceTextMode::UnderVerses => SourceTextMode::Replacing,
+            SourceTextMode::Replacing => SourceTextMode::Hidden,
+        }
+    }
+}
+
+/// Source text of one chapter by verse number
+pub fn chapter_source_text(
+    source_text: &[SourceVerse],
+    book_index: usize,
+    chapter: u32,
+) -> HashMap<u32, SourceVerse> {
+    source_text
+        .iter()
+        .filter(|verse| verse.book_index == book_index && verse.chapter == chapter)
+        .map(|verse| (verse.verse, verse.clone()))
+        .collect()
+}
+
+/// Words of a source text that are looked up by `key`, in Bible order
+pub fn words_with_lexicon_key<'a>(
+    source_text: &'a [SourceVerse],
+    key: &str,
+) -> Vec<&'a SourceWord> {
+    source_text
+        .iter()
+        .flat_map(|verse| verse.words.iter())
+        .filter(|word| word.lexicon_key().as_deref() == Some(key))
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct SourceBible {
+    books: Vec<SourceBook>,
+}
+
+#[derive(Deserialize)]
+struct SourceBook {
+    chapters: Vec<SourceChapter>,
+}
+
+#[derive(Deserialize)]
+struct SourceChapter {
+    chapter: u32,
+    verses: Vec<SourceVerseField>,
+}
+
+#[derive(Deserialize)]
+struct SourceVerseField {
+    verse: u32,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    transliteration: Option<String>,
+    #[serde(default)]
+    words: Vec<SourceWord>,
+}
+
+/// The source text in a Bible JSON file whose verses carry optional `source`,
+/// `transliteration` and `words` fields next to `text`; empty when there is none.
+/// Without a `source` field the text is made from the words.
+pub fn extract_source_text(json: &str) -> Vec<SourceVerse> {
+    let Ok(bible) = serde_json::from_str::<SourceBible>(json) else {
+        return Vec::new();
+    };
+
+    bible
+        .books
+        .into_iter()
+        .enumerate()
+        .flat_map(|(book_index, book)| {
+            book.chapters.into_iter().flat_map(move |chapter| {
+                let chapter_number = chapter.chapter;
+                chapter.verses.into_iter().filter_map(move |verse| {
+                    let text = match verse.source {
+                        Some(source) => source.trim().to_string(),
+                        None => verse
+                            .words
+                            .iter()
+                            .map(|word| word.text.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    };
+                    let transliteration = verse
+                        .transliteration
+                        .map(|transliteration| transliteration.trim().to_string())
+                        .filter(|transliteration| !transliteration.is_empty());
+                    (!text.is_empty() || transliteration.is_some()).then_some(SourceVerse {
+                        book_index,
+                        chapter: chapter_number,
+                        verse: verse.verse,
+                        text,
+                        transliteration,
+                        words: verse.words,
+                    })
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_source_text() {
+        let json = r#"{"books": [
+            {"name": "Genesis", "chapters": [{"chapter": 1, "name": "Genesis 1", "verses": [
+                {"verse": 1, "chapter": 1, "name": "Genesis 1:1", "text": "In the beginning", "source": "בְּרֵאשִׁית בָּרָא", "transliteration": "bəreshit bara"},
+                {"verse": 2, "chapter": 1, "name": "Genesis 1:2", "text": "And the earth"},
+                {"verse": 3, "chapter": 1, "name": "Genesis 1:3", "text": "And God said", "transliteration": "vayomer elohim"}
+            ]}]},
+            {"name": "John", "chapters": [{"chapter": 1, "name": "John 1", "verses": [
+                {"verse": 1, "chapter": 1, "name": "John 1:1", "text": "In the beginning", "source": "Ἐν ἀρχῇ ἦν ὁ λόγος"}
+            ]}]}
+        ]}"#;
+
+        let source_text = extract_source_text(json);
+        assert_eq!(source_text.len(), 3);
+
+        let genesis = chapter_source_text(&source_text, 0, 1);
+        assert_eq!(genesis.len(), 2);
+        assert_eq!(
+            SourceScript::detect(&genesis[&1].text),
+            SourceScript::Hebrew
+        );
+        assert_eq!(
+            genesis[&1].transliteration.as_deref(),
+            Some("bəreshit bara")
+        );
+        assert!(genesis[&3].text.is_empty());
+
+        let john = chapter_source_text(&source_text, 1, 1);
+        assert_eq!(SourceScript::detect(&john[&1].text), SourceScript::Greek);
+        assert_eq!(SourceScript::detect(&john[&1].text).dir(), "ltr");
+        assert_eq!(john[&1].transliteration, None);
+    }
+
+    #[test]
+    fn test_extract_source_text_from_words() {
+        let json = r#"{"books": [{"name": "John", "chapters": [{"chapter": 1, "name": "John 1", "verses": [
+            {"verse": 1, "chapter": 1, "name": "John 1:1", "text": "In the beginning", "words": [
+                {"text": "Ἐν", "lemma": "ἐν", "strongs": "G1722", "morph": "PREP"},
+                {"text": "ἀρχῇ", "lemma": "ἀρχή", "strongs": "G746", "morph": "N-DSF"}
+            ]}
+        ]}]}]}"#;
+
+        let source_text = extract_source_text(json);
+        assert_eq!(source_text[0].text, "Ἐν ἀρχῇ");
+        assert_eq!(source_text[0].words.len(), 2);
+        assert_eq!(source_text[0].words[1].strongs.as_deref(), Some("G746"));
+    }
+
+    #[test]
+    fn test_normalize_lexicon_key() {
+        assert_eq!(normalize_lexicon_key("g03056"), "G3056");
+        assert_eq!(normalize_lexicon_key(" H430 "), "H430");
+        assert_eq!(normalize_lexicon_key("H0"), "H0");
+        assert_eq!(normalize_lexicon_key("λόγος"), "λόγος");
+        assert_eq!(normalize_lexicon_key("Gabriel"), "Gabriel");
+    }
+
+    #[test]
+    fn test_words_with_lexicon_key() {
+        let json = r#"{"books": [{"name": "John", "chapters": [{"chapter": 1, "name": "John 1", "verses": [
+            {"verse": 1, "chapter": 1, "name": "John 1:1", "text": "In the beginning was the Word", "words": [
+                {"text": "ἦν", "lemma": "εἰμί", "strongs": "G1510"},
+                {"text": "ὁ", "lemma": "ὁ"},
+                {"text": "λόγος", "lemma": "λόγος", "strongs": "G3056", "gloss": "word"}
+            ]},
+            {"verse": 14, "chapter": 1, "name": "John 1:14", "text": "And the Word", "words": [
+                {"text": "ὁ", "lemma": "ὁ"},
+                {"text": "λόγος", "lemma": "λόγος", "strongs": "g03056"}
+            ]}
+        ]}]}]}"#;
+
+        let source_text = extract_source_text(json);
+        let words = words_with_lexicon_key(&source_text, "G3056");
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].gloss.as_deref(), Some("word"));
+        // Words without a Strong's number are looked up by lemma
+        assert_eq!(words_with_lexicon_key(&source_text, "ὁ").len(), 2);
+        assert!(words_with_lexicon_key(&source_text, "G1").is_empty());
+    }
+
+    #[test]
+    fn test_extract_source_text_without_source() {
+        assert!(extract_source_text(r#"{"books": []}"#).is_empty());
+        assert!(extract_source_text("not json").is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+const TIMELINE_JSON: &str = include_str!("timeline.json");
+
+/// Whether a timeline entry is an event or the writing of books
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineKind {
+    Event,
+    Book,
+}
+
+/// An entry of the bundled timeline. Years before Christ are negative, and
+/// most dates are traditional and approximate.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TimelineEntry {
+    pub title: String,
+    pub kind: TimelineKind,
+    pub start: i32,
+    /// Last year of an entry spanning a period
+    pub end: Option<i32>,
+    /// The primary passage, as a reference like "Exodus 14:21-31"
+    pub passage: String,
+    pub description: String,
+}
+
+impl TimelineEntry {
+    pub fn end_year(&self) -> i32 {
+        self.end.unwrap_or(self.start)
+    }
+}
+
+static TIMELINE: LazyLock<Vec<TimelineEntry>> =
+    LazyLock::new(|| serde_json::from_str(TIMELINE_JSON).expect("Failed to parse timeline"));
+
+pub fn timeline() -> &'static [TimelineEntry] {
+    &TIMELINE
+}
+
+/// A year as "1446 BC" or "AD 30"
+pub fn format_year(year: i32) -> String {
+    if year < 0 {
+        format!("{} BC", -year)
+    } else {
+        format!("AD {}", year)
+    }
+}
+
+/// The row of each entry, so entries closer than `min_gap` years on a row don't overlap.
+/// Entries are expected in order of their start year.
+pub fn timeline_rows(entries: &[&TimelineEntry], min_gap: f64) -> Vec<usize> {
+    // The year each row is free again
+    let mut row_ends: Vec<f64> = Vec::new();
+    entries
+        .iter()
+        .map(|entry| {
+            let start = f64::from(entry.start);
+            let end = f64::from(entry.end_year()).max(start + min_gap);
+            match row_ends.iter().position(|row_end| *row_end <= start) {
+                Some(row) => {
+                    row_ends[row] = end;
+                    row
+                }
+                None => {
+                    row_ends.push(end);
+                    row_ends.len() - 1
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::detect_references;
+    use crate::translation_map::abbreviations::book_key_for_name;
+
+    #[test]
+    fn test_timeline_passages_are_references() {
+        for entry in timeline() {
+            let references = detect_references(&entry.passage, book_key_for_name);
+            assert_eq!(
+                references.first().map(|r| r.range.clone()),
+                Some(0..entry.passage.len()),
+                "{}",
+                entry.title
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_year() {
+        assert_eq!(format_year(-1446), "1446 BC");
+        assert_eq!(format_year(30), "AD 30");
+    }
+
+    #[test]
+    fn test_timeline_rows() {
+        let entry = |start: i32, end: Option<i32>| TimelineEntry {
+            title: String::new(),
+            kind: TimelineKind::Event,
+            start,
+            end,
+            passage: String::new(),
+            description: String::new(),
+        };
+        let entries = [
+            entry(-1446, None),
+            entry(-1445, Some(-1406)),
+            entry(-1420, None),
+            entry(-1406, None),
+        ];
+        let entries: Vec<&TimelineEntry> = entries.iter().collect();
+        assert_eq!(timeline_rows(&entries, 20.0), vec![0, 1, 0, 1]);
+    }
+}
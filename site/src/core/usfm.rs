@@ -0,0 +1,408 @@
+use crate::core::bible_core::{Bible, Book, Chapter, Verse};
+use crate::core::section_headings::{HeadingKind, SectionHeading};
+
+/// USFM book codes in canonical order with the English book names used by the app
+const USFM_BOOKS: [(&str, &str); 66] = [
+    ("GEN", "Genesis"),
+    ("EXO", "Exodus"),
+    ("LEV", "Leviticus"),
+    ("NUM", "Numbers"),
+    ("DEU", "Deuteronomy"),
+    ("JOS", "Joshua"),
+    ("JDG", "Judges"),
+    ("RUT", "Ruth"),
+    ("1SA", "1 Samuel"),
+    ("2SA", "2 Samuel"),
+    ("1KI", "1 Kings"),
+    ("2KI", "2 Kings"),
+    ("1CH", "1 Chronicles"),
+    ("2CH", "2 Chronicles"),
+    ("EZR", "Ezra"),
+    ("NEH", "Nehemiah"),
+    ("EST", "Esther"),
+    ("JOB", "Job"),
+    ("PSA", "Psalms"),
+    ("PRO", "Proverbs"),
+    ("ECC", "Ecclesiastes"),
+    ("SNG", "Song of Solomon"),
+    ("ISA", "Isaiah"),
+    ("JER", "Jeremiah"),
+    ("LAM", "Lamentations"),
+    ("EZK", "Ezekiel"),
+    ("DAN", "Daniel"),
+    ("HOS", "Hosea"),
+    ("JOL", "Joel"),
+    ("AMO", "Amos"),
+    ("OBA", "Obadiah"),
+    ("JON", "Jonah"),
+    ("MIC", "Micah"),
+    ("NAM", "Nahum"),
+    ("HAB", "Habakkuk"),
+    ("ZEP", "Zephaniah"),
+    ("HAG", "Haggai"),
+    ("ZEC", "Zechariah"),
+    ("MAL", "Malachi"),
+    ("MAT", "Matthew"),
+    ("MRK", "Mark"),
+    ("LUK", "Luke"),
+    ("JHN", "John"),
+    ("ACT", "Acts"),
+    ("ROM", "Romans"),
+    ("1CO", "1 Corinthians"),
+    ("2CO", "2 Corinthians"),
+    ("GAL", "Galatians"),
+    ("EPH", "Ephesians"),
+    ("PHP", "Philippians"),
+    ("COL", "Colossians"),
+    ("1TH", "1 Thessalonians"),
+    ("2TH", "2 Thessalonians"),
+    ("1TI", "1 Timothy"),
+    ("2TI", "2 Timothy"),
+    ("TIT", "Titus"),
+    ("PHM", "Philemon"),
+    ("HEB", "Hebrews"),
+    ("JAS", "James"),
+    ("1PE", "1 Peter"),
+    ("2PE", "2 Peter"),
+    ("1JN", "1 John"),
+    ("2JN", "2 John"),
+    ("3JN", "3 John"),
+    ("JUD", "Jude"),
+    ("REV", "Revelation"),
+];
+
+/// Markers whose whole line is metadata or a heading rather than verse text
+const SKIPPED_LINE_MARKERS: [&str; 13] = [
+    "h", "toc", "mt", "ms", "mr", "s", "sr", "r", "sp", "cl", "ide", "rem", "usfm",
+];
+
+/// Markers whose content (up to the closing marker) is a note, not verse text
+const NOTE_MARKERS: [&str; 3] = ["f", "fe", "x"];
+
+/// English book name for a USFM book code such as `GEN` or `1CO`
+pub fn usfm_book_name(code: &str) -> Option<&'static str> {
+    USFM_BOOKS
+        .iter()
+        .find(|(book_code, _)| book_code.eq_ignore_ascii_case(code))
+        .map(|(_, name)| *name)
+}
+
+/// Parse one or more USFM files (one book per file) into a `Bible` and its
+/// section headings (`\s`, `\s1`, ...) and chapter titles (`\d`).
+///
+/// Books are sorted into canonical order. Footnotes, cross references and
+/// word-level attributes are dropped; only verse text is kept.
+pub fn parse_usfm_files(sources: &[String]) -> Result<(Bible, Vec<SectionHeading>), String> {
+    let mut books: Vec<ParsedBook> = Vec::new();
+
+    for source in sources {
+        let (order, book, headings) = parse_usfm_book(source)?;
+        if books.iter().any(|(existing, _, _)| *existing == order) {
+            return Err(format!("{} appears more than once", book.name));
+        }
+        books.push((order, book, headings));
+    }
+
+    books.sort_by_key(|(order, _, _)| *order);
+    let headings = books
+        .iter()
+        .enumerate()
+        .flat_map(|(book_index, (_, _, headings))| {
+            headings
+                .iter()
+                .map(move |(chapter, verse, title, kind)| SectionHeading {
+                    book_index,
+                    chapter: *chapter,
+                    verse: *verse,
+                    title: title.clone(),
+                    kind: *kind,
+                })
+        })
+        .collect();
+
+    let bible = Bible::new(books.into_iter().map(|(_, book, _)| book).collect());
+    Ok((bible, headings))
+}
+
+type ParsedBook = (usize, Book, Vec<(u32, u32, String, HeadingKind)>);
+
+fn parse_usfm_book(source: &str) -> Result<ParsedBook, String> {
+    let mut parser = UsfmParser::default();
+    parser.parse(source);
+
+    let code = parser.book_code.ok_or("Missing \\id marker")?;
+    let (order, name) = USFM_BOOKS
+        .iter()
+        .enumerate()
+        .find(|(_, (book_code, _))| book_code.eq_ignore_ascii_case(&code))
+        .map(|(order, (_, name))| (order, name.to_string()))
+        .ok_or_else(|| format!("Unknown book code {}", code))?;
+
+    let chapters = parser
+        .chapters
+        .into_iter()
+        .map(|(chapter_number, verses)| {
+            let chapter_name = format!("{} {}", name, chapter_number);
+            Chapter {
+                chapter: chapter_number,
+                name: chapter_name.clone(),
+                verses: verses
+                    .into_iter()
+                    .map(|(verse, text)| Verse {
+                        verse,
+                        chapter: chapter_number,
+                        name: chapter_name.clone(),
+                        text,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok((order, Book { name, chapters }, parser.headings))
+}
+
+#[derive(Default)]
+struct UsfmParser {
+    book_code: Option<String>,
+    chapters: Vec<(u32, Vec<(u32, String)>)>,
+    current_verse: Option<(u32, String)>,
+    /// Headings as (chapter, verse, title, kind), placed before the verse that follows them
+    headings: Vec<(u32, u32, String, HeadingKind)>,
+    pending_headings: Vec<(String, HeadingKind)>,
+}
+
+impl UsfmParser {
+    fn parse(&mut self, source: &str) {
+        let mut rest = source;
+        // Closing marker of the note we're currently skipping, e.g. "f*"
+        let mut skip_until: Option<String> = None;
+
+        while let Some(backslash) = rest.find('\\') {
+            if skip_until.is_none() {
+                self.push_text(&rest[..backslash]);
+            }
+            rest = &rest[backslash + 1..];
+
+            let marker_len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '*' || c == '+'))
+                .unwrap_or(rest.len());
+            let marker = rest[..marker_len].trim_start_matches('+').to_string();
+            rest = &rest[marker_len..];
+            // A single space separates an opening marker from its content
+            if !marker.ends_with('*') {
+                rest = rest.strip_prefix(' ').unwrap_or(rest);
+            }
+
+            if let Some(closing) = &skip_until {
+                if &marker == closing {
+                    skip_until = None;
+                }
+                continue;
+            }
+
+            let base_marker = marker.trim_end_matches(|c: char| c.is_ascii_digit());
+            match marker.as_str() {
+                "id" => {
+                    let (line, remainder) = split_line(rest);
+                    self.book_code = line.split_whitespace().next().map(str::to_string);
+                    rest = remainder;
+                }
+                "c" => {
+                    let (number, remainder) = split_word(rest);
+                    self.finish_verse();
+                    if let Some(chapter) = leading_number(number) {
+                        self.chapters.push((chapter, Vec::new()));
+                    }
+                    rest = remainder;
+                }
+                "v" => {
+                    let (number, remainder) = split_word(rest);
+                    self.finish_verse();
+                    // Verse bridges like "1-2" are stored under their first verse
+                    if let Some(verse) = leading_number(number) {
+                        self.current_verse = Some((verse, String::new()));
+                        if let Some((chapter, _)) = self.chapters.last() {
+                            let chapter = *chapter;
+                            for (title, kind) in self.pending_headings.drain(..) {
+                                self.headings.push((chapter, verse, title, kind));
+                            }
+                        }
+                    }
+                    rest = remainder;
+                }
+                _ if base_marker == "s" || marker == "d" => {
+                    let (line, remainder) = split_line(rest);
+                    let title = line.split_whitespace().collect::<Vec<_>>().join(" ");
+                    let kind = if marker == "d" {
+                        HeadingKind::ChapterTitle
+                    } else {
+                        HeadingKind::Section
+                    };
+                    if !title.is_empty() {
+                        self.pending_headings.push((title, kind));
+                    }
+                    rest = remainder;
+                }
+                _ if NOTE_MARKERS.contains(&marker.as_str()) => {
+                    skip_until = Some(format!("{}*", marker));
+                }
+                _ if SKIPPED_LINE_MARKERS.contains(&base_marker) => {
+                    rest = split_line(rest).1;
+                }
+                _ if marker.ends_with('*') => {
+                    // Closing character marker, e.g. `\add*` before punctuation
+                }
+                _ => {
+                    // Paragraph and character markers only affect layout
+                    self.push_text(" ");
+                }
+            }
+        }
+
+        if skip_until.is_none() {
+            self.push_text(rest);
+        }
+        self.finish_verse();
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some((_, verse_text)) = &mut self.current_verse {
+            // Drop word-level attributes such as `\w grace|strong="H2580"\w*`
+            let text = text.split('|').next().unwrap_or_default();
+            verse_text.push_str(text);
+        }
+    }
+
+    fn finish_verse(&mut self) {
+        if let Some((verse, text)) = self.current_verse.take() {
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if let Some((_, verses)) = self.chapters.last_mut() {
+                verses.push((verse, text));
+            }
+        }
+    }
+}
+
+fn split_line(text: &str) -> (&str, &str) {
+    match text.find('\n') {
+        Some(newline) => (&text[..newline], &text[newline + 1..]),
+        None => (text, ""),
+    }
+}
+
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.find(char::is_whitespace) {
+        Some(end) => (&text[..end], &text[end..]),
+        None => (text, ""),
+    }
+}
+
+fn leading_number(text: &str) -> Option<u32> {
+    let digits: String = text.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"\id GEN Sample translation
+\h Genesis
+\toc1 The Book of Genesis
+\mt1 Genesis
+\c 1
+\s1 The Creation
+\p
+\v 1 In the beginning God created the heavens and the earth.
+\v 2 The earth was \add without form\add*\f + \fr 1:2 \ft Or empty\f*,
+\q1 and void.
+\c 2
+\p
+\v 1-2 Thus the \w heavens|strong="H8064"\w* were finished.
+"#;
+
+    #[test]
+    fn test_parse_usfm_book() {
+        let (bible, _) = parse_usfm_files(&[SAMPLE.to_string()]).unwrap();
+        let genesis = &bible.books[0];
+
+        assert_eq!(genesis.name, "Genesis");
+        assert_eq!(genesis.chapters.len(), 2);
+        assert_eq!(genesis.chapters[0].name, "Genesis 1");
+        assert_eq!(
+            genesis.chapters[0].verses[0].text,
+            "In the beginning God created the heavens and the earth."
+        );
+        assert_eq!(
+            genesis.chapters[0].verses[1].text,
+            "The earth was without form, and void."
+        );
+        assert_eq!(genesis.chapters[1].verses[0].verse, 1);
+        assert_eq!(
+            genesis.chapters[1].verses[0].text,
+            "Thus the heavens were finished."
+        );
+    }
+
+    #[test]
+    fn test_parse_usfm_headings() {
+        let exodus = "\\id EXO\n\\c 1\n\\d For the chief Musician\n\\s1 Israel in Egypt\n\\p\n\\v 1 Now these are the names\n\\s2 Oppression\n\\v 8 Now there arose a new king".to_string();
+        let (bible, headings) = parse_usfm_files(&[exodus, SAMPLE.to_string()]).unwrap();
+
+        assert_eq!(
+            bible.books[1].chapters[0].verses[1].text,
+            "Now there arose a new king"
+        );
+        assert_eq!(
+            headings,
+            vec![
+                SectionHeading {
+                    book_index: 0,
+                    chapter: 1,
+                    verse: 1,
+                    title: "The Creation".to_string(),
+                    kind: HeadingKind::Section,
+                },
+                SectionHeading {
+                    book_index: 1,
+                    chapter: 1,
+                    verse: 1,
+                    title: "For the chief Musician".to_string(),
+                    kind: HeadingKind::ChapterTitle,
+                },
+                SectionHeading {
+                    book_index: 1,
+                    chapter: 1,
+                    verse: 1,
+                    title: "Israel in Egypt".to_string(),
+                    kind: HeadingKind::Section,
+                },
+                SectionHeading {
+                    book_index: 1,
+                    chapter: 1,
+                    verse: 8,
+                    title: "Oppression".to_string(),
+                    kind: HeadingKind::Section,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_usfm_canonical_order() {
+        let exodus = "\\id EXO\n\\c 1\n\\v 1 Now these are the names".to_string();
+        let (bible, _) = parse_usfm_files(&[exodus, SAMPLE.to_string()]).unwrap();
+
+        assert_eq!(bible.books[0].name, "Genesis");
+        assert_eq!(bible.books[1].name, "Exodus");
+    }
+
+    #[test]
+    fn test_parse_usfm_errors() {
+        assert!(parse_usfm_files(&["\\c 1\n\\v 1 text".to_string()]).is_err());
+        assert!(parse_usfm_files(&["\\id XYZ\n\\c 1".to_string()]).is_err());
+        assert!(parse_usfm_files(&[SAMPLE.to_string(), SAMPLE.to_string()]).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::core::bible_core::Bible;
+
+/// Problems found while checking an imported Bible.
+///
+/// Errors make the translation unusable and block the import; warnings are
+/// shown to the user but the translation can still be stored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Check the structure of an imported Bible before storing it
+pub fn validate_bible(bible: &Bible) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if bible.books.is_empty() {
+        report.errors.push("No books found".to_string());
+        return report;
+    }
+
+    for book in &bible.books {
+        if book.name.trim().is_empty() {
+            report.errors.push("Book without a name".to_string());
+        }
+        if book.chapters.is_empty() {
+            report
+                .errors
+                .push(format!("{} has no chapters", book.name));
+            continue;
+        }
+
+        for (index, chapter) in book.chapters.iter().enumerate() {
+            let expected_chapter = index as u32 + 1;
+            if chapter.chapter != expected_chapter {
+                report.warnings.push(format!(
+                    "{}: expected chapter {} but found chapter {}",
+                    book.name, expected_chapter, chapter.chapter
+                ));
+            }
+            if chapter.verses.is_empty() {
+                report
+                    .errors
+                    .push(format!("{} {} has no verses", book.name, chapter.chapter));
+                continue;
+            }
+
+            let mut previous_verse = 0;
+            for verse in &chapter.verses {
+                if verse.verse <= previous_verse {
+                    report.warnings.push(format!(
+                        "{} {}:{} is out of order or duplicated",
+                        book.name, chapter.chapter, verse.verse
+                    ));
+                }
+                if verse.text.trim().is_empty() {
+                    report.warnings.push(format!(
+                        "{} {}:{} has no text",
+                        book.name, chapter.chapter, verse.verse
+                    ));
+                }
+                previous_verse = verse.verse;
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bible_core::{Book, Chapter, Verse};
+
+    fn chapter(number: u32, verses: &[(u32, &str)]) -> Chapter {
+        Chapter {
+            chapter: number,
+            name: format!("Genesis {}", number),
+            verses: verses
+                .iter()
+                .map(|(verse, text)| Verse {
+                    verse: *verse,
+                    chapter: number,
+                    name: format!("Genesis {}", number),
+                    text: text.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_validate_empty_bible() {
+        let report = validate_bible(&Bible::new(vec![]));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_validate_warnings() {
+        let bible = Bible::new(vec![Book {
+            name: "Genesis".to_string(),
+            chapters: vec![chapter(1, &[(1, "In the beginning"), (1, ""), (3, "And")])],
+        }]);
+        let report = validate_bible(&bible);
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_chapter_without_verses() {
+        let bible = Bible::new(vec![Book {
+            name: "Genesis".to_string(),
+            chapters: vec![chapter(1, &[])],
+        }]);
+        assert!(!validate_bible(&bible).is_valid());
+    }
+}
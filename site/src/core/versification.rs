@@ -0,0 +1,634 @@
+use crate::core::bible_core::Verse;
+use crate::core::types::book_name_to_id;
+use serde::{Deserialize, Serialize};
+
+/// How a translation numbers its chapters and verses. Comparisons line
+/// translations up on the English numbering of the King James Version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Versification {
+    /// The numbering of the King James Version
+    #[default]
+    English,
+    /// English chapters, but Psalm titles are numbered as verses, like the
+    /// bundled Statenvertaling
+    HebrewPsalmTitles,
+    /// Hebrew numbering: Psalm titles are verses and some chapters break
+    /// elsewhere, e.g. Malachi has three chapters and Joel four
+    Hebrew,
+    /// Greek and Latin Psalm numbering, as in translations from the Vulgate:
+    /// Psalms 9 and 10 are one Psalm, so are 114 and 115, while 116 and 147
+    /// are split in two. Psalm titles are verses; other books keep English
+    /// chapters
+    Vulgate,
+}
+
+/// Psalms whose title is verse 1 in Hebrew numbering
+const PSALMS_WITH_TITLE_VERSE: [u32; 59] = [
+    3, 4, 5, 6, 7, 8, 9, 12, 13, 18, 19, 20, 21, 22, 30, 31, 34, 36, 38, 39, 40, 41, 42, 44, 45,
+    46, 47, 48, 49, 53, 55, 56, 57, 58, 59, 61, 62, 63, 64, 65, 67, 68, 69, 70, 75, 76, 77, 80, 81,
+    83, 84, 85, 88, 89, 92, 102, 108, 140, 142,
+];
+
+/// Psalms whose title takes verses 1 and 2 in Hebrew numbering
+const PSALMS_WITH_TWO_TITLE_VERSES: [u32; 4] = [51, 52, 54, 60];
+
+/// Psalms whose title is verse 1 in Vulgate numbering, as in the Canisius translation
+const VULGATE_PSALMS_WITH_TITLE_VERSE: [u32; 57] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 17, 18, 19, 20, 21, 29, 30, 33, 35, 37, 38, 39, 40, 41, 44, 45,
+    46, 47, 48, 52, 54, 56, 57, 58, 60, 61, 62, 63, 64, 66, 67, 68, 69, 74, 75, 76, 79, 80, 82, 83,
+    84, 87, 88, 91, 101, 107, 139, 141,
+];
+
+/// Psalms whose title takes verses 1 and 2 in Vulgate numbering
+const VULGATE_PSALMS_WITH_TWO_TITLE_VERSES: [u32; 4] = [50, 51, 53, 59];
+
+/// Hebrew verses that are numbered differently in English, as
+/// (book, Hebrew chapter, first verse, last verse, English chapter, English first verse)
+const HEBREW_CHAPTER_BOUNDARIES: [(&str, u32, u32, u32, u32, u32); 74] = [
+    ("Genesis", 32, 1, 1, 31, 55),
+    ("Genesis", 32, 2, 33, 32, 1),
+    ("Exodus", 7, 26, 29, 8, 1),
+    ("Exodus", 8, 1, 28, 8, 5),
+    ("Exodus", 21, 37, 37, 22, 1),
+    ("Exodus", 22, 1, 30, 22, 2),
+    ("Leviticus", 5, 20, 26, 6, 1),
+    ("Leviticus", 6, 1, 23, 6, 8),
+    ("Numbers", 17, 1, 15, 16, 36),
+    ("Numbers", 17, 16, 28, 17, 1),
+    ("Numbers", 30, 1, 1, 29, 40),
+    ("Numbers", 30, 2, 17, 30, 1),
+    ("Deuteronomy", 13, 1, 1, 12, 32),
+    ("Deuteronomy", 13, 2, 19, 13, 1),
+    ("Deuteronomy", 23, 1, 1, 22, 30),
+    ("Deuteronomy", 23, 2, 26, 23, 1),
+    ("Deuteronomy", 28, 69, 69, 29, 1),
+    ("Deuteronomy", 29, 1, 28, 29, 2),
+    ("1 Samuel", 21, 1, 1, 20, 42),
+    ("1 Samuel", 21, 2, 16, 21, 1),
+    ("1 Samuel", 24, 1, 1, 23, 29),
+    ("1 Samuel", 24, 2, 23, 24, 1),
+    ("2 Samuel", 19, 1, 1, 18, 33),
+    ("2 Samuel", 19, 2, 44, 19, 1),
+    ("1 Kings", 5, 1, 14, 4, 21),
+    ("1 Kings", 5, 15, 32, 5, 1),
+    ("1 Kings", 22, 45, 54, 22, 44),
+    ("2 Kings", 12, 1, 1, 11, 21),
+    ("2 Kings", 12, 2, 22, 12, 1),
+    ("1 Chronicles", 5, 27, 41, 6, 1),
+    ("1 Chronicles", 6, 1, 66, 6, 16),
+    ("1 Chronicles", 12, 6, 41, 12, 5),
+    ("2 Chronicles", 1, 18, 18, 2, 1),
+    ("2 Chronicles", 2, 1, 17, 2, 2),
+    ("2 Chronicles", 13, 23, 23, 14, 1),
+    ("2 Chronicles", 14, 1, 14, 14, 2),
+    ("Nehemiah", 3, 33, 38, 4, 1),
+    ("Nehemiah", 4, 1, 17, 4, 7),
+    ("Nehemiah", 10, 1, 1, 9, 38),
+    ("Nehemiah", 10, 2, 40, 10, 1),
+    ("Job", 40, 25, 32, 41, 1),
+    ("Job", 41, 1, 26, 41, 9),
+    ("Ecclesiastes", 4, 17, 17, 5, 1),
+    ("Ecclesiastes", 5, 1, 19, 5, 2),
+    ("Song of Solomon", 7, 1, 1, 6, 13),
+    ("Song of Solomon", 7, 2, 14, 7, 1),
+    ("Isaiah", 8, 23, 23, 9, 1),
+    ("Isaiah", 9, 1, 20, 9, 2),
+    ("Isaiah", 64, 1, 11, 64, 2),
+    ("Jeremiah", 8, 23, 23, 9, 1),
+    ("Jeremiah", 9, 1, 25, 9, 2),
+    ("Ezekiel", 21, 1, 5, 20, 45),
+    ("Ezekiel", 21, 6, 37, 21, 1),
+    ("Daniel", 3, 31, 33, 4, 1),
+    ("Daniel", 4, 1, 34, 4, 4),
+    ("Daniel", 6, 1, 1, 5, 31),
+    ("Daniel", 6, 2, 29, 6, 1),
+    ("Hosea", 2, 1, 2, 1, 10),
+    ("Hosea", 2, 3, 25, 2, 1),
+    ("Hosea", 12, 1, 1, 11, 12),
+    ("Hosea", 12, 2, 15, 12, 1),
+    ("Hosea", 14, 1, 1, 13, 16),
+    ("Hosea", 14, 2, 10, 14, 1),
+    ("Joel", 3, 1, 5, 2, 28),
+    ("Joel", 4, 1, 21, 3, 1),
+    ("Jonah", 2, 1, 1, 1, 17),
+    ("Jonah", 2, 2, 11, 2, 1),
+    ("Micah", 4, 14, 14, 5, 1),
+    ("Micah", 5, 1, 14, 5, 2),
+    ("Nahum", 2, 1, 1, 1, 15),
+    ("Nahum", 2, 2, 14, 2, 1),
+    ("Zechariah", 2, 1, 4, 1, 18),
+    ("Zechariah", 2, 5, 17, 2, 1),
+    ("Malachi", 3, 19, 24, 4, 1),
+];
+
+/// Position of the Psalms in a 66-book Bible
+const PSALMS_BOOK_INDEX: usize = 18;
+
+fn is_book(book_index: usize, name: &str) -> bool {
+    book_name_to_id(name).is_some_and(|id| id as usize == book_index + 1)
+}
+
+/// The English chapter and verse of a verse numbered in `versification`.
+/// Books are identified by position; verse 0 is a Psalm title, which has no
+/// verse of its own in English.
+pub fn to_english_verse(
+    versification: Versification,
+    book_index: usize,
+    chapter: u32,
+    verse: u32,
+) -> (u32, u32) {
+    if versification == Versification::English {
+        return (chapter, verse);
+    }
+
+    if versification == Versification::Vulgate && book_index == PSALMS_BOOK_INDEX {
+        return vulgate_psalm_to_english(chapter, verse);
+    }
+
+    if book_index == PSALMS_BOOK_INDEX {
+        let title_verses = if PSALMS_WITH_TWO_TITLE_VERSES.contains(&chapter) {
+            2
+        } else if PSALMS_WITH_TITLE_VERSE.contains(&chapter) {
+            1
+        } else {
+            0
+        };
+        return (chapter, verse.saturating_sub(title_verses));
+    }
+
+    if versification == Versification::Hebrew {
+        let boundary =
+            HEBREW_CHAPTER_BOUNDARIES
+                .iter()
+                .find(|(book, hebrew_chapter, first, last, _, _)| {
+                    *hebrew_chapter == chapter
+                        && (*first..=*last).contains(&verse)
+                        && is_book(book_index, book)
+                });
+        if let Some((_, _, first, _, english_chapter, english_first)) = boundary {
+            return (*english_chapter, english_first + (verse - first));
+        }
+    }
+
+    (chapter, verse)
+}
+
+// Vulgate Psalm 9 holds English Psalms 9 and 10 and Psalm 113 holds 114 and 115;
+// English Psalms 116 and 147 are each two Psalms in the Vulgate
+fn vulgate_psalm_to_english(chapter: u32, verse: u32) -> (u32, u32) {
+    let title_verses = if VULGATE_PSALMS_WITH_TWO_TITLE_VERSES.contains(&chapter) {
+        2
+    } else if VULGATE_PSALMS_WITH_TITLE_VERSE.contains(&chapter) {
+        1
+    } else {
+        0
+    };
+    let verse = verse.saturating_sub(title_verses);
+    match chapter {
+        9 if verse > 20 => (10, verse - 20),
+        113 if verse > 8 => (115, verse - 8),
+        113 => (114, verse),
+        114 => (116, verse),
+        115 => (116, verse + 9),
+        146 => (147, verse),
+        147 => (147, verse + 11),
+        10..=145 => (chapter + 1, verse),
+        _ => (chapter, verse),
+    }
+}
+
+/// The chapters of a translation numbered in `versification` that hold
+/// verses of English chapter `chapter`
+pub fn source_chapters(versification: Versification, book_index: usize, chapter: u32) -> Vec<u32> {
+    if versification == Versification::Vulgate && book_index == PSALMS_BOOK_INDEX {
+        return match chapter {
+            10 => vec![9],
+            114 | 115 => vec![113],
+            116 => vec![114, 115],
+            147 => vec![146, 147],
+            11..=146 => vec![chapter - 1],
+            _ => vec![chapter],
+        };
+    }
+
+    let mut chapters = vec![chapter];
+    if versification == Versification::Hebrew {
+        for (book, hebrew_chapter, _, _, english_chapter, _) in HEBREW_CHAPTER_BOUNDARIES {
+            if english_chapter == chapter
+                && !chapters.contains(&hebrew_chapter)
+                && is_book(book_index, book)
+            {
+                chapters.push(hebrew_chapter);
+            }
+        }
+        chapters.sort_unstable();
+    }
+    chapters
+}
+
+/// A verse of one translation placed on the English verse it corresponds to
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappedVerse {
+    /// English verse number, 0 for a Psalm title
+    pub verse: u32,
+    /// The chapter and verse number the translation itself uses
+    pub own_chapter: u32,
+    pub own_verse: u32,
+    pub text: String,
+}
+
+/// The verses of a translation that belong to English chapter `chapter`,
+/// taken from its chapters numbered `own_chapter`
+pub fn map_verses(
+    versification: Versification,
+    book_index: usize,
+    chapter: u32,
+    own_chapter: u32,
+    verses: &[Verse],
+) -> Vec<MappedVerse> {
+    verses
+        .iter()
+        .filter_map(|verse| {
+            let (english_chapter, english_verse) =
+                to_english_verse(versification, book_index, own_chapter, verse.verse);
+            (english_chapter == chapter).then(|| MappedVerse {
+                verse: english_verse,
+                own_chapter,
+                own_verse: verse.verse,
+                text: verse.text.clone(),
+            })
+        })
+        .collect()
+}
+
+/// How one translation fills a single row of an aligned verse comparison
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlignedVerse {
+    /// The translation's text for this verse, under its own chapter and verse number
+    Text {
+        chapter: u32,
+        verse: u32,
+        text: String,
+    },
+    /// The translation merged this verse into the given earlier verse of its own
+    MergedInto(u32),
+    /// The translation has nothing at this position (e.g. the chapter ends earlier)
+    Missing,
+}
+
+/// A row of the comparison: an English verse number (0 for a Psalm title)
+/// and one cell per translation
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedRow {
+    pub verse: u32,
+    pub cells: Vec<AlignedVerse>,
+}
+
+/// Align several translations of the same chapter on their English verse numbers.
+///
+/// Each column holds verses already mapped with `map_verses`, so a Psalm title
+/// or a verse moved across a chapter boundary lands on the right row. An
+/// English verse a translation has no verse for counts as merged into the
+/// preceding verse when the translation goes on past it, either with a later
+/// verse or with a last verse numbered that high; otherwise it is missing.
+pub fn align_verses(columns: &[&[MappedVerse]]) -> Vec<AlignedRow> {
+    let mut verse_numbers: Vec<u32> = columns
+        .iter()
+        .flat_map(|verses| verses.iter().map(|v| v.verse))
+        .collect();
+    verse_numbers.sort_unstable();
+    verse_numbers.dedup();
+
+    verse_numbers
+        .into_iter()
+        .map(|verse| AlignedRow {
+            verse,
+            cells: columns
+                .iter()
+                .map(|verses| align_single(verses, verse))
+                .collect(),
+        })
+        .collect()
+}
+
+fn align_single(verses: &[MappedVerse], verse_number: u32) -> AlignedVerse {
+    // A title of two verses shares one row
+    let matching: Vec<&MappedVerse> = verses.iter().filter(|v| v.verse == verse_number).collect();
+    if let Some(first) = matching.first() {
+        return AlignedVerse::Text {
+            chapter: first.own_chapter,
+            verse: first.own_verse,
+            text: matching
+                .iter()
+                .map(|v| v.text.trim())
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+    }
+
+    let preceding = verses
+        .iter()
+        .filter(|v| v.verse < verse_number)
+        .max_by_key(|v| v.verse);
+    let goes_on = verses
+        .iter()
+        .any(|v| v.verse > verse_number || v.own_verse >= verse_number);
+
+    match preceding {
+        Some(preceding) if goes_on => AlignedVerse::MergedInto(preceding.own_verse),
+        _ => AlignedVerse::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verses(chapter: u32, texts: &[&str]) -> Vec<Verse> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| Verse {
+                verse: i as u32 + 1,
+                chapter,
+                name: format!("Test {}:{}", chapter, i + 1),
+                text: text.to_string(),
+            })
+            .collect()
+    }
+
+    fn english(numbers: &[u32]) -> Vec<MappedVerse> {
+        numbers
+            .iter()
+            .map(|&n| MappedVerse {
+                verse: n,
+                own_chapter: 1,
+                own_verse: n,
+                text: format!("text {}", n),
+            })
+            .collect()
+    }
+
+    fn text(cell: &AlignedVerse) -> &str {
+        match cell {
+            AlignedVerse::Text { text, .. } => text,
+            _ => "",
+        }
+    }
+
+    #[test]
+    fn test_align_identical_versification() {
+        let a = english(&[1, 2, 3]);
+        let b = english(&[1, 2, 3]);
+        let rows = align_verses(&[&a, &b]);
+
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|row| row
+            .cells
+            .iter()
+            .all(|c| matches!(c, AlignedVerse::Text { .. }))));
+    }
+
+    #[test]
+    fn test_align_merged_verse() {
+        let a = english(&[1, 2, 3, 4]);
+        // Verses 2 and 3 combined into verse 2
+        let b = english(&[1, 2, 4]);
+        let rows = align_verses(&[&a, &b]);
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[2].verse, 3);
+        assert_eq!(rows[2].cells[1], AlignedVerse::MergedInto(2));
+        assert_eq!(text(&rows[3].cells[1]), "text 4");
+    }
+
+    #[test]
+    fn test_align_shorter_chapter() {
+        let a = english(&[1, 2, 3]);
+        let b = english(&[1, 2]);
+        let rows = align_verses(&[&a, &b]);
+
+        assert_eq!(rows[2].cells[1], AlignedVerse::Missing);
+    }
+
+    #[test]
+    fn test_align_psalm_3_statenvertaling_with_kjv() {
+        let svv = verses(
+            3,
+            &[
+                "Een psalm van David, als hij vlood voor het aangezicht van zijn zoon Absalom.",
+                "O HEERE! hoe zijn mijn tegenpartijders vermenigvuldigd; velen staan tegen mij op.",
+                "Velen zeggen van mijn ziel: Hij heeft geen heil bij God. Sela.",
+                "Doch Gij, HEERE! zijt een Schild voor mij, mijn eer, en Die mijn hoofd opheft.",
+                "Ik riep met mijn stem tot den HEERE, en Hij verhoorde mij van den berg Zijner heiligheid. Sela.",
+                "Ik lag neder en sliep; ik ontwaakte, want de HEERE ondersteunde mij.",
+                "Ik zal niet vrezen voor tienduizenden des volks, die zich rondom tegen mij zetten.",
+                "Sta op, HEERE, verlos mij, mijn God; want Gij hebt al mijn vijanden op het kinnebakken geslagen; de tanden der goddelozen hebt Gij verbroken. Het heil is des HEEREN; Uw zegen is over Uw volk. Sela.",
+            ],
+        );
+        let kjv = verses(
+            3,
+            &[
+                "Lord, how are they increased that trouble me! many are they that rise up against me.",
+                "Many there be which say of my soul, There is no help for him in God. Selah.",
+                "But thou, O Lord, art a shield for me; my glory, and the lifter up of mine head.",
+                "I cried unto the Lord with my voice, and he heard me out of his holy hill. Selah.",
+                "I laid me down and slept; I awaked; for the Lord sustained me.",
+                "I will not be afraid of ten thousands of people, that have set themselves against me round about.",
+                "Arise, O Lord; save me, O my God: for thou hast smitten all mine enemies upon the cheek bone; thou hast broken the teeth of the ungodly.",
+                "Salvation belongeth unto the Lord: thy blessing is upon thy people. Selah.",
+            ],
+        );
+        let svv = map_verses(
+            Versification::HebrewPsalmTitles,
+            PSALMS_BOOK_INDEX,
+            3,
+            3,
+            &svv,
+        );
+        let kjv = map_verses(Versification::English, PSALMS_BOOK_INDEX, 3, 3, &kjv);
+        let rows = align_verses(&[&svv, &kjv]);
+
+        // The title gets a row of its own, which the KJV has no verse for
+        assert_eq!(rows[0].verse, 0);
+        assert!(text(&rows[0].cells[0]).starts_with("Een psalm van David"));
+        assert_eq!(rows[0].cells[1], AlignedVerse::Missing);
+
+        // "Lord, how are they increased" is the Statenvertaling's verse 2
+        assert_eq!(rows[1].verse, 1);
+        assert!(matches!(
+            rows[1].cells[0],
+            AlignedVerse::Text {
+                chapter: 3,
+                verse: 2,
+                ..
+            }
+        ));
+        assert!(text(&rows[1].cells[0]).starts_with("O HEERE! hoe zijn"));
+        assert!(text(&rows[1].cells[1]).starts_with("Lord, how are they increased"));
+        assert!(text(&rows[3].cells[0]).starts_with("Doch Gij, HEERE!"));
+        assert!(text(&rows[3].cells[1]).starts_with("But thou, O Lord"));
+
+        // Its last verse holds both verse 7 and 8 of the KJV
+        assert_eq!(rows.len(), 9);
+        assert!(text(&rows[7].cells[0]).starts_with("Sta op, HEERE"));
+        assert_eq!(rows[8].cells[0], AlignedVerse::MergedInto(8));
+    }
+
+    #[test]
+    fn test_hebrew_chapter_boundaries() {
+        let malachi = 38;
+        assert_eq!(
+            to_english_verse(Versification::Hebrew, malachi, 3, 18),
+            (3, 18)
+        );
+        assert_eq!(
+            to_english_verse(Versification::Hebrew, malachi, 3, 19),
+            (4, 1)
+        );
+        assert_eq!(
+            to_english_verse(Versification::Hebrew, malachi, 3, 24),
+            (4, 6)
+        );
+        assert_eq!(
+            source_chapters(Versification::Hebrew, malachi, 4),
+            vec![3, 4]
+        );
+
+        let joel = 28;
+        assert_eq!(to_english_verse(Versification::Hebrew, joel, 3, 1), (2, 28));
+        assert_eq!(
+            to_english_verse(Versification::Hebrew, joel, 4, 21),
+            (3, 21)
+        );
+        assert_eq!(source_chapters(Versification::Hebrew, joel, 2), vec![2, 3]);
+
+        // Translations with English chapters keep them
+        assert_eq!(
+            to_english_verse(Versification::HebrewPsalmTitles, joel, 3, 1),
+            (3, 1)
+        );
+        assert_eq!(
+            source_chapters(Versification::HebrewPsalmTitles, malachi, 4),
+            vec![4]
+        );
+
+        let psalm_51 = map_verses(
+            Versification::Hebrew,
+            PSALMS_BOOK_INDEX,
+            51,
+            51,
+            &verses(51, &["title", "occasion", "Have mercy upon me"]),
+        );
+        let numbers: Vec<u32> = psalm_51.iter().map(|verse| verse.verse).collect();
+        assert_eq!(numbers, vec![0, 0, 1]);
+    }
+
+    #[test]
+    fn test_vulgate_psalms_canisius_with_kjv() {
+        // Canisius Psalm 22 is the KJV's Psalm 23, its title run into verse 1
+        let pcv = verses(
+            22,
+            &[
+                "Een psalm van David. Mijn Herder is Jahweh! het ontbreekt mij aan niets:",
+                "Hij laat mij rusten in groene beemden;",
+            ],
+        );
+        let kjv = verses(
+            23,
+            &[
+                "The Lord is my shepherd; I shall not want.",
+                "He maketh me to lie down in green pastures: he leadeth me beside the still waters.",
+            ],
+        );
+        assert_eq!(
+            source_chapters(Versification::Vulgate, PSALMS_BOOK_INDEX, 23),
+            vec![22]
+        );
+        let pcv = map_verses(Versification::Vulgate, PSALMS_BOOK_INDEX, 23, 22, &pcv);
+        let kjv = map_verses(Versification::English, PSALMS_BOOK_INDEX, 23, 23, &kjv);
+        let rows = align_verses(&[&pcv, &kjv]);
+        assert_eq!(rows.len(), 2);
+        assert!(text(&rows[0].cells[0]).contains("Mijn Herder is Jahweh"));
+        assert!(text(&rows[0].cells[1]).starts_with("The Lord is my shepherd"));
+        assert!(text(&rows[1].cells[0]).starts_with("Hij laat mij rusten"));
+
+        // Psalm 113 holds the KJV's Psalms 114 and 115
+        let psalm_113 = verses(
+            113,
+            &[
+                "Halleluja! Toen Israël uit Egypte trok, Jakobs huis uit een volk van barbaren,",
+                "Werd Juda zijn heiligdom, En Israël zijn rijk.",
+                "De zee zag het, en sloeg op de vlucht, De Jordaan deinsde terug;",
+                "Als rammen sprongen de bergen weg, Als lammeren de heuvels.",
+                "Zee, wat was er, dat gij gingt vluchten, Jordaan, dat gij achteruit zijt geweken;",
+                "Bergen, dat gij wegsprongt als rammen, Gij heuvels als lammeren?",
+                "Voor den Heer kromp de aarde ineen, Voor het aangezicht van Jakobs God;",
+                "Die de rots in een vijver herschiep, De klip in een borrelende bron!",
+                "Niet ons, o Jahweh, niet ons, Maar uw Naam geef eer om uw goedheid en trouw!",
+                "Waarom zouden de heidenen zeggen: \"Waar is toch hun God?\"",
+            ],
+        );
+        assert_eq!(
+            source_chapters(Versification::Vulgate, PSALMS_BOOK_INDEX, 115),
+            vec![113]
+        );
+        let psalm_114 = map_verses(
+            Versification::Vulgate,
+            PSALMS_BOOK_INDEX,
+            114,
+            113,
+            &psalm_113,
+        );
+        assert_eq!(psalm_114.len(), 8);
+        assert!(psalm_114[7].text.starts_with("Die de rots in een vijver"));
+        let psalm_115 = map_verses(
+            Versification::Vulgate,
+            PSALMS_BOOK_INDEX,
+            115,
+            113,
+            &psalm_113,
+        );
+        assert_eq!(psalm_115[0].verse, 1);
+        assert_eq!(psalm_115[0].own_verse, 9);
+        assert!(psalm_115[0].text.starts_with("Niet ons, o Jahweh"));
+        assert!(psalm_115[1].text.starts_with("Waarom zouden de heidenen"));
+
+        // Psalm 9 has a title and runs on into the KJV's Psalm 10
+        assert_eq!(
+            to_english_verse(Versification::Vulgate, PSALMS_BOOK_INDEX, 9, 1),
+            (9, 0)
+        );
+        assert_eq!(
+            to_english_verse(Versification::Vulgate, PSALMS_BOOK_INDEX, 9, 21),
+            (9, 20)
+        );
+        assert_eq!(
+            to_english_verse(Versification::Vulgate, PSALMS_BOOK_INDEX, 9, 22),
+            (10, 1)
+        );
+        assert_eq!(
+            source_chapters(Versification::Vulgate, PSALMS_BOOK_INDEX, 116),
+            vec![114, 115]
+        );
+        assert_eq!(
+            to_english_verse(Versification::Vulgate, PSALMS_BOOK_INDEX, 115, 1),
+            (116, 10)
+        );
+
+        // Outside the Psalms the chapters are English
+        let malachi = 38;
+        assert_eq!(
+            to_english_verse(Versification::Vulgate, malachi, 4, 1),
+            (4, 1)
+        );
+        assert_eq!(source_chapters(Versification::Vulgate, malachi, 4), vec![4]);
+    }
+
+    #[test]
+    fn test_hebrew_chapter_boundaries_name_books() {
+        for (book, ..) in HEBREW_CHAPTER_BOUNDARIES {
+            assert!(book_name_to_id(book).is_some(), "{}", book);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::core::types::Language;
+use crate::storage::get_ui_language;
+use crate::storage::translations::get_current_translation;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+const ENGLISH_JSON: &str = include_str!("en.json");
+const DUTCH_JSON: &str = include_str!("nl.json");
+
+/// Languages the interface is available in
+pub const UI_LANGUAGES: [Language; 2] = [Language::English, Language::Dutch];
+
+static ENGLISH_STRINGS: LazyLock<HashMap<String, String>> =
+    LazyLock::new(|| serde_json::from_str(ENGLISH_JSON).expect("Failed to parse en.json"));
+
+static DUTCH_STRINGS: LazyLock<HashMap<String, String>> =
+    LazyLock::new(|| serde_json::from_str(DUTCH_JSON).expect("Failed to parse nl.json"));
+
+fn strings(language: Language) -> &'static HashMap<String, String> {
+    match language {
+        Language::Dutch => &DUTCH_STRINGS,
+        Language::English => &ENGLISH_STRINGS,
+    }
+}
+
+/// Resolved on first use; choosing another interface language reloads the page
+static UI_LANGUAGE: LazyLock<Language> = LazyLock::new(|| {
+    get_ui_language()
+        .or_else(|| get_current_translation()?.languages.first().copied())
+        .unwrap_or(Language::English)
+});
+
+/// The language the interface is shown in: the reader's choice, or else the
+/// language of the current translation
+pub fn ui_language() -> Language {
+    *UI_LANGUAGE
+}
+
+/// UI text for `key` in `language`, falling back to English and then to the key itself
+pub fn translate(language: Language, key: &str) -> String {
+    strings(language)
+        .get(key)
+        .or_else(|| ENGLISH_STRINGS.get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// UI text for `key` in the interface language
+pub fn t(key: &str) -> String {
+    translate(ui_language(), key)
+}
+
+/// UI text for `key` with each `{name}` placeholder replaced by its value
+pub fn t_with(key: &str, values: &[(&str, &str)]) -> String {
+    fill(t(key), values)
+}
+
+fn fill(mut text: String, values: &[(&str, &str)]) -> String {
+    for (name, value) in values {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locales_have_the_same_keys() {
+        let mut english: Vec<&String> = ENGLISH_STRINGS.keys().collect();
+        let mut dutch: Vec<&String> = DUTCH_STRINGS.keys().collect();
+        english.sort();
+        dutch.sort();
+        assert_eq!(english, dutch);
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(translate(Language::Dutch, "books"), "Boeken");
+        assert_eq!(translate(Language::English, "books"), "Books");
+        assert_eq!(translate(Language::Dutch, "unknown_key"), "unknown_key");
+        assert_eq!(
+            fill(
+                translate(Language::Dutch, "released_in"),
+                &[("year", "1637")]
+            ),
+            "Uitgegeven in 1637"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::core::bible_core::Bible;
+use crate::storage::{find_note, HighlightColor, VerseHighlight, VerseNote};
+use std::collections::HashMap;
+
+/// A Markdown document of every highlighted or annotated verse, grouped by book and chapter
+/// in Bible order. Each verse is quoted, followed by its highlight color and the reader's note.
+pub fn export_annotations_to_markdown(
+    bible: &Bible,
+    highlights: &[VerseHighlight],
+    notes: &[VerseNote],
+    labels: &HashMap<HighlightColor, String>,
+) -> String {
+    let mut markdown = String::from("# Notes and highlights\n");
+
+    for book in &bible.books {
+        let mut book_written = false;
+        for chapter in &book.chapters {
+            let key = bible.chapter_key(chapter);
+            let mut chapter_written = false;
+
+            for verse in &chapter.verses {
+                let highlight = highlights
+                    .iter()
+                    .find(|h| h.is_in(&key) && h.verse == verse.verse);
+                let note = find_note(notes, &key, verse.verse);
+                if highlight.is_none() && note.is_none() {
+                    continue;
+                }
+
+                if !book_written {
+                    markdown.push_str(&format!("\n## {}\n", book.name));
+                    book_written = true;
+                }
+                if !chapter_written {
+                    markdown.push_str(&format!("\n### {}\n", chapter.name));
+                    chapter_written = true;
+                }
+
+                markdown.push_str(&format!(
+                    "\n> **{}:{}** {}\n",
+                    chapter.name, verse.verse, verse.text
+                ));
+                if let Some(highlight) = highlight {
+                    let color = highlight.color.name();
+                    match labels.get(&highlight.color) {
+                        Some(label) => markdown.push_str(&format!("\n*{}: {}*\n", color, label)),
+                        None => markdown.push_str(&format!("\n*{}*\n", color)),
+                    }
+                }
+                if let Some(note) = note {
+                    markdown.push_str(&format!("\n{}\n", note.text));
+                }
+            }
+        }
+    }
+
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bible_core::{Book, Chapter, Verse};
+
+    fn chapter(book: &str, number: u32, texts: &[&str]) -> Chapter {
+        Chapter {
+            chapter: number,
+            name: format!("{} {}", book, number),
+            verses: texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| Verse {
+                    verse: i as u32 + 1,
+                    chapter: number,
+                    name: format!("{} {}:{}", book, number, i + 1),
+                    text: text.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_export_annotations_to_markdown() {
+        let bible = Bible::new(vec![
+            Book {
+                name: "Ruth".to_string(),
+                chapters: vec![chapter("Ruth", 1, &["In the days", "Naomi"])],
+            },
+            Book {
+                name: "John".to_string(),
+                chapters: vec![
+                    chapter("John", 1, &["In the beginning"]),
+                    chapter("John", 2, &["A wedding"]),
+                ],
+            },
+        ]);
+        let highlights = vec![VerseHighlight {
+            book: "john".to_string(),
+            chapter: 1,
+            verse: 1,
+            color: HighlightColor::Yellow,
+        }];
+        let notes = vec![
+            VerseNote {
+                book: "john".to_string(),
+                chapter: 1,
+                verse: 1,
+                text: "Compare Genesis 1".to_string(),
+            },
+            VerseNote {
+                book: "ruth".to_string(),
+                chapter: 1,
+                verse: 2,
+                text: "Pleasant".to_string(),
+            },
+        ];
+        let labels = HashMap::from([(HighlightColor::Yellow, "Promises".to_string())]);
+
+        assert_eq!(
+            export_annotations_to_markdown(&bible, &highlights, &notes, &labels),
+            "# Notes and highlights\n\
+             \n## Ruth\n\
+             \n### Ruth 1\n\
+             \n> **Ruth 1:2** Naomi\n\
+             \nPleasant\n\
+             \n## John\n\
+             \n### John 1\n\
+             \n> **John 1:1** In the beginning\n\
+             \n*Yellow: Promises*\n\
+             \nCompare Genesis 1\n"
+        );
+    }
+}
@@ -1,238 +1,121 @@
-use leptos::prelude::*;
-use wasm_bindgen_futures::spawn_local;
+use crate::core::SectionHeading;
+use crate::storage::section_headings::get_section_headings;
+use crate::storage::{get_selected_translation, get_show_section_headings};
+
+/// Section headings to include in exports; none when they are hidden for plain text
+async fn export_section_headings() -> Vec<SectionHeading> {
+    if !get_show_section_headings() {
+        return Vec::new();
+    }
+    match get_selected_translation() {
+        Some(short_name) => get_section_headings(&short_name).await,
+        None => Vec::new(),
+    }
+}
 
-/// Handle PDF export functionality
-pub fn handle_export_to_pdf(
-    set_is_pdf_exporting: WriteSignal<bool>,
-    set_pdf_progress: WriteSignal<f32>,
-    set_pdf_status: WriteSignal<String>,
-) {
-    web_sys::console::log_1(&"🎯 PDF Export instruction received!".into());
-    let set_is_pdf_exporting = set_is_pdf_exporting.clone();
-    let set_pdf_progress = set_pdf_progress.clone();
-    let set_pdf_status = set_pdf_status.clone();
-    spawn_local(async move {
-        web_sys::console::log_1(&"🚀 Setting PDF export flags...".into());
-        set_is_pdf_exporting.set(true);
-        set_pdf_progress.set(0.0);
-        set_pdf_status.set("Getting Bible data...".to_string());
-        web_sys::console::log_1(&"✅ PDF export flags set".into());
+fn log_progress(progress: f32, status: String) {
+    web_sys::console::log_1(&format!("⏳ {:.0}% {}", progress * 100.0, status).into());
+}
 
-        web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
-        let bible = crate::core::get_current_bible().unwrap_or_else(|| {
-            web_sys::console::log_1(&"⚠️ No current Bible found, using default".into());
-            crate::core::get_bible().clone()
+// The file name of an export of the current translation, e.g. `King_James_Bible.pdf`
+fn export_filename(suffix: &str) -> String {
+    let name = crate::storage::translations::get_current_translation()
+        .map(|translation| translation.name)
+        .unwrap_or_else(|| {
+            web_sys::console::log_1(&"⚠️ No translation info found, using default".into());
+            "Unknown_Bible".to_string()
         });
-        web_sys::console::log_1(
-            &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
-        );
-
-        // Create progress callback
-        let progress_callback = {
-            let set_progress = set_pdf_progress.clone();
-            let set_status = set_pdf_status.clone();
-            move |progress: f32, status: String| {
-                set_progress.set(progress);
-                set_status.set(status);
-            }
-        };
-
-        web_sys::console::log_1(&"🔄 Starting PDF generation...".into());
-        match crate::instructions::logic::export_bible_to_pdf(&bible, Some(progress_callback)) {
-            Ok(pdf_bytes) => {
-                web_sys::console::log_1(
-                    &format!("✅ PDF generation successful! {} bytes", pdf_bytes.len()).into(),
-                );
-                set_pdf_status.set("Preparing download...".to_string());
-
-                let translation_info = crate::storage::translations::get_current_translation()
-                    .unwrap_or_else(|| {
-                        web_sys::console::log_1(
-                            &"⚠️ No translation info found, using default".into(),
-                        );
-                        crate::storage::translation_storage::BibleTranslation {
-                            name: "Unknown_Bible".to_string(),
-                            short_name: "unknown".to_string(),
-                            release_year: 2024,
-                            languages: vec![],
-                            iagon: "".to_string(),
-                        }
-                    });
-                let filename = format!("{}_Bible.pdf", translation_info.name.replace(" ", "_"));
-                web_sys::console::log_1(&format!("📁 Generated filename: {}", filename).into());
+    let filename = format!("{}_{}", name.replace(" ", "_"), suffix);
+    web_sys::console::log_1(&format!("📁 Generated filename: {}", filename).into());
+    filename
+}
 
-                web_sys::console::log_1(&"🔽 Triggering PDF download...".into());
-                crate::instructions::logic::trigger_pdf_download(pdf_bytes, &filename);
-            }
-            Err(e) => {
-                web_sys::console::log_1(&format!("❌ Failed to generate PDF: {:?}", e).into());
-                set_pdf_status.set("Export failed!".to_string());
-            }
+/// Export the whole current translation as a PDF download
+pub async fn export_to_pdf() {
+    web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
+    crate::api::load_all_books().await;
+    let bible = crate::core::get_bible();
+    web_sys::console::log_1(
+        &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
+    );
+
+    let headings = export_section_headings().await;
+    match crate::instructions::logic::export_bible_to_pdf(&bible, &headings, Some(log_progress)) {
+        Ok(pdf_bytes) => {
+            web_sys::console::log_1(
+                &format!("✅ PDF generation successful! {} bytes", pdf_bytes.len()).into(),
+            );
+            let filename = export_filename("Bible.pdf");
+            crate::instructions::logic::trigger_pdf_download(pdf_bytes, &filename);
         }
-        set_is_pdf_exporting.set(false);
-    });
+        Err(e) => {
+            web_sys::console::log_1(&format!("❌ Failed to generate PDF: {:?}", e).into());
+        }
+    }
 }
 
-/// Handle Markdown export functionality
-pub fn handle_export_to_markdown(
-    set_is_pdf_exporting: WriteSignal<bool>,
-    set_pdf_progress: WriteSignal<f32>,
-    set_pdf_status: WriteSignal<String>,
-) {
-    web_sys::console::log_1(&"📝 Markdown Export instruction received!".into());
-    let set_is_pdf_exporting = set_is_pdf_exporting.clone();
-    let set_pdf_progress = set_pdf_progress.clone();
-    let set_pdf_status = set_pdf_status.clone();
-    spawn_local(async move {
-        web_sys::console::log_1(&"🚀 Setting Markdown export flags...".into());
-        set_is_pdf_exporting.set(true);
-        set_pdf_progress.set(0.0);
-        set_pdf_status.set("Getting Bible data...".to_string());
-        web_sys::console::log_1(&"✅ Markdown export flags set".into());
-
-        web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
-        let bible = crate::core::get_current_bible().unwrap_or_else(|| {
-            web_sys::console::log_1(&"⚠️ No current Bible found, using default".into());
-            crate::core::get_bible().clone()
-        });
-        web_sys::console::log_1(
-            &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
-        );
-
-        // Create progress callback
-        let progress_callback = {
-            let set_progress = set_pdf_progress.clone();
-            let set_status = set_pdf_status.clone();
-            move |progress: f32, status: String| {
-                set_progress.set(progress);
-                set_status.set(status);
-            }
-        };
-
-        web_sys::console::log_1(&"🔄 Starting Markdown generation...".into());
-        match crate::instructions::logic::export_bible_to_markdown(&bible, Some(progress_callback))
-        {
-            Ok(markdown_content) => {
-                web_sys::console::log_1(
-                    &format!(
-                        "✅ Markdown generation successful! {} characters",
-                        markdown_content.len()
-                    )
-                    .into(),
-                );
-                set_pdf_status.set("Preparing download...".to_string());
-
-                let translation_info = crate::storage::translations::get_current_translation()
-                    .unwrap_or_else(|| {
-                        web_sys::console::log_1(
-                            &"⚠️ No translation info found, using default".into(),
-                        );
-                        crate::storage::translation_storage::BibleTranslation {
-                            name: "Unknown_Bible".to_string(),
-                            short_name: "unknown".to_string(),
-                            release_year: 2024,
-                            languages: vec![],
-                            iagon: "".to_string(),
-                        }
-                    });
-                let filename = format!("{}_Bible.md", translation_info.name.replace(" ", "_"));
-                web_sys::console::log_1(&format!("📁 Generated filename: {}", filename).into());
-
-                web_sys::console::log_1(&"🔽 Triggering Markdown download...".into());
-                crate::instructions::logic::trigger_markdown_download(markdown_content, &filename);
-            }
-            Err(e) => {
-                web_sys::console::log_1(&format!("❌ Failed to generate Markdown: {:?}", e).into());
-                set_pdf_status.set("Export failed!".to_string());
-            }
+/// Export the whole current translation as a Markdown download
+pub async fn export_to_markdown() {
+    web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
+    crate::api::load_all_books().await;
+    let bible = crate::core::get_bible();
+    web_sys::console::log_1(
+        &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
+    );
+
+    let headings = export_section_headings().await;
+    match crate::instructions::logic::export_bible_to_markdown(
+        &bible,
+        &headings,
+        Some(log_progress),
+    ) {
+        Ok(markdown_content) => {
+            web_sys::console::log_1(
+                &format!(
+                    "✅ Markdown generation successful! {} characters",
+                    markdown_content.len()
+                )
+                .into(),
+            );
+            let filename = export_filename("Bible.md");
+            crate::instructions::logic::trigger_markdown_download(markdown_content, &filename);
+        }
+        Err(e) => {
+            web_sys::console::log_1(&format!("❌ Failed to generate Markdown: {:?}", e).into());
         }
-        set_is_pdf_exporting.set(false);
-    });
+    }
 }
 
-/// Handle Linked Markdown export functionality
-pub fn handle_export_linked_markdown(
-    set_is_pdf_exporting: WriteSignal<bool>,
-    set_pdf_progress: WriteSignal<f32>,
-    set_pdf_status: WriteSignal<String>,
-) {
-    web_sys::console::log_1(&"🔗 Linked Markdown Export instruction received!".into());
-    let set_is_pdf_exporting = set_is_pdf_exporting.clone();
-    let set_pdf_progress = set_pdf_progress.clone();
-    let set_pdf_status = set_pdf_status.clone();
-    spawn_local(async move {
-        web_sys::console::log_1(&"🚀 Setting Linked Markdown export flags...".into());
-        set_is_pdf_exporting.set(true);
-        set_pdf_progress.set(0.0);
-        set_pdf_status.set("Getting Bible data...".to_string());
-        web_sys::console::log_1(&"✅ Linked Markdown export flags set".into());
-
-        web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
-        let bible = crate::core::get_current_bible().unwrap_or_else(|| {
-            web_sys::console::log_1(&"⚠️ No current Bible found, using default".into());
-            crate::core::get_bible().clone()
-        });
-        web_sys::console::log_1(
-            &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
-        );
-
-        // Create progress callback
-        let progress_callback = {
-            let set_progress = set_pdf_progress.clone();
-            let set_status = set_pdf_status.clone();
-            move |progress: f32, status: String| {
-                set_progress.set(progress);
-                set_status.set(status);
-            }
-        };
-
-        web_sys::console::log_1(&"🔄 Starting Linked Markdown generation...".into());
-        match crate::instructions::logic::export_bible_to_linked_markdown(
-            &bible,
-            Some(progress_callback),
-        ) {
-            Ok(linked_export) => {
-                web_sys::console::log_1(
-                    &format!(
-                        "✅ Linked Markdown generation successful! {} files created",
-                        linked_export.files.len()
-                    )
-                    .into(),
-                );
-                set_pdf_status.set("Preparing download...".to_string());
-
-                let translation_info = crate::storage::translations::get_current_translation()
-                    .unwrap_or_else(|| {
-                        web_sys::console::log_1(
-                            &"⚠️ No translation info found, using default".into(),
-                        );
-                        crate::storage::translation_storage::BibleTranslation {
-                            name: "Unknown_Bible".to_string(),
-                            short_name: "unknown".to_string(),
-                            release_year: 2024,
-                            languages: vec![],
-                            iagon: "".to_string(),
-                        }
-                    });
-                let filename = format!(
-                    "{}_Obsidian_Vault.zip",
-                    translation_info.name.replace(" ", "_")
-                );
-                web_sys::console::log_1(&format!("📁 Generated filename: {}", filename).into());
-
-                web_sys::console::log_1(&"🔽 Triggering Linked Markdown download...".into());
-                crate::instructions::logic::trigger_linked_markdown_download(
-                    linked_export,
-                    &filename,
-                );
-            }
-            Err(e) => {
-                web_sys::console::log_1(
-                    &format!("❌ Failed to generate Linked Markdown: {:?}", e).into(),
-                );
-                set_pdf_status.set("Export failed!".to_string());
-            }
+/// Export the whole current translation as a zip of linked Markdown files
+pub async fn export_linked_markdown() {
+    web_sys::console::log_1(&"🔄 Getting current Bible data...".into());
+    crate::api::load_all_books().await;
+    let bible = crate::core::get_bible();
+    web_sys::console::log_1(
+        &format!("✅ Bible data obtained with {} books", bible.books.len()).into(),
+    );
+
+    let headings = export_section_headings().await;
+    match crate::instructions::logic::export_bible_to_linked_markdown(
+        &bible,
+        &headings,
+        Some(log_progress),
+    ) {
+        Ok(linked_export) => {
+            web_sys::console::log_1(
+                &format!(
+                    "✅ Linked Markdown generation successful! {} files created",
+                    linked_export.files.len()
+                )
+                .into(),
+            );
+            let filename = export_filename("Obsidian_Vault.zip");
+            crate::instructions::logic::trigger_linked_markdown_download(linked_export, &filename);
+        }
+        Err(e) => {
+            web_sys::console::log_1(
+                &format!("❌ Failed to generate Linked Markdown: {:?}", e).into(),
+            );
         }
-        set_is_pdf_exporting.set(false);
-    });
+    }
 }
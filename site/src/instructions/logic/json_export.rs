@@ -0,0 +1,58 @@
+use crate::core::Bible;
+use web_sys::console;
+
+/// Serialize a Bible to the JSON format accepted by the custom translation import
+pub fn export_bible_to_json(bible: &Bible) -> Result<String, String> {
+    serde_json::to_string_pretty(bible).map_err(|e| format!("Failed to serialize Bible: {}", e))
+}
+
+/// Trigger JSON download in the browser
+pub fn trigger_json_download(json_content: String, filename: &str) {
+    use wasm_bindgen::JsCast;
+    use web_sys::{window, Blob, HtmlAnchorElement, Url};
+
+    let Some(document) = window().and_then(|w| w.document()) else {
+        console::log_1(&"❌ Failed to get document object".into());
+        return;
+    };
+
+    let array = js_sys::Array::new();
+    array.push(&js_sys::Uint8Array::from(json_content.as_bytes()));
+
+    let blob_options = web_sys::BlobPropertyBag::new();
+    blob_options.set_type("application/json");
+
+    let blob = match Blob::new_with_u8_array_sequence_and_options(&array, &blob_options) {
+        Ok(b) => b,
+        Err(e) => {
+            console::log_1(&format!("❌ Failed to create blob: {:?}", e).into());
+            return;
+        }
+    };
+
+    let url = match Url::create_object_url_with_blob(&blob) {
+        Ok(u) => u,
+        Err(e) => {
+            console::log_1(&format!("❌ Failed to create object URL: {:?}", e).into());
+            return;
+        }
+    };
+
+    let anchor = match document
+        .create_element("a")
+        .ok()
+        .and_then(|elem| elem.dyn_into::<HtmlAnchorElement>().ok())
+    {
+        Some(a) => a,
+        None => {
+            console::log_1(&"❌ Failed to create anchor element".into());
+            return;
+        }
+    };
+
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    let _ = Url::revoke_object_url(&url);
+}
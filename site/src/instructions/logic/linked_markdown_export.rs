@@ -1,4 +1,6 @@
 use crate::core::bible_core::Bible;
+use crate::core::SectionHeading;
+use crate::instructions::logic::markdown_export::chapter_markdown;
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
 use std::collections::HashMap;
@@ -43,6 +45,7 @@ pub struct LinkedMarkdownExport {
 /// Core Linked Markdown export business logic
 pub fn export_bible_to_linked_markdown<F>(
     bible: &Bible,
+    headings: &[SectionHeading],
     progress_callback: Option<F>,
 ) -> Result<LinkedMarkdownExport, Box<dyn std::error::Error>>
 where
@@ -64,6 +67,9 @@ where
             release_year: 2024,
             languages: vec![],
             iagon: "".to_string(),
+            revision: 0,
+            changelog: Vec::new(),
+            versification: Default::default(),
         }
     });
 
@@ -244,11 +250,9 @@ where
 
             chapter_content.push_str("\n");
 
-            // Add verses
-            for verse in &chapter.verses {
-                verse_count += 1;
-                chapter_content.push_str(&format!("{} {}\n", verse.verse, verse.text));
-            }
+            // Add verses under their section headings
+            verse_count += chapter.verses.len();
+            chapter_content.push_str(&chapter_markdown(chapter, book_count - 1, headings));
 
             files.insert(chapter_filename, chapter_content);
         }
@@ -1,4 +1,5 @@
-use crate::core::bible_core::Bible;
+use crate::core::bible_core::{Bible, Chapter};
+use crate::core::{chapter_titles, headings_by_verse, SectionHeading};
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
 use web_sys::console;
@@ -42,9 +43,37 @@ fn get_translated_book_name(book_name: &str) -> String {
     book_name.to_string()
 }
 
+/// A chapter's verses as "number text" lines, after its titles in italics and
+/// with each section heading as a `###` heading before the verse it starts
+pub fn chapter_markdown(
+    chapter: &Chapter,
+    book_index: usize,
+    headings: &[SectionHeading],
+) -> String {
+    let mut markdown = String::new();
+    for title in chapter_titles(headings, book_index, chapter.chapter) {
+        markdown.push_str(&format!("*{}*\n\n", title));
+    }
+
+    let sections = headings_by_verse(headings, book_index, chapter.chapter);
+    for (index, verse) in chapter.verses.iter().enumerate() {
+        if let Some(titles) = sections.get(&verse.verse) {
+            if index > 0 {
+                markdown.push('\n');
+            }
+            for title in titles {
+                markdown.push_str(&format!("### {}\n\n", title));
+            }
+        }
+        markdown.push_str(&format!("{} {}\n", verse.verse, verse.text));
+    }
+    markdown
+}
+
 /// Core Markdown export business logic
 pub fn export_bible_to_markdown<F>(
     bible: &Bible,
+    headings: &[SectionHeading],
     progress_callback: Option<F>,
 ) -> Result<String, Box<dyn std::error::Error>>
 where
@@ -66,6 +95,9 @@ where
             release_year: 2024,
             languages: vec![],
             iagon: "".to_string(),
+            revision: 0,
+            changelog: Vec::new(),
+            versification: Default::default(),
         }
     });
 
@@ -136,13 +168,9 @@ where
                 chapter.chapter
             ));
 
-            // Render verses with verse numbers at the start
-            for verse in &chapter.verses {
-                verse_count += 1;
-
-                // Simple format: verse number followed by verse text, single line break
-                markdown.push_str(&format!("{} {}\n", verse.verse, verse.text));
-            }
+            // Render verses with verse numbers at the start, under their section headings
+            verse_count += chapter.verses.len();
+            markdown.push_str(&chapter_markdown(chapter, book_count - 1, headings));
 
             // Add double line break after chapter
             markdown.push_str("\n");
@@ -321,3 +349,56 @@ pub fn trigger_markdown_download(markdown_content: String, filename: &str) {
 
     console::log_1(&format!("🎉 Markdown download process completed! File: {}", filename).into());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bible_core::Verse;
+    use crate::core::HeadingKind;
+
+    #[test]
+    fn test_chapter_markdown() {
+        let chapter = Chapter {
+            chapter: 3,
+            name: "Psalms 3".to_string(),
+            verses: (1..=3)
+                .map(|verse| Verse {
+                    verse,
+                    chapter: 3,
+                    name: format!("Psalms 3:{}", verse),
+                    text: format!("Verse {}", verse),
+                })
+                .collect(),
+        };
+        let heading = |verse: u32, title: &str, kind: HeadingKind| SectionHeading {
+            book_index: 18,
+            chapter: 3,
+            verse,
+            title: title.to_string(),
+            kind,
+        };
+        let headings = vec![
+            heading(1, "A Psalm of David", HeadingKind::ChapterTitle),
+            heading(3, "Deliverance", HeadingKind::Section),
+        ];
+
+        assert_eq!(
+            chapter_markdown(&chapter, 18, &headings),
+            "*A Psalm of David*\n\n1 Verse 1\n2 Verse 2\n\n### Deliverance\n\n3 Verse 3\n"
+        );
+        assert_eq!(
+            chapter_markdown(&chapter, 18, &[]),
+            "1 Verse 1\n2 Verse 2\n3 Verse 3\n"
+        );
+
+        // Headings of other chapters and books are left out
+        let mut elsewhere = heading(2, "Elsewhere", HeadingKind::Section);
+        elsewhere.chapter = 4;
+        let mut other_book = heading(2, "Other book", HeadingKind::Section);
+        other_book.book_index = 19;
+        assert_eq!(
+            chapter_markdown(&chapter, 18, &[elsewhere, other_book]),
+            "1 Verse 1\n2 Verse 2\n3 Verse 3\n"
+        );
+    }
+}
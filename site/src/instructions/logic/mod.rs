@@ -1,5 +1,6 @@
-pub mod event_handlers;
+pub mod annotations_export;
 pub mod export_handlers;
+pub mod json_export;
 pub mod linked_markdown_export;
 pub mod markdown_export;
 pub mod navigation_handlers;
@@ -10,6 +11,8 @@ pub mod ui_toggles;
 pub use navigation_handlers::update_view_state_from_url;
 
 // Re-export business logic functions
+pub use annotations_export::export_annotations_to_markdown;
+pub use json_export::{export_bible_to_json, trigger_json_download};
 pub use linked_markdown_export::{
     export_bible_to_linked_markdown, trigger_linked_markdown_download,
 };
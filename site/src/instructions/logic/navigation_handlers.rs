@@ -65,7 +65,7 @@ pub fn handle_go_to_verse<F>(
     view_state: ViewStateSignal,
     processor: &InstructionProcessor<F>,
 ) where
-    F: Fn(&str, NavigateOptions) + Clone,
+    F: Fn(&str, NavigateOptions) + Clone + 'static,
 {
     // Process the instruction using the view state
     view_state.with(|state| {
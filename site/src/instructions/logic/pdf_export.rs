@@ -1,10 +1,30 @@
 use crate::core::bible_core::Bible;
+use crate::core::{chapter_titles, headings_by_verse, SectionHeading};
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
 use printpdf::*;
 use std::io::BufWriter;
 use web_sys::console;
 
+/// Split text into lines of at most `max_chars` characters at word boundaries
+fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line_buffer = String::new();
+    for word in text.split_whitespace() {
+        if !line_buffer.is_empty() && line_buffer.len() + 1 + word.len() > max_chars {
+            lines.push(std::mem::take(&mut line_buffer));
+        }
+        if !line_buffer.is_empty() {
+            line_buffer.push(' ');
+        }
+        line_buffer.push_str(word);
+    }
+    if !line_buffer.is_empty() {
+        lines.push(line_buffer);
+    }
+    lines
+}
+
 /// Add book header to the top of a page
 fn add_book_header_to_page(
     layer: &PdfLayerReference,
@@ -63,6 +83,7 @@ fn get_translated_book_name(book_name: &str) -> String {
 /// Core PDF export business logic
 pub fn export_bible_to_pdf<F>(
     bible: &Bible,
+    headings: &[SectionHeading],
     progress_callback: Option<F>,
 ) -> Result<Vec<u8>, Box<dyn std::error::Error>>
 where
@@ -84,6 +105,9 @@ where
             release_year: 2024,
             languages: vec![],
             iagon: "".to_string(),
+            revision: 0,
+            changelog: Vec::new(),
+            versification: Default::default(),
         }
     });
 
@@ -243,14 +267,38 @@ where
 
             current_y -= line_height * 2.0;
 
-            // Render verses with subscript verse numbers and continuous flow
+            // Chapter titles in italics, then the verses with subscript verse numbers in
+            // continuous flow, broken where a section heading starts
             let max_chars_per_line = 85;
+            let mut lines: Vec<(String, f32, &IndirectFontRef)> = Vec::new();
+            for title in chapter_titles(headings, book_count - 1, chapter.chapter) {
+                for line in wrap_text(&title, max_chars_per_line) {
+                    lines.push((line, 10.0, &italic_font));
+                }
+            }
+            let sections = headings_by_verse(headings, book_count - 1, chapter.chapter);
             let mut current_line = String::new();
             let mut first_verse_in_chapter = true;
 
             for verse in &chapter.verses {
                 verse_count += 1;
 
+                if let Some(titles) = sections.get(&verse.verse) {
+                    for line in wrap_text(&current_line, max_chars_per_line) {
+                        lines.push((line, 11.0, &font));
+                    }
+                    current_line.clear();
+                    if !lines.is_empty() {
+                        lines.push((String::new(), 11.0, &font));
+                    }
+                    for title in titles {
+                        for line in wrap_text(title, max_chars_per_line) {
+                            lines.push((line, 12.0, &bold_font));
+                        }
+                    }
+                    first_verse_in_chapter = true;
+                }
+
                 // Add space before verse number if not first verse in chapter
                 if !first_verse_in_chapter {
                     current_line.push(' ');
@@ -266,53 +314,33 @@ where
                 current_line.push_str(&verse.text);
                 current_line.push(' '); // Space between verses
             }
-
-            // Split the continuous text into lines that fit the page width
-            let words: Vec<&str> = current_line.split_whitespace().collect();
-            let mut line_buffer = String::new();
-
-            for word in words {
-                let test_line = if line_buffer.is_empty() {
-                    word.to_string()
-                } else {
-                    format!("{} {}", line_buffer, word)
-                };
-
-                // Check if this line would be too long
-                if test_line.len() > max_chars_per_line && !line_buffer.is_empty() {
-                    // Render the current line and start a new one
-                    current_layer_ref.use_text(&line_buffer, 11.0, margin_left, current_y, &font);
-                    current_y -= line_height;
-
-                    // Check if we need a new page
-                    if current_y < page_bottom_margin + line_height {
-                        console::log_1(&"📄 Adding new page for chapter text".into());
-                        let (new_page, new_layer) = doc.add_page(Mm(210.0), Mm(297.0), "Layer 1");
-                        current_page = new_page;
-                        current_layer_ref = doc.get_page(current_page).get_layer(new_layer);
-                        current_y = Mm(270.0);
-
-                        // Add book header to new page
-                        add_book_header_to_page(
-                            &current_layer_ref,
-                            &current_book_name,
-                            margin_left,
-                            &italic_font,
-                        );
-                    }
-
-                    // Start new line with current word
-                    line_buffer = word.to_string();
-                } else {
-                    // Add word to current line
-                    line_buffer = test_line;
-                }
+            for line in wrap_text(&current_line, max_chars_per_line) {
+                lines.push((line, 11.0, &font));
             }
 
-            // Render any remaining text
-            if !line_buffer.is_empty() {
-                current_layer_ref.use_text(&line_buffer, 11.0, margin_left, current_y, &font);
+            let line_count = lines.len();
+            for (index, (line, font_size, line_font)) in lines.into_iter().enumerate() {
+                if !line.is_empty() {
+                    current_layer_ref.use_text(&line, font_size, margin_left, current_y, line_font);
+                }
                 current_y -= line_height;
+
+                // Check if we need a new page
+                if index + 1 < line_count && current_y < page_bottom_margin + line_height {
+                    console::log_1(&"📄 Adding new page for chapter text".into());
+                    let (new_page, new_layer) = doc.add_page(Mm(210.0), Mm(297.0), "Layer 1");
+                    current_page = new_page;
+                    current_layer_ref = doc.get_page(current_page).get_layer(new_layer);
+                    current_y = Mm(270.0);
+
+                    // Add book header to new page
+                    add_book_header_to_page(
+                        &current_layer_ref,
+                        &current_book_name,
+                        margin_left,
+                        &italic_font,
+                    );
+                }
             }
 
             // Move to next line after chapter content
@@ -1,5 +1,14 @@
+use super::logic::export_handlers::{export_linked_markdown, export_to_markdown, export_to_pdf};
+use super::logic::{
+    export_annotations_to_markdown, trigger_json_download, trigger_markdown_download,
+};
 use super::types::Instruction;
-use crate::core::{get_bible, VerseRange};
+use crate::api::load_all_books;
+use crate::core::{daily_reading_link, get_bible, todays_readings, VerseRange};
+use crate::storage::annotation_backup::AnnotationBackup;
+use crate::storage::get_highlight_labels;
+use crate::storage::pinned_chapters::get_pinned_chapters;
+use crate::storage::registers::{get_register, store_in_register};
 use crate::storage::translations::get_current_translation;
 use crate::translation_map::translation::Translation;
 use crate::view_state::AppState;
@@ -46,6 +55,47 @@ pub fn get_random_chapter_path() -> Option<String> {
         .map(|chapter| chapter.to_path())
 }
 
+// Picks a verse from the loaded books of the current Bible
+fn get_random_verse_path() -> Option<String> {
+    let bible = get_bible();
+
+    // Calculate total number of verses in the entire Bible
+    let mut total_verses = 0;
+    let mut verse_locations = Vec::new();
+
+    for book in &bible.books {
+        for chapter in &book.chapters {
+            for verse in &chapter.verses {
+                verse_locations.push((chapter, verse.verse));
+                total_verses += 1;
+            }
+        }
+    }
+
+    if total_verses == 0 {
+        return None; // No verses found
+    }
+
+    // Get the current counter value and increment it for next time
+    let counter = RANDOM_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    // Use a simple linear congruential generator with the counter as seed
+    let mut rng_state = counter.wrapping_mul(1103515245).wrapping_add(12345);
+    rng_state = rng_state.wrapping_mul(1664525).wrapping_add(1013904223);
+
+    let random_index = rng_state % total_verses;
+
+    // Ensure the index is within bounds
+    let safe_index = random_index.min(total_verses - 1);
+
+    verse_locations.get(safe_index).map(|(chapter, verse_num)| {
+        chapter.to_path_with_verses(&[VerseRange {
+            start: *verse_num,
+            end: *verse_num,
+        }])
+    })
+}
+
 pub struct InstructionProcessor<F>
 where
     F: Fn(&str, NavigateOptions),
@@ -55,7 +105,7 @@ where
 
 impl<F> InstructionProcessor<F>
 where
-    F: Fn(&str, NavigateOptions),
+    F: Fn(&str, NavigateOptions) + Clone + 'static,
 {
     pub fn new(navigate: F) -> Self {
         Self { navigate }
@@ -94,14 +144,46 @@ where
             }
             Instruction::BeginningOfChapter => self.handle_beginning_of_chapter(context),
             Instruction::EndOfChapter => self.handle_end_of_chapter(context),
+            Instruction::NextParagraph => self.handle_paragraph(context, multiplier, true),
+            Instruction::PreviousParagraph => self.handle_paragraph(context, multiplier, false),
             Instruction::GoToVerse(verse_id) => self.handle_go_to_verse(context, verse_id),
             Instruction::CopyRawVerse => self.handle_copy_raw_verse(context),
             Instruction::CopyVerseWithReference => self.handle_copy_verse_with_reference(context),
+            Instruction::CopyRawVerseToRegister(name) => {
+                self.handle_copy_to_register(name, self.raw_verse_text(context))
+            }
+            Instruction::CopyVerseWithReferenceToRegister(name) => {
+                self.handle_copy_to_register(name, self.verse_text_with_reference(context))
+            }
+            Instruction::PasteRegister(name) => self.handle_paste_register(name),
+            Instruction::ExportRegister(name) => self.handle_export_register(name),
             Instruction::OpenGithubRepository => self.handle_open_github_repository(),
             Instruction::RandomVerse => self.handle_random_verse(),
             Instruction::RandomChapter => self.handle_random_chapter(),
+            Instruction::PsalmOfTheDay => {
+                self.handle_daily_reading("psalms", todays_readings().psalm)
+            }
+            Instruction::ProverbOfTheDay => {
+                self.handle_daily_reading("proverbs", todays_readings().proverb)
+            }
             Instruction::OpenAboutPage => self.handle_open_about_page(),
+            Instruction::OpenPeopleIndex => self.handle_open_people_index(),
+            Instruction::OpenTimeline => self.handle_open_timeline(),
             Instruction::ShowTranslations => self.handle_show_translations(),
+            Instruction::ExportToPDF => {
+                spawn_local(export_to_pdf());
+                true
+            }
+            Instruction::ExportToMarkdown => {
+                spawn_local(export_to_markdown());
+                true
+            }
+            Instruction::ExportLinkedMarkdown => {
+                spawn_local(export_linked_markdown());
+                true
+            }
+            Instruction::ExportAnnotations => self.handle_export_annotations(context),
+            Instruction::ExportAnnotationBackup => self.handle_export_annotation_backup(context),
             _ => {
                 // Other instructions need to be handled by the UI components
                 // Return false to indicate this processor didn't handle it
@@ -151,6 +233,37 @@ where
         }
     }
 
+    fn handle_paragraph(&self, context: &AppState, multiplier: u32, forward: bool) -> bool {
+        if let Some(ref current_chapter) = context.current_chapter {
+            let current_verse = context.get_current_verse();
+            let target = if forward {
+                current_chapter.get_next_paragraph_verse(current_verse, multiplier)
+            } else {
+                current_chapter.get_previous_paragraph_verse(current_verse, multiplier)
+            };
+
+            if let Some(verse) = target {
+                let verse_range = VerseRange {
+                    start: verse,
+                    end: verse,
+                };
+                let new_path = current_chapter.to_path_with_verses(&[verse_range]);
+                (self.navigate)(
+                    &new_path,
+                    NavigateOptions {
+                        scroll: false,
+                        ..Default::default()
+                    },
+                );
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
     fn handle_go_to_verse(
         &self,
         context: &AppState,
@@ -181,6 +294,58 @@ where
     }
 
     fn handle_copy_raw_verse(&self, context: &AppState) -> bool {
+        match self.raw_verse_text(context) {
+            Some(text) => {
+                self.copy_to_clipboard(text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn handle_copy_verse_with_reference(&self, context: &AppState) -> bool {
+        match self.verse_text_with_reference(context) {
+            Some(text) => {
+                self.copy_to_clipboard(text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn handle_copy_to_register(&self, name: char, text: Option<String>) -> bool {
+        match text {
+            Some(text) => {
+                store_in_register(name, &text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn handle_paste_register(&self, name: char) -> bool {
+        match get_register(name) {
+            Some(text) => {
+                self.copy_to_clipboard(text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn handle_export_register(&self, name: char) -> bool {
+        match get_register(name) {
+            Some(text) => {
+                let filename = format!("Register_{}.md", name.to_ascii_lowercase());
+                trigger_markdown_download(text, &filename);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Text of the selected verses, or the chapter name when nothing is selected
+    fn raw_verse_text(&self, context: &AppState) -> Option<String> {
         use leptos::web_sys::console;
 
         let verse_ranges = context.get_verse_ranges();
@@ -239,14 +404,14 @@ where
             }
         } else {
             console::log_1(&"❌ No current chapter available for copy".into());
-            return false;
+            return None;
         };
 
-        self.copy_to_clipboard(copy_text);
-        true
+        Some(copy_text)
     }
 
-    fn handle_copy_verse_with_reference(&self, context: &AppState) -> bool {
+    /// Selected verses followed by their reference and a link to them
+    fn verse_text_with_reference(&self, context: &AppState) -> Option<String> {
         use leptos::web_sys::console;
 
         let verse_ranges = context.get_verse_ranges();
@@ -362,11 +527,10 @@ where
             }
         } else {
             console::log_1(&"❌ No current chapter available for copy with reference".into());
-            return false;
+            return None;
         }
 
-        self.copy_to_clipboard(copy_text);
-        true
+        Some(copy_text)
     }
 
     fn copy_to_clipboard(&self, text: String) {
@@ -794,46 +958,27 @@ where
     }
 
     fn handle_random_verse(&self) -> bool {
-        let bible = get_bible();
-
-        // Calculate total number of verses in the entire Bible
-        let mut total_verses = 0;
-        let mut verse_locations = Vec::new();
-
-        for book in &bible.books {
-            for chapter in &book.chapters {
-                for verse in &chapter.verses {
-                    verse_locations.push((chapter.clone(), verse.verse));
-                    total_verses += 1;
-                }
+        // Every book has to be read before a verse can be drawn from the whole Bible
+        let navigate = self.navigate.clone();
+        spawn_local(async move {
+            load_all_books().await;
+            if let Some(path) = get_random_verse_path() {
+                navigate(
+                    &path,
+                    NavigateOptions {
+                        scroll: false,
+                        ..Default::default()
+                    },
+                );
             }
-        }
-
-        if total_verses == 0 {
-            return false; // No verses found
-        }
-
-        // Get the current counter value and increment it for next time
-        let counter = RANDOM_COUNTER.fetch_add(1, Ordering::Relaxed);
-
-        // Use a simple linear congruential generator with the counter as seed
-        let mut rng_state = counter.wrapping_mul(1103515245).wrapping_add(12345);
-        rng_state = rng_state.wrapping_mul(1664525).wrapping_add(1013904223);
-
-        let random_index = rng_state % total_verses;
-
-        // Ensure the index is within bounds
-        let safe_index = random_index.min(total_verses - 1);
+        });
+        true
+    }
 
-        if let Some((chapter, verse_num)) = verse_locations.get(safe_index) {
-            let verse_range = VerseRange {
-                start: *verse_num,
-                end: *verse_num,
-            };
-            let verse_ranges: &[VerseRange] = &[verse_range];
-            let new_path = chapter.to_path_with_verses(verse_ranges);
+    fn handle_random_chapter(&self) -> bool {
+        if let Some(random_path) = get_random_chapter_path() {
             (self.navigate)(
-                &new_path,
+                &random_path,
                 NavigateOptions {
                     scroll: false,
                     ..Default::default()
@@ -845,10 +990,10 @@ where
         }
     }
 
-    fn handle_random_chapter(&self) -> bool {
-        if let Some(random_path) = get_random_chapter_path() {
+    fn handle_daily_reading(&self, book_key: &str, chapter: u32) -> bool {
+        if let Some((_, path)) = daily_reading_link(&get_bible(), book_key, chapter) {
             (self.navigate)(
-                &random_path,
+                &path,
                 NavigateOptions {
                     scroll: false,
                     ..Default::default()
@@ -860,6 +1005,28 @@ where
         }
     }
 
+    fn handle_open_people_index(&self) -> bool {
+        (self.navigate)(
+            "/people",
+            NavigateOptions {
+                scroll: false,
+                ..Default::default()
+            },
+        );
+        true
+    }
+
+    fn handle_open_timeline(&self) -> bool {
+        (self.navigate)(
+            "/timeline",
+            NavigateOptions {
+                scroll: false,
+                ..Default::default()
+            },
+        );
+        true
+    }
+
     fn handle_open_about_page(&self) -> bool {
         (self.navigate)(
             "/about",
@@ -881,4 +1048,37 @@ where
         );
         true
     }
+
+    fn handle_export_annotations(&self, context: &AppState) -> bool {
+        let highlights = context.highlights.clone();
+        let notes = context.notes.clone();
+        // Annotated verses are quoted, so their books have to be read first
+        spawn_local(async move {
+            load_all_books().await;
+            let markdown = export_annotations_to_markdown(
+                &get_bible(),
+                &highlights,
+                &notes,
+                &get_highlight_labels(),
+            );
+            trigger_markdown_download(markdown, "Bible_notes.md");
+        });
+        true
+    }
+
+    fn handle_export_annotation_backup(&self, context: &AppState) -> bool {
+        let backup = AnnotationBackup::new(
+            context.highlights.clone(),
+            get_highlight_labels(),
+            context.notes.clone(),
+            get_pinned_chapters(),
+        );
+        match serde_json::to_string_pretty(&backup) {
+            Ok(json) => {
+                trigger_json_download(json, "Bible_annotations.json");
+                true
+            }
+            Err(_) => false,
+        }
+    }
 }
@@ -7,6 +7,7 @@
  */
 
 use crate::core::types::VerseId;
+use crate::storage::HighlightColor;
 
 /// Enum representing all possible user instructions
 ///
@@ -36,10 +37,16 @@ pub enum Instruction {
     // Direct navigation to specific locations
     BeginningOfChapter,
     EndOfChapter,
+    /// Jump forward by a paragraph (`}`)
+    NextParagraph,
+    /// Jump backward by a paragraph (`{`)
+    PreviousParagraph,
     /// Navigate to a specific verse number
     GoToVerse(VerseId),
     /// Navigate to a specific chapter (handles all side effects like mobile sidebar closing)
     GoToChapter(String), // chapter path
+    /// Navigate to the chapter a percentage of the way through the current book (`50%`)
+    GoToBookPercent(u8),
 
     // === Special Navigation Instructions ===
     // Advanced navigation features
@@ -49,6 +56,22 @@ pub enum Instruction {
     // Text copying functionality
     CopyRawVerse,
     CopyVerseWithReference,
+    /// Copy the selected verses into a named register (`"ay`); uppercase appends
+    CopyRawVerseToRegister(char),
+    /// Copy the selected verses with their reference into a named register (`"aY`)
+    CopyVerseWithReferenceToRegister(char),
+    /// Copy the contents of a register to the clipboard
+    PasteRegister(char),
+    /// Download the contents of a register as a Markdown file
+    ExportRegister(char),
+    /// Open the palette listing all registers
+    ShowRegisters,
+    /// Open the palette listing saved workspaces
+    ShowWorkspaces,
+
+    // === Repeat Instructions ===
+    /// Re-run the last repeatable instruction with its count (`.`)
+    RepeatLast,
 
     // === UI Toggle Instructions ===
     // Interface visibility controls
@@ -60,6 +83,8 @@ pub enum Instruction {
     ToggleTranslationComparison, // Added: Toggle translation comparison panel
     ToggleVerseVisibility,
     ToggleVersePallate,
+    /// Show or hide the map of places named in the current chapter
+    TogglePlaceMap,
 
     // === UI Close Instructions ===
     // Direct close actions for specific UI elements
@@ -68,11 +93,64 @@ pub enum Instruction {
     CloseRightSidebar,
     CloseThemeSidebar,
     CloseTranslationComparison,
+    ClosePlaceMap,
+    CloseHighlightLegend,
+    CloseNoteEditor,
+    CloseAnnotationImport,
+    CloseLexicon,
+
+    // === Reading Progress Instructions ===
+    // Tracking which chapters have been read
+    /// Toggle the completion state of the current chapter
+    MarkChapterComplete,
+
+    // === Highlight Instructions ===
+    // Coloring verses the reader wants to find again
+    /// Highlight the selected verses, or remove the color if they already have it
+    HighlightVerse(HighlightColor),
+    /// Remove the highlight of the selected verses
+    RemoveHighlight,
+    /// Show or hide the panel explaining what each color means
+    ToggleHighlightLegend,
+    /// Show only the highlighted verses of a chapter
+    ToggleHighlightedOnly,
+    /// Write or change the note on the selected verse
+    EditNote,
+
+    // === Source Text Instructions ===
+    // The Hebrew or Greek text some translations are packaged with
+    /// Show the source text under each verse, instead of the translation, or not at all
+    ToggleSourceText,
+    /// Show the source text in Latin letters under each verse
+    ToggleTransliteration,
+    /// Show or hide the panel with dictionary entries for source text words
+    ToggleLexicon,
+    /// Open the lexicon at the entry for a Strong's number or lemma
+    ShowLexiconEntry(String),
+
+    // === Section Heading Instructions ===
+    /// Show or hide the section headings and chapter titles that came with the translation
+    ToggleSectionHeadings,
+
+    // === Read Aloud Instructions ===
+    // Speaking the chapter with the browser's voices while the selection follows along
+    /// Start reading the chapter aloud from the selected verse, or pause or resume
+    ToggleReadAloud,
+    /// Stop reading aloud
+    StopReadAloud,
+    /// Read aloud faster
+    ReadAloudFaster,
+    /// Read aloud slower
+    ReadAloudSlower,
 
     // === Book Selection Instructions ===
-    // Book navigation and selection
-    SelectBook(String),
-    ClearSelectedBook,
+    // Expanding and collapsing books in the sidebar
+    /// Show or hide the chapters of a book in the sidebar
+    ToggleBook(String),
+    /// Hide the chapters of every book in the sidebar
+    CollapseAllBooks,
+    /// Show the chapters of the book being read
+    ExpandCurrentBook,
 
     // === External Actions ===
     // Actions that interact with external systems
@@ -82,10 +160,18 @@ pub enum Instruction {
     // Serendipitous discovery features
     RandomVerse,
     RandomChapter,
+    /// Open the Psalm scheduled for today
+    PsalmOfTheDay,
+    /// Open the Proverb matching today's day of the month
+    ProverbOfTheDay,
 
     // === Information & Settings ===
     // Application information and configuration
     OpenAboutPage,
+    /// Open the index of biblical people
+    OpenPeopleIndex,
+    /// Open the timeline of biblical events
+    OpenTimeline,
     ShowTranslations,
 
     // === Export Instructions ===
@@ -93,4 +179,46 @@ pub enum Instruction {
     ExportToPDF,
     ExportToMarkdown,
     ExportLinkedMarkdown,
+    /// Download the highlights and notes as a Markdown document
+    ExportAnnotations,
+    /// Download the highlights, notes and pinned chapters as a JSON backup
+    ExportAnnotationBackup,
+    /// Open the panel for importing a JSON backup of highlights, notes and pinned chapters
+    ImportAnnotationBackup,
+}
+
+impl Instruction {
+    /// Whether `.` may replay this instruction.
+    ///
+    /// Only navigation and copy instructions are remembered; toggles would
+    /// undo themselves and palette instructions depend on transient UI state.
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            Instruction::NextVerse
+                | Instruction::PreviousVerse
+                | Instruction::NextChapter
+                | Instruction::PreviousChapter
+                | Instruction::NextBook
+                | Instruction::PreviousBook
+                | Instruction::NextReference
+                | Instruction::PreviousReference
+                | Instruction::ExtendSelectionNextVerse
+                | Instruction::ExtendSelectionPreviousVerse
+                | Instruction::BeginningOfChapter
+                | Instruction::EndOfChapter
+                | Instruction::NextParagraph
+                | Instruction::PreviousParagraph
+                | Instruction::GoToVerse(_)
+                | Instruction::GoToChapter(_)
+                | Instruction::GoToBookPercent(_)
+                | Instruction::SwitchToPreviousChapter
+                | Instruction::CopyRawVerse
+                | Instruction::CopyVerseWithReference
+                | Instruction::CopyRawVerseToRegister(_)
+                | Instruction::CopyVerseWithReferenceToRegister(_)
+                | Instruction::RandomVerse
+                | Instruction::RandomChapter
+        )
+    }
 }
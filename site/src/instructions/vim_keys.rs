@@ -1,9 +1,14 @@
 use super::types::Instruction;
 use crate::core::types::VerseId;
+use crate::storage::registers::is_register_name;
+use crate::storage::HighlightColor;
 use leptos::web_sys::KeyboardEvent;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+// How long after "16g" a second 'g' still belongs to it, like Vim's 'timeoutlen'
+const COUNT_G_TIMEOUT_MS: f64 = 1000.0;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct VimKey {
     pub key: String,
@@ -86,6 +91,10 @@ impl VimKey {
                 // Special case for colon - it's typed with Shift+; but treated as a single character
                 key = ":".to_string();
                 shift = true; // Colon requires shift
+            } else if vim_key == "{" || vim_key == "}" {
+                // Braces are typed with Shift+[ and Shift+]
+                key = vim_key.to_string();
+                shift = true;
             } else {
                 key = vim_key.to_string();
             }
@@ -151,29 +160,61 @@ impl KeyboardMappings {
             "PreviousBook" => Some(Instruction::PreviousBook),
             "BeginningOfChapter" => Some(Instruction::BeginningOfChapter),
             "EndOfChapter" => Some(Instruction::EndOfChapter),
+            "NextParagraph" => Some(Instruction::NextParagraph),
+            "PreviousParagraph" => Some(Instruction::PreviousParagraph),
             "SwitchToPreviousChapter" => Some(Instruction::SwitchToPreviousChapter),
             "CopyRawVerse" => Some(Instruction::CopyRawVerse),
             "CopyVerseWithReference" => Some(Instruction::CopyVerseWithReference),
+            "ShowRegisters" => Some(Instruction::ShowRegisters),
+            "ShowWorkspaces" => Some(Instruction::ShowWorkspaces),
+            "RepeatLast" => Some(Instruction::RepeatLast),
             "ToggleSidebar" => Some(Instruction::ToggleSidebar),
+            "CollapseAllBooks" => Some(Instruction::CollapseAllBooks),
+            "ExpandCurrentBook" => Some(Instruction::ExpandCurrentBook),
             "ToggleCrossReferences" => Some(Instruction::ToggleCrossReferences),
             "ToggleThemeSidebar" => Some(Instruction::ToggleThemeSidebar),
             "ToggleBiblePallate" => Some(Instruction::ToggleBiblePallate),
             "ToggleCommandPallate" => Some(Instruction::ToggleCommandPallate),
             "ToggleTranslationComparison" => Some(Instruction::ToggleTranslationComparison),
+            "TogglePlaceMap" => Some(Instruction::TogglePlaceMap),
             "NextReference" => Some(Instruction::NextReference),
             "PreviousReference" => Some(Instruction::PreviousReference),
             "NextPaletteResult" => Some(Instruction::NextPaletteResult),
             "PreviousPaletteResult" => Some(Instruction::PreviousPaletteResult),
             "RandomVerse" => Some(Instruction::RandomVerse),
             "RandomChapter" => Some(Instruction::RandomChapter),
+            "PsalmOfTheDay" => Some(Instruction::PsalmOfTheDay),
+            "ProverbOfTheDay" => Some(Instruction::ProverbOfTheDay),
             "OpenAboutPage" => Some(Instruction::OpenAboutPage),
+            "OpenPeopleIndex" => Some(Instruction::OpenPeopleIndex),
+            "OpenTimeline" => Some(Instruction::OpenTimeline),
             "ShowTranslations" => Some(Instruction::ShowTranslations),
             "ToggleVersePallate" => Some(Instruction::ToggleVersePallate),
             "ToggleVerseVisibility" => Some(Instruction::ToggleVerseVisibility),
             "ExportToPDF" => Some(Instruction::ExportToPDF),
             "ExportToMarkdown" => Some(Instruction::ExportToMarkdown),
             "ExportLinkedMarkdown" => Some(Instruction::ExportLinkedMarkdown),
-            _ => None,
+            "MarkChapterComplete" => Some(Instruction::MarkChapterComplete),
+            "RemoveHighlight" => Some(Instruction::RemoveHighlight),
+            "ToggleHighlightLegend" => Some(Instruction::ToggleHighlightLegend),
+            "ToggleHighlightedOnly" => Some(Instruction::ToggleHighlightedOnly),
+            "ToggleSourceText" => Some(Instruction::ToggleSourceText),
+            "ToggleTransliteration" => Some(Instruction::ToggleTransliteration),
+            "ToggleLexicon" => Some(Instruction::ToggleLexicon),
+            "ToggleSectionHeadings" => Some(Instruction::ToggleSectionHeadings),
+            "ToggleReadAloud" => Some(Instruction::ToggleReadAloud),
+            "StopReadAloud" => Some(Instruction::StopReadAloud),
+            "ReadAloudFaster" => Some(Instruction::ReadAloudFaster),
+            "ReadAloudSlower" => Some(Instruction::ReadAloudSlower),
+            "EditNote" => Some(Instruction::EditNote),
+            "ExportAnnotations" => Some(Instruction::ExportAnnotations),
+            "ExportAnnotationBackup" => Some(Instruction::ExportAnnotationBackup),
+            "ImportAnnotationBackup" => Some(Instruction::ImportAnnotationBackup),
+            // "HighlightYellow", "HighlightGreen", ...
+            _ => instruction_name
+                .strip_prefix("Highlight")
+                .and_then(HighlightColor::from_name)
+                .map(Instruction::HighlightVerse),
         }
     }
 }
@@ -183,6 +224,10 @@ pub struct VimKeyboardMapper {
     mappings: KeyboardMappings,
     sequence_buffer: String,
     multiplier_buffer: String,
+    // Time of a count followed by "g", so a second "g" right after ("16gg") is ignored
+    count_g_time: Option<f64>,
+    // `"` while waiting for the register name, then `"a` until the next instruction
+    register_buffer: String,
 }
 
 impl VimKeyboardMapper {
@@ -191,10 +236,56 @@ impl VimKeyboardMapper {
             mappings: KeyboardMappings::load(),
             sequence_buffer: String::new(),
             multiplier_buffer: String::new(),
+            count_g_time: None,
+            register_buffer: String::new(),
         }
     }
 
     pub fn map_to_instruction(&mut self, e: &KeyboardEvent) -> Option<(Instruction, u32)> {
+        let key = e.key();
+        // Modifier keydowns arrive on their own before the key they modify
+        if matches!(key.as_str(), "Shift" | "Control" | "Alt" | "Meta") {
+            return None;
+        }
+
+        // "16gg" already jumped to verse 16 on the first 'g'. Any other key, or a 'g'
+        // typed later on, starts over.
+        if let Some(time) = self.count_g_time.take() {
+            if key == "g" && !e.shift_key() && e.time_stamp() - time < COUNT_G_TIMEOUT_MS {
+                return None;
+            }
+        }
+
+        // `"a` selects register a for the next copy instruction
+        if self.register_buffer == "\"" {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(name), None) if is_register_name(name) => self.register_buffer.push(name),
+                _ => self.register_buffer.clear(),
+            }
+            return None;
+        }
+        if key == "\"" && !e.ctrl_key() && !e.meta_key() && !e.alt_key() {
+            self.clear_buffers();
+            self.register_buffer = key;
+            return None;
+        }
+
+        let (instruction, multiplier) = self.map_key(e)?;
+        let register = self.register_buffer.chars().nth(1);
+        self.register_buffer.clear();
+
+        let instruction = match (instruction, register) {
+            (Instruction::CopyRawVerse, Some(name)) => Instruction::CopyRawVerseToRegister(name),
+            (Instruction::CopyVerseWithReference, Some(name)) => {
+                Instruction::CopyVerseWithReferenceToRegister(name)
+            }
+            (instruction, _) => instruction,
+        };
+        Some((instruction, multiplier))
+    }
+
+    fn map_key(&mut self, e: &KeyboardEvent) -> Option<(Instruction, u32)> {
         // Handle modified keys (including shift)
         if e.ctrl_key() || e.meta_key() || e.alt_key() || e.shift_key() {
             // Get current multiplier before processing modified keys
@@ -204,6 +295,25 @@ impl VimKeyboardMapper {
                 self.multiplier_buffer.parse().unwrap_or(1)
             };
 
+            // A count before 'G' jumps to that verse (e.g., "16G") instead of the end of the chapter
+            if e.key() == "G"
+                && !self.multiplier_buffer.is_empty()
+                && !e.ctrl_key()
+                && !e.meta_key()
+                && !e.alt_key()
+            {
+                self.clear_buffers();
+                let verse_id = VerseId::new(0, 0, multiplier);
+                return Some((Instruction::GoToVerse(verse_id), 1));
+            }
+
+            // A count before '%' jumps that far into the book (e.g., "50%")
+            if e.key() == "%" && !self.multiplier_buffer.is_empty() {
+                self.clear_buffers();
+                let percent = multiplier.min(100) as u8;
+                return Some((Instruction::GoToBookPercent(percent), 1));
+            }
+
             // Try to match modified keys first
             let mut found_instruction = None;
             for (vim_key_str, _) in &self.mappings.mappings {
@@ -243,9 +353,10 @@ impl VimKeyboardMapper {
                 self.clear_buffers();
                 return Some((Instruction::BeginningOfChapter, multiplier));
             } else if !self.multiplier_buffer.is_empty() {
-                // This is a multiplier followed by 'g' (e.g., "33g" -> go to verse 33)
+                // This is a multiplier followed by 'g' (e.g., "33g" or "33gg" -> go to verse 33)
                 let verse_num = multiplier;
                 self.clear_buffers();
+                self.count_g_time = Some(e.time_stamp());
                 // Create a VerseId with placeholder values - will be resolved with current context
                 let verse_id = VerseId::new(0, 0, verse_num as u32);
                 return Some((Instruction::GoToVerse(verse_id), 1));
@@ -256,9 +367,14 @@ impl VimKeyboardMapper {
             }
         }
 
-        // Try to match single-key mappings
+        // Try to match single-key mappings, unless a key completes a pending sequence ("zm")
         let mut found_instruction = None;
-        for (vim_key_str, _) in &self.mappings.mappings {
+        for (vim_key_str, _) in self
+            .mappings
+            .mappings
+            .iter()
+            .filter(|_| self.sequence_buffer.is_empty())
+        {
             if let Some(vim_key) = VimKey::from_vim_syntax(vim_key_str) {
                 if !vim_key.is_multi_char_sequence() && vim_key.matches_event(e) {
                     found_instruction = self.mappings.get_instruction(vim_key_str);
@@ -314,14 +430,20 @@ impl VimKeyboardMapper {
     pub fn clear_buffers(&mut self) {
         self.sequence_buffer.clear();
         self.multiplier_buffer.clear();
+        self.count_g_time = None;
     }
 
     pub fn has_pending_sequence(&self) -> bool {
-        !self.sequence_buffer.is_empty() || !self.multiplier_buffer.is_empty()
+        !self.sequence_buffer.is_empty()
+            || !self.multiplier_buffer.is_empty()
+            || !self.register_buffer.is_empty()
     }
 
     pub fn get_current_input_display(&self) -> String {
-        format!("{}{}", self.multiplier_buffer, self.sequence_buffer)
+        format!(
+            "{}{}{}",
+            self.register_buffer, self.multiplier_buffer, self.sequence_buffer
+        )
     }
 
     pub fn get_sequence_buffer(&self) -> &str {
@@ -68,6 +68,20 @@ pub fn KeyboardNavigationHandler(view_state: ViewStateSignal) -> impl IntoView {
             false
         };
 
+        // Grids like the sidebar's chapter grid move focus with the arrow keys themselves
+        let is_grid_key = matches!(
+            e.key().as_str(),
+            "ArrowUp" | "ArrowDown" | "ArrowLeft" | "ArrowRight" | "Home" | "End"
+        );
+        let is_in_keyboard_grid = leptos::web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.closest("[data-keyboard-grid]").ok().flatten())
+            .is_some();
+        if is_grid_key && is_in_keyboard_grid {
+            return;
+        }
+
         // If user is typing in input and palette is open, only intercept specific control keys
         let palette_open = view_state
             .try_with(|state| state.is_command_palette_open)
@@ -83,6 +97,9 @@ pub fn KeyboardNavigationHandler(view_state: ViewStateSignal) -> impl IntoView {
             if !is_control_sequence {
                 return;
             }
+        } else if is_typing_in_input {
+            // Typing in other fields (sidebar filter, settings) isn't a shortcut
+            return;
         }
 
         // Get instruction from vim-style keyboard mapper
@@ -174,6 +191,14 @@ pub fn KeyboardNavigationHandler(view_state: ViewStateSignal) -> impl IntoView {
 
             e.prevent_default();
 
+            // Replace "." with the last repeatable instruction and remember this one
+            let Some((instruction, multiplier)) = view_state
+                .try_update_untracked(|state| state.resolve_repeat(instruction, multiplier))
+                .flatten()
+            else {
+                return;
+            };
+
             // Execute instruction in ViewState
             let instruction_result = view_state
                 .try_update(|state| {
@@ -217,8 +242,9 @@ pub fn KeyboardNavigationHandler(view_state: ViewStateSignal) -> impl IntoView {
                     );
 
                     // Create processor and try to handle the instruction
+                    let navigate = navigate.clone();
                     let processor = crate::instructions::processor::InstructionProcessor::new(
-                        |path: &str, opts: leptos_router::NavigateOptions| {
+                        move |path: &str, opts: leptos_router::NavigateOptions| {
                             navigate(path, opts);
                         },
                     );
@@ -15,20 +15,26 @@ use wasm_bindgen_futures::spawn_local;
 // === Internal Dependencies ===
 use crate::api::init_bible;
 use crate::components::{
-    CommandPalette, CrossReferencesSidebar, Sidebar, ThemeSidebar, TranslationComparison,
+    AnnotationImport, CommandPalette, CrossReferencesSidebar, DailyReadingsCard, HighlightLegend,
+    LexiconPanel, NoteEditor, PlaceMap, Sidebar, ThemeSidebar, TranslationComparison,
+    TranslationUpdateNotice,
 };
-use crate::core::{get_bible, parse_verse_ranges_from_url, Chapter};
+use crate::core::{get_bible, parse_verse_ranges_from_url, Chapter, SourceTextMode};
+use crate::i18n::t;
 use crate::instructions::types::Instruction;
 use crate::keyboard_navigation::KeyboardNavigationHandler;
 use crate::storage::{add_recent_chapter, get_selected_theme};
-use crate::themes::{get_default_theme, get_theme_by_id, theme_to_css_vars, Theme};
-use crate::utils::{is_mobile_screen, parse_book_chapter_from_url};
+use crate::themes::{
+    get_default_theme, get_theme_by_id, load_theme_by_id, theme_to_css_vars, Theme,
+};
+use crate::utils::{is_mobile_screen, resolve_book_chapter_from_url};
 use crate::view_state::{create_view_state, ViewStateSignal};
-use crate::views::{About, ChapterDetail, HomeTranslationPicker};
+use crate::views::{About, ChapterDetail, HomeTranslationPicker, People, Timeline};
 
 mod api;
 mod components;
 mod core;
+mod i18n;
 mod instructions;
 mod keyboard_navigation;
 mod storage;
@@ -104,6 +110,15 @@ fn BibleApp() -> impl IntoView {
     let (current_theme, set_current_theme) =
         signal(get_theme_by_id(&get_selected_theme()).unwrap_or_else(get_default_theme));
 
+    // The selected theme may not be bundled or cached yet; fetch it and switch once it arrives
+    if get_theme_by_id(&get_selected_theme()).is_none() {
+        spawn_local(async move {
+            if let Some(theme) = load_theme_by_id(&get_selected_theme()).await {
+                set_current_theme.set(theme);
+            }
+        });
+    }
+
     // Apply theme CSS variables to document at app level
     Effect::new(move |_| {
         let theme = current_theme.get();
@@ -194,7 +209,7 @@ fn BibleWithSidebar(
         let _search = location.search.get();
 
         // Parse URL to get book and chapter info
-        if let Some((book_name, chapter_num)) = parse_book_chapter_from_url(&pathname) {
+        if let Some((book_name, chapter_num)) = resolve_book_chapter_from_url(&pathname) {
             // Check if there are verse parameters and if it's exactly one verse
             let verse_ranges = parse_verse_ranges_from_url();
             if verse_ranges.len() == 1 {
@@ -212,14 +227,14 @@ fn BibleWithSidebar(
     // Current book and chapter data for translation comparison
     let current_book_chapter = Memo::new(move |_| {
         let pathname = location.pathname.get();
-        parse_book_chapter_from_url(&pathname)
+        resolve_book_chapter_from_url(&pathname)
     });
 
     // Track recent chapters when URL changes
     Effect::new(move |_| {
         let pathname = location.pathname.get();
 
-        if let Some((book_name, chapter_num)) = parse_book_chapter_from_url(&pathname) {
+        if let Some((book_name, chapter_num)) = resolve_book_chapter_from_url(&pathname) {
             if let Ok(_chapter) = get_bible().get_chapter(&book_name, chapter_num) {
                 let chapter_display = format!("{} {}", book_name, chapter_num);
                 add_recent_chapter(book_name, chapter_num, chapter_display, pathname);
@@ -231,6 +246,7 @@ fn BibleWithSidebar(
         <KeyboardNavigationHandler view_state=view_state />
         <SidebarAutoHide view_state=view_state />
         <CommandPalette view_state=view_state />
+        <TranslationUpdateNotice />
         <nav class="border-b px-4 py-2" style="background-color: var(--theme-header-background); border-color: var(--theme-header-border)">
             <div class="flex items-center justify-between">
                 <div class="flex items-center space-x-2">
@@ -239,8 +255,8 @@ fn BibleWithSidebar(
                         on:click=move |_| {
                             view_state.update(|state| { state.execute(&Instruction::ToggleSidebar); });
                         }
-                        aria-label=move || if view_state.with(|state| state.is_left_sidebar_open) { "Hide books sidebar" } else { "Show books sidebar" }
-                        title=move || if view_state.with(|state| state.is_left_sidebar_open) { "Hide books sidebar" } else { "Show books sidebar" }
+                        aria-label=move || if view_state.with(|state| state.is_left_sidebar_open) { t("hide_books_sidebar") } else { t("show_books_sidebar") }
+                        title=move || if view_state.with(|state| state.is_left_sidebar_open) { t("hide_books_sidebar") } else { t("show_books_sidebar") }
                     >
                             <svg
                                 width="24"
@@ -261,13 +277,13 @@ fn BibleWithSidebar(
                         <a
                             href="/?choose=true"
                             class="flex items-center px-3 py-2 text-sm rounded transition-colors header-button"
-                            aria-label="Kies vertaling"
-                            title="Terug naar vertalingskeuze"
+                            aria-label=t("choose_translation")
+                            title=t("back_to_translation_choice")
                         >
                             <svg class="w-4 h-4 mr-1" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 19l-7-7 7-7"></path>
                             </svg>
-                            "Kies vertaling"
+                            {t("choose_translation")}
                         </a>
                     </div>
                     <div class="flex items-center space-x-2">
@@ -283,10 +299,10 @@ fn BibleWithSidebar(
                                 view_state.update(|state| { state.execute(&Instruction::ToggleCrossReferences); });
                             }
                             aria-label=move || {
-                                if view_state.with(|state| state.is_right_sidebar_open) { "Hide cross-references" } else { "Show cross-references" }
+                                if view_state.with(|state| state.is_right_sidebar_open) { t("hide_cross_references") } else { t("show_cross_references") }
                             }
                             title=move || {
-                                if view_state.with(|state| state.is_right_sidebar_open) { "Hide cross-references" } else { "Show cross-references" }
+                                if view_state.with(|state| state.is_right_sidebar_open) { t("hide_cross_references") } else { t("show_cross_references") }
                             }
                         >
                             <svg
@@ -310,8 +326,8 @@ fn BibleWithSidebar(
                             on:click=move |_| {
                                 view_state.update(|state| { state.execute(&Instruction::ToggleThemeSidebar); });
                             }
-                            aria-label="Theme options"
-                            title="Theme options"
+                            aria-label=t("theme_options")
+                            title=t("theme_options")
                         >
                             <svg
                                 width="20"
@@ -382,6 +398,8 @@ fn BibleWithSidebar(
                 <main class="flex-1 p-4 md:p-6 overflow-y-auto">
                     <Routes fallback=|| "Not found.">
                         <Route path=path!("/about") view=About />
+                        <Route path=path!("/people") view=People />
+                        <Route path=path!("/timeline") view=Timeline />
                         <Route
                             path=path!("/:book/:chapter")
                             view=move || {
@@ -513,6 +531,21 @@ fn BibleWithSidebar(
                         view! { <></> }.into_any()
                     }
                 }}
+
+                // Map of places named in the chapter
+                <PlaceMap current_book_chapter=current_book_chapter view_state=view_state />
+
+                // Dictionary entries for Hebrew and Greek words
+                <LexiconPanel view_state=view_state />
+
+                // Highlight colors and what they mean
+                <HighlightLegend view_state=view_state />
+
+                // Note on the selected verse
+                <NoteEditor view_state=view_state />
+
+                // Restoring highlights, notes and pinned chapters from a backup
+                <AnnotationImport view_state=view_state />
             </div>
     }
 }
@@ -579,6 +612,7 @@ fn Home(current_theme: ReadSignal<Theme>, set_current_theme: WriteSignal<Theme>)
 
     view! {
         <div class="min-h-screen" style="background-color: var(--theme-background)">
+            <DailyReadingsCard />
             <HomeTranslationPicker current_theme=current_theme set_current_theme=set_current_theme />
         </div>
     }
@@ -651,13 +685,35 @@ fn ChapterWrapper(view_state: ViewStateSignal) -> impl IntoView {
                 match Chapter::from_url() {
                     Ok(chapter) => {
                         let (verse_visibility_read, verse_visibility_write) = signal(false);
+                        let (text_columns_read, text_columns_write) = signal(1u8);
+                        let (highlights_read, highlights_write) = signal(Vec::new());
+                        let (highlighted_only_read, highlighted_only_write) = signal(false);
+                        let (notes_read, notes_write) = signal(Vec::new());
+                        let (source_text_mode_read, source_text_mode_write) = signal(SourceTextMode::Hidden);
+                        let (transliteration_read, transliteration_write) = signal(false);
+                        let (section_headings_read, section_headings_write) = signal(true);
                         Effect::new(move |_| {
                             verse_visibility_write.set(view_state.with(|state| state.verse_visibility_enabled));
+                            text_columns_write.set(view_state.with(|state| state.text_columns));
+                            highlights_write.set(view_state.with(|state| state.highlights.clone()));
+                            highlighted_only_write.set(view_state.with(|state| state.show_only_highlighted));
+                            notes_write.set(view_state.with(|state| state.notes.clone()));
+                            source_text_mode_write.set(view_state.with(|state| state.source_text_mode));
+                            transliteration_write.set(view_state.with(|state| state.show_transliteration));
+                            section_headings_write.set(view_state.with(|state| state.show_section_headings));
                         });
                         view! {
                             <ChapterDetail
                                 chapter=chapter
                                 verse_visibility_enabled=verse_visibility_read
+                                text_columns=text_columns_read
+                                highlights=highlights_read
+                                show_only_highlighted=highlighted_only_read
+                                notes=notes_read
+                                source_text_mode=source_text_mode_read
+                                show_transliteration=transliteration_read
+                                show_section_headings=section_headings_read
+                                view_state=view_state
                             />
                         }
                     }.into_any(),
@@ -0,0 +1,243 @@
+use super::highlights::{save_highlight_labels, save_highlights, HighlightColor, VerseHighlight};
+use super::notes::{save_notes, VerseNote};
+use super::pinned_chapters::{save_pinned_chapters, PinnedChapter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Version written into backups; older versions are still accepted on import
+pub const ANNOTATION_BACKUP_VERSION: u32 = 1;
+
+/// The reader's highlights, notes and bookmarks (pinned chapters) as a single JSON document
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AnnotationBackup {
+    pub version: u32,
+    #[serde(default)]
+    pub highlights: Vec<VerseHighlight>,
+    #[serde(default)]
+    pub highlight_labels: HashMap<HighlightColor, String>,
+    #[serde(default)]
+    pub notes: Vec<VerseNote>,
+    #[serde(default)]
+    pub bookmarks: Vec<PinnedChapter>,
+}
+
+/// How an imported backup combines with the annotations already stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Keep existing annotations; imported ones win where both annotate the same verse
+    Merge,
+    /// Discard existing annotations in favor of the backup
+    Replace,
+}
+
+impl AnnotationBackup {
+    pub fn new(
+        highlights: Vec<VerseHighlight>,
+        highlight_labels: HashMap<HighlightColor, String>,
+        notes: Vec<VerseNote>,
+        bookmarks: Vec<PinnedChapter>,
+    ) -> Self {
+        Self {
+            version: ANNOTATION_BACKUP_VERSION,
+            highlights,
+            highlight_labels,
+            notes,
+            bookmarks,
+        }
+    }
+
+    /// Write every part of the backup to storage, replacing what is there
+    pub fn save(&self) {
+        save_highlights(&self.highlights);
+        save_highlight_labels(&self.highlight_labels);
+        save_notes(&self.notes);
+        save_pinned_chapters(&self.bookmarks);
+    }
+}
+
+/// Whether `path` looks like a chapter path such as `/John/3`
+fn is_chapter_path(path: &str) -> bool {
+    path.strip_prefix('/')
+        .and_then(|path| path.rsplit_once('/'))
+        .is_some_and(|(book, chapter)| {
+            !book.is_empty() && chapter.parse::<u32>().is_ok_and(|chapter| chapter > 0)
+        })
+}
+
+fn validate_verse(kind: &str, book: &str, chapter: u32, verse: u32) -> Result<(), String> {
+    if book.is_empty() || chapter == 0 {
+        return Err(format!(
+            "{} has an invalid chapter \"{} {}\"",
+            kind, book, chapter
+        ));
+    }
+    if verse == 0 {
+        return Err(format!(
+            "{} in {} {} has verse number 0",
+            kind, book, chapter
+        ));
+    }
+    Ok(())
+}
+
+/// Parse and validate a backup file, describing the first problem found
+pub fn parse_annotation_backup(json: &str) -> Result<AnnotationBackup, String> {
+    let backup: AnnotationBackup =
+        serde_json::from_str(json).map_err(|e| format!("Not a valid annotation backup: {}", e))?;
+
+    if backup.version == 0 || backup.version > ANNOTATION_BACKUP_VERSION {
+        return Err(format!(
+            "Unsupported backup version {} (expected {} or lower)",
+            backup.version, ANNOTATION_BACKUP_VERSION
+        ));
+    }
+    for highlight in &backup.highlights {
+        validate_verse(
+            "Highlight",
+            &highlight.book,
+            highlight.chapter,
+            highlight.verse,
+        )?;
+    }
+    for note in &backup.notes {
+        validate_verse("Note", &note.book, note.chapter, note.verse)?;
+    }
+    if let Some(bookmark) = backup
+        .bookmarks
+        .iter()
+        .find(|bookmark| !is_chapter_path(&bookmark.path) || bookmark.chapter == 0)
+    {
+        return Err(format!(
+            "Bookmark \"{}\" has an invalid path",
+            bookmark.display_name
+        ));
+    }
+
+    Ok(backup)
+}
+
+/// Combine an imported backup with the current annotations
+pub fn merge_annotation_backup(
+    mut current: AnnotationBackup,
+    imported: AnnotationBackup,
+    mode: ImportMode,
+) -> AnnotationBackup {
+    if mode == ImportMode::Replace {
+        return AnnotationBackup {
+            version: ANNOTATION_BACKUP_VERSION,
+            ..imported
+        };
+    }
+
+    for highlight in imported.highlights {
+        current.highlights.retain(|h| {
+            !(h.book == highlight.book
+                && h.chapter == highlight.chapter
+                && h.verse == highlight.verse)
+        });
+        current.highlights.push(highlight);
+    }
+    for note in imported.notes {
+        current.notes.retain(|n| {
+            !(n.book == note.book && n.chapter == note.chapter && n.verse == note.verse)
+        });
+        current.notes.push(note);
+    }
+    current.highlight_labels.extend(imported.highlight_labels);
+    for bookmark in imported.bookmarks {
+        if !current.bookmarks.iter().any(|b| b.path == bookmark.path) {
+            current.bookmarks.push(bookmark);
+        }
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(chapter: u32, verse: u32, text: &str) -> VerseNote {
+        VerseNote {
+            book: "john".to_string(),
+            chapter,
+            verse,
+            text: text.to_string(),
+        }
+    }
+
+    fn bookmark(book_name: &str, chapter: u32) -> PinnedChapter {
+        PinnedChapter {
+            book_name: book_name.to_string(),
+            chapter,
+            display_name: format!("{} {}", book_name, chapter),
+            path: format!("/{}/{}", book_name, chapter),
+        }
+    }
+
+    #[test]
+    fn test_parse_annotation_backup() {
+        let backup = AnnotationBackup::new(
+            vec![VerseHighlight {
+                book: "john".to_string(),
+                chapter: 3,
+                verse: 16,
+                color: HighlightColor::Green,
+            }],
+            HashMap::from([(HighlightColor::Green, "Gospel".to_string())]),
+            vec![note(3, 16, "Gospel in a nutshell")],
+            vec![bookmark("Psalms", 23)],
+        );
+        let json = serde_json::to_string(&backup).unwrap();
+        assert_eq!(parse_annotation_backup(&json), Ok(backup));
+
+        assert!(parse_annotation_backup("{\"version\": 1, \"notes\": []}").is_ok());
+        assert!(parse_annotation_backup("{\"books\": []}").is_err());
+        assert!(parse_annotation_backup("{\"version\": 2}").is_err());
+        assert!(parse_annotation_backup(
+            "{\"version\": 1, \"notes\": [{\"chapter_path\": \"John 3\", \"verse\": 16, \"text\": \"\"}]}"
+        )
+        .is_err());
+        assert!(parse_annotation_backup(
+            "{\"version\": 1, \"highlights\": [{\"chapter_path\": \"/John/3\", \"verse\": 16, \"color\": \"red\"}]}"
+        )
+        .is_err());
+        assert!(parse_annotation_backup(
+            "{\"version\": 1, \"notes\": [{\"book\": \"john\", \"chapter\": 0, \"verse\": 16, \"text\": \"\"}]}"
+        )
+        .is_err());
+
+        // Backups from before annotations were keyed by book still import
+        let old = parse_annotation_backup(
+            "{\"version\": 1, \"notes\": [{\"chapter_path\": \"/Johannes/3\", \"verse\": 16, \"text\": \"Gospel\"}]}",
+        )
+        .unwrap();
+        assert_eq!(old.notes, vec![note(3, 16, "Gospel")]);
+    }
+
+    #[test]
+    fn test_merge_annotation_backup() {
+        let current = AnnotationBackup::new(
+            Vec::new(),
+            HashMap::new(),
+            vec![note(3, 16, "Old"), note(3, 17, "Kept")],
+            vec![bookmark("Psalms", 23)],
+        );
+        let imported = AnnotationBackup::new(
+            Vec::new(),
+            HashMap::new(),
+            vec![note(3, 16, "New")],
+            vec![bookmark("Psalms", 23), bookmark("Romans", 8)],
+        );
+
+        let merged = merge_annotation_backup(current.clone(), imported.clone(), ImportMode::Merge);
+        assert_eq!(merged.notes, vec![note(3, 17, "Kept"), note(3, 16, "New")]);
+        assert_eq!(merged.bookmarks.len(), 2);
+
+        assert_eq!(
+            merge_annotation_backup(current, imported.clone(), ImportMode::Replace),
+            imported
+        );
+    }
+}
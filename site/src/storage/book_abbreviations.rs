@@ -0,0 +1,29 @@
+use crate::translation_map::abbreviations::{book_key_for_name, normalize_abbreviation};
+use gloo_storage::{LocalStorage, Storage};
+use std::collections::BTreeMap;
+
+const BOOK_ABBREVIATIONS_KEY: &str = "book_abbreviations";
+
+/// The user's own book abbreviations, from normalized abbreviation to book key
+pub fn get_user_book_abbreviations() -> BTreeMap<String, String> {
+    LocalStorage::get(BOOK_ABBREVIATIONS_KEY).unwrap_or_default()
+}
+
+/// Add an abbreviation for a book given by name (in any language), key or existing abbreviation
+pub fn add_user_book_abbreviation(abbreviation: &str, book: &str) -> Result<(), String> {
+    let abbreviation = normalize_abbreviation(abbreviation);
+    if abbreviation.is_empty() {
+        return Err("An abbreviation can't be empty".to_string());
+    }
+    let key = book_key_for_name(book).ok_or_else(|| format!("Unknown book: {}", book))?;
+
+    let mut abbreviations = get_user_book_abbreviations();
+    abbreviations.insert(abbreviation, key.to_string());
+    LocalStorage::set(BOOK_ABBREVIATIONS_KEY, &abbreviations).map_err(|e| e.to_string())
+}
+
+pub fn remove_user_book_abbreviation(abbreviation: &str) {
+    let mut abbreviations = get_user_book_abbreviations();
+    abbreviations.remove(abbreviation);
+    let _ = LocalStorage::set(BOOK_ABBREVIATIONS_KEY, &abbreviations);
+}
@@ -0,0 +1,148 @@
+use rexie::{KeyRange, ObjectStore, Rexie, TransactionMode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// Data packaged with a translation, such as source text or section headings, is kept
+// in its own IndexedDB database with one record per chapter, so a whole Bible's worth
+// never has to fit in one localStorage key and a chapter can be read on its own.
+
+const CHAPTERS_STORE: &str = "chapters";
+
+async fn open_database(database: &str) -> Result<Rexie, Box<dyn std::error::Error>> {
+    let rexie = Rexie::builder(database)
+        .version(1)
+        .add_object_store(ObjectStore::new(CHAPTERS_STORE))
+        .build()
+        .await
+        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    Ok(rexie)
+}
+
+fn chapter_key(translation_short_name: &str, book_index: usize, chapter: u32) -> String {
+    format!("{}/{}/{}", translation_short_name, book_index, chapter)
+}
+
+// All keys of one translation: they share the `{short_name}/` prefix
+fn translation_range(translation_short_name: &str) -> Result<KeyRange, String> {
+    KeyRange::bound(
+        &format!("{}/", translation_short_name).into(),
+        &format!("{}/\u{FFFF}", translation_short_name).into(),
+        None,
+        None,
+    )
+    .map_err(|e| format!("Failed to create key range: {:?}", e))
+}
+
+/// Replace what a translation has stored in `database` with `items`, one record per
+/// `(book_index, chapter)` that `chapter_of` puts them in
+pub async fn save_chapter_records<T: Serialize>(
+    database: &str,
+    translation_short_name: &str,
+    items: &[T],
+    chapter_of: impl Fn(&T) -> (usize, u32),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut chapters: BTreeMap<(usize, u32), Vec<&T>> = BTreeMap::new();
+    for item in items {
+        chapters.entry(chapter_of(item)).or_default().push(item);
+    }
+
+    let rexie = open_database(database).await?;
+    let transaction = rexie
+        .transaction(&[CHAPTERS_STORE], TransactionMode::ReadWrite)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(CHAPTERS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let old_keys = store
+        .get_all_keys(Some(translation_range(translation_short_name)?), None)
+        .await
+        .map_err(|e| format!("Failed to read stored chapters: {:?}", e))?;
+    for key in old_keys {
+        store
+            .delete(key)
+            .await
+            .map_err(|e| format!("Failed to delete stored chapter: {:?}", e))?;
+    }
+
+    for ((book_index, chapter), items) in chapters {
+        let json = serde_json::to_string(&items)
+            .map_err(|e| format!("Failed to serialize chapter: {:?}", e))?;
+        let key = chapter_key(translation_short_name, book_index, chapter);
+        store
+            .put(&json.into(), Some(&key.into()))
+            .await
+            .map_err(|e| format!("Failed to save chapter {}/{}: {:?}", book_index, chapter, e))?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {:?}", e))?;
+    Ok(())
+}
+
+fn parse_record<T: DeserializeOwned>(
+    value: wasm_bindgen::JsValue,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    let json = value.as_string().ok_or("Invalid stored chapter format")?;
+    let items = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse stored chapter: {:?}", e))?;
+    Ok(items)
+}
+
+/// What a translation has stored in `database` for one chapter; empty when it has nothing
+pub async fn load_chapter_records<T: DeserializeOwned>(
+    database: &str,
+    translation_short_name: &str,
+    book_index: usize,
+    chapter: u32,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    let rexie = open_database(database).await?;
+    let transaction = rexie
+        .transaction(&[CHAPTERS_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(CHAPTERS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let key = chapter_key(translation_short_name, book_index, chapter);
+    match store.get(key.into()).await {
+        Ok(Some(value)) => parse_record(value),
+        Ok(None) => Ok(Vec::new()),
+        Err(e) => Err(format!("Failed to read stored chapter: {:?}", e).into()),
+    }
+}
+
+/// Everything a translation has stored in `database`, in no particular order
+pub async fn load_all_chapter_records<T: DeserializeOwned>(
+    database: &str,
+    translation_short_name: &str,
+) -> Result<Vec<T>, Box<dyn std::error::Error>> {
+    let rexie = open_database(database).await?;
+    let transaction = rexie
+        .transaction(&[CHAPTERS_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(CHAPTERS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let values = store
+        .get_all(Some(translation_range(translation_short_name)?), None)
+        .await
+        .map_err(|e| format!("Failed to read stored chapters: {:?}", e))?;
+    let mut items = Vec::new();
+    for value in values {
+        items.extend(parse_record(value)?);
+    }
+    Ok(items)
+}
+
+/// Remove everything a translation has stored in `database`
+pub async fn clear_chapter_records(
+    database: &str,
+    translation_short_name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    save_chapter_records::<()>(database, translation_short_name, &[], |_| (0, 0)).await
+}
@@ -0,0 +1,204 @@
+use crate::core::{CommentaryEntry, CommentarySet};
+use gloo_storage::{LocalStorage, Storage};
+use rexie::{ObjectStore, Rexie, Store, TransactionMode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const COMMENTARIES_KEY: &str = "commentaries";
+
+// The comments themselves are kept in IndexedDB with one record per commentary and
+// book, so a whole commentary never has to fit in localStorage
+const COMMENTARY_DATABASE: &str = "CommentaryCache";
+const BOOKS_STORE: &str = "books";
+
+/// An imported commentary without its entries, which are stored per book
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommentaryInfo {
+    pub name: String,
+    pub abbreviation: String,
+    pub books: Vec<String>,
+}
+
+// Comments used to be kept in one localStorage key per commentary and book
+fn legacy_commentary_book_key(name: &str, book: &str) -> String {
+    format!("commentary_{}_{}", name, book)
+}
+
+fn commentary_book_key(name: &str, book: &str) -> String {
+    format!("{}/{}", name, book)
+}
+
+async fn open_database() -> Result<Rexie, Box<dyn std::error::Error>> {
+    let rexie = Rexie::builder(COMMENTARY_DATABASE)
+        .version(1)
+        .add_object_store(ObjectStore::new(BOOKS_STORE))
+        .build()
+        .await
+        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    Ok(rexie)
+}
+
+async fn write_books(
+    store: &Store,
+    name: &str,
+    old_books: &[String],
+    books: &BTreeMap<String, Vec<CommentaryEntry>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for book in old_books {
+        store
+            .delete(commentary_book_key(name, book).into())
+            .await
+            .map_err(|e| format!("Failed to delete stored comments on {}: {:?}", book, e))?;
+    }
+    for (book, entries) in books {
+        let json = serde_json::to_string(entries)
+            .map_err(|e| format!("Failed to serialize comments: {:?}", e))?;
+        store
+            .put(&json.into(), Some(&commentary_book_key(name, book).into()))
+            .await
+            .map_err(|e| format!("Failed to save comments on {}: {:?}", book, e))?;
+    }
+    Ok(())
+}
+
+// Replace the stored `old_books` of a commentary with `books` in one transaction,
+// which is aborted so nothing changes when any of it fails
+async fn replace_books(
+    name: &str,
+    old_books: &[String],
+    books: &BTreeMap<String, Vec<CommentaryEntry>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[BOOKS_STORE], TransactionMode::ReadWrite)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(BOOKS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    if let Err(e) = write_books(&store, name, old_books, books).await {
+        let _ = transaction.abort().await;
+        return Err(e);
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {:?}", e))?;
+    Ok(())
+}
+
+async fn load_book(
+    name: &str,
+    book: &str,
+) -> Result<Vec<CommentaryEntry>, Box<dyn std::error::Error>> {
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[BOOKS_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(BOOKS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let Some(value) = store
+        .get(commentary_book_key(name, book).into())
+        .await
+        .map_err(|e| format!("Failed to read stored comments: {:?}", e))?
+    else {
+        return Ok(Vec::new());
+    };
+    let json = value.as_string().ok_or("Invalid stored comments format")?;
+    let entries = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse stored comments: {:?}", e))?;
+    Ok(entries)
+}
+
+// Move comments saved before they were stored in IndexedDB
+async fn migrate_legacy_commentaries() {
+    for info in get_commentaries() {
+        let books: BTreeMap<String, Vec<CommentaryEntry>> = info
+            .books
+            .iter()
+            .filter_map(|book| {
+                LocalStorage::get(legacy_commentary_book_key(&info.name, book))
+                    .ok()
+                    .map(|entries| (book.clone(), entries))
+            })
+            .collect();
+        if books.is_empty() {
+            continue;
+        }
+        match replace_books(&info.name, &[], &books).await {
+            Ok(()) => {
+                for book in books.keys() {
+                    LocalStorage::delete(legacy_commentary_book_key(&info.name, book));
+                }
+            }
+            Err(e) => leptos::logging::error!("Failed to migrate commentary: {}", e),
+        }
+    }
+}
+
+/// Imported commentaries in the order they were imported
+pub fn get_commentaries() -> Vec<CommentaryInfo> {
+    LocalStorage::get(COMMENTARIES_KEY).unwrap_or_default()
+}
+
+/// The comments of the commentary called `name` on one book
+pub async fn get_commentary_book(name: &str, book: &str) -> Vec<CommentaryEntry> {
+    migrate_legacy_commentaries().await;
+    load_book(name, book).await.unwrap_or_else(|e| {
+        leptos::logging::error!("Failed to load commentary: {}", e);
+        Vec::new()
+    })
+}
+
+/// Store a commentary, replacing an earlier import with the same name; when the
+/// comments can't be stored, the earlier import is left as it was
+pub async fn save_commentary(
+    commentary: CommentarySet,
+) -> Result<CommentaryInfo, Box<dyn std::error::Error>> {
+    let mut books: BTreeMap<String, Vec<CommentaryEntry>> = BTreeMap::new();
+    for entry in commentary.entries {
+        books.entry(entry.book.clone()).or_default().push(entry);
+    }
+    let info = CommentaryInfo {
+        name: commentary.name,
+        abbreviation: commentary.abbreviation,
+        books: books.keys().cloned().collect(),
+    };
+
+    let previous = get_commentaries();
+    let old_books = previous
+        .iter()
+        .find(|stored| stored.name == info.name)
+        .map(|stored| stored.books.clone())
+        .unwrap_or_default();
+    let mut commentaries = previous.clone();
+    commentaries.retain(|stored| stored.name != info.name);
+    commentaries.push(info.clone());
+    LocalStorage::set(COMMENTARIES_KEY, &commentaries)?;
+
+    if let Err(e) = replace_books(&info.name, &old_books, &books).await {
+        let _ = LocalStorage::set(COMMENTARIES_KEY, &previous);
+        return Err(e);
+    }
+    for book in &old_books {
+        LocalStorage::delete(legacy_commentary_book_key(&info.name, book));
+    }
+    Ok(info)
+}
+
+pub async fn remove_commentary(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut commentaries = get_commentaries();
+    let Some(position) = commentaries.iter().position(|info| info.name == name) else {
+        return Ok(());
+    };
+    let info = commentaries.remove(position);
+    replace_books(&info.name, &info.books, &BTreeMap::new()).await?;
+    for book in &info.books {
+        LocalStorage::delete(legacy_commentary_book_key(&info.name, book));
+    }
+    LocalStorage::set(COMMENTARIES_KEY, &commentaries)?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use gloo_storage::{LocalStorage, Storage};
+
+const COMPLETED_CHAPTERS_KEY: &str = "completed_chapters";
+
+/// Paths of all chapters the reader has marked as complete
+pub fn get_completed_chapters() -> Vec<String> {
+    LocalStorage::get(COMPLETED_CHAPTERS_KEY).unwrap_or_default()
+}
+
+pub fn save_completed_chapters(chapter_paths: &[String]) {
+    let _ = LocalStorage::set(COMPLETED_CHAPTERS_KEY, chapter_paths);
+}
@@ -0,0 +1,238 @@
+use crate::core::ChapterKey;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const HIGHLIGHTS_KEY: &str = "bible_highlights";
+const HIGHLIGHT_LABELS_KEY: &str = "bible_highlight_labels";
+
+/// A named highlight color
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightColor {
+    Yellow,
+    Green,
+    Blue,
+    Pink,
+    Orange,
+    Purple,
+}
+
+impl HighlightColor {
+    pub const ALL: [HighlightColor; 6] = [
+        HighlightColor::Yellow,
+        HighlightColor::Green,
+        HighlightColor::Blue,
+        HighlightColor::Pink,
+        HighlightColor::Orange,
+        HighlightColor::Purple,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HighlightColor::Yellow => "Yellow",
+            HighlightColor::Green => "Green",
+            HighlightColor::Blue => "Blue",
+            HighlightColor::Pink => "Pink",
+            HighlightColor::Orange => "Orange",
+            HighlightColor::Purple => "Purple",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|color| color.name().eq_ignore_ascii_case(name))
+    }
+
+    /// CSS background for highlighted text, mixed into the theme background
+    /// so the text stays readable on light and dark themes
+    pub fn css_background(self) -> String {
+        let base = match self {
+            HighlightColor::Yellow => "#facc15",
+            HighlightColor::Green => "#4ade80",
+            HighlightColor::Blue => "#60a5fa",
+            HighlightColor::Pink => "#f472b6",
+            HighlightColor::Orange => "#fb923c",
+            HighlightColor::Purple => "#a78bfa",
+        };
+        format!("color-mix(in srgb, {} 40%, var(--theme-background))", base)
+    }
+}
+
+/// A verse the reader highlighted, by book key so it shows in every translation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "StoredHighlight")]
+pub struct VerseHighlight {
+    pub book: String,
+    pub chapter: u32,
+    pub verse: u32,
+    pub color: HighlightColor,
+}
+
+impl VerseHighlight {
+    pub fn is_in(&self, key: &ChapterKey) -> bool {
+        self.book == key.book && self.chapter == key.chapter
+    }
+}
+
+/// A highlight as stored, either by book key or by the chapter path highlights
+/// used to be stored under
+#[derive(Deserialize)]
+struct StoredHighlight {
+    #[serde(default)]
+    book: Option<String>,
+    #[serde(default)]
+    chapter: u32,
+    #[serde(default)]
+    chapter_path: Option<String>,
+    verse: u32,
+    color: HighlightColor,
+}
+
+impl TryFrom<StoredHighlight> for VerseHighlight {
+    type Error = String;
+
+    fn try_from(stored: StoredHighlight) -> Result<Self, String> {
+        let key = stored_chapter_key(stored.book, stored.chapter, stored.chapter_path)?;
+        Ok(VerseHighlight {
+            book: key.book,
+            chapter: key.chapter,
+            verse: stored.verse,
+            color: stored.color,
+        })
+    }
+}
+
+/// The chapter of a stored highlight or note, migrating its old chapter path if it has one
+pub(crate) fn stored_chapter_key(
+    book: Option<String>,
+    chapter: u32,
+    chapter_path: Option<String>,
+) -> Result<ChapterKey, String> {
+    match (book, chapter_path) {
+        (Some(book), _) => Ok(ChapterKey { book, chapter }),
+        (None, Some(path)) => {
+            ChapterKey::from_path(&path).ok_or_else(|| format!("invalid chapter path \"{}\"", path))
+        }
+        (None, None) => Err("missing field `book`".to_string()),
+    }
+}
+
+pub fn get_highlights() -> Vec<VerseHighlight> {
+    LocalStorage::get(HIGHLIGHTS_KEY).unwrap_or_default()
+}
+
+pub fn save_highlights(highlights: &[VerseHighlight]) {
+    let _ = LocalStorage::set(HIGHLIGHTS_KEY, highlights);
+}
+
+/// What the reader uses each color for, such as "Promises"
+pub fn get_highlight_labels() -> HashMap<HighlightColor, String> {
+    LocalStorage::get(HIGHLIGHT_LABELS_KEY).unwrap_or_default()
+}
+
+pub fn save_highlight_label(color: HighlightColor, label: &str) {
+    let mut labels = get_highlight_labels();
+    if label.trim().is_empty() {
+        labels.remove(&color);
+    } else {
+        labels.insert(color, label.trim().to_string());
+    }
+    save_highlight_labels(&labels);
+}
+
+pub fn save_highlight_labels(labels: &HashMap<HighlightColor, String>) {
+    let _ = LocalStorage::set(HIGHLIGHT_LABELS_KEY, labels);
+}
+
+/// Highlight `verses` of a chapter in `color`, or remove their highlight when `color` is None.
+/// Highlighting verses that already all have that color removes it again.
+pub fn apply_highlight(
+    highlights: &mut Vec<VerseHighlight>,
+    key: &ChapterKey,
+    verses: &[u32],
+    color: Option<HighlightColor>,
+) {
+    let is_selected = |h: &VerseHighlight| h.is_in(key) && verses.contains(&h.verse);
+    let already_colored = verses.iter().all(|verse| {
+        highlights
+            .iter()
+            .any(|h| is_selected(h) && h.verse == *verse && Some(h.color) == color)
+    });
+
+    highlights.retain(|h| !is_selected(h));
+    if let Some(color) = color.filter(|_| !already_colored) {
+        highlights.extend(verses.iter().map(|verse| VerseHighlight {
+            book: key.book.clone(),
+            chapter: key.chapter,
+            verse: *verse,
+            color,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(highlights: &[VerseHighlight]) -> Vec<(u32, HighlightColor)> {
+        let mut colors: Vec<_> = highlights.iter().map(|h| (h.verse, h.color)).collect();
+        colors.sort_by_key(|(verse, _)| *verse);
+        colors
+    }
+
+    fn john(chapter: u32) -> ChapterKey {
+        ChapterKey {
+            book: "john".to_string(),
+            chapter,
+        }
+    }
+
+    #[test]
+    fn test_apply_highlight() {
+        let mut highlights = Vec::new();
+        apply_highlight(
+            &mut highlights,
+            &john(3),
+            &[16, 17],
+            Some(HighlightColor::Yellow),
+        );
+        apply_highlight(&mut highlights, &john(3), &[17], Some(HighlightColor::Blue));
+        assert_eq!(
+            colors(&highlights),
+            vec![(16, HighlightColor::Yellow), (17, HighlightColor::Blue)]
+        );
+
+        // The same color again toggles it off
+        apply_highlight(&mut highlights, &john(3), &[17], Some(HighlightColor::Blue));
+        assert_eq!(colors(&highlights), vec![(16, HighlightColor::Yellow)]);
+
+        apply_highlight(&mut highlights, &john(4), &[16], None);
+        apply_highlight(&mut highlights, &john(3), &[16], None);
+        assert!(highlights.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_highlight_chapter_path() {
+        let stored = "[{\"chapter_path\": \"/Johannes/3\", \"verse\": 16, \"color\": \"green\"}]";
+        let highlights: Vec<VerseHighlight> = serde_json::from_str(stored).unwrap();
+        assert!(highlights[0].is_in(&john(3)));
+
+        let json = serde_json::to_string(&highlights).unwrap();
+        assert!(!json.contains("chapter_path"));
+        assert_eq!(
+            serde_json::from_str::<Vec<VerseHighlight>>(&json).unwrap(),
+            highlights
+        );
+    }
+
+    #[test]
+    fn test_highlight_color_from_name() {
+        assert_eq!(
+            HighlightColor::from_name("pink"),
+            Some(HighlightColor::Pink)
+        );
+        assert_eq!(HighlightColor::from_name("Legend"), None);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::core::normalize_lexicon_key;
+use gloo_storage::{LocalStorage, Storage};
+use rexie::{ObjectStore, Rexie, TransactionMode};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// The lexicon used to be kept in a single localStorage key
+const LEGACY_LEXICON_KEY: &str = "lexicon";
+const LEXICON_HISTORY_KEY: &str = "lexicon_history";
+
+/// How many recently viewed entries the lexicon panel keeps
+pub const LEXICON_HISTORY_LIMIT: usize = 20;
+
+/// A dictionary entry for a Strong's number or a lemma
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LexiconEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lemma: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+    /// A short translation such as "word"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definition: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct KeyedLexiconEntry {
+    #[serde(alias = "strongs", alias = "id")]
+    key: String,
+    #[serde(flatten)]
+    entry: LexiconEntry,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LexiconFile {
+    Map(HashMap<String, LexiconEntry>),
+    List(Vec<KeyedLexiconEntry>),
+}
+
+/// Entries of a JSON lexicon, either an object keyed by Strong's number or a
+/// list of entries each with a `strongs` field
+pub fn parse_lexicon(json: &str) -> Result<HashMap<String, LexiconEntry>, String> {
+    let file: LexiconFile = serde_json::from_str(json).map_err(|_| {
+        "Expected an object keyed by Strong's number or a list of entries".to_string()
+    })?;
+    let entries: HashMap<String, LexiconEntry> = match file {
+        LexiconFile::Map(entries) => entries
+            .into_iter()
+            .map(|(key, entry)| (normalize_lexicon_key(&key), entry))
+            .collect(),
+        LexiconFile::List(entries) => entries
+            .into_iter()
+            .map(|keyed| (normalize_lexicon_key(&keyed.key), keyed.entry))
+            .collect(),
+    };
+    if entries.is_empty() {
+        return Err("The lexicon has no entries".to_string());
+    }
+    Ok(entries)
+}
+
+// Imported entries are kept in IndexedDB with one record per key, so a full lexicon
+// never has to fit in one localStorage key and an entry can be read on its own
+const LEXICON_DATABASE: &str = "LexiconCache";
+const ENTRIES_STORE: &str = "entries";
+
+async fn open_database() -> Result<Rexie, Box<dyn std::error::Error>> {
+    let rexie = Rexie::builder(LEXICON_DATABASE)
+        .version(1)
+        .add_object_store(ObjectStore::new(ENTRIES_STORE))
+        .build()
+        .await
+        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    Ok(rexie)
+}
+
+async fn save_entries(
+    entries: &HashMap<String, LexiconEntry>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[ENTRIES_STORE], TransactionMode::ReadWrite)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(ENTRIES_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    for (key, entry) in entries {
+        let json = serde_json::to_string(entry)
+            .map_err(|e| format!("Failed to serialize lexicon entry: {:?}", e))?;
+        store
+            .put(&json.into(), Some(&key.into()))
+            .await
+            .map_err(|e| format!("Failed to save lexicon entry {}: {:?}", key, e))?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {:?}", e))?;
+    Ok(())
+}
+
+// Move a lexicon imported before it was stored per entry into IndexedDB
+async fn migrate_legacy_lexicon() {
+    let Ok(lexicon) = LocalStorage::get::<HashMap<String, LexiconEntry>>(LEGACY_LEXICON_KEY) else {
+        return;
+    };
+    match save_entries(&lexicon).await {
+        Ok(()) => LocalStorage::delete(LEGACY_LEXICON_KEY),
+        Err(e) => leptos::logging::error!("Failed to migrate lexicon: {}", e),
+    }
+}
+
+async fn load_entry(key: &str) -> Result<Option<LexiconEntry>, Box<dyn std::error::Error>> {
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[ENTRIES_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(ENTRIES_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+    let Some(value) = store
+        .get(key.into())
+        .await
+        .map_err(|e| format!("Failed to read lexicon entry: {:?}", e))?
+    else {
+        return Ok(None);
+    };
+    let json = value.as_string().ok_or("Invalid stored lexicon entry")?;
+    let entry = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse lexicon entry: {:?}", e))?;
+    Ok(Some(entry))
+}
+
+async fn count_entries() -> Result<usize, Box<dyn std::error::Error>> {
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[ENTRIES_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(ENTRIES_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+    let count = store
+        .count(None)
+        .await
+        .map_err(|e| format!("Failed to count lexicon entries: {:?}", e))?;
+    Ok(count as usize)
+}
+
+/// The imported entry for a normalized Strong's number or lemma
+pub async fn get_lexicon_entry(key: &str) -> Option<LexiconEntry> {
+    migrate_legacy_lexicon().await;
+    load_entry(key).await.unwrap_or_else(|e| {
+        leptos::logging::error!("Failed to load lexicon entry: {}", e);
+        None
+    })
+}
+
+/// How many entries the reader has imported
+pub async fn count_lexicon_entries() -> usize {
+    migrate_legacy_lexicon().await;
+    count_entries().await.unwrap_or_else(|e| {
+        leptos::logging::error!("Failed to count lexicon entries: {}", e);
+        0
+    })
+}
+
+/// Add imported entries to the stored lexicon, replacing entries with the same key
+pub async fn import_lexicon(
+    entries: HashMap<String, LexiconEntry>,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    migrate_legacy_lexicon().await;
+    save_entries(&entries).await?;
+    Ok(entries.len())
+}
+
+pub async fn clear_lexicon() -> Result<(), Box<dyn std::error::Error>> {
+    LocalStorage::delete(LEGACY_LEXICON_KEY);
+    let rexie = open_database().await?;
+    let transaction = rexie
+        .transaction(&[ENTRIES_STORE], TransactionMode::ReadWrite)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(ENTRIES_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+    store
+        .clear()
+        .await
+        .map_err(|e| format!("Failed to clear lexicon: {:?}", e))?;
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("Failed to commit transaction: {:?}", e))?;
+    Ok(())
+}
+
+/// Recently viewed lexicon keys, most recent first
+pub fn get_lexicon_history() -> Vec<String> {
+    LocalStorage::get(LEXICON_HISTORY_KEY).unwrap_or_default()
+}
+
+pub fn save_lexicon_history(history: &[String]) {
+    let _ = LocalStorage::set(LEXICON_HISTORY_KEY, history);
+}
+
+/// Move `key` to the front of `history`, dropping the oldest beyond the limit
+pub fn push_lexicon_history(history: &mut Vec<String>, key: &str) {
+    history.retain(|viewed| viewed != key);
+    history.insert(0, key.to_string());
+    history.truncate(LEXICON_HISTORY_LIMIT);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lexicon() {
+        let map = parse_lexicon(
+            r#"{"G3056": {"lemma": "λόγος", "gloss": "word", "definition": "something said"}}"#,
+        )
+        .unwrap();
+        assert_eq!(map["G3056"].gloss.as_deref(), Some("word"));
+
+        let list = parse_lexicon(
+            r#"[{"strongs": "H0430", "lemma": "אֱלֹהִים", "transliteration": "elohim", "gloss": "God"}]"#,
+        )
+        .unwrap();
+        assert_eq!(list["H430"].transliteration.as_deref(), Some("elohim"));
+        assert_eq!(list["H430"].definition, None);
+
+        assert!(parse_lexicon("[]").is_err());
+        assert!(parse_lexicon("not json").is_err());
+    }
+
+    #[test]
+    fn test_push_lexicon_history() {
+        let mut history = vec!["G3056".to_string(), "H430".to_string()];
+        push_lexicon_history(&mut history, "H430");
+        assert_eq!(history, vec!["H430", "G3056"]);
+
+        for number in 0..LEXICON_HISTORY_LIMIT {
+            push_lexicon_history(&mut history, &format!("G{}", number));
+        }
+        assert_eq!(history.len(), LEXICON_HISTORY_LIMIT);
+        assert_eq!(history[0], format!("G{}", LEXICON_HISTORY_LIMIT - 1));
+    }
+}
@@ -1,13 +1,37 @@
+pub mod annotation_backup;
+pub mod book_abbreviations;
+pub mod chapter_records;
+pub mod commentaries;
+pub mod completed_chapters;
+pub mod highlights;
+pub mod lexicon;
+pub mod notes;
+pub mod palette_aliases;
+pub mod pinned_chapters;
 pub mod recent_chapters;
+pub mod recent_searches;
+pub mod registers;
+pub mod section_headings;
 pub mod sidebar_storage;
+pub mod source_text;
+pub mod translation_edits;
 pub mod translation_manager;
 pub mod translation_storage;
 pub mod translations;
+pub mod workspaces;
 
+pub use completed_chapters::{get_completed_chapters, save_completed_chapters};
+pub use highlights::*;
+pub use notes::*;
 pub use recent_chapters::*;
 pub use sidebar_storage::{
-    get_references_sidebar_open, get_selected_theme, get_sidebar_open, get_verse_visibility,
-    save_references_sidebar_open, save_selected_theme, save_sidebar_open, save_verse_visibility,
+    get_collapsed_testaments, get_expanded_books, get_read_aloud_rate, get_references_sidebar_open,
+    get_selected_theme, get_show_section_headings, get_sidebar_open, get_text_columns,
+    get_ui_language, get_verse_visibility, save_collapsed_testaments, save_expanded_books,
+    save_read_aloud_rate, save_references_sidebar_open, save_selected_theme,
+    save_show_section_headings, save_sidebar_open, save_text_columns, save_ui_language,
+    save_verse_visibility, MAX_TEXT_COLUMNS,
 };
+pub use translation_edits::*;
 pub use translation_storage::*;
 pub use translations::*;
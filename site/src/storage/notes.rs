@@ -0,0 +1,122 @@
+use super::highlights::stored_chapter_key;
+use crate::core::ChapterKey;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const NOTES_KEY: &str = "bible_notes";
+
+/// A note the reader wrote on a verse, by book key so it shows in every translation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "StoredNote")]
+pub struct VerseNote {
+    pub book: String,
+    pub chapter: u32,
+    pub verse: u32,
+    pub text: String,
+}
+
+impl VerseNote {
+    pub fn is_in(&self, key: &ChapterKey) -> bool {
+        self.book == key.book && self.chapter == key.chapter
+    }
+}
+
+/// A note as stored, either by book key or by the chapter path notes used to be stored under
+#[derive(Deserialize)]
+struct StoredNote {
+    #[serde(default)]
+    book: Option<String>,
+    #[serde(default)]
+    chapter: u32,
+    #[serde(default)]
+    chapter_path: Option<String>,
+    verse: u32,
+    text: String,
+}
+
+impl TryFrom<StoredNote> for VerseNote {
+    type Error = String;
+
+    fn try_from(stored: StoredNote) -> Result<Self, String> {
+        let key = stored_chapter_key(stored.book, stored.chapter, stored.chapter_path)?;
+        Ok(VerseNote {
+            book: key.book,
+            chapter: key.chapter,
+            verse: stored.verse,
+            text: stored.text,
+        })
+    }
+}
+
+pub fn get_notes() -> Vec<VerseNote> {
+    LocalStorage::get(NOTES_KEY).unwrap_or_default()
+}
+
+pub fn save_notes(notes: &[VerseNote]) {
+    let _ = LocalStorage::set(NOTES_KEY, notes);
+}
+
+/// The note on a verse, if there is one
+pub fn find_note<'a>(
+    notes: &'a [VerseNote],
+    key: &ChapterKey,
+    verse: u32,
+) -> Option<&'a VerseNote> {
+    notes
+        .iter()
+        .find(|note| note.is_in(key) && note.verse == verse)
+}
+
+/// Replace the note on a verse, removing it when `text` is blank
+pub fn set_note(notes: &mut Vec<VerseNote>, key: &ChapterKey, verse: u32, text: &str) {
+    notes.retain(|note| !(note.is_in(key) && note.verse == verse));
+    if !text.trim().is_empty() {
+        notes.push(VerseNote {
+            book: key.book.clone(),
+            chapter: key.chapter,
+            verse,
+            text: text.trim_end().to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_note() {
+        let john_3 = ChapterKey {
+            book: "john".to_string(),
+            chapter: 3,
+        };
+        let mut notes = Vec::new();
+        set_note(&mut notes, &john_3, 16, "God so loved ");
+        set_note(&mut notes, &john_3, 17, "Not to condemn");
+        set_note(&mut notes, &john_3, 16, "Gospel in a nutshell");
+        assert_eq!(
+            find_note(&notes, &john_3, 16).map(|note| note.text.as_str()),
+            Some("Gospel in a nutshell")
+        );
+        assert_eq!(notes.len(), 2);
+
+        set_note(&mut notes, &john_3, 17, "  ");
+        assert_eq!(find_note(&notes, &john_3, 17), None);
+    }
+
+    #[test]
+    fn test_migrate_note_chapter_path() {
+        let stored =
+            "[{\"chapter_path\": \"/1%20John/4\", \"verse\": 8, \"text\": \"God is love\"}]";
+        let notes: Vec<VerseNote> = serde_json::from_str(stored).unwrap();
+        assert_eq!(
+            notes,
+            vec![VerseNote {
+                book: "first_john".to_string(),
+                chapter: 4,
+                verse: 8,
+                text: "God is love".to_string(),
+            }]
+        );
+    }
+}
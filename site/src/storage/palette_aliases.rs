@@ -0,0 +1,87 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const PALETTE_ALIASES_KEY: &str = "palette_aliases";
+
+/// A shortcut typed in the palette, e.g. `sotm` for `/Matthew/5`.
+///
+/// The target is a path, the name of a palette command, or any other palette query.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PaletteAlias {
+    pub name: String,
+    pub target: String,
+}
+
+pub fn get_palette_aliases() -> Vec<PaletteAlias> {
+    LocalStorage::get(PALETTE_ALIASES_KEY).unwrap_or_default()
+}
+
+/// Add an alias, replacing an existing alias with the same name
+pub fn add_palette_alias(name: &str, target: &str) -> Result<(), String> {
+    let mut aliases = get_palette_aliases();
+    upsert_alias(&mut aliases, name, target)?;
+    LocalStorage::set(PALETTE_ALIASES_KEY, &aliases).map_err(|e| e.to_string())
+}
+
+pub fn remove_palette_alias(name: &str) {
+    let mut aliases = get_palette_aliases();
+    aliases.retain(|alias| alias.name != name);
+    let _ = LocalStorage::set(PALETTE_ALIASES_KEY, &aliases);
+}
+
+/// The alias whose name is exactly the query, ignoring case and surrounding spaces
+pub fn find_palette_alias<'a>(
+    aliases: &'a [PaletteAlias],
+    query: &str,
+) -> Option<&'a PaletteAlias> {
+    let query = query.trim();
+    aliases
+        .iter()
+        .find(|alias| alias.name.eq_ignore_ascii_case(query))
+}
+
+fn upsert_alias(aliases: &mut Vec<PaletteAlias>, name: &str, target: &str) -> Result<(), String> {
+    let name = name.trim().to_lowercase();
+    let target = target.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err("An alias must be a single word".to_string());
+    }
+    if target.is_empty() {
+        return Err("An alias needs a target".to_string());
+    }
+
+    let alias = PaletteAlias {
+        name,
+        target: target.to_string(),
+    };
+    match aliases
+        .iter_mut()
+        .find(|existing| existing.name == alias.name)
+    {
+        Some(existing) => *existing = alias,
+        None => aliases.push(alias),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upsert_and_find_alias() {
+        let mut aliases = Vec::new();
+        upsert_alias(&mut aliases, " SotM ", "/Matthew/4").unwrap();
+        upsert_alias(&mut aliases, "sotm", "/Matthew/5").unwrap();
+        upsert_alias(&mut aliases, "xp", "Export to PDF").unwrap();
+
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(
+            find_palette_alias(&aliases, "SOTM").map(|a| a.target.as_str()),
+            Some("/Matthew/5")
+        );
+        assert!(find_palette_alias(&aliases, "sot").is_none());
+        assert!(upsert_alias(&mut aliases, "two words", "/John/3").is_err());
+        assert!(upsert_alias(&mut aliases, "empty", " ").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PinnedChapter {
+    pub book_name: String,
+    pub chapter: u32,
+    pub display_name: String,
+    pub path: String,
+}
+
+const PINNED_CHAPTERS_KEY: &str = "bible_pinned_chapters";
+
+pub fn get_pinned_chapters() -> Vec<PinnedChapter> {
+    LocalStorage::get(PINNED_CHAPTERS_KEY).unwrap_or_default()
+}
+
+/// Pin the chapter if it isn't pinned yet, otherwise unpin it.
+/// Returns whether the chapter is pinned afterwards.
+pub fn toggle_pinned_chapter(
+    book_name: String,
+    chapter: u32,
+    display_name: String,
+    path: String,
+) -> bool {
+    let mut pinned_chapters = get_pinned_chapters();
+
+    let is_pinned = if pinned_chapters.iter().any(|ch| ch.path == path) {
+        pinned_chapters.retain(|ch| ch.path != path);
+        false
+    } else {
+        // Newly pinned chapters go to the end so the existing order stays stable
+        pinned_chapters.push(PinnedChapter {
+            book_name,
+            chapter,
+            display_name,
+            path,
+        });
+        true
+    };
+
+    save_pinned_chapters(&pinned_chapters);
+    is_pinned
+}
+
+pub fn save_pinned_chapters(pinned_chapters: &[PinnedChapter]) {
+    let _ = LocalStorage::set(PINNED_CHAPTERS_KEY, pinned_chapters);
+}
@@ -11,31 +11,79 @@ pub struct RecentChapter {
 }
 
 const RECENT_CHAPTERS_KEY: &str = "bible_recent_chapters";
-const MAX_RECENT_CHAPTERS: usize = 10;
+const RECENT_CHAPTERS_LIMIT_KEY: &str = "bible_recent_chapters_limit";
+const PRIVATE_HISTORY_KEY: &str = "bible_private_history";
+const DEFAULT_RECENT_CHAPTERS: usize = 10;
 
-pub fn get_recent_chapters() -> Vec<RecentChapter> {
+/// History sizes offered in the palette footer
+pub const RECENT_CHAPTERS_LIMITS: [usize; 4] = [5, 10, 25, 50];
+
+fn get_item(key: &str) -> Option<String> {
+    web_sys::window()?.local_storage().ok()??.get_item(key).ok()?
+}
+
+fn set_item(key: &str, value: &str) {
     if let Some(window) = web_sys::window() {
         if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(Some(stored)) = storage.get_item(RECENT_CHAPTERS_KEY) {
-                if let Ok(chapters) = serde_json::from_str::<Vec<RecentChapter>>(&stored) {
-                    return chapters;
-                }
-            }
+            let _ = storage.set_item(key, value);
         }
     }
-    Vec::new()
 }
 
-pub fn add_recent_chapter(book_name: String, chapter: u32, display_name: String, path: String) {
+pub fn get_recent_chapters() -> Vec<RecentChapter> {
+    get_item(RECENT_CHAPTERS_KEY)
+        .and_then(|stored| serde_json::from_str::<Vec<RecentChapter>>(&stored).ok())
+        .unwrap_or_default()
+}
+
+fn save_recent_chapters(recent_chapters: &[RecentChapter]) {
+    if let Ok(serialized) = serde_json::to_string(recent_chapters) {
+        set_item(RECENT_CHAPTERS_KEY, &serialized);
+    }
+}
+
+/// How many chapters the history keeps
+pub fn get_recent_chapters_limit() -> usize {
+    get_item(RECENT_CHAPTERS_LIMIT_KEY)
+        .and_then(|stored| stored.parse().ok())
+        .unwrap_or(DEFAULT_RECENT_CHAPTERS)
+}
+
+/// Change the history size, dropping the oldest chapters beyond it
+pub fn save_recent_chapters_limit(limit: usize) {
+    set_item(RECENT_CHAPTERS_LIMIT_KEY, &limit.to_string());
+
     let mut recent_chapters = get_recent_chapters();
+    if recent_chapters.len() > limit {
+        recent_chapters.truncate(limit);
+        save_recent_chapters(&recent_chapters);
+    }
+}
 
-    // Remove if already exists (to avoid duplicates and move to front)
-    recent_chapters.retain(|ch| !(ch.book_name == book_name && ch.chapter == chapter));
+/// Whether private mode is on, in which visited chapters aren't recorded
+pub fn is_history_private() -> bool {
+    get_item(PRIVATE_HISTORY_KEY).is_some_and(|stored| stored == "true")
+}
+
+pub fn save_history_private(private: bool) {
+    set_item(PRIVATE_HISTORY_KEY, &private.to_string());
+}
+
+pub fn clear_recent_chapters() {
+    save_recent_chapters(&[]);
+}
+
+pub fn add_recent_chapter(book_name: String, chapter: u32, display_name: String, path: String) {
+    if is_history_private() {
+        return;
+    }
+
+    let mut recent_chapters = get_recent_chapters();
 
     // Add to front (use simple incrementing timestamp)
     let timestamp = recent_chapters.len() as u64;
-    recent_chapters.insert(
-        0,
+    push_recent_chapter(
+        &mut recent_chapters,
         RecentChapter {
             book_name,
             chapter,
@@ -43,17 +91,52 @@ pub fn add_recent_chapter(book_name: String, chapter: u32, display_name: String,
             path,
             timestamp,
         },
+        get_recent_chapters_limit(),
     );
 
+    save_recent_chapters(&recent_chapters);
+}
+
+fn push_recent_chapter(
+    recent_chapters: &mut Vec<RecentChapter>,
+    recent: RecentChapter,
+    limit: usize,
+) {
+    // Remove if already exists (to avoid duplicates and move to front)
+    recent_chapters
+        .retain(|ch| !(ch.book_name == recent.book_name && ch.chapter == recent.chapter));
+    recent_chapters.insert(0, recent);
+
     // Keep only the most recent ones
-    recent_chapters.truncate(MAX_RECENT_CHAPTERS);
+    recent_chapters.truncate(limit);
+}
 
-    // Save to localStorage
-    if let Some(window) = web_sys::window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(serialized) = serde_json::to_string(&recent_chapters) {
-                let _ = storage.set_item(RECENT_CHAPTERS_KEY, &serialized);
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recent(book_name: &str, chapter: u32) -> RecentChapter {
+        RecentChapter {
+            book_name: book_name.to_string(),
+            chapter,
+            display_name: format!("{} {}", book_name, chapter),
+            path: format!("/{}/{}", book_name, chapter),
+            timestamp: 0,
         }
     }
+
+    #[test]
+    fn test_push_recent_chapter() {
+        let mut recent_chapters = Vec::new();
+        for (book_name, chapter) in [("John", 1), ("John", 2), ("Acts", 1), ("John", 1)] {
+            push_recent_chapter(&mut recent_chapters, recent(book_name, chapter), 3);
+        }
+        assert_eq!(
+            recent_chapters,
+            vec![recent("John", 1), recent("Acts", 1), recent("John", 2)]
+        );
+
+        push_recent_chapter(&mut recent_chapters, recent("Ruth", 4), 2);
+        assert_eq!(recent_chapters, vec![recent("Ruth", 4), recent("John", 1)]);
+    }
 }
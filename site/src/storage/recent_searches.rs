@@ -0,0 +1,42 @@
+use gloo_storage::{LocalStorage, Storage};
+
+const RECENT_SEARCHES_KEY: &str = "recent_searches";
+const MAX_RECENT_SEARCHES: usize = 5;
+
+/// Bible text searches from the palette, most recent first
+pub fn get_recent_searches() -> Vec<String> {
+    LocalStorage::get(RECENT_SEARCHES_KEY).unwrap_or_default()
+}
+
+pub fn add_recent_search(query: &str) {
+    let mut searches = get_recent_searches();
+    push_recent_search(&mut searches, query);
+    let _ = LocalStorage::set(RECENT_SEARCHES_KEY, &searches);
+}
+
+fn push_recent_search(searches: &mut Vec<String>, query: &str) {
+    let query = query.trim();
+    if query.is_empty() {
+        return;
+    }
+
+    // Repeating a search moves it to the front instead of adding a duplicate
+    searches.retain(|search| !search.eq_ignore_ascii_case(query));
+    searches.insert(0, query.to_string());
+    searches.truncate(MAX_RECENT_SEARCHES);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_recent_search() {
+        let mut searches = Vec::new();
+        for query in ["light", "shepherd", " Light ", "", "a", "b", "c", "d"] {
+            push_recent_search(&mut searches, query);
+        }
+
+        assert_eq!(searches, vec!["d", "c", "b", "a", "Light"]);
+    }
+}
@@ -0,0 +1,55 @@
+use gloo_storage::{LocalStorage, Storage};
+use std::collections::BTreeMap;
+
+const REGISTERS_KEY: &str = "registers";
+
+/// Named copy registers (`"a` to `"z`) and their text
+pub type Registers = BTreeMap<char, String>;
+
+/// Whether a key can name a register; uppercase letters append to the lowercase register
+pub fn is_register_name(name: char) -> bool {
+    name.is_ascii_alphabetic()
+}
+
+pub fn get_registers() -> Registers {
+    LocalStorage::get(REGISTERS_KEY).unwrap_or_default()
+}
+
+pub fn get_register(name: char) -> Option<String> {
+    get_registers().remove(&name.to_ascii_lowercase())
+}
+
+/// Store copied text in a register, appending when the name is uppercase like in vim
+pub fn store_in_register(name: char, text: &str) {
+    let mut registers = get_registers();
+    write_register(&mut registers, name, text);
+    let _ = LocalStorage::set(REGISTERS_KEY, &registers);
+}
+
+fn write_register(registers: &mut Registers, name: char, text: &str) {
+    let register = registers.entry(name.to_ascii_lowercase()).or_default();
+    if name.is_ascii_uppercase() && !register.is_empty() {
+        register.push_str("\n\n");
+        register.push_str(text);
+    } else {
+        *register = text.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_register_append() {
+        let mut registers = Registers::new();
+        write_register(&mut registers, 'a', "In the beginning");
+        write_register(&mut registers, 'A', "And the earth");
+        write_register(&mut registers, 'b', "Jesus wept.");
+        write_register(&mut registers, 'b', "He is risen");
+
+        assert_eq!(registers[&'a'], "In the beginning\n\nAnd the earth");
+        assert_eq!(registers[&'b'], "He is risen");
+        assert!(!registers.contains_key(&'A'));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::core::SectionHeading;
+use crate::storage::chapter_records::{
+    clear_chapter_records, load_all_chapter_records, load_chapter_records, save_chapter_records,
+};
+use gloo_storage::{LocalStorage, Storage};
+
+const SECTION_HEADINGS_DATABASE: &str = "SectionHeadingCache";
+
+// Headings used to be kept in a single localStorage key per translation
+fn legacy_headings_key(translation_short_name: &str) -> String {
+    format!("section_headings_{}", translation_short_name)
+}
+
+// Move headings saved before they were stored per chapter into IndexedDB
+async fn migrate_legacy_headings(translation_short_name: &str) {
+    let key = legacy_headings_key(translation_short_name);
+    let Ok(headings) = LocalStorage::get::<Vec<SectionHeading>>(&key) else {
+        return;
+    };
+    match save_section_headings(translation_short_name, &headings).await {
+        Ok(()) => LocalStorage::delete(&key),
+        Err(e) => leptos::logging::error!("Failed to migrate section headings: {}", e),
+    }
+}
+
+/// Section headings and titles of one chapter; empty when the translation has none
+pub async fn get_chapter_section_headings(
+    translation_short_name: &str,
+    book_index: usize,
+    chapter: u32,
+) -> Vec<SectionHeading> {
+    migrate_legacy_headings(translation_short_name).await;
+    load_chapter_records(
+        SECTION_HEADINGS_DATABASE,
+        translation_short_name,
+        book_index,
+        chapter,
+    )
+    .await
+    .unwrap_or_else(|e| {
+        leptos::logging::error!("Failed to load section headings: {}", e);
+        Vec::new()
+    })
+}
+
+/// Section headings of a translation; empty when its source had none
+pub async fn get_section_headings(translation_short_name: &str) -> Vec<SectionHeading> {
+    migrate_legacy_headings(translation_short_name).await;
+    load_all_chapter_records(SECTION_HEADINGS_DATABASE, translation_short_name)
+        .await
+        .unwrap_or_else(|e| {
+            leptos::logging::error!("Failed to load section headings: {}", e);
+            Vec::new()
+        })
+}
+
+pub async fn save_section_headings(
+    translation_short_name: &str,
+    headings: &[SectionHeading],
+) -> Result<(), Box<dyn std::error::Error>> {
+    save_chapter_records(
+        SECTION_HEADINGS_DATABASE,
+        translation_short_name,
+        headings,
+        |heading| (heading.book_index, heading.chapter),
+    )
+    .await
+}
+
+pub async fn clear_section_headings(
+    translation_short_name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    LocalStorage::delete(legacy_headings_key(translation_short_name));
+    clear_chapter_records(SECTION_HEADINGS_DATABASE, translation_short_name).await
+}
@@ -1,9 +1,20 @@
+use crate::core::types::Language;
+use crate::core::{Testament, READ_ALOUD_RATES};
 use gloo_storage::{LocalStorage, Storage};
 
 const SIDEBAR_OPEN_KEY: &str = "sidebar_open";
 const REFERENCES_SIDEBAR_OPEN_KEY: &str = "references_sidebar_open";
 const VERSE_VISIBILITY_KEY: &str = "verse_visibility";
 const SELECTED_THEME_KEY: &str = "selected_theme";
+const TEXT_COLUMNS_KEY: &str = "text_columns";
+const COLLAPSED_TESTAMENTS_KEY: &str = "collapsed_testaments";
+const EXPANDED_BOOKS_KEY: &str = "expanded_books";
+const UI_LANGUAGE_KEY: &str = "ui_language";
+const READ_ALOUD_RATE_KEY: &str = "read_aloud_rate";
+const SHOW_SECTION_HEADINGS_KEY: &str = "show_section_headings";
+
+/// Most columns the chapter text can be split into
+pub const MAX_TEXT_COLUMNS: u8 = 3;
 
 pub fn get_sidebar_open() -> bool {
     LocalStorage::get(SIDEBAR_OPEN_KEY).unwrap_or(true)
@@ -29,6 +40,57 @@ pub fn save_verse_visibility(visible: bool) {
     let _ = LocalStorage::set(VERSE_VISIBILITY_KEY, visible);
 }
 
+/// Number of columns the chapter text is laid out in on wide screens
+pub fn get_text_columns() -> u8 {
+    LocalStorage::get(TEXT_COLUMNS_KEY)
+        .unwrap_or(1)
+        .clamp(1, MAX_TEXT_COLUMNS)
+}
+
+pub fn save_text_columns(columns: u8) {
+    let _ = LocalStorage::set(TEXT_COLUMNS_KEY, columns);
+}
+
+/// Speaking rate for reading chapters aloud
+pub fn get_read_aloud_rate() -> f32 {
+    LocalStorage::get::<f32>(READ_ALOUD_RATE_KEY)
+        .unwrap_or(1.0)
+        .clamp(
+            READ_ALOUD_RATES[0],
+            READ_ALOUD_RATES[READ_ALOUD_RATES.len() - 1],
+        )
+}
+
+pub fn save_read_aloud_rate(rate: f32) {
+    let _ = LocalStorage::set(READ_ALOUD_RATE_KEY, rate);
+}
+
+pub fn get_show_section_headings() -> bool {
+    LocalStorage::get(SHOW_SECTION_HEADINGS_KEY).unwrap_or(true)
+}
+
+pub fn save_show_section_headings(show: bool) {
+    let _ = LocalStorage::set(SHOW_SECTION_HEADINGS_KEY, show);
+}
+
+/// Testaments whose books are hidden in the books sidebar
+pub fn get_collapsed_testaments() -> Vec<Testament> {
+    LocalStorage::get(COLLAPSED_TESTAMENTS_KEY).unwrap_or_default()
+}
+
+pub fn save_collapsed_testaments(testaments: &[Testament]) {
+    let _ = LocalStorage::set(COLLAPSED_TESTAMENTS_KEY, testaments);
+}
+
+/// Books whose chapters are shown in the books sidebar, `None` until it has been saved once
+pub fn get_expanded_books() -> Option<Vec<String>> {
+    LocalStorage::get(EXPANDED_BOOKS_KEY).ok()
+}
+
+pub fn save_expanded_books(books: &[String]) {
+    let _ = LocalStorage::set(EXPANDED_BOOKS_KEY, books);
+}
+
 pub fn get_selected_theme() -> String {
     LocalStorage::get(SELECTED_THEME_KEY).unwrap_or_else(|_| "light".to_string())
 }
@@ -36,3 +98,17 @@ pub fn get_selected_theme() -> String {
 pub fn save_selected_theme(theme_id: &str) {
     let _ = LocalStorage::set(SELECTED_THEME_KEY, theme_id);
 }
+
+/// Language chosen for the interface, `None` to follow the current translation
+pub fn get_ui_language() -> Option<Language> {
+    LocalStorage::get(UI_LANGUAGE_KEY).ok()
+}
+
+pub fn save_ui_language(language: Option<Language>) {
+    match language {
+        Some(language) => {
+            let _ = LocalStorage::set(UI_LANGUAGE_KEY, language);
+        }
+        None => LocalStorage::delete(UI_LANGUAGE_KEY),
+    }
+}
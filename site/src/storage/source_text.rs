@@ -0,0 +1,78 @@
+use crate::core::SourceVerse;
+use crate::storage::chapter_records::{
+    clear_chapter_records, load_all_chapter_records, load_chapter_records, save_chapter_records,
+};
+use gloo_storage::{LocalStorage, Storage};
+
+const SOURCE_TEXT_DATABASE: &str = "SourceTextCache";
+
+// Source text used to be kept in a single localStorage key per translation
+fn legacy_source_text_key(translation_short_name: &str) -> String {
+    format!("source_text_{}", translation_short_name)
+}
+
+// Move source text saved before it was stored per chapter into IndexedDB
+async fn migrate_legacy_source_text(translation_short_name: &str) {
+    let key = legacy_source_text_key(translation_short_name);
+    let Ok(source_text) = LocalStorage::get::<Vec<SourceVerse>>(&key) else {
+        return;
+    };
+    match save_source_text(translation_short_name, &source_text).await {
+        Ok(()) => LocalStorage::delete(&key),
+        Err(e) => leptos::logging::error!("Failed to migrate source text: {}", e),
+    }
+}
+
+/// Hebrew and Greek text of one chapter; empty when the translation has none
+pub async fn get_chapter_source_text(
+    translation_short_name: &str,
+    book_index: usize,
+    chapter: u32,
+) -> Vec<SourceVerse> {
+    migrate_legacy_source_text(translation_short_name).await;
+    load_chapter_records(
+        SOURCE_TEXT_DATABASE,
+        translation_short_name,
+        book_index,
+        chapter,
+    )
+    .await
+    .unwrap_or_else(|e| {
+        leptos::logging::error!("Failed to load source text: {}", e);
+        Vec::new()
+    })
+}
+
+/// All Hebrew and Greek text packaged with a translation; empty when it has none
+pub async fn get_source_text(translation_short_name: &str) -> Vec<SourceVerse> {
+    migrate_legacy_source_text(translation_short_name).await;
+    let mut source_text: Vec<SourceVerse> =
+        load_all_chapter_records(SOURCE_TEXT_DATABASE, translation_short_name)
+            .await
+            .unwrap_or_else(|e| {
+                leptos::logging::error!("Failed to load source text: {}", e);
+                Vec::new()
+            });
+    source_text.sort_by_key(|verse| (verse.book_index, verse.chapter, verse.verse));
+    source_text
+}
+
+pub async fn save_source_text(
+    translation_short_name: &str,
+    source_text: &[SourceVerse],
+) -> Result<(), Box<dyn std::error::Error>> {
+    save_chapter_records(
+        SOURCE_TEXT_DATABASE,
+        translation_short_name,
+        source_text,
+        |verse| (verse.book_index, verse.chapter),
+    )
+    .await
+}
+
+pub async fn clear_source_text(
+    translation_short_name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    LocalStorage::delete(legacy_source_text_key(translation_short_name));
+    clear_chapter_records(SOURCE_TEXT_DATABASE, translation_short_name).await
+}
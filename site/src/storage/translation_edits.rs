@@ -0,0 +1,53 @@
+use crate::core::Book;
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+/// A corrected verse text, stored on top of the imported translation.
+/// Books are identified by position so edits survive book name translation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VerseEdit {
+    pub book_index: usize,
+    pub chapter: u32,
+    pub verse: u32,
+    pub text: String,
+}
+
+fn edits_key(translation_short_name: &str) -> String {
+    format!("translation_edits_{}", translation_short_name)
+}
+
+pub fn get_translation_edits(translation_short_name: &str) -> Vec<VerseEdit> {
+    LocalStorage::get(edits_key(translation_short_name)).unwrap_or_default()
+}
+
+/// Store a corrected verse, replacing an earlier correction of the same verse
+pub fn save_verse_edit(
+    translation_short_name: &str,
+    edit: VerseEdit,
+) -> Result<(), gloo_storage::errors::StorageError> {
+    let mut edits = get_translation_edits(translation_short_name);
+    edits.retain(|e| {
+        !(e.book_index == edit.book_index && e.chapter == edit.chapter && e.verse == edit.verse)
+    });
+    edits.push(edit);
+    LocalStorage::set(edits_key(translation_short_name), &edits)
+}
+
+pub fn clear_translation_edits(translation_short_name: &str) {
+    LocalStorage::delete(edits_key(translation_short_name));
+}
+
+/// Apply stored corrections to a freshly loaded book, the one at `book_index`
+pub fn apply_book_edits(book_index: usize, mut book: Book, edits: &[VerseEdit]) -> Book {
+    for edit in edits.iter().filter(|edit| edit.book_index == book_index) {
+        let verse = book
+            .chapters
+            .iter_mut()
+            .find(|c| c.chapter == edit.chapter)
+            .and_then(|chapter| chapter.verses.iter_mut().find(|v| v.verse == edit.verse));
+        if let Some(verse) = verse {
+            verse.text = edit.text.clone();
+        }
+    }
+    book
+}
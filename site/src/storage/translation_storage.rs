@@ -1,11 +1,17 @@
-use crate::api::{try_fetch_bible, try_fetch_bible_with_progress};
+use crate::api::{set_current_bible, try_fetch_bible, try_fetch_bible_with_progress, BookSource};
 use crate::components::custom_translation_import::_remove_custom_translation;
 use crate::core::types::Language;
-use crate::core::{init_bible_signal, Bible};
+use crate::core::{decode_bible, decode_book, encode_book, Bible, BibleIndex, Book, Versification};
+use crate::storage::section_headings::clear_section_headings;
+use crate::storage::source_text::clear_source_text;
+use crate::storage::translation_edits::{
+    apply_book_edits, clear_translation_edits, get_translation_edits,
+};
 use gloo_storage::{LocalStorage, Storage};
-use leptos::prelude::Set;
-use rexie::{ObjectStore, Rexie, TransactionMode};
+use rexie::{KeyRange, ObjectStore, Rexie, TransactionMode};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BibleTranslation {
@@ -14,10 +20,20 @@ pub struct BibleTranslation {
     pub release_year: u16,
     pub iagon: String,
     pub languages: Vec<Language>,
+    /// Revision of the hosted translation data, bumped whenever the text is corrected
+    #[serde(default)]
+    pub revision: u32,
+    /// What changed in each revision, newest last
+    #[serde(default)]
+    pub changelog: Vec<String>,
+    /// How the translation numbers chapters and verses, so comparisons line verses up
+    #[serde(default)]
+    pub versification: Versification,
 }
 
 const SELECTED_TRANSLATION_KEY: &str = "selected_translation";
 const DOWNLOADED_TRANSLATIONS_KEY: &str = "downloaded_translations";
+const DOWNLOADED_REVISIONS_KEY: &str = "downloaded_translation_revisions";
 
 pub fn get_selected_translation() -> Option<String> {
     LocalStorage::get(SELECTED_TRANSLATION_KEY).ok()
@@ -48,6 +64,82 @@ pub fn is_translation_downloaded(translation_short_name: &str) -> bool {
     get_downloaded_translations().contains(&translation_short_name.to_string())
 }
 
+/// Revision of the translation data that was downloaded. Translations downloaded
+/// before revisions were tracked are assumed to be at the first revision.
+pub fn get_downloaded_revision(translation_short_name: &str) -> u32 {
+    LocalStorage::get::<HashMap<String, u32>>(DOWNLOADED_REVISIONS_KEY)
+        .ok()
+        .and_then(|revisions| revisions.get(translation_short_name).copied())
+        .unwrap_or(1)
+}
+
+fn save_downloaded_revision(
+    translation_short_name: &str,
+    revision: u32,
+) -> Result<(), gloo_storage::errors::StorageError> {
+    let mut revisions =
+        LocalStorage::get::<HashMap<String, u32>>(DOWNLOADED_REVISIONS_KEY).unwrap_or_default();
+    revisions.insert(translation_short_name.to_string(), revision);
+    LocalStorage::set(DOWNLOADED_REVISIONS_KEY, &revisions)
+}
+
+/// Latest revision of a translation as listed in the hosted translation index
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HostedRevision {
+    pub short_name: String,
+    pub revision: u32,
+    #[serde(default)]
+    pub changelog: Vec<String>,
+    /// Where the new revision is hosted, when it moved from the compiled-in link
+    #[serde(default)]
+    pub iagon: Option<String>,
+}
+
+/// Translations whose downloaded revision is older than the one the hosted index lists,
+/// carrying the hosted revision, changelog and link so updating fetches the new data.
+/// Translations missing from `downloaded_revisions` are not downloaded and never listed.
+pub fn translations_with_updates(
+    translations: &[BibleTranslation],
+    downloaded_revisions: &HashMap<String, u32>,
+    hosted: &[HostedRevision],
+) -> Vec<BibleTranslation> {
+    translations
+        .iter()
+        .filter_map(|translation| {
+            let downloaded = downloaded_revisions.get(&translation.short_name)?;
+            let latest = hosted
+                .iter()
+                .find(|h| h.short_name == translation.short_name)?;
+            if latest.revision <= *downloaded {
+                return None;
+            }
+            let mut updated = translation.clone();
+            updated.revision = latest.revision;
+            updated.changelog = latest.changelog.clone();
+            if let Some(iagon) = &latest.iagon {
+                updated.iagon = iagon.clone();
+            }
+            Some(updated)
+        })
+        .collect()
+}
+
+/// Downloaded translations for which the hosted index lists a newer revision
+pub fn get_translations_with_updates(hosted: &[HostedRevision]) -> Vec<BibleTranslation> {
+    let downloaded_revisions: HashMap<String, u32> = get_downloaded_translations()
+        .into_iter()
+        .map(|short_name| {
+            let revision = get_downloaded_revision(&short_name);
+            (short_name, revision)
+        })
+        .collect();
+    translations_with_updates(
+        &crate::storage::translations::get_builtin_translations(),
+        &downloaded_revisions,
+        hosted,
+    )
+}
+
 pub fn remove_downloaded_translation(
     translation_short_name: &str,
 ) -> Result<(), gloo_storage::errors::StorageError> {
@@ -59,14 +151,17 @@ pub fn remove_downloaded_translation(
 pub async fn switch_bible_translation(
     translation_short_name: &str,
 ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-    let bible = if is_translation_downloaded(translation_short_name) {
-        load_downloaded_translation(translation_short_name).await?
-    } else {
+    if !is_translation_downloaded(translation_short_name) {
         return Err("Translation not downloaded".into());
-    };
+    }
 
-    let bible_signal = init_bible_signal();
-    bible_signal.set(Some(bible));
+    // Only the book names are read now; books follow as they are opened
+    let index = load_translation_index(translation_short_name).await?;
+    set_current_bible(
+        Bible::from_index(&index),
+        BookSource::Translation(translation_short_name.to_string()),
+        None,
+    );
 
     Ok(())
 }
@@ -81,8 +176,14 @@ pub async fn uninstall_translation(
         _remove_custom_translation(translation_short_name)?;
     }
 
-    let translation_cache_key = format!("translation_{}", translation_short_name);
-    remove_translation_from_cache(&translation_cache_key).await?;
+    remove_translation_from_cache(translation_short_name).await?;
+    clear_translation_edits(translation_short_name);
+    if let Err(e) = clear_section_headings(translation_short_name).await {
+        leptos::logging::error!("Failed to remove section headings: {}", e);
+    }
+    if let Err(e) = clear_source_text(translation_short_name).await {
+        leptos::logging::error!("Failed to remove source text: {}", e);
+    }
 
     if let Some(selected) = get_selected_translation() {
         if selected == translation_short_name {
@@ -96,12 +197,15 @@ pub async fn uninstall_translation(
 pub async fn download_translation(
     translation: &BibleTranslation,
 ) -> Result<Bible, Box<dyn std::error::Error>> {
-    let bible = fetch_translation_from_url(&translation.iagon).await?;
+    let bible = match fetch_published_translation(&translation.short_name).await {
+        Ok(bible) => bible,
+        Err(_) => fetch_translation_from_url(&translation.iagon).await?,
+    };
 
-    let translation_cache_key = format!("translation_{}", translation.short_name);
-    save_translation_to_cache_internal(&translation_cache_key, &bible).await?;
+    save_translation_to_cache(&translation.short_name, &bible).await?;
 
     add_downloaded_translation(&translation.short_name)?;
+    save_downloaded_revision(&translation.short_name, translation.revision)?;
 
     Ok(bible)
 }
@@ -115,29 +219,44 @@ where
 {
     progress_callback(0.1, "Starting download...".to_string());
 
-    let bible =
-        fetch_translation_from_url_with_progress(&translation.iagon, progress_callback.clone())
-            .await?;
+    let bible = match fetch_published_translation(&translation.short_name).await {
+        Ok(bible) => bible,
+        Err(_) => {
+            fetch_translation_from_url_with_progress(&translation.iagon, progress_callback.clone())
+                .await?
+        }
+    };
 
     progress_callback(0.8, "Saving to storage...".to_string());
 
-    let translation_cache_key = format!("translation_{}", translation.short_name);
-    save_translation_to_cache_internal(&translation_cache_key, &bible).await?;
+    save_translation_to_cache(&translation.short_name, &bible).await?;
 
     progress_callback(0.95, "Updating translation list...".to_string());
 
     add_downloaded_translation(&translation.short_name)?;
+    save_downloaded_revision(&translation.short_name, translation.revision)?;
 
     progress_callback(1.0, "Download complete!".to_string());
 
     Ok(bible)
 }
 
-pub async fn load_downloaded_translation(
+/// One book of a downloaded translation, with the corrections made in the editor
+pub async fn load_downloaded_book(
     translation_short_name: &str,
+    book_index: usize,
+) -> Result<Book, Box<dyn std::error::Error>> {
+    let book = load_translation_book(translation_short_name, book_index).await?;
+    let edits = get_translation_edits(translation_short_name);
+    Ok(apply_book_edits(book_index, book, &edits))
+}
+
+/// The translation in the binary format, served with the site when it was built
+/// with the sources
+async fn fetch_published_translation(
+    short_name: &str,
 ) -> Result<Bible, Box<dyn std::error::Error>> {
-    let translation_cache_key = format!("translation_{}", translation_short_name);
-    load_translation_from_cache(&translation_cache_key).await
+    try_fetch_bible(&format!("/bibles/{}.bibl", short_name)).await
 }
 
 async fn fetch_translation_from_url(url: &str) -> Result<Bible, Box<dyn std::error::Error>> {
@@ -193,69 +312,184 @@ where
     Err(last_error.unwrap_or_else(|| "All proxy attempts failed".into()))
 }
 
-async fn load_translation_from_cache(cache_key: &str) -> Result<Bible, Box<dyn std::error::Error>> {
+// The translation cache keeps each translation as an index of its books plus one
+// binary record per book, so a book can be read without parsing the others.
+// Translations cached before that were stored whole under `translation_{short_name}`.
+
+const TRANSLATIONS_STORE: &str = "translations";
+const BOOKS_STORE: &str = "books";
+
+async fn open_translation_cache() -> Result<Rexie, Box<dyn std::error::Error>> {
     let rexie = Rexie::builder("TranslationCache")
-        .version(1)
-        .add_object_store(ObjectStore::new("translations"))
+        .version(2)
+        .add_object_store(ObjectStore::new(TRANSLATIONS_STORE))
+        .add_object_store(ObjectStore::new(BOOKS_STORE))
         .build()
         .await
         .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
+    Ok(rexie)
+}
+
+fn legacy_translation_key(translation_short_name: &str) -> String {
+    format!("translation_{}", translation_short_name)
+}
+
+fn index_key(translation_short_name: &str) -> String {
+    format!("{}/index", translation_short_name)
+}
+
+fn book_key(translation_short_name: &str, book_index: usize) -> String {
+    format!("{}/{}", translation_short_name, book_index)
+}
+
+// All book records of one translation: they share the `{short_name}/` prefix
+fn translation_range(translation_short_name: &str) -> Result<KeyRange, String> {
+    KeyRange::bound(
+        &format!("{}/", translation_short_name).into(),
+        &format!("{}/\u{FFFF}", translation_short_name).into(),
+        None,
+        None,
+    )
+    .map_err(|e| format!("Failed to create key range: {:?}", e))
+}
+
+/// Book names and chapter counts of a downloaded translation
+pub async fn load_translation_index(
+    translation_short_name: &str,
+) -> Result<BibleIndex, Box<dyn std::error::Error>> {
+    let rexie = open_translation_cache().await?;
+    let transaction = rexie
+        .transaction(&[BOOKS_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(BOOKS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
+    match store.get(index_key(translation_short_name).into()).await {
+        Ok(Some(index_value)) => {
+            let json_str = index_value
+                .as_string()
+                .ok_or("Invalid cached translation index format")?;
+            let index = serde_json::from_str(&json_str)
+                .map_err(|e| format!("Failed to parse cached translation index: {:?}", e))?;
+            Ok(index)
+        }
+        Ok(None) => {
+            drop(transaction);
+            migrate_legacy_translation(translation_short_name).await
+        }
+        Err(_) => Err("Failed to read cached translation index".into()),
+    }
+}
+
+/// Split a translation cached as a whole into books, returning its index
+async fn migrate_legacy_translation(
+    translation_short_name: &str,
+) -> Result<BibleIndex, Box<dyn std::error::Error>> {
+    let rexie = open_translation_cache().await?;
     let transaction = rexie
-        .transaction(&["translations"], TransactionMode::ReadOnly)
+        .transaction(&[TRANSLATIONS_STORE], TransactionMode::ReadOnly)
         .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
     let store = transaction
-        .store("translations")
+        .store(TRANSLATIONS_STORE)
         .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
-    let data_result = store.get(cache_key.into()).await;
-    match data_result {
+    let bible = match store
+        .get(legacy_translation_key(translation_short_name).into())
+        .await
+    {
         Ok(Some(data_value)) => {
+            // Translations cached before the binary format are stored as JSON strings
             if let Some(json_str) = data_value.as_string() {
-                let bible: Bible = serde_json::from_str(&json_str)
-                    .map_err(|e| format!("Failed to parse cached translation: {:?}", e))?;
-                Ok(bible)
+                serde_json::from_str::<Bible>(&json_str)
+                    .map_err(|e| format!("Failed to parse cached translation: {:?}", e))?
+            } else if let Ok(bytes) = data_value.dyn_into::<js_sys::Uint8Array>() {
+                decode_bible(&bytes.to_vec())?
             } else {
-                Err("Invalid cached translation format".into())
+                return Err("Invalid cached translation format".into());
             }
         }
-        Ok(None) => Err("Translation not found in cache".into()),
-        Err(_) => Err("Failed to read cached translation".into()),
-    }
+        Ok(None) => return Err("Translation not found in cache".into()),
+        Err(_) => return Err("Failed to read cached translation".into()),
+    };
+    drop(transaction);
+
+    save_translation_to_cache(translation_short_name, &bible).await?;
+    Ok(bible.index())
 }
 
-pub async fn save_translation_to_cache(
-    cache_key: &str,
-    bible: &Bible,
-) -> Result<(), Box<dyn std::error::Error>> {
-    save_translation_to_cache_internal(cache_key, bible).await
+/// One book of a downloaded translation as it was stored, without editor corrections
+async fn load_translation_book(
+    translation_short_name: &str,
+    book_index: usize,
+) -> Result<Book, Box<dyn std::error::Error>> {
+    let rexie = open_translation_cache().await?;
+    let transaction = rexie
+        .transaction(&[BOOKS_STORE], TransactionMode::ReadOnly)
+        .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
+    let store = transaction
+        .store(BOOKS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?;
+
+    let book_value = store
+        .get(book_key(translation_short_name, book_index).into())
+        .await
+        .map_err(|e| format!("Failed to read cached book: {:?}", e))?
+        .ok_or_else(|| format!("Book {} missing from cache", book_index))?;
+    let bytes = book_value
+        .dyn_into::<js_sys::Uint8Array>()
+        .map_err(|_| "Invalid cached book format")?;
+    Ok(decode_book(&bytes.to_vec())?)
 }
 
-async fn save_translation_to_cache_internal(
-    cache_key: &str,
+/// Store a translation book by book, replacing what was cached for it before
+pub async fn save_translation_to_cache(
+    translation_short_name: &str,
     bible: &Bible,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let rexie = Rexie::builder("TranslationCache")
-        .version(1)
-        .add_object_store(ObjectStore::new("translations"))
-        .build()
-        .await
-        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
-
+    let rexie = open_translation_cache().await?;
     let transaction = rexie
-        .transaction(&["translations"], TransactionMode::ReadWrite)
+        .transaction(
+            &[TRANSLATIONS_STORE, BOOKS_STORE],
+            TransactionMode::ReadWrite,
+        )
         .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
-    let store = transaction
-        .store("translations")
+    let books_store = transaction
+        .store(BOOKS_STORE)
         .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
-    let json_data = serde_json::to_string(bible)
-        .map_err(|e| format!("Failed to serialize Bible data: {:?}", e))?;
+    books_store
+        .delete(translation_range(translation_short_name)?.into())
+        .await
+        .map_err(|e| format!("Failed to delete cached books: {:?}", e))?;
+    for (book_index, book) in bible.books.iter().enumerate() {
+        let bytes = encode_book(book)?;
+        let data = js_sys::Uint8Array::from(bytes.as_slice());
+        books_store
+            .put(
+                &data.into(),
+                Some(&book_key(translation_short_name, book_index).into()),
+            )
+            .await
+            .map_err(|e| format!("Failed to save {}: {:?}", book.name, e))?;
+    }
 
-    store
-        .put(&json_data.into(), Some(&cache_key.into()))
+    let index_json = serde_json::to_string(&bible.index())
+        .map_err(|e| format!("Failed to serialize translation index: {:?}", e))?;
+    books_store
+        .put(
+            &index_json.into(),
+            Some(&index_key(translation_short_name).into()),
+        )
         .await
-        .map_err(|e| format!("Failed to save translation data: {:?}", e))?;
+        .map_err(|e| format!("Failed to save translation index: {:?}", e))?;
+
+    transaction
+        .store(TRANSLATIONS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?
+        .delete(legacy_translation_key(translation_short_name).into())
+        .await
+        .map_err(|e| format!("Failed to delete cached translation: {:?}", e))?;
 
     transaction
         .commit()
@@ -264,23 +498,27 @@ async fn save_translation_to_cache_internal(
     Ok(())
 }
 
-async fn remove_translation_from_cache(cache_key: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let rexie = Rexie::builder("TranslationCache")
-        .version(1)
-        .add_object_store(ObjectStore::new("translations"))
-        .build()
-        .await
-        .map_err(|e| format!("Failed to open IndexedDB: {:?}", e))?;
-
+async fn remove_translation_from_cache(
+    translation_short_name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let rexie = open_translation_cache().await?;
     let transaction = rexie
-        .transaction(&["translations"], TransactionMode::ReadWrite)
+        .transaction(
+            &[TRANSLATIONS_STORE, BOOKS_STORE],
+            TransactionMode::ReadWrite,
+        )
         .map_err(|e| format!("Failed to create transaction: {:?}", e))?;
-    let store = transaction
-        .store("translations")
-        .map_err(|e| format!("Failed to get store: {:?}", e))?;
 
-    store
-        .delete(cache_key.into())
+    transaction
+        .store(BOOKS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?
+        .delete(translation_range(translation_short_name)?.into())
+        .await
+        .map_err(|e| format!("Failed to delete translation from cache: {:?}", e))?;
+    transaction
+        .store(TRANSLATIONS_STORE)
+        .map_err(|e| format!("Failed to get store: {:?}", e))?
+        .delete(legacy_translation_key(translation_short_name).into())
         .await
         .map_err(|e| format!("Failed to delete translation from cache: {:?}", e))?;
 
@@ -290,3 +528,77 @@ async fn remove_translation_from_cache(cache_key: &str) -> Result<(), Box<dyn st
         .map_err(|e| format!("Failed to commit transaction: {:?}", e))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::translations::get_builtin_translations;
+
+    fn hosted(short_name: &str, revision: u32) -> HostedRevision {
+        HostedRevision {
+            short_name: short_name.to_string(),
+            revision,
+            changelog: vec![format!("Revision {}", revision)],
+            iagon: None,
+        }
+    }
+
+    #[test]
+    fn test_translations_with_updates_lists_only_newer_downloaded_revisions() {
+        let translations = get_builtin_translations();
+        let downloaded_revisions = HashMap::from([
+            ("nl_sv".to_string(), 1),
+            ("en_kjv".to_string(), 2),
+            ("en_asv".to_string(), 3),
+        ]);
+        let hosted = vec![
+            hosted("nl_sv", 2),
+            hosted("en_kjv", 2),
+            hosted("en_asv", 2),
+            hosted("pcv", 5),
+        ];
+
+        let updates = translations_with_updates(&translations, &downloaded_revisions, &hosted);
+
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].short_name, "nl_sv");
+        assert_eq!(updates[0].revision, 2);
+        assert_eq!(updates[0].changelog, vec!["Revision 2".to_string()]);
+    }
+
+    #[test]
+    fn test_translations_with_updates_uses_hosted_link() {
+        let translations = get_builtin_translations();
+        let downloaded_revisions = HashMap::from([("en_kjv".to_string(), 1)]);
+        let mut latest = hosted("en_kjv", 2);
+        latest.iagon = Some("https://example.com/en_kjv".to_string());
+
+        let updates = translations_with_updates(&translations, &downloaded_revisions, &[latest]);
+
+        assert_eq!(updates[0].iagon, "https://example.com/en_kjv");
+    }
+
+    #[test]
+    fn test_translations_with_updates_ignores_translations_missing_from_index() {
+        let translations = get_builtin_translations();
+        let downloaded_revisions = HashMap::from([("en_kjv".to_string(), 1)]);
+
+        assert!(translations_with_updates(&translations, &downloaded_revisions, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_hosted_index_lists_every_builtin_translation() {
+        let index: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/translations.json")).unwrap();
+        let hosted: Vec<HostedRevision> =
+            serde_json::from_value(index["translations"].clone()).unwrap();
+
+        for translation in get_builtin_translations() {
+            let latest = hosted
+                .iter()
+                .find(|h| h.short_name == translation.short_name)
+                .unwrap_or_else(|| panic!("{} missing from index", translation.short_name));
+            assert!(latest.revision >= translation.revision);
+        }
+    }
+}
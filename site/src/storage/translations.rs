@@ -1,4 +1,5 @@
 use crate::core::types::Language;
+use crate::core::Versification;
 use crate::storage::translation_storage::{get_selected_translation, BibleTranslation};
 use gloo_storage::{LocalStorage, Storage};
 
@@ -16,6 +17,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1637,
             languages: vec![Language::Dutch],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/Njg5MjEyOTM0NzVmZTAwZjg3Y2VjN2Iy:MjhiNDNiOTMyNDllYTAwMzRmYWM4ZTdmOTdlZDU3NGExNzQxNjA4MzBiNzU3MThmNjE5ZGEzODZiNjVlOWE2MA"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::HebrewPsalmTitles,
         },
         BibleTranslation {
             name: String::from("Petrus Canicius vertaling"),
@@ -23,6 +27,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1939,
             languages: vec![Language::Dutch],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/NjhhNWE4M2NlZDM0YjkxMmFjZjBlZWUx:OGI2ODYxMDRmMWNlMTNmNDBhOWQ0M2U5NjAwZjA1OGY2ZWI4MGQwNDE0MThkYWQwYTc3NDc2YWI4OWJhMTViYQ"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::Vulgate,
         },
         BibleTranslation {
             name: String::from("King james version"),
@@ -30,6 +37,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1611,
             languages: vec![Language::English],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/Njg5MjEyZGM0NzVmZTAwZjg3Y2VkNDU0:Yjc0MjAwNzMzN2RmM2UyMGVkZDgzYThiMWRjZWIxMjM0OTUwMjZhNDVhMWFkOGZmMThjOTU4NTUzMmUwY2FhYQ"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::English,
         },
         BibleTranslation {
             name: String::from("American King james version"),
@@ -37,6 +47,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1999,
             languages: vec![Language::English],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/Njg5MjEyZGI0NzVmZTAwZjg3Y2VkNDQ2:MWRjOGI2N2Y3OGE1MWY5MmU1YmMwYjhiZjY2NjM3ZWRkMjY0OWZiMWY4ZDg3MTZmMmU1ODViOTgwNDM4ZjU3Zg"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::English,
         },
         BibleTranslation {
             name: String::from("Americain Standard Version"),
@@ -44,6 +57,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1901,
             languages: vec![Language::English],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/Njg5MjI0ZmM0NzVmZTAwZjg3Y2YzMTg4:MTEzMjZkOTVlZTFkMWNhOGM0YmFmNDkwOWFkMjdmOTI3NjY5YjQ2NzA3NjViOTJlYTE2MzNmMzFkMzRiY2MwNQ"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::English,
         },
         BibleTranslation {
             name: String::from("Green's Modern King James Version"),
@@ -51,6 +67,9 @@ pub fn get_builtin_translations() -> Vec<BibleTranslation> {
             release_year: 1962,
             languages: vec![Language::English],
             iagon: String::from("https://gw.iagon.com/api/v2/storage/shareable/link/Njg5MjIxMTQ0NzVmZTAwZjg3Y2VmOTEw:YzEzMGExYjU0OWI1M2I4ODk4MWJmYjgwNmM3YzE1ODJkZWJmMjhiNmYxOGMzMGY2ZTk0MTFlYjUyN2IzOGRjZQ"),
+            revision: 1,
+            changelog: Vec::new(),
+            versification: Versification::English,
         }
     ]
 }
@@ -0,0 +1,84 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const WORKSPACES_KEY: &str = "bible_workspaces";
+
+/// A named reading layout the reader can return to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Workspace {
+    pub name: String,
+    /// Chapter path with the selected verses, e.g. `/John/3?verses=16`
+    pub path: String,
+    pub is_left_sidebar_open: bool,
+    pub is_right_sidebar_open: bool,
+    pub is_translation_comparison_open: bool,
+    pub is_place_map_open: bool,
+    pub comparison_translations: Vec<String>,
+    pub text_columns: u8,
+}
+
+pub fn get_workspaces() -> Vec<Workspace> {
+    LocalStorage::get(WORKSPACES_KEY).unwrap_or_default()
+}
+
+pub fn save_workspaces(workspaces: &[Workspace]) {
+    let _ = LocalStorage::set(WORKSPACES_KEY, workspaces);
+}
+
+/// The workspace called `name`, ignoring case
+pub fn find_workspace<'a>(workspaces: &'a [Workspace], name: &str) -> Option<&'a Workspace> {
+    workspaces
+        .iter()
+        .find(|workspace| workspace.name.eq_ignore_ascii_case(name))
+}
+
+/// Add a workspace, replacing one with the same name in place
+pub fn upsert_workspace(workspaces: &mut Vec<Workspace>, workspace: Workspace) {
+    match workspaces
+        .iter_mut()
+        .find(|existing| existing.name.eq_ignore_ascii_case(&workspace.name))
+    {
+        Some(existing) => *existing = workspace,
+        None => workspaces.push(workspace),
+    }
+}
+
+pub fn delete_workspace(name: &str) {
+    let mut workspaces = get_workspaces();
+    workspaces.retain(|workspace| !workspace.name.eq_ignore_ascii_case(name));
+    save_workspaces(&workspaces);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str, path: &str) -> Workspace {
+        Workspace {
+            name: name.to_string(),
+            path: path.to_string(),
+            is_left_sidebar_open: false,
+            is_right_sidebar_open: true,
+            is_translation_comparison_open: false,
+            is_place_map_open: false,
+            comparison_translations: Vec::new(),
+            text_columns: 1,
+        }
+    }
+
+    #[test]
+    fn test_upsert_workspace() {
+        let mut workspaces = Vec::new();
+        upsert_workspace(&mut workspaces, workspace("Sermon", "/Romans/8"));
+        upsert_workspace(&mut workspaces, workspace("Psalms study", "/Psalms/1"));
+        upsert_workspace(&mut workspaces, workspace("sermon", "/Romans/9?verses=1-5"));
+
+        assert_eq!(workspaces.len(), 2);
+        assert_eq!(workspaces[0].path, "/Romans/9?verses=1-5");
+        assert_eq!(
+            find_workspace(&workspaces, "PSALMS STUDY").map(|w| w.path.as_str()),
+            Some("/Psalms/1")
+        );
+        assert_eq!(find_workspace(&workspaces, "Psalms"), None);
+    }
+}
@@ -1,3 +1,5 @@
+use gloo_storage::errors::StorageError;
+use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,127 +102,355 @@ pub struct CommandPaletteColors {
     pub highlight_background: String,
 }
 
-// Static theme loading (compile-time)
-pub fn get_themes() -> Vec<Theme> {
+/// Themes served as static JSON from `/themes/`, in the order they are listed.
+/// They are only compiled in when the `bundled-themes` feature is enabled.
+pub const EXTRA_THEME_IDS: [&str; 56] = [
+    "cherry_blossom",
+    "rose_gold",
+    "lavender_dreams",
+    "deuteranopia_safe",
+    "protanopia_safe",
+    "tritanopia_safe",
+    "matrix",
+    "dracula",
+    "nord",
+    "monokai",
+    "cotton_candy",
+    "mint_cream",
+    "peach_sorbet",
+    "sky_blue",
+    "forest_green",
+    "ocean_deep",
+    "sunset_orange",
+    "retro_amber",
+    "cyberpunk",
+    "autumn_leaves",
+    "spring_meadow",
+    "winter_frost",
+    "royal_purple",
+    "midnight_blue",
+    "coral_reef",
+    "golden_hour",
+    "monochrome",
+    "emerald_city",
+    "neon_nights",
+    "vintage_paper",
+    "coffee_shop",
+    "arctic_ice",
+    "sunset_beach",
+    "space_nebula",
+    "volcano_fire",
+    "candy_pink",
+    "terminal_green",
+    "deep_ocean",
+    "desert_sand",
+    "midnight_purple",
+    "electric_blue",
+    "forest_night",
+    "sunshine_yellow",
+    "bubblegum_pop",
+    "steel_gray",
+    "lime_twist",
+    "cosmic_purple",
+    "summer_breeze",
+    "wine_red",
+    "glacier_blue",
+    "tropical_sunset",
+    "charcoal_night",
+    "teal_wave",
+    "amber_glow",
+    "midnight_teal",
+    "raspberry_cream",
+];
+
+#[cfg(not(feature = "bundled-themes"))]
+const THEME_CACHE_PREFIX: &str = "theme_cache_";
+
+// Core themes are always embedded so the app can render before anything is fetched
+fn core_themes() -> Vec<Theme> {
     vec![
         serde_json::from_str(include_str!("light.json")).expect("Failed to parse light theme"),
         serde_json::from_str(include_str!("dark.json")).expect("Failed to parse dark theme"),
         serde_json::from_str(include_str!("sepia.json")).expect("Failed to parse sepia theme"),
-        serde_json::from_str(include_str!("cherry_blossom.json"))
+    ]
+}
+
+#[cfg(feature = "bundled-themes")]
+fn extra_themes() -> Vec<Theme> {
+    vec![
+        serde_json::from_str(include_str!("../../assets/themes/cherry_blossom.json"))
             .expect("Failed to parse cherry_blossom theme"),
-        serde_json::from_str(include_str!("rose_gold.json"))
+        serde_json::from_str(include_str!("../../assets/themes/rose_gold.json"))
             .expect("Failed to parse rose_gold theme"),
-        serde_json::from_str(include_str!("lavender_dreams.json"))
+        serde_json::from_str(include_str!("../../assets/themes/lavender_dreams.json"))
             .expect("Failed to parse lavender_dreams theme"),
-        serde_json::from_str(include_str!("deuteranopia_safe.json"))
+        serde_json::from_str(include_str!("../../assets/themes/deuteranopia_safe.json"))
             .expect("Failed to parse deuteranopia_safe theme"),
-        serde_json::from_str(include_str!("protanopia_safe.json"))
+        serde_json::from_str(include_str!("../../assets/themes/protanopia_safe.json"))
             .expect("Failed to parse protanopia_safe theme"),
-        serde_json::from_str(include_str!("tritanopia_safe.json"))
+        serde_json::from_str(include_str!("../../assets/themes/tritanopia_safe.json"))
             .expect("Failed to parse tritanopia_safe theme"),
-        serde_json::from_str(include_str!("matrix.json")).expect("Failed to parse matrix theme"),
-        serde_json::from_str(include_str!("dracula.json")).expect("Failed to parse dracula theme"),
-        serde_json::from_str(include_str!("nord.json")).expect("Failed to parse nord theme"),
-        serde_json::from_str(include_str!("monokai.json")).expect("Failed to parse monokai theme"),
-        serde_json::from_str(include_str!("cotton_candy.json"))
+        serde_json::from_str(include_str!("../../assets/themes/matrix.json"))
+            .expect("Failed to parse matrix theme"),
+        serde_json::from_str(include_str!("../../assets/themes/dracula.json"))
+            .expect("Failed to parse dracula theme"),
+        serde_json::from_str(include_str!("../../assets/themes/nord.json"))
+            .expect("Failed to parse nord theme"),
+        serde_json::from_str(include_str!("../../assets/themes/monokai.json"))
+            .expect("Failed to parse monokai theme"),
+        serde_json::from_str(include_str!("../../assets/themes/cotton_candy.json"))
             .expect("Failed to parse cotton_candy theme"),
-        serde_json::from_str(include_str!("mint_cream.json"))
+        serde_json::from_str(include_str!("../../assets/themes/mint_cream.json"))
             .expect("Failed to parse mint_cream theme"),
-        serde_json::from_str(include_str!("peach_sorbet.json"))
+        serde_json::from_str(include_str!("../../assets/themes/peach_sorbet.json"))
             .expect("Failed to parse peach_sorbet theme"),
-        serde_json::from_str(include_str!("sky_blue.json"))
+        serde_json::from_str(include_str!("../../assets/themes/sky_blue.json"))
             .expect("Failed to parse sky_blue theme"),
-        serde_json::from_str(include_str!("forest_green.json"))
+        serde_json::from_str(include_str!("../../assets/themes/forest_green.json"))
             .expect("Failed to parse forest_green theme"),
-        serde_json::from_str(include_str!("ocean_deep.json"))
+        serde_json::from_str(include_str!("../../assets/themes/ocean_deep.json"))
             .expect("Failed to parse ocean_deep theme"),
-        serde_json::from_str(include_str!("sunset_orange.json"))
+        serde_json::from_str(include_str!("../../assets/themes/sunset_orange.json"))
             .expect("Failed to parse sunset_orange theme"),
-        serde_json::from_str(include_str!("retro_amber.json"))
+        serde_json::from_str(include_str!("../../assets/themes/retro_amber.json"))
             .expect("Failed to parse retro_amber theme"),
-        serde_json::from_str(include_str!("cyberpunk.json"))
+        serde_json::from_str(include_str!("../../assets/themes/cyberpunk.json"))
             .expect("Failed to parse cyberpunk theme"),
-        serde_json::from_str(include_str!("autumn_leaves.json"))
+        serde_json::from_str(include_str!("../../assets/themes/autumn_leaves.json"))
             .expect("Failed to parse autumn_leaves theme"),
-        serde_json::from_str(include_str!("spring_meadow.json"))
+        serde_json::from_str(include_str!("../../assets/themes/spring_meadow.json"))
             .expect("Failed to parse spring_meadow theme"),
-        serde_json::from_str(include_str!("winter_frost.json"))
+        serde_json::from_str(include_str!("../../assets/themes/winter_frost.json"))
             .expect("Failed to parse winter_frost theme"),
-        serde_json::from_str(include_str!("royal_purple.json"))
+        serde_json::from_str(include_str!("../../assets/themes/royal_purple.json"))
             .expect("Failed to parse royal_purple theme"),
-        serde_json::from_str(include_str!("midnight_blue.json"))
+        serde_json::from_str(include_str!("../../assets/themes/midnight_blue.json"))
             .expect("Failed to parse midnight_blue theme"),
-        serde_json::from_str(include_str!("coral_reef.json"))
+        serde_json::from_str(include_str!("../../assets/themes/coral_reef.json"))
             .expect("Failed to parse coral_reef theme"),
-        serde_json::from_str(include_str!("golden_hour.json"))
+        serde_json::from_str(include_str!("../../assets/themes/golden_hour.json"))
             .expect("Failed to parse golden_hour theme"),
-        serde_json::from_str(include_str!("monochrome.json"))
+        serde_json::from_str(include_str!("../../assets/themes/monochrome.json"))
             .expect("Failed to parse monochrome theme"),
-        serde_json::from_str(include_str!("emerald_city.json"))
+        serde_json::from_str(include_str!("../../assets/themes/emerald_city.json"))
             .expect("Failed to parse emerald_city theme"),
-        serde_json::from_str(include_str!("neon_nights.json"))
+        serde_json::from_str(include_str!("../../assets/themes/neon_nights.json"))
             .expect("Failed to parse neon_nights theme"),
-        serde_json::from_str(include_str!("vintage_paper.json"))
+        serde_json::from_str(include_str!("../../assets/themes/vintage_paper.json"))
             .expect("Failed to parse vintage_paper theme"),
-        serde_json::from_str(include_str!("coffee_shop.json"))
+        serde_json::from_str(include_str!("../../assets/themes/coffee_shop.json"))
             .expect("Failed to parse coffee_shop theme"),
-        serde_json::from_str(include_str!("arctic_ice.json"))
+        serde_json::from_str(include_str!("../../assets/themes/arctic_ice.json"))
             .expect("Failed to parse arctic_ice theme"),
-        serde_json::from_str(include_str!("sunset_beach.json"))
+        serde_json::from_str(include_str!("../../assets/themes/sunset_beach.json"))
             .expect("Failed to parse sunset_beach theme"),
-        serde_json::from_str(include_str!("space_nebula.json"))
+        serde_json::from_str(include_str!("../../assets/themes/space_nebula.json"))
             .expect("Failed to parse space_nebula theme"),
-        serde_json::from_str(include_str!("volcano_fire.json"))
+        serde_json::from_str(include_str!("../../assets/themes/volcano_fire.json"))
             .expect("Failed to parse volcano_fire theme"),
-        serde_json::from_str(include_str!("candy_pink.json"))
+        serde_json::from_str(include_str!("../../assets/themes/candy_pink.json"))
             .expect("Failed to parse candy_pink theme"),
-        serde_json::from_str(include_str!("terminal_green.json"))
+        serde_json::from_str(include_str!("../../assets/themes/terminal_green.json"))
             .expect("Failed to parse terminal_green theme"),
-        serde_json::from_str(include_str!("deep_ocean.json"))
+        serde_json::from_str(include_str!("../../assets/themes/deep_ocean.json"))
             .expect("Failed to parse deep_ocean theme"),
-        serde_json::from_str(include_str!("desert_sand.json"))
+        serde_json::from_str(include_str!("../../assets/themes/desert_sand.json"))
             .expect("Failed to parse desert_sand theme"),
-        serde_json::from_str(include_str!("midnight_purple.json"))
+        serde_json::from_str(include_str!("../../assets/themes/midnight_purple.json"))
             .expect("Failed to parse midnight_purple theme"),
-        serde_json::from_str(include_str!("electric_blue.json"))
+        serde_json::from_str(include_str!("../../assets/themes/electric_blue.json"))
             .expect("Failed to parse electric_blue theme"),
-        serde_json::from_str(include_str!("forest_night.json"))
+        serde_json::from_str(include_str!("../../assets/themes/forest_night.json"))
             .expect("Failed to parse forest_night theme"),
-        serde_json::from_str(include_str!("sunshine_yellow.json"))
+        serde_json::from_str(include_str!("../../assets/themes/sunshine_yellow.json"))
             .expect("Failed to parse sunshine_yellow theme"),
-        serde_json::from_str(include_str!("bubblegum_pop.json"))
+        serde_json::from_str(include_str!("../../assets/themes/bubblegum_pop.json"))
             .expect("Failed to parse bubblegum_pop theme"),
-        serde_json::from_str(include_str!("steel_gray.json"))
+        serde_json::from_str(include_str!("../../assets/themes/steel_gray.json"))
             .expect("Failed to parse steel_gray theme"),
-        serde_json::from_str(include_str!("lime_twist.json"))
+        serde_json::from_str(include_str!("../../assets/themes/lime_twist.json"))
             .expect("Failed to parse lime_twist theme"),
-        serde_json::from_str(include_str!("cosmic_purple.json"))
+        serde_json::from_str(include_str!("../../assets/themes/cosmic_purple.json"))
             .expect("Failed to parse cosmic_purple theme"),
-        serde_json::from_str(include_str!("summer_breeze.json"))
+        serde_json::from_str(include_str!("../../assets/themes/summer_breeze.json"))
             .expect("Failed to parse summer_breeze theme"),
-        serde_json::from_str(include_str!("wine_red.json"))
+        serde_json::from_str(include_str!("../../assets/themes/wine_red.json"))
             .expect("Failed to parse wine_red theme"),
-        serde_json::from_str(include_str!("glacier_blue.json"))
+        serde_json::from_str(include_str!("../../assets/themes/glacier_blue.json"))
             .expect("Failed to parse glacier_blue theme"),
-        serde_json::from_str(include_str!("tropical_sunset.json"))
+        serde_json::from_str(include_str!("../../assets/themes/tropical_sunset.json"))
             .expect("Failed to parse tropical_sunset theme"),
-        serde_json::from_str(include_str!("charcoal_night.json"))
+        serde_json::from_str(include_str!("../../assets/themes/charcoal_night.json"))
             .expect("Failed to parse charcoal_night theme"),
-        serde_json::from_str(include_str!("teal_wave.json"))
+        serde_json::from_str(include_str!("../../assets/themes/teal_wave.json"))
             .expect("Failed to parse teal_wave theme"),
-        serde_json::from_str(include_str!("amber_glow.json"))
+        serde_json::from_str(include_str!("../../assets/themes/amber_glow.json"))
             .expect("Failed to parse amber_glow theme"),
-        serde_json::from_str(include_str!("midnight_teal.json"))
+        serde_json::from_str(include_str!("../../assets/themes/midnight_teal.json"))
             .expect("Failed to parse midnight_teal theme"),
-        serde_json::from_str(include_str!("raspberry_cream.json"))
+        serde_json::from_str(include_str!("../../assets/themes/raspberry_cream.json"))
             .expect("Failed to parse raspberry_cream theme"),
     ]
 }
 
+// Without bundled themes, only extra themes that were fetched before are available
+#[cfg(not(feature = "bundled-themes"))]
+fn extra_themes() -> Vec<Theme> {
+    EXTRA_THEME_IDS
+        .iter()
+        .filter_map(|id| cached_theme(id))
+        .collect()
+}
+
+#[cfg(not(feature = "bundled-themes"))]
+fn cached_theme(id: &str) -> Option<Theme> {
+    LocalStorage::get(format!("{}{}", THEME_CACHE_PREFIX, id)).ok()
+}
+
+#[cfg(not(feature = "bundled-themes"))]
+async fn fetch_theme(id: &str) -> Result<Theme, Box<dyn std::error::Error>> {
+    let theme: Theme = gloo_net::http::Request::get(&format!("/themes/{}.json", id))
+        .send()
+        .await?
+        .json()
+        .await?;
+    LocalStorage::set(format!("{}{}", THEME_CACHE_PREFIX, id), &theme)?;
+    Ok(theme)
+}
+
+const USER_THEMES_KEY: &str = "user_themes";
+
+/// Themes the user added at runtime from a URL or pasted JSON
+pub fn get_user_themes() -> Vec<Theme> {
+    LocalStorage::get(USER_THEMES_KEY).unwrap_or_default()
+}
+
+/// Store a user theme, replacing an earlier version with the same id
+pub fn add_user_theme(theme: Theme) -> Result<(), StorageError> {
+    let mut themes = get_user_themes();
+    match themes.iter_mut().find(|t| t.id == theme.id) {
+        Some(existing) => *existing = theme,
+        None => themes.push(theme),
+    }
+    LocalStorage::set(USER_THEMES_KEY, &themes)
+}
+
+pub fn remove_user_theme(id: &str) -> Result<(), StorageError> {
+    let mut themes = get_user_themes();
+    themes.retain(|t| t.id != id);
+    LocalStorage::set(USER_THEMES_KEY, &themes)
+}
+
+/// Parse a theme definition and check it can be used safely.
+///
+/// Colors end up inside CSS declarations, so values that could close a
+/// declaration or block are rejected. Built-in theme ids can't be reused.
+pub fn parse_theme_json(json: &str) -> Result<Theme, String> {
+    let theme: Theme =
+        serde_json::from_str(json).map_err(|e| format!("Invalid theme JSON: {}", e))?;
+
+    if theme.id.trim().is_empty() || theme.name.trim().is_empty() {
+        return Err("A theme needs an id and a name".to_string());
+    }
+    if core_themes().iter().any(|t| t.id == theme.id)
+        || EXTRA_THEME_IDS.contains(&theme.id.as_str())
+    {
+        return Err(format!(
+            "A built-in theme already uses the id '{}'",
+            theme.id
+        ));
+    }
+
+    let colors = serde_json::to_value(&theme.colors).map_err(|e| e.to_string())?;
+    if let Some(color) = find_unsafe_color(&colors) {
+        return Err(format!("Invalid color value '{}'", color));
+    }
+
+    Ok(theme)
+}
+
+fn find_unsafe_color(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(color) => color
+            .contains([';', '{', '}', '<', '>', '"'])
+            .then(|| color.clone()),
+        serde_json::Value::Object(fields) => fields.values().find_map(find_unsafe_color),
+        _ => None,
+    }
+}
+
+/// Download a theme definition from a URL and validate it
+pub async fn fetch_user_theme(url: &str) -> Result<Theme, String> {
+    let response = gloo_net::http::Request::get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download theme: {}", e))?;
+    if !response.ok() {
+        return Err(format!(
+            "Failed to download theme: HTTP {}",
+            response.status()
+        ));
+    }
+    let json = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read theme: {}", e))?;
+    parse_theme_json(&json)
+}
+
+/// All themes that can be used right now: the core themes, the extra themes
+/// that are bundled or already cached, and the user's own themes
+pub fn get_themes() -> Vec<Theme> {
+    let mut themes = core_themes();
+    themes.extend(extra_themes());
+    themes.extend(get_user_themes());
+    themes
+}
+
 pub fn get_theme_by_id(id: &str) -> Option<Theme> {
     get_themes().into_iter().find(|theme| theme.id == id)
 }
 
+/// Like `get_theme_by_id`, but fetches and caches an extra theme that isn't available yet
+pub async fn load_theme_by_id(id: &str) -> Option<Theme> {
+    if let Some(theme) = get_theme_by_id(id) {
+        return Some(theme);
+    }
+
+    #[cfg(not(feature = "bundled-themes"))]
+    if EXTRA_THEME_IDS.contains(&id) {
+        return match fetch_theme(id).await {
+            Ok(theme) => Some(theme),
+            Err(e) => {
+                leptos::logging::error!("Failed to load theme {}: {}", id, e);
+                None
+            }
+        };
+    }
+
+    None
+}
+
+/// Fetch every extra theme that isn't cached yet in the background, calling
+/// `on_loaded` after each one so theme lists can refresh from `get_themes()`
+pub fn load_missing_themes(on_loaded: impl Fn() + Clone + 'static) {
+    #[cfg(not(feature = "bundled-themes"))]
+    for id in EXTRA_THEME_IDS
+        .iter()
+        .filter(|id| cached_theme(id).is_none())
+    {
+        let on_loaded = on_loaded.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            if load_theme_by_id(id).await.is_some() {
+                on_loaded();
+            }
+        });
+    }
+
+    #[cfg(feature = "bundled-themes")]
+    let _ = on_loaded;
+}
+
 pub fn get_default_theme() -> Theme {
     get_theme_by_id("light").expect("Default light theme not found")
 }
@@ -312,3 +542,56 @@ pub fn theme_to_css_vars(theme: &Theme) -> String {
         theme.colors.command_palette.highlight_background,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extra_theme_files_match_ids() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/themes");
+        let mut file_ids: Vec<String> = std::fs::read_dir(dir)
+            .expect("assets/themes should exist")
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let theme: Theme = serde_json::from_str(&std::fs::read_to_string(&path).unwrap())
+                    .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e));
+                assert_eq!(path.file_stem().unwrap().to_str(), Some(theme.id.as_str()));
+                theme.id
+            })
+            .collect();
+        file_ids.sort();
+
+        let mut ids: Vec<String> = EXTRA_THEME_IDS.iter().map(|id| id.to_string()).collect();
+        ids.sort();
+        assert_eq!(file_ids, ids);
+    }
+
+    fn light_theme_json_with(id: &str, background: &str) -> String {
+        let mut theme: serde_json::Value =
+            serde_json::from_str(include_str!("light.json")).unwrap();
+        theme["id"] = id.into();
+        theme["colors"]["background"] = background.into();
+        theme.to_string()
+    }
+
+    #[test]
+    fn test_parse_theme_json() {
+        let theme = parse_theme_json(&light_theme_json_with("my_theme", "#101010")).unwrap();
+        assert_eq!(theme.id, "my_theme");
+        assert_eq!(theme.colors.background, "#101010");
+    }
+
+    #[test]
+    fn test_parse_theme_json_rejections() {
+        assert!(parse_theme_json("{}").is_err());
+        assert!(parse_theme_json(&light_theme_json_with("light", "#101010")).is_err());
+        assert!(parse_theme_json(&light_theme_json_with("dracula", "#101010")).is_err());
+        assert!(parse_theme_json(&light_theme_json_with("", "#101010")).is_err());
+        assert!(parse_theme_json(&light_theme_json_with(
+            "my_theme",
+            "red; background: url(x)"
+        ))
+        .is_err());
+    }
+}
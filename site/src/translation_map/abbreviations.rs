@@ -0,0 +1,231 @@
+use crate::core::types::Language;
+use crate::storage::book_abbreviations::get_user_book_abbreviations;
+use crate::translation_map::translation::Translation;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+const DUTCH_ABBREVIATIONS_JSON: &str = include_str!("dutch_abbreviations.json");
+const ENGLISH_ABBREVIATIONS_JSON: &str = include_str!("english_abbreviations.json");
+
+/// Book keys (the field names of `Translation`) in canonical order
+pub const BOOK_KEYS: [&str; 66] = [
+    "genesis",
+    "exodus",
+    "leviticus",
+    "numbers",
+    "deuteronomy",
+    "joshua",
+    "judges",
+    "ruth",
+    "first_samuel",
+    "second_samuel",
+    "first_kings",
+    "second_kings",
+    "first_chronicles",
+    "second_chronicles",
+    "ezra",
+    "nehemiah",
+    "esther",
+    "job",
+    "psalms",
+    "proverbs",
+    "ecclesiastes",
+    "song_of_solomon",
+    "isaiah",
+    "jeremiah",
+    "lamentations",
+    "ezekiel",
+    "daniel",
+    "hosea",
+    "joel",
+    "amos",
+    "obadiah",
+    "jonah",
+    "micah",
+    "nahum",
+    "habakkuk",
+    "zephaniah",
+    "haggai",
+    "zechariah",
+    "malachi",
+    "matthew",
+    "mark",
+    "luke",
+    "john",
+    "acts",
+    "romans",
+    "first_corinthians",
+    "second_corinthians",
+    "galatians",
+    "ephesians",
+    "philippians",
+    "colossians",
+    "first_thessalonians",
+    "second_thessalonians",
+    "first_timothy",
+    "second_timothy",
+    "titus",
+    "philemon",
+    "hebrews",
+    "james",
+    "first_peter",
+    "second_peter",
+    "first_john",
+    "second_john",
+    "third_john",
+    "jude",
+    "revelation",
+];
+
+const LANGUAGES: [Language; 2] = [Language::English, Language::Dutch];
+
+/// Book abbreviations of one language, keyed by normalized abbreviation
+#[derive(Debug)]
+pub struct AbbreviationTable {
+    books: HashMap<String, &'static str>,
+}
+
+static DUTCH_ABBREVIATIONS: LazyLock<AbbreviationTable> =
+    LazyLock::new(|| AbbreviationTable::from_json(DUTCH_ABBREVIATIONS_JSON));
+
+static ENGLISH_ABBREVIATIONS: LazyLock<AbbreviationTable> =
+    LazyLock::new(|| AbbreviationTable::from_json(ENGLISH_ABBREVIATIONS_JSON));
+
+impl AbbreviationTable {
+    pub fn for_language(language: Language) -> &'static AbbreviationTable {
+        match language {
+            Language::Dutch => &DUTCH_ABBREVIATIONS,
+            Language::English => &ENGLISH_ABBREVIATIONS,
+        }
+    }
+
+    fn from_json(json: &str) -> Self {
+        let entries: HashMap<String, Vec<String>> =
+            serde_json::from_str(json).expect("Failed to parse book abbreviations");
+
+        let mut books = HashMap::new();
+        for (key, abbreviations) in entries {
+            let key = book_key(&key).expect("Unknown book key in abbreviations");
+            for abbreviation in abbreviations {
+                books.insert(normalize_abbreviation(&abbreviation), key);
+            }
+        }
+        Self { books }
+    }
+
+    /// Book key for an abbreviation such as "gen", "1 Kor." or "I Sam"
+    pub fn book_key(&self, abbreviation: &str) -> Option<&'static str> {
+        self.books
+            .get(&normalize_abbreviation(abbreviation))
+            .copied()
+    }
+}
+
+/// Lowercase, drop a trailing period and write numbered books without a space,
+/// so "I Sam.", "1 sam" and "1Sam" all become "1sam"
+pub fn normalize_abbreviation(text: &str) -> String {
+    let text = text.trim().trim_end_matches('.').to_lowercase();
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    match words.split_first() {
+        Some((first, rest)) if !rest.is_empty() => {
+            let number = match *first {
+                "1" | "i" => "1",
+                "2" | "ii" => "2",
+                "3" | "iii" => "3",
+                _ => return words.join(" "),
+            };
+            format!("{}{}", number, rest.join(" "))
+        }
+        _ => words.join(" "),
+    }
+}
+
+/// The static book key matching `key`, e.g. "first_samuel"
+pub fn book_key(key: &str) -> Option<&'static str> {
+    BOOK_KEYS.iter().find(|k| **k == key).copied()
+}
+
+/// Book key for a full book name in any supported language, a book key, or an abbreviation
+pub fn book_key_for_name(name: &str) -> Option<&'static str> {
+    let normalized = normalize_abbreviation(name);
+    book_key(&normalized.replace(' ', "_"))
+        .or_else(|| {
+            BOOK_KEYS.iter().copied().find(|key| {
+                book_names(key)
+                    .iter()
+                    .any(|book_name| normalize_abbreviation(book_name) == normalized)
+            })
+        })
+        .or_else(|| builtin_book_key(name))
+}
+
+/// Book key for an abbreviation, preferring the user's own abbreviations over the built-in tables
+pub fn resolve_book_abbreviation(abbreviation: &str) -> Option<&'static str> {
+    get_user_book_abbreviations()
+        .get(&normalize_abbreviation(abbreviation))
+        .and_then(|key| book_key(key))
+        .or_else(|| builtin_book_key(abbreviation))
+}
+
+fn builtin_book_key(abbreviation: &str) -> Option<&'static str> {
+    LANGUAGES
+        .iter()
+        .find_map(|language| AbbreviationTable::for_language(*language).book_key(abbreviation))
+}
+
+/// The names a book has in every supported language
+pub fn book_names(key: &str) -> Vec<&'static str> {
+    LANGUAGES
+        .iter()
+        .filter_map(|language| Translation::from_language(*language).get_book(key))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_abbreviation() {
+        assert_eq!(normalize_abbreviation("Gen."), "gen");
+        assert_eq!(normalize_abbreviation("1 Kor"), "1kor");
+        assert_eq!(normalize_abbreviation("I Sam."), "1sam");
+        assert_eq!(normalize_abbreviation("Song of Solomon"), "song of solomon");
+    }
+
+    #[test]
+    fn test_builtin_abbreviations() {
+        let english = AbbreviationTable::for_language(Language::English);
+        let dutch = AbbreviationTable::for_language(Language::Dutch);
+
+        assert_eq!(english.book_key("Mt"), Some("matthew"));
+        assert_eq!(english.book_key("1 Cor."), Some("first_corinthians"));
+        assert_eq!(dutch.book_key("joh"), Some("john"));
+        assert_eq!(dutch.book_key("hand"), Some("acts"));
+        assert_eq!(dutch.book_key("unknown"), None);
+    }
+
+    #[test]
+    fn test_abbreviation_tables_agree() {
+        // An abbreviation may not point at different books in different languages
+        let english = AbbreviationTable::for_language(Language::English);
+        let dutch = AbbreviationTable::for_language(Language::Dutch);
+        for (abbreviation, key) in &dutch.books {
+            if let Some(english_key) = english.books.get(abbreviation) {
+                assert_eq!(english_key, key, "{} is ambiguous", abbreviation);
+            }
+        }
+    }
+
+    #[test]
+    fn test_book_key_for_name() {
+        assert_eq!(book_key_for_name("Matteüs"), Some("matthew"));
+        assert_eq!(book_key_for_name("I Samuel"), Some("first_samuel"));
+        assert_eq!(
+            book_key_for_name("song_of_solomon"),
+            Some("song_of_solomon")
+        );
+        assert_eq!(book_key_for_name("openb"), Some("revelation"));
+    }
+}
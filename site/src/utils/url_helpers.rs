@@ -5,6 +5,8 @@
  * book, chapter, and verse information from URL paths and parameters.
  */
 
+use crate::core::init_bible_signal;
+use leptos::prelude::WithUntracked;
 use urlencoding::decode;
 
 /// Parse book and chapter information from URL pathname
@@ -41,6 +43,21 @@ pub fn parse_book_chapter_from_url(pathname: &str) -> Option<(String, u32)> {
     None
 }
 
+/// Parse book and chapter from a URL pathname, expanding abbreviated book
+/// names (`/gen/1`) to the name used by the loaded Bible
+pub fn resolve_book_chapter_from_url(pathname: &str) -> Option<(String, u32)> {
+    let (book_name, chapter) = parse_book_chapter_from_url(pathname)?;
+    let book_name = init_bible_signal()
+        .with_untracked(|bible| {
+            bible
+                .as_ref()?
+                .find_book(&book_name)
+                .map(|book| book.name.clone())
+        })
+        .unwrap_or(book_name);
+    Some((book_name, chapter))
+}
+
 /// Validate if a URL path represents a valid Bible chapter
 ///
 /// Checks if the given pathname follows the expected format
@@ -1,9 +1,13 @@
 use crate::instructions::Instruction;
 use crate::view_state::{InstructionResult, ViewStateSignal};
-use leptos::prelude::Update;
+use leptos::ev;
+use leptos::prelude::{
+    on_cleanup, signal, window_event_listener, GetUntracked, ReadSignal, Set, Update,
+};
 use leptos_router::NavigateOptions;
 
 const MOBILE_BREAKPOINT: f64 = 768.0;
+const WIDE_BREAKPOINT: f64 = 1024.0;
 
 /// Helper function to execute an instruction and handle navigation results
 /// This provides a clean interface for components to execute instructions without handling the result manually
@@ -56,3 +60,24 @@ pub fn is_mobile_screen() -> bool {
     }
     false
 }
+
+/// Whether the viewport is wide enough to lay the chapter text out in columns
+pub fn is_wide_screen() -> bool {
+    leptos::web_sys::window()
+        .and_then(|window| window.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .is_some_and(|width| width >= WIDE_BREAKPOINT)
+}
+
+/// `is_wide_screen`, kept up to date as the window is resized
+pub fn wide_screen_signal() -> ReadSignal<bool> {
+    let (is_wide, set_is_wide) = signal(is_wide_screen());
+    let handle = window_event_listener(ev::resize, move |_| {
+        let wide = is_wide_screen();
+        if wide != is_wide.get_untracked() {
+            set_is_wide.set(wide);
+        }
+    });
+    on_cleanup(move || handle.remove());
+    is_wide
+}
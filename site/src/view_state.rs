@@ -1,8 +1,23 @@
-use crate::core::{get_bible, Bible, Chapter, VerseRange};
+use crate::core::{
+    daily_reading_link, faster_read_aloud_rate, init_bible_signal, normalize_lexicon_key,
+    slower_read_aloud_rate, todays_readings, Bible, Chapter, ChapterKey, ReadAloudState,
+    SourceTextMode, VerseRange,
+};
 use crate::instructions::Instruction;
+use crate::storage::annotation_backup::{merge_annotation_backup, AnnotationBackup, ImportMode};
+use crate::storage::lexicon::{get_lexicon_history, push_lexicon_history, save_lexicon_history};
+use crate::storage::pinned_chapters::get_pinned_chapters;
+use crate::storage::workspaces::{
+    find_workspace, get_workspaces, save_workspaces, upsert_workspace, Workspace,
+};
 use crate::storage::{
-    get_references_sidebar_open, get_sidebar_open, get_verse_visibility,
-    save_references_sidebar_open, save_sidebar_open, save_verse_visibility,
+    apply_highlight, get_completed_chapters, get_expanded_books, get_highlight_labels,
+    get_highlights, get_notes, get_read_aloud_rate, get_references_sidebar_open,
+    get_show_section_headings, get_sidebar_open, get_text_columns, get_verse_visibility,
+    save_completed_chapters, save_expanded_books, save_highlights, save_notes,
+    save_read_aloud_rate, save_references_sidebar_open, save_show_section_headings,
+    save_sidebar_open, save_text_columns, save_verse_visibility, set_note, HighlightColor,
+    VerseHighlight, VerseNote, MAX_TEXT_COLUMNS,
 };
 use crate::storage::{get_selected_theme, get_selected_translation};
 use crate::utils::is_mobile_screen;
@@ -19,11 +34,49 @@ pub struct AppState {
 
     // Panel states
     pub is_translation_comparison_open: bool,
+    pub is_place_map_open: bool,
     pub is_command_palette_open: bool,
 
+    // Translations shown side by side in the comparison panel
+    pub comparison_translations: Vec<String>,
+
     // Feature toggles
     pub verse_visibility_enabled: bool,
 
+    // Reading layout (number of text columns on wide screens)
+    pub text_columns: u8,
+
+    // Reading progress (paths of completed chapters)
+    pub completed_chapters: Vec<String>,
+
+    // Highlighted verses, and whether a chapter shows only those
+    pub highlights: Vec<VerseHighlight>,
+    pub is_highlight_legend_open: bool,
+    pub show_only_highlighted: bool,
+
+    // Whether chapters show their Hebrew or Greek source text, and its transliteration
+    pub source_text_mode: SourceTextMode,
+    pub show_transliteration: bool,
+
+    // Whether the lexicon panel is open, the entry it shows and recently viewed entries
+    pub is_lexicon_open: bool,
+    pub lexicon_entry: Option<String>,
+    pub lexicon_history: Vec<String>,
+
+    // Whether chapters and exports include the translation's section headings and chapter titles
+    pub show_section_headings: bool,
+
+    // Whether the chapter is being read aloud, and how fast
+    pub read_aloud: ReadAloudState,
+    pub read_aloud_rate: f32,
+
+    // Notes on verses, and the chapter and verse whose note is being edited
+    pub notes: Vec<VerseNote>,
+    pub note_editor_verse: Option<(ChapterKey, u32)>,
+
+    // Whether the panel for importing an annotation backup is open
+    pub is_annotation_import_open: bool,
+
     // Command palette navigation
     pub next_palette_result_trigger: bool,
     pub previous_palette_result_trigger: bool,
@@ -36,16 +89,16 @@ pub struct AppState {
     // Navigation history
     pub previous_chapter_path: Option<String>,
 
+    // Last repeatable instruction and its count, replayed by `.`
+    pub last_instruction: Option<(Instruction, u32)>,
+
     // Export progress state
     pub export_progress: f32,
     pub export_status: String,
     pub is_exporting: bool,
 
-    // Bible data - single source of truth
-    pub current_bible: Option<Bible>,
-
     // Component-specific state
-    pub selected_book: String,
+    pub expanded_books: Vec<String>,
     pub command_palette_input: String,
     pub command_palette_search_query: String,
     pub command_palette_selected_index: usize,
@@ -71,21 +124,39 @@ impl Default for AppState {
             is_right_sidebar_open: get_references_sidebar_open(),
             is_theme_sidebar_open: false,
             is_translation_comparison_open: false,
+            is_place_map_open: false,
             is_command_palette_open: false,
+            comparison_translations: Vec::new(),
             verse_visibility_enabled: get_verse_visibility(),
+            text_columns: get_text_columns(),
+            completed_chapters: get_completed_chapters(),
+            highlights: get_highlights(),
+            is_highlight_legend_open: false,
+            show_only_highlighted: false,
+            source_text_mode: SourceTextMode::Hidden,
+            show_transliteration: false,
+            is_lexicon_open: false,
+            lexicon_entry: None,
+            lexicon_history: get_lexicon_history(),
+            show_section_headings: get_show_section_headings(),
+            read_aloud: ReadAloudState::Stopped,
+            read_aloud_rate: get_read_aloud_rate(),
+            notes: get_notes(),
+            note_editor_verse: None,
+            is_annotation_import_open: false,
             next_palette_result_trigger: false,
             previous_palette_result_trigger: false,
             initial_search_query: None,
             current_chapter: None,
             search_params: String::new(),
             previous_chapter_path: None,
+            last_instruction: None,
             export_progress: 0.0,
             export_status: String::new(),
             is_exporting: false,
-            current_bible: Some(get_bible().clone()),
 
             // Component-specific state
-            selected_book: String::new(),
+            expanded_books: get_expanded_books().unwrap_or_default(),
             command_palette_input: String::new(),
             command_palette_search_query: String::new(),
             command_palette_selected_index: 0,
@@ -112,21 +183,32 @@ impl AppState {
         Self::default()
     }
 
-    /// Get a reference to the current Bible
-    pub fn get_bible(&self) -> Option<&Bible> {
-        self.current_bible.as_ref()
+    /// The current translation. Its books are shared, so this copies no verses
+    pub fn get_bible(&self) -> Option<Bible> {
+        init_bible_signal().get_untracked()
     }
 
-    /// Set the current Bible
-    pub fn set_bible(&mut self, bible: Bible) {
-        self.current_bible = Some(bible);
+    /// The key highlights and notes on `chapter` are stored under
+    fn chapter_key(&self, chapter: &Chapter) -> ChapterKey {
+        match self.get_bible() {
+            Some(bible) => bible.chapter_key(chapter),
+            None => ChapterKey::for_book_name(&chapter.book_name(), chapter.chapter),
+        }
     }
 
     // Component-specific state management
 
-    /// Get selected book
-    pub fn get_selected_book(&self) -> &str {
-        &self.selected_book
+    /// Whether the chapters of a book are shown in the sidebar
+    pub fn is_book_expanded(&self, book_name: &str) -> bool {
+        self.expanded_books.iter().any(|book| book == book_name)
+    }
+
+    /// Show the chapters of a book in the sidebar
+    pub fn expand_book(&mut self, book_name: &str) {
+        if !self.is_book_expanded(book_name) {
+            self.expanded_books.push(book_name.to_string());
+            save_expanded_books(&self.expanded_books);
+        }
     }
 
     /// Update command palette input
@@ -306,6 +388,10 @@ impl AppState {
                 self.toggle_translation_comparison();
                 InstructionResult::Handled
             }
+            Instruction::TogglePlaceMap => {
+                self.is_place_map_open = !self.is_place_map_open;
+                InstructionResult::Handled
+            }
             Instruction::ToggleVerseVisibility => {
                 self.toggle_verse_visibility();
                 InstructionResult::Handled
@@ -348,7 +434,10 @@ impl AppState {
             Instruction::PreviousBook => self.handle_previous_book(),
             Instruction::BeginningOfChapter => self.handle_beginning_of_chapter(),
             Instruction::EndOfChapter => self.handle_end_of_chapter(),
+            Instruction::NextParagraph => self.handle_next_paragraph_with_multiplier(1),
+            Instruction::PreviousParagraph => self.handle_previous_paragraph_with_multiplier(1),
             Instruction::GoToVerse(verse_id) => self.handle_go_to_verse(*verse_id),
+            Instruction::GoToBookPercent(percent) => self.handle_go_to_book_percent(*percent),
             Instruction::GoToChapter(chapter_path) => {
                 // Handle mobile sidebar closing as a side effect
                 if is_mobile_screen() {
@@ -389,16 +478,13 @@ impl AppState {
                 InstructionResult::Handled
             }
             Instruction::OpenAboutPage => InstructionResult::Navigate("/about".to_string()),
+            Instruction::OpenPeopleIndex => InstructionResult::Navigate("/people".to_string()),
+            Instruction::OpenTimeline => InstructionResult::Navigate("/timeline".to_string()),
             Instruction::ShowTranslations => {
                 InstructionResult::Navigate("/?choose=true".to_string())
             }
-            Instruction::RandomVerse => {
-                if let Some(path) = self.get_random_verse_path() {
-                    InstructionResult::Navigate(path)
-                } else {
-                    InstructionResult::Failed("No verses available".to_string())
-                }
-            }
+            // The processor reads every book before drawing a verse
+            Instruction::RandomVerse => InstructionResult::NotHandled,
             Instruction::RandomChapter => {
                 if let Some(path) = self.get_random_chapter_path() {
                     InstructionResult::Navigate(path)
@@ -406,6 +492,12 @@ impl AppState {
                     InstructionResult::Failed("No chapters available".to_string())
                 }
             }
+            Instruction::PsalmOfTheDay => {
+                self.daily_reading_result("psalms", todays_readings().psalm)
+            }
+            Instruction::ProverbOfTheDay => {
+                self.daily_reading_result("proverbs", todays_readings().proverb)
+            }
 
             // UI Close instructions
             Instruction::CloseCommandPalette => {
@@ -428,23 +520,138 @@ impl AppState {
                 self.is_translation_comparison_open = false;
                 InstructionResult::Handled
             }
+            Instruction::ClosePlaceMap => {
+                self.is_place_map_open = false;
+                InstructionResult::Handled
+            }
+            Instruction::CloseHighlightLegend => {
+                self.is_highlight_legend_open = false;
+                InstructionResult::Handled
+            }
+            Instruction::CloseNoteEditor => {
+                self.note_editor_verse = None;
+                InstructionResult::Handled
+            }
+            Instruction::CloseAnnotationImport => {
+                self.is_annotation_import_open = false;
+                InstructionResult::Handled
+            }
+            Instruction::CloseLexicon => {
+                self.is_lexicon_open = false;
+                InstructionResult::Handled
+            }
+
+            // Reading progress instructions
+            Instruction::MarkChapterComplete => self.toggle_current_chapter_complete(),
+
+            // Highlight instructions
+            Instruction::HighlightVerse(color) => self.highlight_selected_verses(Some(*color)),
+            Instruction::RemoveHighlight => self.highlight_selected_verses(None),
+            Instruction::ToggleHighlightLegend => {
+                self.is_highlight_legend_open = !self.is_highlight_legend_open;
+                InstructionResult::Handled
+            }
+            Instruction::ToggleHighlightedOnly => {
+                self.show_only_highlighted = !self.show_only_highlighted;
+                InstructionResult::Handled
+            }
+
+            // Source text instructions
+            Instruction::ToggleSourceText => {
+                self.source_text_mode = self.source_text_mode.next();
+                InstructionResult::Handled
+            }
+            Instruction::ToggleTransliteration => {
+                self.show_transliteration = !self.show_transliteration;
+                InstructionResult::Handled
+            }
+            Instruction::ToggleLexicon => {
+                self.is_lexicon_open = !self.is_lexicon_open;
+                InstructionResult::Handled
+            }
+            Instruction::ShowLexiconEntry(key) => self.show_lexicon_entry(key),
+
+            // Section heading instructions
+            Instruction::ToggleSectionHeadings => {
+                self.show_section_headings = !self.show_section_headings;
+                save_show_section_headings(self.show_section_headings);
+                InstructionResult::Handled
+            }
+
+            // Read aloud instructions
+            Instruction::ToggleReadAloud => {
+                self.read_aloud = self.read_aloud.toggled();
+                InstructionResult::Handled
+            }
+            Instruction::StopReadAloud => {
+                self.read_aloud = ReadAloudState::Stopped;
+                InstructionResult::Handled
+            }
+            Instruction::ReadAloudFaster => {
+                self.set_read_aloud_rate(faster_read_aloud_rate(self.read_aloud_rate));
+                InstructionResult::Handled
+            }
+            Instruction::ReadAloudSlower => {
+                self.set_read_aloud_rate(slower_read_aloud_rate(self.read_aloud_rate));
+                InstructionResult::Handled
+            }
+
+            // Note instructions
+            Instruction::EditNote => self.open_note_editor(),
+            Instruction::ImportAnnotationBackup => {
+                self.is_annotation_import_open = true;
+                InstructionResult::Handled
+            }
+
+            // Dot repeat
+            Instruction::RepeatLast => match self.last_instruction.clone() {
+                Some((last, multiplier)) => self.execute_with_multiplier(&last, multiplier),
+                None => InstructionResult::Failed("No instruction to repeat".to_string()),
+            },
 
             // Book Selection instructions
-            Instruction::SelectBook(book_name) => {
-                self.selected_book = book_name.clone();
+            Instruction::ToggleBook(book_name) => {
+                if self.is_book_expanded(book_name) {
+                    self.expanded_books.retain(|book| book != book_name);
+                    save_expanded_books(&self.expanded_books);
+                } else {
+                    self.expand_book(book_name);
+                }
                 InstructionResult::Handled
             }
-            Instruction::ClearSelectedBook => {
-                self.selected_book = String::new();
+            Instruction::CollapseAllBooks => {
+                self.expanded_books.clear();
+                save_expanded_books(&self.expanded_books);
+                InstructionResult::Handled
+            }
+            Instruction::ExpandCurrentBook => self.handle_expand_current_book(),
+
+            // Register listing opens the palette with the `"` prefix
+            Instruction::ShowRegisters => {
+                self.set_initial_search_query(Some("\"".to_string()));
+                self.set_command_palette(true);
+                InstructionResult::Handled
+            }
+
+            // Workspace listing opens the palette with the `ws:` prefix
+            Instruction::ShowWorkspaces => {
+                self.set_initial_search_query(Some("ws:".to_string()));
+                self.set_command_palette(true);
                 InstructionResult::Handled
             }
 
             // Instructions that still need external handling (exports, copy operations, palette toggles)
             Instruction::CopyRawVerse
             | Instruction::CopyVerseWithReference
+            | Instruction::CopyRawVerseToRegister(_)
+            | Instruction::CopyVerseWithReferenceToRegister(_)
+            | Instruction::PasteRegister(_)
+            | Instruction::ExportRegister(_)
             | Instruction::ExportToPDF
             | Instruction::ExportToMarkdown
             | Instruction::ExportLinkedMarkdown
+            | Instruction::ExportAnnotations
+            | Instruction::ExportAnnotationBackup
             | Instruction::ToggleBiblePallate
             | Instruction::ToggleVersePallate
             | Instruction::NextReference
@@ -475,6 +682,10 @@ impl AppState {
             }
             Instruction::NextBook => self.handle_next_book_with_multiplier(multiplier),
             Instruction::PreviousBook => self.handle_previous_book_with_multiplier(multiplier),
+            Instruction::NextParagraph => self.handle_next_paragraph_with_multiplier(multiplier),
+            Instruction::PreviousParagraph => {
+                self.handle_previous_paragraph_with_multiplier(multiplier)
+            }
             _ => self.execute(instruction),
         }
     }
@@ -562,6 +773,151 @@ impl AppState {
         save_verse_visibility(self.verse_visibility_enabled);
     }
 
+    /// Set the number of text columns and persist to storage
+    pub fn set_text_columns(&mut self, columns: u8) {
+        self.text_columns = columns.clamp(1, MAX_TEXT_COLUMNS);
+        save_text_columns(self.text_columns);
+    }
+
+    pub fn set_read_aloud_rate(&mut self, rate: f32) {
+        self.read_aloud_rate = rate;
+        save_read_aloud_rate(rate);
+    }
+
+    /// Check whether the chapter at the given path is marked as complete
+    pub fn is_chapter_complete(&self, chapter_path: &str) -> bool {
+        self.completed_chapters
+            .iter()
+            .any(|path| path == chapter_path)
+    }
+
+    /// Toggle the completion state of the current chapter and persist to storage
+    pub fn toggle_current_chapter_complete(&mut self) -> InstructionResult {
+        let Some(chapter_path) = self.current_chapter.as_ref().map(|ch| ch.to_path()) else {
+            return InstructionResult::Failed("No current chapter".to_string());
+        };
+
+        if self.is_chapter_complete(&chapter_path) {
+            self.completed_chapters.retain(|path| path != &chapter_path);
+        } else {
+            self.completed_chapters.push(chapter_path);
+        }
+        save_completed_chapters(&self.completed_chapters);
+        InstructionResult::Handled
+    }
+
+    /// Color the selected verses of the current chapter, or remove their color, and persist to storage
+    pub fn highlight_selected_verses(
+        &mut self,
+        color: Option<HighlightColor>,
+    ) -> InstructionResult {
+        let Some(chapter) = self.current_chapter.as_ref() else {
+            return InstructionResult::Failed("No current chapter".to_string());
+        };
+        let verse_ranges = self.get_verse_ranges();
+        let verses: Vec<u32> = chapter
+            .verses
+            .iter()
+            .map(|verse| verse.verse)
+            .filter(|verse| verse_ranges.iter().any(|range| range.contains(*verse)))
+            .collect();
+        if verses.is_empty() {
+            return InstructionResult::Failed("No verse selected".to_string());
+        }
+
+        let key = self.chapter_key(chapter);
+        apply_highlight(&mut self.highlights, &key, &verses, color);
+        save_highlights(&self.highlights);
+        InstructionResult::Handled
+    }
+
+    /// Open the note editor for the first selected verse of the current chapter
+    pub fn open_note_editor(&mut self) -> InstructionResult {
+        let Some(chapter) = self.current_chapter.as_ref() else {
+            return InstructionResult::Failed("No current chapter".to_string());
+        };
+        let Some(verse) = self
+            .get_verse_ranges()
+            .iter()
+            .map(|range| range.start)
+            .min()
+        else {
+            return InstructionResult::Failed("No verse selected".to_string());
+        };
+        self.note_editor_verse = Some((self.chapter_key(chapter), verse));
+        InstructionResult::Handled
+    }
+
+    /// Replace the note on a verse and persist to storage
+    pub fn save_note(&mut self, key: &ChapterKey, verse: u32, text: &str) {
+        set_note(&mut self.notes, key, verse, text);
+        save_notes(&self.notes);
+    }
+
+    /// Combine a backup with the stored highlights, notes and pinned chapters, and persist the result
+    pub fn import_annotations(&mut self, backup: AnnotationBackup, mode: ImportMode) {
+        let current = AnnotationBackup::new(
+            self.highlights.clone(),
+            get_highlight_labels(),
+            self.notes.clone(),
+            get_pinned_chapters(),
+        );
+        let merged = merge_annotation_backup(current, backup, mode);
+        merged.save();
+        self.highlights = merged.highlights;
+        self.notes = merged.notes;
+    }
+
+    /// Open the lexicon at `key` and remember it among the recently viewed entries
+    fn show_lexicon_entry(&mut self, key: &str) -> InstructionResult {
+        let key = normalize_lexicon_key(key);
+        if key.is_empty() {
+            return InstructionResult::Failed("No lexicon entry given".to_string());
+        }
+        push_lexicon_history(&mut self.lexicon_history, &key);
+        save_lexicon_history(&self.lexicon_history);
+        self.lexicon_entry = Some(key);
+        self.is_lexicon_open = true;
+        InstructionResult::Handled
+    }
+
+    /// Save the current chapter, verse selection and panels as a workspace called `name`,
+    /// replacing a workspace with the same name
+    pub fn save_workspace(&self, name: &str) -> InstructionResult {
+        let Some(chapter) = self.current_chapter.as_ref() else {
+            return InstructionResult::Failed("No current chapter".to_string());
+        };
+        let workspace = Workspace {
+            name: name.trim().to_string(),
+            path: chapter.to_path_with_verses(&self.get_verse_ranges()),
+            is_left_sidebar_open: self.is_left_sidebar_open,
+            is_right_sidebar_open: self.is_right_sidebar_open,
+            is_translation_comparison_open: self.is_translation_comparison_open,
+            is_place_map_open: self.is_place_map_open,
+            comparison_translations: self.comparison_translations.clone(),
+            text_columns: self.text_columns,
+        };
+        let mut workspaces = get_workspaces();
+        upsert_workspace(&mut workspaces, workspace);
+        save_workspaces(&workspaces);
+        InstructionResult::Handled
+    }
+
+    /// Reopen the panels of the workspace called `name` and navigate to its chapter
+    pub fn restore_workspace(&mut self, name: &str) -> InstructionResult {
+        let workspaces = get_workspaces();
+        let Some(workspace) = find_workspace(&workspaces, name) else {
+            return InstructionResult::Failed(format!("No workspace named {}", name));
+        };
+        self.set_left_sidebar(workspace.is_left_sidebar_open);
+        self.set_right_sidebar(workspace.is_right_sidebar_open);
+        self.is_translation_comparison_open = workspace.is_translation_comparison_open;
+        self.is_place_map_open = workspace.is_place_map_open;
+        self.comparison_translations = workspace.comparison_translations.clone();
+        self.set_text_columns(workspace.text_columns);
+        InstructionResult::Navigate(workspace.path.clone())
+    }
+
     /// Trigger next palette result navigation
     pub fn trigger_next_palette_result(&mut self) {
         self.next_palette_result_trigger = !self.next_palette_result_trigger;
@@ -607,6 +963,40 @@ impl AppState {
         self.handle_previous_book_with_multiplier(1)
     }
 
+    fn handle_expand_current_book(&mut self) -> InstructionResult {
+        let Some(current_chapter) = self.current_chapter.as_ref() else {
+            return InstructionResult::Failed("No current chapter".to_string());
+        };
+        let book_name = self.get_bible().and_then(|bible| {
+            bible
+                .books
+                .iter()
+                .find(|book| book.chapters.iter().any(|c| c.name == current_chapter.name))
+                .map(|book| book.name.clone())
+        });
+        match book_name {
+            Some(book_name) => {
+                self.expand_book(&book_name);
+                InstructionResult::Handled
+            }
+            None => InstructionResult::Failed("Current book not found".to_string()),
+        }
+    }
+
+    fn handle_go_to_book_percent(&mut self, percent: u8) -> InstructionResult {
+        if let Some(ref current_chapter) = self.current_chapter {
+            match self
+                .get_bible()
+                .and_then(|bible| bible.get_book_percent_chapter(current_chapter, percent))
+            {
+                Some(chapter) => InstructionResult::Navigate(chapter.to_path()),
+                None => InstructionResult::Failed("Book has no chapters".to_string()),
+            }
+        } else {
+            InstructionResult::Failed("No current chapter".to_string())
+        }
+    }
+
     fn handle_beginning_of_chapter(&mut self) -> InstructionResult {
         if let Some(ref current_chapter) = self.current_chapter {
             let new_path = current_chapter.to_path();
@@ -634,6 +1024,45 @@ impl AppState {
         }
     }
 
+    fn handle_next_paragraph_with_multiplier(&mut self, multiplier: u32) -> InstructionResult {
+        if let Some(ref current_chapter) = self.current_chapter {
+            let current_verse = self.get_current_verse();
+            if let Some(verse) = current_chapter.get_next_paragraph_verse(current_verse, multiplier)
+            {
+                let verse_range = VerseRange {
+                    start: verse,
+                    end: verse,
+                };
+                let new_path = current_chapter.to_path_with_verses(&[verse_range]);
+                InstructionResult::Navigate(new_path)
+            } else {
+                InstructionResult::Failed("Already at end of chapter".to_string())
+            }
+        } else {
+            InstructionResult::Failed("No current chapter".to_string())
+        }
+    }
+
+    fn handle_previous_paragraph_with_multiplier(&mut self, multiplier: u32) -> InstructionResult {
+        if let Some(ref current_chapter) = self.current_chapter {
+            let current_verse = self.get_current_verse();
+            if let Some(verse) =
+                current_chapter.get_previous_paragraph_verse(current_verse, multiplier)
+            {
+                let verse_range = VerseRange {
+                    start: verse,
+                    end: verse,
+                };
+                let new_path = current_chapter.to_path_with_verses(&[verse_range]);
+                InstructionResult::Navigate(new_path)
+            } else {
+                InstructionResult::Failed("Already at beginning of chapter".to_string())
+            }
+        } else {
+            InstructionResult::Failed("No current chapter".to_string())
+        }
+    }
+
     fn handle_go_to_verse(&mut self, verse_id: crate::core::types::VerseId) -> InstructionResult {
         if let Some(ref current_chapter) = self.current_chapter {
             let verse_num = verse_id.verse();
@@ -939,6 +1368,11 @@ impl AppState {
         self.execute(&Instruction::CloseRightSidebar);
         self.execute(&Instruction::CloseThemeSidebar);
         self.execute(&Instruction::CloseTranslationComparison);
+        self.execute(&Instruction::ClosePlaceMap);
+        self.execute(&Instruction::CloseHighlightLegend);
+        self.execute(&Instruction::CloseNoteEditor);
+        self.execute(&Instruction::CloseAnnotationImport);
+        self.execute(&Instruction::CloseLexicon);
     }
 
     /// Close all overlays (useful for mobile)
@@ -1012,6 +1446,32 @@ impl AppState {
         self.previous_chapter_path = path;
     }
 
+    /// Resolve `.` to the remembered instruction and remember repeatable ones.
+    ///
+    /// A count typed before `.` replaces the remembered count, like in vim.
+    /// Returns `None` when `.` is pressed before anything repeatable ran.
+    pub fn resolve_repeat(
+        &mut self,
+        instruction: Instruction,
+        multiplier: u32,
+    ) -> Option<(Instruction, u32)> {
+        if instruction == Instruction::RepeatLast {
+            let (last, last_multiplier) = self.last_instruction.clone()?;
+            let multiplier = if multiplier > 1 {
+                multiplier
+            } else {
+                last_multiplier
+            };
+            self.last_instruction = Some((last.clone(), multiplier));
+            return Some((last, multiplier));
+        }
+
+        if instruction.is_repeatable() {
+            self.last_instruction = Some((instruction.clone(), multiplier));
+        }
+        Some((instruction, multiplier))
+    }
+
     /// Update export progress
     pub fn set_export_progress(&mut self, progress: f32, status: String) {
         self.export_progress = progress;
@@ -1027,46 +1487,17 @@ impl AppState {
         }
     }
 
-    /// Get a random verse path
-    fn get_random_verse_path(&self) -> Option<String> {
-        use std::sync::atomic::{AtomicUsize, Ordering};
-        static RANDOM_COUNTER: AtomicUsize = AtomicUsize::new(1);
-
-        let bible = self.get_bible()?;
-        let mut total_verses = 0;
-        let mut verse_locations = Vec::new();
-
-        for book in &bible.books {
-            for chapter in &book.chapters {
-                for verse in &chapter.verses {
-                    verse_locations.push((chapter.clone(), verse.verse));
-                    total_verses += 1;
-                }
-            }
-        }
-
-        if total_verses == 0 {
-            return None;
-        }
-
-        let counter = RANDOM_COUNTER.fetch_add(1, Ordering::Relaxed);
-        let mut rng_state = counter.wrapping_mul(1103515245).wrapping_add(12345);
-        rng_state = rng_state.wrapping_mul(1664525).wrapping_add(1013904223);
-        let random_index = rng_state % total_verses;
-        let safe_index = random_index.min(total_verses - 1);
-
-        if let Some((chapter, verse_num)) = verse_locations.get(safe_index) {
-            let verse_range = VerseRange {
-                start: *verse_num,
-                end: *verse_num,
-            };
-            Some(chapter.to_path_with_verses(&[verse_range]))
-        } else {
-            None
+    /// Get a random chapter path
+    fn daily_reading_result(&self, book_key: &str, chapter: u32) -> InstructionResult {
+        match self
+            .get_bible()
+            .and_then(|bible| daily_reading_link(&bible, book_key, chapter))
+        {
+            Some((_, path)) => InstructionResult::Navigate(path),
+            None => InstructionResult::Failed("Today's reading is not available".to_string()),
         }
     }
 
-    /// Get a random chapter path
     fn get_random_chapter_path(&self) -> Option<String> {
         use std::sync::atomic::{AtomicUsize, Ordering};
         static RANDOM_COUNTER: AtomicUsize = AtomicUsize::new(1);
@@ -1077,7 +1508,7 @@ impl AppState {
 
         for book in &bible.books {
             for chapter in &book.chapters {
-                chapter_locations.push(chapter.clone());
+                chapter_locations.push(chapter);
                 total_chapters += 1;
             }
         }
@@ -1188,6 +1619,8 @@ impl AppState {
             Instruction::PreviousBook => self.handle_previous_book(),
             Instruction::BeginningOfChapter => self.handle_beginning_of_chapter(),
             Instruction::EndOfChapter => self.handle_end_of_chapter(),
+            Instruction::NextParagraph => self.handle_next_paragraph_with_multiplier(1),
+            Instruction::PreviousParagraph => self.handle_previous_paragraph_with_multiplier(1),
             Instruction::GoToVerse(verse_num) => self.handle_go_to_verse(verse_num),
 
             // Selection instructions
@@ -1215,10 +1648,16 @@ impl AppState {
             | Instruction::ExportToPDF
             | Instruction::ExportToMarkdown
             | Instruction::ExportLinkedMarkdown
+            | Instruction::ExportAnnotations
+            | Instruction::ExportAnnotationBackup
             | Instruction::OpenGithubRepository
             | Instruction::RandomVerse
             | Instruction::RandomChapter
+            | Instruction::PsalmOfTheDay
+            | Instruction::ProverbOfTheDay
             | Instruction::OpenAboutPage
+            | Instruction::OpenPeopleIndex
+            | Instruction::OpenTimeline
             | Instruction::ShowTranslations => InstructionResult::NotHandled,
 
             // Other instructions
@@ -1241,6 +1680,10 @@ impl AppState {
             }
             Instruction::NextBook => self.handle_next_book_with_multiplier(multiplier),
             Instruction::PreviousBook => self.handle_previous_book_with_multiplier(multiplier),
+            Instruction::NextParagraph => self.handle_next_paragraph_with_multiplier(multiplier),
+            Instruction::PreviousParagraph => {
+                self.handle_previous_paragraph_with_multiplier(multiplier)
+            }
             _ => self.apply_instruction(instruction),
         }
     }
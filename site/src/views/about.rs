@@ -1,15 +1,16 @@
+use crate::i18n::t;
 use leptos::prelude::*;
 
 #[component]
 pub fn About() -> impl IntoView {
     view! {
         <article class="max-w-2xl mx-auto px-4 py-12">
-            <h1 class="text-2xl font-bold mb-8" style="color: var(--theme-text-primary)">"About"</h1>
+            <h1 class="text-2xl font-bold mb-8" style="color: var(--theme-text-primary)">{t("about")}</h1>
 
             <div class="space-y-8 text-sm leading-relaxed" style="color: var(--theme-text-primary)">
                 <section>
                     <p class="mb-4">
-                        "A keyboard-driven Bible reading website. Built with Rust and open source."
+                        {t("about_description")}
                     </p>
                     <a
                         href="https://github.com/biblecomputer/bible"
@@ -17,42 +18,42 @@ pub fn About() -> impl IntoView {
                         rel="noopener noreferrer"
                         class="hover:underline translation-link"
                     >
-                        "View source on GitHub"
+                        {t("view_source")}
                     </a>
                 </section>
 
                 <section>
-                    <h2 class="font-medium mb-3" style="color: var(--theme-text-primary)">"Basic Navigation"</h2>
+                    <h2 class="font-medium mb-3" style="color: var(--theme-text-primary)">{t("basic_navigation")}</h2>
                     <div class="space-y-1 font-mono text-xs">
                         <div class="flex justify-between">
-                            <span style="color: var(--theme-text-secondary)">"Next verse"</span>
+                            <span style="color: var(--theme-text-secondary)">{t("next_verse")}</span>
                             <span style="color: var(--theme-text-muted)">"j"</span>
                         </div>
                         <div class="flex justify-between">
-                            <span style="color: var(--theme-text-secondary)">"Previous verse"</span>
+                            <span style="color: var(--theme-text-secondary)">{t("previous_verse")}</span>
                             <span style="color: var(--theme-text-muted)">"k"</span>
                         </div>
                         <div class="flex justify-between">
-                            <span style="color: var(--theme-text-secondary)">"Open command palette"</span>
+                            <span style="color: var(--theme-text-secondary)">{t("open_command_palette")}</span>
                             <span style="color: var(--theme-text-muted)">"Ctrl+O"</span>
                         </div>
                         <div class="flex justify-between">
-                            <span style="color: var(--theme-text-secondary)">"Navigate palette results"</span>
+                            <span style="color: var(--theme-text-secondary)">{t("navigate_palette_results")}</span>
                             <span style="color: var(--theme-text-muted)">"Ctrl+J/K"</span>
                         </div>
                     </div>
                 </section>
 
                 <section>
-                    <h2 class="font-medium mb-3" style="color: var(--theme-text-primary)">"Command Palette"</h2>
+                    <h2 class="font-medium mb-3" style="color: var(--theme-text-primary)">{t("command_palette")}</h2>
                     <p class="mb-2">
-                        "Press " <code class="px-1 rounded text-xs about-code">"Ctrl+O"</code> " to search:"
+                        {t("press")} <code class="px-1 rounded text-xs about-code">"Ctrl+O"</code> {t("to_search")}
                     </p>
                     <ul class="space-y-1 text-xs">
-                        <li style="color: var(--theme-text-secondary)">"• Bible text: " <code class="px-1 rounded about-code">"love"</code></li>
-                        <li style="color: var(--theme-text-secondary)">"• Verses: " <code class="px-1 rounded about-code">"John 3:16"</code></li>
-                        <li style="color: var(--theme-text-secondary)">"• Chapters: " <code class="px-1 rounded about-code">"Genesis 1"</code></li>
-                        <li style="color: var(--theme-text-secondary)">"• Commands: " <code class="px-1 rounded about-code">">copy"</code></li>
+                        <li style="color: var(--theme-text-secondary)">{t("about_search_text")} <code class="px-1 rounded about-code">"love"</code></li>
+                        <li style="color: var(--theme-text-secondary)">{t("about_search_verses")} <code class="px-1 rounded about-code">"John 3:16"</code></li>
+                        <li style="color: var(--theme-text-secondary)">{t("about_search_chapters")} <code class="px-1 rounded about-code">"Genesis 1"</code></li>
+                        <li style="color: var(--theme-text-secondary)">{t("about_search_commands")} <code class="px-1 rounded about-code">">copy"</code></li>
                     </ul>
                 </section>
             </div>
@@ -1,42 +1,124 @@
-use crate::core::types::Language;
-use crate::core::{get_bible, init_bible_signal, Chapter, VerseRange};
+use crate::api::load_all_books;
+use crate::components::{
+    is_read_aloud_supported, prefetch_chapter_references, source_words_view, ReadAloud,
+    ReferenceText, SelectedWord, WordPopover,
+};
+use crate::core::{
+    chapter_source_text, chapter_titles, headings_by_verse, init_bible_signal, person_named_at,
+    Chapter, ChapterKey, Person, ReadAloudState, SourceScript, SourceTextMode, SourceWord,
+    VerseRange,
+};
+use crate::i18n::t;
+use crate::instructions::logic::{export_bible_to_json, trigger_json_download};
+use crate::instructions::types::Instruction;
+use crate::storage::section_headings::get_chapter_section_headings;
+use crate::storage::source_text::get_chapter_source_text;
 use crate::storage::translations::get_current_translation;
+use crate::storage::{
+    find_note, get_selected_translation, save_verse_edit, HighlightColor, VerseEdit,
+    VerseHighlight, VerseNote,
+};
+use crate::utils::wide_screen_signal;
+use crate::view_state::ViewStateSignal;
+use crate::views::person_path;
 use leptos::prelude::*;
 use leptos::view;
 use leptos::wasm_bindgen::JsCast;
 use leptos::IntoView;
 use leptos_router::components::A;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+use std::collections::HashMap;
+use std::ops::Range;
 use wasm_bindgen_futures::spawn_local;
+use web_sys::TouchEvent;
+
+/// Delay before prefetching neighbouring chapters, so the current chapter renders first
+const PREFETCH_DELAY_MS: u32 = 300;
+
+/// How long a verse has to be pressed before touch selection starts
+const LONG_PRESS_MS: u32 = 500;
+
+/// How far (in pixels) a finger may move before a press becomes a scroll
+const LONG_PRESS_MOVE_TOLERANCE: i32 = 10;
+
+/// The verse number of the verse span under a point on the screen
+fn verse_at_point(x: i32, y: i32) -> Option<u32> {
+    let element = web_sys::window()?
+        .document()?
+        .element_from_point(x as f32, y as f32)?;
+    element
+        .closest("[id^='verse-']")
+        .ok()??
+        .id()
+        .strip_prefix("verse-")?
+        .parse()
+        .ok()
+}
 
-// Removed redundant get_translated_chapter_name function - names are already translated
+/// Byte index in `text` of a caret offset given in UTF-16 code units, as the DOM counts them
+fn utf16_to_byte_index(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    text.char_indices()
+        .find(|(_, c)| {
+            units += c.len_utf16();
+            units > utf16_offset
+        })
+        .map_or(text.len(), |(index, _)| index)
+}
 
-fn get_navigation_text(key: &str) -> String {
-    if let Some(current_translation) = get_current_translation() {
-        if let Some(first_language) = current_translation.languages.first() {
-            match (key, first_language) {
-                ("previous_chapter", Language::Dutch) => "Vorig Hoofdstuk".to_string(),
-                ("next_chapter", Language::Dutch) => "Volgend Hoofdstuk".to_string(),
-                ("previous_chapter", Language::English) => "Previous Chapter".to_string(),
-                ("next_chapter", Language::English) => "Next Chapter".to_string(),
-                _ => key.to_string(),
-            }
-        } else {
-            key.to_string()
-        }
-    } else {
-        // Default to English
-        match key {
-            "previous_chapter" => "Previous Chapter".to_string(),
-            "next_chapter" => "Next Chapter".to_string(),
-            _ => key.to_string(),
+/// The person whose name is under a point on the screen
+fn person_at_point(x: i32, y: i32) -> Option<&'static Person> {
+    let position = web_sys::window()?
+        .document()?
+        .caret_position_from_point(x as f32, y as f32)?;
+    let text = position.offset_node()?.text_content()?;
+    person_named_at(&text, utf16_to_byte_index(&text, position.offset() as usize))
+}
+
+/// Split verses into `columns` consecutive runs holding roughly the same amount of text.
+/// There is always one range per column; columns left without verses get an empty one.
+fn split_into_columns(verse_lengths: &[usize], columns: usize) -> Vec<Range<usize>> {
+    let total: usize = verse_lengths.iter().sum();
+    let mut ranges = Vec::with_capacity(columns);
+    let mut start = 0;
+    let mut length = 0;
+
+    for (i, verse_length) in verse_lengths.iter().enumerate() {
+        length += verse_length;
+        // Close a column once everything up to here fills its share of the text
+        if ranges.len() + 1 < columns && length * columns >= total * (ranges.len() + 1) {
+            ranges.push(start..i + 1);
+            start = i + 1;
         }
     }
+    ranges.push(start..verse_lengths.len());
+    while ranges.len() < columns {
+        ranges.push(verse_lengths.len()..verse_lengths.len());
+    }
+    ranges
+}
+
+/// Position of the first finger of a touch event
+fn touch_point(ev: &TouchEvent) -> Option<(i32, i32)> {
+    let touch = ev.touches().get(0)?;
+    Some((touch.client_x(), touch.client_y()))
 }
 
+// Removed redundant get_translated_chapter_name function - names are already translated
+
 #[component]
 pub fn ChapterDetail(
     chapter: Chapter,
     verse_visibility_enabled: ReadSignal<bool>,
+    text_columns: ReadSignal<u8>,
+    highlights: ReadSignal<Vec<VerseHighlight>>,
+    show_only_highlighted: ReadSignal<bool>,
+    notes: ReadSignal<Vec<VerseNote>>,
+    source_text_mode: ReadSignal<SourceTextMode>,
+    show_transliteration: ReadSignal<bool>,
+    show_section_headings: ReadSignal<bool>,
+    view_state: ViewStateSignal,
 ) -> impl IntoView {
     let bible_signal = init_bible_signal();
 
@@ -158,45 +240,79 @@ pub fn ChapterDetail(
     let chapter_for_data = chapter.clone();
 
     // Create reactive computations for navigation chapters
+    // Read the Bible signal by reference; `get()` would copy the whole Bible on every change
     let prev_chapter = Memo::new(move |_| {
-        if let Some(bible) = bible_signal.get() {
-            bible.get_previous_chapter(&chapter_for_prev)
-        } else {
-            get_bible().get_previous_chapter(&chapter_for_prev)
-        }
+        bible_signal.with(|bible| {
+            bible
+                .as_ref()
+                .and_then(|bible| bible.get_previous_chapter(&chapter_for_prev))
+        })
     });
 
     let next_chapter = Memo::new(move |_| {
-        if let Some(bible) = bible_signal.get() {
-            bible.get_next_chapter(&chapter_for_next)
-        } else {
-            get_bible().get_next_chapter(&chapter_for_next)
-        }
+        bible_signal.with(|bible| {
+            bible
+                .as_ref()
+                .and_then(|bible| bible.get_next_chapter(&chapter_for_next))
+        })
     });
 
     let prev_path = Memo::new(move |_| prev_chapter.get().as_ref().map(|ch| ch.to_path()));
     let next_path = Memo::new(move |_| next_chapter.get().as_ref().map(|ch| ch.to_path()));
 
+    // Prepare the neighbouring chapters in the background so next/previous
+    // navigation doesn't have to do that work on the way in
+    Effect::new(move |_| {
+        let neighbours: Vec<Chapter> = [prev_chapter.get(), next_chapter.get()]
+            .into_iter()
+            .flatten()
+            .collect();
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(PREFETCH_DELAY_MS).await;
+            for neighbour in neighbours {
+                if let Some((book_name, _)) = neighbour.name.rsplit_once(' ') {
+                    prefetch_chapter_references(book_name, neighbour.chapter);
+                    // The next book is read before its first chapter is opened
+                    bible_signal.with_untracked(|bible| {
+                        if let Some(bible) = bible {
+                            if let Some(book) = bible.find_book(book_name) {
+                                bible.request_book(book);
+                            }
+                        }
+                    });
+                }
+            }
+        });
+    });
+
     // Create reactive chapter data - only update when bible translation changes, not on verse navigation
     let current_chapter_data = Memo::new(move |_| {
-        if let Some(bible) = bible_signal.get() {
-            // Try to get the equivalent chapter from the new Bible
-            let book_name = chapter_for_data
-                .name
-                .split_whitespace()
-                .take(chapter_for_data.name.split_whitespace().count() - 1)
-                .collect::<Vec<_>>()
-                .join(" ");
-            if let Ok(new_chapter) = bible.get_chapter(&book_name, chapter_for_data.chapter) {
-                new_chapter
+        bible_signal.with(|bible| {
+            if let Some(bible) = bible {
+                // Try to get the equivalent chapter from the new Bible
+                let book_name = chapter_for_data
+                    .name
+                    .split_whitespace()
+                    .take(chapter_for_data.name.split_whitespace().count() - 1)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if let Ok(new_chapter) = bible.get_chapter(&book_name, chapter_for_data.chapter) {
+                    new_chapter
+                } else {
+                    chapter_for_data.clone()
+                }
             } else {
                 chapter_for_data.clone()
             }
-        } else {
-            chapter_for_data.clone()
-        }
+        })
     });
 
+    // Only imported custom translations can be corrected in the editor
+    let editable_translation =
+        get_selected_translation().filter(|short_name| short_name.starts_with("custom_"));
+    let is_editable = editable_translation.is_some();
+    let (is_editing, set_is_editing) = signal(false);
+
     // Cache the chapter data to prevent unnecessary re-renders during verse navigation
     let stable_chapter_data = RwSignal::new(current_chapter_data.get_untracked());
 
@@ -205,29 +321,438 @@ pub fn ChapterDetail(
         let new_chapter = current_chapter_data.get();
         let current_stable = stable_chapter_data.get_untracked();
 
-        // Only update if the chapter book/number changed, not just verse highlighting
-        if new_chapter.name != current_stable.name || new_chapter.chapter != current_stable.chapter
+        // Only update if the chapter book/number changed, not just verse highlighting,
+        // or its verses came in with the rest of the book
+        if new_chapter.name != current_stable.name
+            || new_chapter.chapter != current_stable.chapter
+            || new_chapter.verses.len() != current_stable.verses.len()
         {
             stable_chapter_data.set(new_chapter);
         }
     });
 
+    // Position of the chapter's book, which verse edits and source text are keyed by
+    let book_index_of = move |chapter_name: &str| {
+        let find_book = |bible: &crate::core::Bible| {
+            bible
+                .books
+                .iter()
+                .position(|book| book.chapters.iter().any(|c| c.name == chapter_name))
+        };
+        bible_signal.with_untracked(|bible| bible.as_ref().and_then(find_book))
+    };
+
+    // Hebrew or Greek text of the chapter, when the translation was packaged with it
+    let chapter_source = RwSignal::new(HashMap::new());
+    Effect::new(move |_| {
+        let chapter_key = stable_chapter_data.with(|c| (c.name.clone(), c.chapter));
+        let (Some(short_name), Some(book_index)) =
+            (get_selected_translation(), book_index_of(&chapter_key.0))
+        else {
+            chapter_source.set(HashMap::new());
+            return;
+        };
+        spawn_local(async move {
+            let source_text = get_chapter_source_text(&short_name, book_index, chapter_key.1).await;
+            // Skip the result when the chapter changed while it was loading
+            let current = stable_chapter_data.try_with_untracked(|c| (c.name.clone(), c.chapter));
+            if current.as_ref() == Some(&chapter_key) {
+                chapter_source.set(chapter_source_text(&source_text, book_index, chapter_key.1));
+            }
+        });
+    });
+    let source_script = Memo::new(move |_| {
+        chapter_source.with(|source| {
+            source
+                .values()
+                .find(|verse| !verse.text.is_empty())
+                .map(|verse| SourceScript::detect(&verse.text))
+        })
+    });
+    // Chapter titles and section headings by verse, when the translation came with them
+    let chapter_headings = RwSignal::new((Vec::new(), HashMap::new()));
+    Effect::new(move |_| {
+        let chapter_key = stable_chapter_data.with(|c| (c.name.clone(), c.chapter));
+        let (Some(short_name), Some(book_index)) =
+            (get_selected_translation(), book_index_of(&chapter_key.0))
+        else {
+            chapter_headings.set((Vec::new(), HashMap::new()));
+            return;
+        };
+        spawn_local(async move {
+            let headings =
+                get_chapter_section_headings(&short_name, book_index, chapter_key.1).await;
+            // Skip the result when the chapter changed while it was loading
+            let current = stable_chapter_data.try_with_untracked(|c| (c.name.clone(), c.chapter));
+            if current.as_ref() == Some(&chapter_key) {
+                chapter_headings.set((
+                    chapter_titles(&headings, book_index, chapter_key.1),
+                    headings_by_verse(&headings, book_index, chapter_key.1),
+                ));
+            }
+        });
+    });
+    let has_headings = Memo::new(move |_| {
+        chapter_headings.with(|(titles, sections)| !titles.is_empty() || !sections.is_empty())
+    });
+    // Source text word whose parsing is shown
+    let selected_word = RwSignal::new(None::<SelectedWord>);
+    let has_transliteration = Memo::new(move |_| {
+        chapter_source.with(|source| source.values().any(|verse| verse.transliteration.is_some()))
+    });
+
+    // Store a corrected verse as an overlay and update the text in memory
+    let save_verse = move |verse_number: u32, text: String| {
+        let Some(short_name) = editable_translation.clone() else {
+            return;
+        };
+        let chapter_name = stable_chapter_data.with_untracked(|c| c.name.clone());
+        let chapter_number = stable_chapter_data.with_untracked(|c| c.chapter);
+        let Some(book_index) = book_index_of(&chapter_name) else {
+            leptos::logging::error!("Could not find book for {}", chapter_name);
+            return;
+        };
+
+        let edit = VerseEdit {
+            book_index,
+            chapter: chapter_number,
+            verse: verse_number,
+            text: text.clone(),
+        };
+        if let Err(e) = save_verse_edit(&short_name, edit) {
+            leptos::logging::error!("Failed to save verse edit: {}", e);
+            return;
+        }
+
+        stable_chapter_data.update(|chapter| {
+            if let Some(verse) = chapter.verses.iter_mut().find(|v| v.verse == verse_number) {
+                verse.text = text.clone();
+            }
+        });
+        bible_signal.update(|bible| {
+            if let Some(verse) = bible
+                .as_mut()
+                .and_then(|b| b.books.get_mut(book_index))
+                .and_then(|book| {
+                    std::sync::Arc::make_mut(book)
+                        .chapters
+                        .iter_mut()
+                        .find(|c| c.chapter == chapter_number)
+                })
+                .and_then(|chapter| chapter.verses.iter_mut().find(|v| v.verse == verse_number))
+            {
+                verse.text = text;
+            }
+        });
+    };
+
+    // Long-press a verse to select it, then drag to extend the selection.
+    // The selection goes through the `?verses=` URL, just like keyboard selection.
+    let navigate = use_navigate();
+    let navigate_to_person = navigate.clone();
+    let select_verses = move |anchor: u32, verse: u32| {
+        let range = VerseRange {
+            start: anchor.min(verse),
+            end: anchor.max(verse),
+        };
+        let path = stable_chapter_data.with_untracked(|c| c.to_path_with_verses(&[range]));
+        navigate(
+            &path,
+            NavigateOptions {
+                scroll: false,
+                ..Default::default()
+            },
+        );
+    };
+    // Bumped on every new touch so a pending long press can tell it was cancelled
+    let long_press_id = StoredValue::new(0u32);
+    let touch_start = StoredValue::new((0, 0));
+    let is_pressing = StoredValue::new(false);
+    // Anchor and current end of an active touch selection
+    let touch_selection = StoredValue::new(None::<(u32, u32)>);
+
+    let cancel_long_press = move || {
+        long_press_id.update_value(|id| *id += 1);
+        is_pressing.set_value(false);
+        touch_selection.set_value(None);
+    };
+
+    let select_verses_on_press = select_verses.clone();
+    let navigate_on_press = navigate_to_person.clone();
+    let handle_touch_start = move |ev: TouchEvent| {
+        cancel_long_press();
+        if ev.touches().length() != 1 {
+            return;
+        }
+        let Some((x, y)) = touch_point(&ev) else {
+            return;
+        };
+        let Some(verse) = verse_at_point(x, y) else {
+            return;
+        };
+
+        let id = long_press_id.get_value();
+        touch_start.set_value((x, y));
+        is_pressing.set_value(true);
+        let select_verses = select_verses_on_press.clone();
+        let navigate = navigate_on_press.clone();
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(LONG_PRESS_MS).await;
+            if long_press_id.try_get_value() != Some(id) {
+                return;
+            }
+            // Long-pressing a name opens that person, like right-clicking it does
+            if let Some(person) = person_at_point(x, y) {
+                cancel_long_press();
+                navigate(&person_path(person), Default::default());
+                return;
+            }
+            touch_selection.set_value(Some((verse, verse)));
+            select_verses(verse, verse);
+        });
+    };
+
+    let handle_touch_move = move |ev: TouchEvent| {
+        let Some((x, y)) = touch_point(&ev) else {
+            return;
+        };
+        match touch_selection.get_value() {
+            Some((anchor, end)) => {
+                // Dragging extends the selection instead of scrolling
+                ev.prevent_default();
+                if let Some(verse) = verse_at_point(x, y).filter(|verse| *verse != end) {
+                    touch_selection.set_value(Some((anchor, verse)));
+                    select_verses(anchor, verse);
+                }
+            }
+            None => {
+                let (start_x, start_y) = touch_start.get_value();
+                if (x - start_x).abs() > LONG_PRESS_MOVE_TOLERANCE
+                    || (y - start_y).abs() > LONG_PRESS_MOVE_TOLERANCE
+                {
+                    cancel_long_press();
+                }
+            }
+        }
+    };
+
+    // Columns only make sense when there's room for them
+    let is_wide = wide_screen_signal();
+    let columns = Memo::new(move |_| {
+        if is_wide.get() {
+            text_columns.get().max(1)
+        } else {
+            1
+        }
+    });
+
+    let export_translation = move || {
+        spawn_local(async move {
+            load_all_books().await;
+            let Some(bible) = bible_signal.get_untracked() else {
+                return;
+            };
+            match export_bible_to_json(&bible) {
+                Ok(json) => {
+                    let name = get_current_translation()
+                        .map(|t| t.name)
+                        .unwrap_or_else(|| "translation".to_string());
+                    trigger_json_download(json, &format!("{}.json", name.replace(' ', "_")));
+                }
+                Err(e) => leptos::logging::error!("{}", e),
+            }
+        });
+    };
+
     view! {
-        <article class="chapter-detail max-w-2xl mx-auto px-4 pb-32">
-            <header class="mb-8">
+        <article class=move || {
+            if columns.get() > 1 {
+                "chapter-detail max-w-6xl mx-auto px-4 pb-32"
+            } else {
+                "chapter-detail max-w-2xl mx-auto px-4 pb-32"
+            }
+        }>
+            <header class="mb-8 flex items-center justify-between gap-4">
                 <h1 id="chapter-heading" class="text-3xl font-bold" style="color: var(--theme-text-primary)" tabindex="-1">{move || stable_chapter_data.get().name.clone()}</h1>
+                <Show when=move || source_script.get().is_some() fallback=|| view! { <></> }>
+                    <button
+                        class="px-3 py-1 text-sm rounded border transition-colors"
+                        style="color: var(--theme-text-primary); border-color: var(--theme-sidebar-border)"
+                        aria-pressed=move || (source_text_mode.get() != SourceTextMode::Hidden).to_string()
+                        on:click=move |_| view_state.update(|state| { state.execute(&Instruction::ToggleSourceText); })
+                    >
+                        {move || match source_text_mode.get() {
+                            SourceTextMode::Hidden => t("source_text_hidden"),
+                            SourceTextMode::UnderVerses => t("source_text_under_verses"),
+                            SourceTextMode::Replacing => t("source_text_replacing"),
+                        }}
+                    </button>
+                </Show>
+                <Show when=move || has_transliteration.get() fallback=|| view! { <></> }>
+                    <button
+                        class="px-3 py-1 text-sm rounded border transition-colors"
+                        style=move || if show_transliteration.get() {
+                            "background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text); border-color: var(--theme-button-primary-background)"
+                        } else {
+                            "color: var(--theme-text-primary); border-color: var(--theme-sidebar-border)"
+                        }
+                        aria-pressed=move || show_transliteration.get().to_string()
+                        on:click=move |_| view_state.update(|state| { state.execute(&Instruction::ToggleTransliteration); })
+                    >
+                        {t("transliteration")}
+                    </button>
+                </Show>
+                <Show when=move || has_headings.get() fallback=|| view! { <></> }>
+                    <button
+                        class="px-3 py-1 text-sm rounded border transition-colors"
+                        style=move || if show_section_headings.get() {
+                            "background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text); border-color: var(--theme-button-primary-background)"
+                        } else {
+                            "color: var(--theme-text-primary); border-color: var(--theme-sidebar-border)"
+                        }
+                        aria-pressed=move || show_section_headings.get().to_string()
+                        on:click=move |_| view_state.update(|state| { state.execute(&Instruction::ToggleSectionHeadings); })
+                    >
+                        {t("section_headings")}
+                    </button>
+                </Show>
+                <Show when=is_read_aloud_supported fallback=|| view! { <></> }>
+                    <button
+                        class="px-3 py-1 text-sm rounded border transition-colors"
+                        style="color: var(--theme-text-primary); border-color: var(--theme-sidebar-border)"
+                        aria-pressed=move || view_state.with(|state| state.read_aloud == ReadAloudState::Playing).to_string()
+                        on:click=move |_| view_state.update(|state| { state.execute(&Instruction::ToggleReadAloud); })
+                    >
+                        {t("read_aloud")}
+                    </button>
+                </Show>
+                <Show when=move || is_editable fallback=|| view! { <></> }>
+                    <div class="flex items-center gap-2 text-sm">
+                        <Show when=move || is_editing.get() fallback=|| view! { <></> }>
+                            <button
+                                class="px-3 py-1 rounded border transition-colors"
+                                style="color: var(--theme-text-primary); border-color: var(--theme-sidebar-border)"
+                                on:click=move |_| export_translation()
+                            >
+                                {t("export_json")}
+                            </button>
+                        </Show>
+                        <button
+                            class="px-3 py-1 rounded transition-colors"
+                            style="background-color: var(--theme-button-primary-background); color: var(--theme-button-primary-text)"
+                            on:click=move |_| set_is_editing.update(|editing| *editing = !*editing)
+                        >
+                            {move || if is_editing.get() { t("done") } else { t("edit") }}
+                        </button>
+                    </div>
+                </Show>
             </header>
 
-            <div class="verses text-lg leading-8" style="color: var(--theme-text-primary)" role="main" aria-label="Chapter text">
+            {move || {
+                if !show_section_headings.get() {
+                    return None;
+                }
+                let titles = chapter_headings.with(|(titles, _)| titles.clone());
+                (!titles.is_empty()).then(|| view! {
+                    <div class="chapter-title">
+                        {titles.into_iter().map(|title| view! { <p><ReferenceText text=title/></p> }).collect_view()}
+                    </div>
+                })
+            }}
+
+            <div
+                class="verses text-lg leading-8"
+                style="color: var(--theme-text-primary); -webkit-touch-callout: none"
+                role="main"
+                aria-label=t("chapter_text")
+                dir=move || match (source_text_mode.get(), source_script.get()) {
+                    (SourceTextMode::Replacing, Some(script)) => script.dir(),
+                    _ => "ltr",
+                }
+                on:touchstart=handle_touch_start
+                on:touchmove=handle_touch_move
+                on:touchend=move |_| cancel_long_press()
+                on:touchcancel=move |_| cancel_long_press()
+                on:contextmenu={
+                    let navigate = navigate_to_person.clone();
+                    move |ev: web_sys::MouseEvent| {
+                        // Keep the browser's long-press menu from covering the selection
+                        if is_pressing.get_value() {
+                            ev.prevent_default();
+                            return;
+                        }
+                        // Right-clicking a name opens that person in the people index
+                        if let Some(person) = person_at_point(ev.client_x(), ev.client_y()) {
+                            ev.prevent_default();
+                            navigate(&person_path(person), Default::default());
+                        }
+                    }
+                }
+            >
                 {move || {
+                    if is_editing.get() {
+                        let chapter_data = stable_chapter_data.get_untracked();
+                        return chapter_data
+                            .verses
+                            .iter()
+                            .map(|verse| {
+                                let verse_number = verse.verse;
+                                let save_verse = save_verse.clone();
+                                view! {
+                                    <label class="flex gap-2 mb-2">
+                                        <span class="text-xs mt-2" style="color: var(--theme-verse-number)">{verse_number}</span>
+                                        <textarea
+                                            class="flex-1 px-2 py-1 border rounded-md text-base"
+                                            style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                                            rows="2"
+                                            prop:value=verse.text.clone()
+                                            on:change=move |ev| save_verse(verse_number, event_target_value(&ev))
+                                        ></textarea>
+                                    </label>
+                                }
+                            })
+                            .collect_view()
+                            .into_any();
+                    }
+
                     let chapter_data = stable_chapter_data.get();
-                    let verses = &chapter_data.verses;
+                    let annotation_key = bible_signal.with_untracked(|bible| match bible {
+                        Some(bible) => bible.chapter_key(&chapter_data),
+                        None => ChapterKey::for_book_name(&chapter_data.book_name(), chapter_data.chapter),
+                    });
+                    let highlight_colors: HashMap<u32, HighlightColor> = highlights.with(|highlights| {
+                        highlights
+                            .iter()
+                            .filter(|highlight| highlight.is_in(&annotation_key))
+                            .map(|highlight| (highlight.verse, highlight.color))
+                            .collect()
+                    });
+                    let show_only_highlighted = show_only_highlighted.get();
+                    let verses: Vec<_> = chapter_data
+                        .verses
+                        .iter()
+                        .filter(|verse| !show_only_highlighted || highlight_colors.contains_key(&verse.verse))
+                        .collect();
+                    if verses.is_empty() && show_only_highlighted {
+                        return view! {
+                            <p class="text-base" style="color: var(--theme-text-muted)">{t("no_highlighted_verses")}</p>
+                        }
+                        .into_any();
+                    }
                     let verse_ranges = highlighted_verses.get(); // Single reactive read
+                    let source_text_mode = source_text_mode.get();
+                    let chapter_source = chapter_source.get();
+                    let show_transliteration = show_transliteration.get();
+                    let section_headings = if show_section_headings.get() {
+                        chapter_headings.with(|(_, sections)| sections.clone())
+                    } else {
+                        HashMap::new()
+                    };
 
                     // Pre-allocate vector with exact capacity for better memory efficiency
                     let mut verse_views = Vec::with_capacity(verses.len());
 
-                    for verse in verses {
+                    for verse in &verses {
                         let is_highlighted = verse_ranges.iter().any(|range| range.contains(verse.verse));
 
                         // Use theme colors via CSS custom properties
@@ -243,25 +768,100 @@ pub fn ChapterDetail(
                             "color: var(--theme-verse-number)"
                         };
 
+                        let highlight_color = highlight_colors.get(&verse.verse);
                         let verse_text_class = if is_highlighted {
                             "font-bold px-1 rounded"
+                        } else if highlight_color.is_some() {
+                            "rounded"
                         } else {
                             ""
                         };
 
-                        let verse_text_style = if is_highlighted {
-                            "color: var(--theme-verse-text-highlighted); background-color: var(--theme-verse-background-highlighted)"
-                        } else {
-                            "color: var(--theme-text-primary)"
+                        // The selection stands out over a highlight color
+                        let verse_text_style = match highlight_color {
+                            _ if is_highlighted => "color: var(--theme-verse-text-highlighted); background-color: var(--theme-verse-background-highlighted)".to_string(),
+                            Some(color) => format!("color: var(--theme-text-primary); background-color: {}", color.css_background()),
+                            None => "color: var(--theme-text-primary)".to_string(),
                         };
 
                         let tabindex = if is_highlighted { "0" } else { "-1" };
-                        // Clone verse text for view (required by Leptos)
-                        let verse_text = verse.text.clone();
                         let verse_number = verse.verse;
+                        let source = chapter_source
+                            .get(&verse_number)
+                            .filter(|source| !source.text.is_empty())
+                            .map(|source| (SourceScript::detect(&source.text), source.text.clone(), source.words.clone()));
+                        let transliteration = chapter_source
+                            .get(&verse_number)
+                            .and_then(|source| source.transliteration.clone())
+                            .filter(|_| show_transliteration);
+                        // Words with morphology can be clicked for their parsing
+                        let source_view = move |script: SourceScript, text: String, words: Vec<SourceWord>| {
+                            if words.is_empty() {
+                                text.into_any()
+                            } else {
+                                source_words_view(words, script, selected_word).into_any()
+                            }
+                        };
+                        // Clone verse text for view (required by Leptos)
+                        let (verse_text, verse_script) = match (source_text_mode, &source) {
+                            (SourceTextMode::Replacing, Some((script, text, words))) => {
+                                (source_view(*script, text.clone(), words.clone()), Some(*script))
+                            }
+                            _ => (verse.text.clone().into_any(), None),
+                        };
+                        let source_under_verse = source
+                            .filter(|_| source_text_mode == SourceTextMode::UnderVerses)
+                            .map(|(script, text, words)| view! {
+                                <span
+                                    class=format!("block {} {}", if transliteration.is_some() { "" } else { "mb-3" }, script.css_class())
+                                    style="color: var(--theme-text-secondary)"
+                                    lang=script.lang()
+                                    dir=script.dir()
+                                >
+                                    {source_view(script, text, words)}
+                                </span>
+                            });
+                        let transliteration_under_verse = transliteration.map(|transliteration| view! {
+                            <span class="block mb-3 text-base italic" style="color: var(--theme-text-muted)">
+                                {transliteration}
+                            </span>
+                        });
+                        // The marker opens the note under the verse, where its references are links
+                        let note_marker = notes
+                            .with(|notes| find_note(notes, &annotation_key, verse_number).map(|note| note.text.clone()))
+                            .map(|note| {
+                                let is_note_open = RwSignal::new(false);
+                                view! {
+                                    <sup>
+                                        <button
+                                            class="ml-0.5 cursor-help"
+                                            style="color: var(--theme-verse-number)"
+                                            title=note.clone()
+                                            aria-label=t("note")
+                                            aria-expanded=move || is_note_open.get().to_string()
+                                            on:click=move |_| is_note_open.update(|open| *open = !*open)
+                                        >
+                                            "✎"
+                                        </button>
+                                    </sup>
+                                    <Show when=move || is_note_open.get() fallback=|| view! { <></> }>
+                                        <span class="block my-1 text-sm" style="color: var(--theme-text-secondary)">
+                                            <ReferenceText text=note.clone()/>
+                                        </span>
+                                    </Show>
+                                }
+                            });
+
+                        let heading_views = section_headings.get(&verse_number).map(|titles| {
+                            titles
+                                .iter()
+                                .map(|title| view! { <h2 class="section-heading"><ReferenceText text=title.clone()/></h2> })
+                                .collect_view()
+                        });
 
                         verse_views.push(view! {
                             <>
+                                {heading_views}
                                 <Show
                                     when=move || verse_visibility_enabled.get() && verse_number != 1
                                     fallback=|| view! { <></> }
@@ -275,25 +875,61 @@ pub fn ChapterDetail(
                                     </span>
                                 </Show>
                                 <span
-                                    class=verse_text_class
+                                    class=match verse_script {
+                                        Some(script) => format!("{} {}", verse_text_class, script.css_class()),
+                                        None => verse_text_class.to_string(),
+                                    }
                                     style=verse_text_style
                                     id=format!("verse-{}", verse_number)
                                     tabindex=tabindex
+                                    lang=verse_script.map(|script| script.lang())
                                 >
                                     {verse_text}
                                 </span>
+                                {note_marker}
+                                {source_under_verse}
+                                {transliteration_under_verse}
                             </>
                         });
                     }
 
-                    verse_views
+                    let columns = columns.get();
+                    if columns <= 1 {
+                        return verse_views.into_any();
+                    }
+
+                    // Each column scrolls on its own, so a long chapter doesn't have to be read
+                    // down one column and back up to the top of the next
+                    let verse_lengths: Vec<usize> = verses.iter().map(|verse| verse.text.len()).collect();
+                    let mut verse_views = verse_views.into_iter();
+                    let column_views = split_into_columns(&verse_lengths, columns as usize)
+                        .into_iter()
+                        .map(|range| {
+                            let column: Vec<_> = verse_views.by_ref().take(range.len()).collect();
+                            view! {
+                                <div class="overflow-y-auto pr-2" style="max-height: calc(100vh - 12rem)">
+                                    {column}
+                                </div>
+                            }
+                        })
+                        .collect_view();
+
+                    view! {
+                        <div
+                            class="grid gap-8"
+                            style=format!("grid-template-columns: repeat({}, minmax(0, 1fr))", columns)
+                        >
+                            {column_views}
+                        </div>
+                    }
+                    .into_any()
                 }}
             </div>
 
-            <nav class="flex justify-between items-center mt-8 pt-6 border-t" style="border-color: var(--theme-sidebar-border)" role="navigation" aria-label="Chapter navigation">
+            <nav class="flex justify-between items-center mt-8 pt-6 border-t" style="border-color: var(--theme-sidebar-border)" role="navigation" aria-label=t("chapter_navigation")>
                 {move || if let Some(path) = prev_path.get() {
                     view! {
-                        <A href=path attr:class="p-4 rounded-md transition-colors group navigation-button" attr:style="color: var(--theme-navigation-text); display: flex; align-items: center; justify-content: center;" attr:aria-label={get_navigation_text("previous_chapter")} attr:title={get_navigation_text("previous_chapter")}>
+                        <A href=path attr:class="p-4 rounded-md transition-colors group navigation-button" attr:style="color: var(--theme-navigation-text); display: flex; align-items: center; justify-content: center;" attr:aria-label={t("previous_chapter")} attr:title={t("previous_chapter")}>
                             <svg class="w-8 h-8 group-hover:transform group-hover:-translate-x-1 transition-transform" fill="none" stroke="currentColor" stroke-width="3" viewBox="0 0 24 24" aria-hidden="true" style="min-width: 32px; min-height: 32px;">
                                 <path stroke-linecap="round" stroke-linejoin="round" d="M15 19l-7-7 7-7"></path>
                             </svg>
@@ -305,7 +941,7 @@ pub fn ChapterDetail(
 
                 {move || if let Some(path) = next_path.get() {
                     view! {
-                        <A href=path attr:class="p-4 rounded-md transition-colors group navigation-button" attr:style="color: var(--theme-navigation-text); display: flex; align-items: center; justify-content: center;" attr:aria-label={get_navigation_text("next_chapter")} attr:title={get_navigation_text("next_chapter")}>
+                        <A href=path attr:class="p-4 rounded-md transition-colors group navigation-button" attr:style="color: var(--theme-navigation-text); display: flex; align-items: center; justify-content: center;" attr:aria-label={t("next_chapter")} attr:title={t("next_chapter")}>
                             <svg class="w-8 h-8 group-hover:transform group-hover:translate-x-1 transition-transform" fill="none" stroke="currentColor" stroke-width="3" viewBox="0 0 24 24" aria-hidden="true" style="min-width: 32px; min-height: 32px;">
                                 <path stroke-linecap="round" stroke-linejoin="round" d="M9 5l7 7-7 7"></path>
                             </svg>
@@ -315,6 +951,36 @@ pub fn ChapterDetail(
                     view! { <div class="invisible"></div> }.into_any()
                 }}
             </nav>
+
+            <WordPopover selected_word=selected_word view_state=view_state />
+            <ReadAloud chapter=chapter.clone() selected_verses=highlighted_verses view_state=view_state />
         </article>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf16_to_byte_index() {
+        let text = "Toen zei Ezechiël tot Petrus:";
+        assert_eq!(utf16_to_byte_index(text, 0), 0);
+        assert_eq!(utf16_to_byte_index(text, 9), 9);
+        // "ë" is one UTF-16 unit but two bytes
+        assert_eq!(utf16_to_byte_index(text, 22), 23);
+        assert_eq!(utf16_to_byte_index(text, 100), text.len());
+    }
+
+    #[test]
+    fn test_split_into_columns() {
+        assert_eq!(split_into_columns(&[10; 6], 1), vec![0..6]);
+        assert_eq!(split_into_columns(&[10; 6], 3), vec![0..2, 2..4, 4..6]);
+        // A long first verse fills most of the first column on its own
+        assert_eq!(split_into_columns(&[50, 10, 10, 10, 10, 10], 2), vec![0..1, 1..6]);
+        // Fewer verses than columns leaves the remaining columns empty
+        assert_eq!(split_into_columns(&[10], 3), vec![0..1, 1..1, 1..1]);
+        assert_eq!(split_into_columns(&[10, 10], 3), vec![0..1, 1..2, 2..2]);
+        assert_eq!(split_into_columns(&[], 2), vec![0..0, 0..0]);
+    }
+}
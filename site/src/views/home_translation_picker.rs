@@ -1,5 +1,6 @@
 use crate::components::{theme_switcher::ThemeSwitcher, CustomTranslationImport};
 use crate::core::types::Language;
+use crate::i18n::{t, t_with};
 use crate::storage::{
     download_translation_with_progress, get_available_languages, get_selected_translation,
     get_translations_by_language, is_translation_downloaded, set_selected_translation,
@@ -73,7 +74,7 @@ fn TranslationItem(
                                         {translation_name.clone()}
                                     </h3>
                                     <p class="text-sm" style="color: var(--theme-text-secondary)">
-                                        "Uitgegeven in " {translation_release_year.to_string()}
+                                        {t_with("released_in", &[("year", &translation_release_year.to_string())])}
                                     </p>
                                 </div>
                                 <div class="ml-6 flex items-center">
@@ -130,14 +131,14 @@ fn TranslationItem(
                                             let translation_short_name_clone2 = translation_short_name_ref.clone();
                                             move || {
                                             if is_switching.get() && selected_translation.get() == translation_short_name_clone2 {
-                                                " (laden...)"
+                                                t("loading_suffix")
                                             } else {
-                                                ""
+                                                String::new()
                                             }
                                         }}
                                     </h3>
                                     <p class="text-sm" style="color: var(--theme-text-secondary)">
-                                        "Uitgegeven in " {translation_release_year.to_string()}
+                                        {t_with("released_in", &[("year", &translation_release_year.to_string())])}
                                     </p>
                                 </div>
                                 <div class="ml-6">
@@ -186,18 +187,18 @@ fn TranslationItem(
                                                                         <circle class="opacity-25" cx="12" cy="12" r="10" stroke="currentColor" stroke-width="4"></circle>
                                                                         <path class="opacity-75" fill="currentColor" d="m12 2a10 10 0 0 1 10 10h-2a8 8 0 0 0-8-8v-2z"></path>
                                                                     </svg>
-                                                                    <span>"Verwijderen..."</span>
+                                                                    <span>{t("removing")}</span>
                                                                 </div>
-                                                            }
+                                                            }.into_any()
                                                         } else {
                                                             view! {
                                                                 <div class="flex items-center gap-1">
                                                                     <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                                                                         <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16"></path>
                                                                     </svg>
-                                                                    <span>"Verwijderen"</span>
+                                                                    <span>{t("remove")}</span>
                                                                 </div>
-                                                            }
+                                                            }.into_any()
                                                         }
                                                     }}
                                                 </button>
@@ -256,7 +257,7 @@ fn TranslationItem(
                                                 navigate_clone2();
                                             }
                                             Err(e) => {
-                                                set_download_error.set(Some(format!("Download mislukt: {}", e)));
+                                                set_download_error.set(Some(t_with("download_failed", &[("error", &e.to_string())])));
                                                 set_downloading_translation.set(None);
                                                 set_download_progress.set(0.0);
                                             }
@@ -271,12 +272,12 @@ fn TranslationItem(
                                         {translation_name.clone()}
                                     </h3>
                                     <p class="text-sm" style="color: var(--theme-text-secondary)">
-                                        "Uitgegeven in " {translation_release_year.to_string()}
+                                        {t_with("released_in", &[("year", &translation_release_year.to_string())])}
                                     </p>
                                 </div>
                                 <div class="ml-6">
                                     <div class="px-3 py-1 rounded text-sm font-medium translation-button-success">
-                                        "Download"
+                                        {t("download")}
                                     </div>
                                 </div>
                             </div>
@@ -421,11 +422,11 @@ pub fn HomeTranslationPicker(
                 <ThemeSwitcher current_theme=current_theme set_current_theme=set_current_theme />
             </div>
             <div class="text-center mb-8">
-                <h1 class="text-4xl font-bold mb-4" style="color: var(--theme-text-primary)">"Bijbel"</h1>
+                <h1 class="text-4xl font-bold mb-4" style="color: var(--theme-text-primary)">{t("bible_title")}</h1>
                 <p class="text-lg mb-8" style="color: var(--theme-text-secondary)">
                     {move || match view_state.get() {
-                        ViewState::LanguageSelection => "Kies een taal om te beginnen",
-                        ViewState::TranslationSelection(_) => "Kies een vertaling om te beginnen met lezen",
+                        ViewState::LanguageSelection => t("choose_language_to_start"),
+                        ViewState::TranslationSelection(_) => t("choose_translation_to_start"),
                     }}
                 </p>
             </div>
@@ -469,7 +470,7 @@ pub fn HomeTranslationPicker(
                         view! {
                             <div class="mb-6">
                                 <h2 class="text-2xl font-semibold" style="color: var(--theme-text-primary)">
-                                    {selected_language_name} " vertalingen"
+                                    {t_with("language_translations", &[("language", &selected_language_name)])}
                                 </h2>
                             </div>
                             <div class="space-y-4">
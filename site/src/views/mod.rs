@@ -1,7 +1,11 @@
 pub mod about;
 pub mod chapter_view;
 pub mod home_translation_picker;
+pub mod people;
+pub mod timeline;
 
 pub use about::*;
 pub use chapter_view::*;
 pub use home_translation_picker::*;
+pub use people::*;
+pub use timeline::*;
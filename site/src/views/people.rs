@@ -0,0 +1,107 @@
+use crate::api::load_all_books;
+use crate::core::{find_person, init_bible_signal, people, person_verses, Person, VerseRange};
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+use urlencoding::encode;
+
+/// Link to a person in the people index
+pub fn person_path(person: &Person) -> String {
+    format!("/people?name={}", encode(&person.name))
+}
+
+/// Verses naming a person, as (reference, link, text)
+fn person_appearances(person: &Person) -> Vec<(String, String, String)> {
+    let appearances = |bible: &crate::core::Bible| {
+        person_verses(bible, person)
+            .into_iter()
+            .map(|(chapter, verse)| {
+                let path = chapter.to_path_with_verses(&[VerseRange {
+                    start: verse.verse,
+                    end: verse.verse,
+                }]);
+                (
+                    format!("{}:{}", chapter.name, verse.verse),
+                    path,
+                    verse.text.clone(),
+                )
+            })
+            .collect()
+    };
+    init_bible_signal()
+        .with(|bible| bible.as_ref().map(appearances))
+        .unwrap_or_default()
+}
+
+/// Index of biblical people and the verses naming them
+#[component]
+pub fn People() -> impl IntoView {
+    let query = use_query_map();
+    let selected = Memo::new(move |_| {
+        query
+            .with(|query| query.get("name"))
+            .and_then(|name| find_person(&name))
+    });
+    let (filter, set_filter) = signal(String::new());
+    // Appearances are found in every book
+    leptos::task::spawn_local(load_all_books());
+
+    let mut sorted_people: Vec<&'static Person> = people().iter().collect();
+    sorted_people.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let person_detail = move || {
+        let person = selected.get()?;
+        let appearances = person_appearances(person);
+        Some(view! {
+            <section class="mb-8">
+                <h2 class="text-xl font-semibold mb-1">{person.name.clone()}</h2>
+                <p class="text-sm mb-1" style="color: var(--theme-text-secondary)">{person.description.clone()}</p>
+                <p class="text-xs mb-4" style="color: var(--theme-text-muted)">
+                    {format!("Named in {} verses", appearances.len())}
+                </p>
+                <ul class="space-y-2 text-sm">
+                    {appearances.into_iter().map(|(reference, path, text)| view! {
+                        <li>
+                            <A href=path attr:class="font-medium hover:underline translation-link">{reference}</A>
+                            <span class="ml-2" style="color: var(--theme-text-secondary)">{text}</span>
+                        </li>
+                    }).collect_view()}
+                </ul>
+            </section>
+        })
+    };
+
+    view! {
+        <article class="max-w-2xl mx-auto px-4 py-12" style="color: var(--theme-text-primary)">
+            <h1 class="text-2xl font-bold mb-8">"People"</h1>
+            {person_detail}
+            <input
+                type="search"
+                class="w-full mb-4 px-2 py-1 text-sm border rounded"
+                style="background-color: var(--theme-background); border-color: var(--theme-sidebar-border); color: var(--theme-text-primary)"
+                placeholder="Filter people"
+                aria-label="Filter people"
+                prop:value=move || filter.get()
+                on:input=move |ev| set_filter.set(event_target_value(&ev))
+            />
+            <ul class="grid grid-cols-2 md:grid-cols-3 gap-2 text-sm">
+                {sorted_people.into_iter().map(|person| {
+                    let is_shown = move || {
+                        let filter = filter.get().to_lowercase();
+                        filter.is_empty()
+                            || std::iter::once(&person.name)
+                                .chain(&person.aliases)
+                                .any(|name| name.to_lowercase().contains(&filter))
+                    };
+                    view! {
+                        <li class:hidden=move || !is_shown()>
+                            <A href=person_path(person) attr:class="hover:underline translation-link" attr:title=person.description.clone()>
+                                {person.name.clone()}
+                            </A>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        </article>
+    }
+}
@@ -0,0 +1,182 @@
+use crate::components::reference_links;
+use crate::core::{format_year, timeline, timeline_rows, TimelineEntry, TimelineKind};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Pixels per year at each zoom level
+const ZOOM_LEVELS: [f64; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+const DEFAULT_ZOOM: usize = 1;
+/// Width kept free for an entry's title
+const LABEL_WIDTH: f64 = 150.0;
+const ROW_HEIGHT: f64 = 44.0;
+/// Padding before the first and after the last year
+const TIMELINE_PADDING_YEARS: i32 = 50;
+
+/// Years between axis ticks, so ticks are at least 80 pixels apart
+fn tick_interval(pixels_per_year: f64) -> i32 {
+    [50, 100, 250, 500, 1000]
+        .into_iter()
+        .find(|years| f64::from(*years) * pixels_per_year >= 80.0)
+        .unwrap_or(1000)
+}
+
+fn period_label(entry: &TimelineEntry) -> String {
+    match entry.end {
+        Some(end) => format!("c. {} – {}", format_year(entry.start), format_year(end)),
+        None => format!("c. {}", format_year(entry.start)),
+    }
+}
+
+/// One lane of the timeline, with the entries of `kind` spread over rows
+fn timeline_lane(kind: TimelineKind, first_year: i32, pixels_per_year: f64) -> (f64, Vec<AnyView>) {
+    let mut entries: Vec<&TimelineEntry> = timeline().iter().filter(|e| e.kind == kind).collect();
+    entries.sort_by_key(|entry| entry.start);
+    let rows = timeline_rows(&entries, LABEL_WIDTH / pixels_per_year);
+    let height = (rows.iter().max().map_or(0, |row| row + 1)) as f64 * ROW_HEIGHT;
+
+    let views = entries
+        .into_iter()
+        .zip(rows)
+        .map(|(entry, row)| {
+            let left = f64::from(entry.start - first_year) * pixels_per_year;
+            let bar_width = (f64::from(entry.end_year() - entry.start) * pixels_per_year).max(8.0);
+            let style = format!(
+                "left: {}px; top: {}px; width: {}px",
+                left,
+                row as f64 * ROW_HEIGHT,
+                bar_width.max(LABEL_WIDTH)
+            );
+            let period = period_label(entry);
+            let title = format!("{}\n{} ({})", entry.title, entry.description, entry.passage);
+            let body = view! {
+                <span
+                    class="block h-2 rounded-full"
+                    style=format!("width: {}px; background-color: var(--theme-text-secondary)", bar_width)
+                ></span>
+                <span class="block truncate text-xs font-medium mt-1">{entry.title.clone()}</span>
+                <span class="block truncate text-xs" style="color: var(--theme-text-muted)">{period.clone()}</span>
+            };
+            let label = format!("{}, {}", entry.title, period);
+            match reference_links(&entry.passage).into_iter().next() {
+                Some((_, path)) => view! {
+                    <A
+                        href=path
+                        attr:class="absolute block hover:underline focus:outline-none focus:ring-2 rounded"
+                        attr:style=style
+                        attr:title=title
+                        attr:aria-label=label
+                    >
+                        {body}
+                    </A>
+                }
+                .into_any(),
+                None => view! {
+                    <div class="absolute" style=style title=title aria-label=label>{body}</div>
+                }
+                .into_any(),
+            }
+        })
+        .collect();
+    (height, views)
+}
+
+/// Horizontal timeline of major biblical events and the writing of the books
+#[component]
+pub fn Timeline() -> impl IntoView {
+    let (zoom, set_zoom) = signal(DEFAULT_ZOOM);
+    let zoom_in = move || set_zoom.update(|z| *z = (*z + 1).min(ZOOM_LEVELS.len() - 1));
+    let zoom_out = move || set_zoom.update(|z| *z = z.saturating_sub(1));
+
+    let first_year = timeline().iter().map(|e| e.start).min().unwrap_or(0) - TIMELINE_PADDING_YEARS;
+    let last_year =
+        timeline().iter().map(|e| e.end_year()).max().unwrap_or(0) + TIMELINE_PADDING_YEARS;
+
+    let content = move || {
+        let pixels_per_year = ZOOM_LEVELS[zoom.get()];
+        let width = f64::from(last_year - first_year) * pixels_per_year + LABEL_WIDTH;
+        let interval = tick_interval(pixels_per_year);
+        let first_tick = first_year.div_euclid(interval) * interval + interval;
+        let ticks = (first_tick..=last_year)
+            .step_by(interval as usize)
+            .map(|year| {
+                let left = f64::from(year - first_year) * pixels_per_year;
+                view! {
+                    <span
+                        class="absolute top-0 h-full border-l pl-1 text-xs"
+                        style=format!("left: {}px; border-color: var(--theme-sidebar-border); color: var(--theme-text-muted)", left)
+                    >
+                        {format_year(year)}
+                    </span>
+                }
+            })
+            .collect_view();
+        let (events_height, events) =
+            timeline_lane(TimelineKind::Event, first_year, pixels_per_year);
+        let (books_height, books) = timeline_lane(TimelineKind::Book, first_year, pixels_per_year);
+
+        view! {
+            <div class="relative" style=format!("width: {}px", width)>
+                <div class="relative h-6 mb-2">{ticks}</div>
+                <h2 class="sticky left-0 inline-block text-sm font-semibold mb-2">"Events"</h2>
+                <div class="relative mb-6" style=format!("height: {}px", events_height)>{events}</div>
+                <h2 class="sticky left-0 inline-block text-sm font-semibold mb-2">"Books"</h2>
+                <div class="relative" style=format!("height: {}px", books_height)>{books}</div>
+            </div>
+        }
+    };
+
+    view! {
+        <article class="px-4 py-12" style="color: var(--theme-text-primary)">
+            <div class="max-w-2xl mx-auto flex items-center justify-between mb-8">
+                <h1 class="text-2xl font-bold">"Timeline"</h1>
+                <div class="flex gap-2 text-sm">
+                    <button
+                        class="px-2 py-1 border rounded"
+                        style="border-color: var(--theme-sidebar-border)"
+                        aria-label="Zoom out"
+                        disabled=move || zoom.get() == 0
+                        on:click=move |_| zoom_out()
+                    >
+                        "−"
+                    </button>
+                    <button
+                        class="px-2 py-1 border rounded"
+                        style="border-color: var(--theme-sidebar-border)"
+                        aria-label="Zoom in"
+                        disabled=move || zoom.get() == ZOOM_LEVELS.len() - 1
+                        on:click=move |_| zoom_in()
+                    >
+                        "+"
+                    </button>
+                </div>
+            </div>
+            <p class="max-w-2xl mx-auto text-xs mb-4" style="color: var(--theme-text-muted)">
+                "Dates are traditional and approximate. Select an entry to read its passage."
+            </p>
+            <div
+                class="overflow-x-auto pb-4"
+                tabindex="0"
+                aria-label="Timeline, use + and - to zoom"
+                on:keydown=move |ev| match ev.key().as_str() {
+                    "+" | "=" => zoom_in(),
+                    "-" => zoom_out(),
+                    _ => {}
+                }
+            >
+                {content}
+            </div>
+        </article>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_interval() {
+        assert_eq!(tick_interval(0.25), 500);
+        assert_eq!(tick_interval(1.0), 100);
+        assert_eq!(tick_interval(4.0), 50);
+    }
+}